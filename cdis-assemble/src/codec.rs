@@ -329,11 +329,11 @@ pub fn encode(
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::IntercomSignal => (
+            PduBody::IntercomSignal(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::IntercomControl => (
+            PduBody::IntercomControl(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
@@ -353,59 +353,59 @@ pub fn encode(
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::MinefieldState => (
+            PduBody::MinefieldState(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::MinefieldQuery => (
+            PduBody::MinefieldQuery(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::MinefieldData => (
+            PduBody::MinefieldData(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::MinefieldResponseNACK => (
+            PduBody::MinefieldResponseNACK(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::EnvironmentalProcess => (
+            PduBody::EnvironmentalProcess(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::GriddedData => (
+            PduBody::GriddedData(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::PointObjectState => (
+            PduBody::PointObjectState(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::LinearObjectState => (
+            PduBody::LinearObjectState(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::ArealObjectState => (
+            PduBody::ArealObjectState(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::TSPI => (
+            PduBody::TSPI(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::Appearance => (
+            PduBody::Appearance(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::ArticulatedParts => (
+            PduBody::ArticulatedParts(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::LEFire => (
+            PduBody::LEFire(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::LEDetonation => (
+            PduBody::LEDetonation(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
@@ -477,19 +477,19 @@ pub fn encode(
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::DirectedEnergyFire => (
+            PduBody::DirectedEnergyFire(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::EntityDamageStatus => (
+            PduBody::EntityDamageStatus(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::InformationOperationsAction => (
+            PduBody::InformationOperationsAction(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
-            PduBody::InformationOperationsReport => (
+            PduBody::InformationOperationsReport(_) => (
                 Self::Unsupported(Unsupported),
                 CodecStateResult::StateUnaffected,
             ),
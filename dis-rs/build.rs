@@ -31,7 +31,7 @@
     (8, None, None, false),                    // Domain
     // 9-28 // (Sub-)Categories
     (29, None, None, false), // Country
-    // 30 // Entity Types records
+    // 30 // Entity Types records, see `ENTITY_TYPES_UID`
     // 31-43 // Bitfields, see `BITFIELD_UIDS`
     (44, None, None, false), // Dead Reckoning Algorithm
     (45, None, None, false), // Entity Marking Character Set
@@ -211,6 +211,10 @@
 /// from the XML structure will be changed to a regular `EnumItem::Basic`
 const SKIP_XREF_UIDS: [usize; 1] = [220];
 
+/// The uid of the Entity Types records ('cet' element), which are generated
+/// as lookup tables when the `entity-types` feature is enabled.
+const ENTITY_TYPES_UID: usize = 30;
+
 #[derive(Debug, Clone)]
 pub enum GenerationItem {
     Enum(Enum),
@@ -298,6 +302,20 @@ pub struct BitfieldItem {
     pub xref: Option<usize>,
 }
 
+/// A category, subcategory, specific or extra row of the Entity Types records,
+/// with the fields of the full entity type it describes.
+#[derive(Debug, Clone)]
+pub struct EntityTypeItem {
+    pub kind: u8,
+    pub domain: u8,
+    pub country: u16,
+    pub category: u8,
+    pub subcategory: u8,
+    pub specific: u8,
+    pub extra: u8,
+    pub description: String,
+}
+
 fn main() {
     let mut reader = Reader::from_file(Path::new(SISO_REF_FILE)).unwrap();
     reader.config_mut().trim_text(true);
@@ -316,6 +334,22 @@ fn main() {
     // Save to file
     let dest_path = Path::new(&env::var("OUT_DIR").unwrap()).join("enumerations.rs");
     fs::write(dest_path, contents).unwrap();
+
+    // Generate the entity type lookup tables only when requested, as these are large
+    if env::var_os("CARGO_FEATURE_ENTITY_TYPES").is_some() {
+        let mut reader = Reader::from_file(Path::new(SISO_REF_FILE)).unwrap();
+        reader.config_mut().trim_text(true);
+
+        let entity_types = extraction::extract_entity_types(&mut reader);
+        let generated = generation::generate_entity_types(entity_types);
+
+        let ast = syn::parse_file(&generated.to_string())
+            .expect("Error parsing generated entity types for pretty printing.");
+        let contents = prettyplease::unparse(&ast);
+
+        let dest_path = Path::new(&env::var("OUT_DIR").unwrap()).join("entity_types.rs");
+        fs::write(dest_path, contents).unwrap();
+    }
 }
 
 fn format_name_postfix(value: &str, uid: usize, needs_postfix: bool) -> String {
@@ -393,8 +427,8 @@ fn format_field_name(name: &str) -> String {
 
 mod extraction {
     use crate::{
-        BITFIELD_UIDS, BasicEnumItem, Bitfield, BitfieldItem, CrossRefEnumItem, ENUM_UIDS, Enum,
-        EnumItem, GenerationItem, RangeEnumItem, SKIP_XREF_UIDS,
+        BITFIELD_UIDS, BasicEnumItem, Bitfield, BitfieldItem, CrossRefEnumItem, ENTITY_TYPES_UID,
+        ENUM_UIDS, EntityTypeItem, Enum, EnumItem, GenerationItem, RangeEnumItem, SKIP_XREF_UIDS,
     };
     use quick_xml::Reader;
     use quick_xml::events::{BytesStart, Event};
@@ -422,6 +456,15 @@ mod extraction {
     const BITFIELD_ROW_ATTR_BIT_POSITION: QName = QName(b"bit_position");
     const BITFIELD_ROW_ATTR_LENGTH: QName = QName(b"length");
     const BITFIELD_ROW_ATTR_XREF: QName = QName(b"xref");
+    const ENTITY_TYPES_ELEMENT: QName = QName(b"cet");
+    const ENTITY_ELEMENT: QName = QName(b"entity");
+    const ENTITY_ATTR_KIND: QName = QName(b"kind");
+    const ENTITY_ATTR_DOMAIN: QName = QName(b"domain");
+    const ENTITY_ATTR_COUNTRY: QName = QName(b"country");
+    const CATEGORY_ELEMENT: QName = QName(b"category");
+    const SUBCATEGORY_ELEMENT: QName = QName(b"subcategory");
+    const SPECIFIC_ELEMENT: QName = QName(b"specific");
+    const EXTRA_ELEMENT: QName = QName(b"extra");
 
     #[allow(clippy::too_many_lines)]
     pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
@@ -760,12 +803,123 @@ fn extract_bitfield_item(
             Err(())
         }
     }
+
+    /// Extracts all rows of the Entity Types records as a flat list of full entity types.
+    ///
+    /// Cross-referencing rows (such as `subcategory_xref`) are not included.
+    pub fn extract_entity_types(reader: &mut Reader<BufReader<File>>) -> Vec<EntityTypeItem> {
+        let mut buf = Vec::new();
+        let mut items = Vec::new();
+        let mut in_entity_types = false;
+        // the entity type of the row that is currently being read
+        let mut current = None;
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref element) | Event::Empty(ref element)) => match element.name() {
+                    ENTITY_TYPES_ELEMENT => {
+                        in_entity_types = read_attribute(element, reader, ELEMENT_ATTR_UID)
+                            == Some(ENTITY_TYPES_UID);
+                    }
+                    ENTITY_ELEMENT if in_entity_types => {
+                        current = extract_entity(element, reader);
+                    }
+                    CATEGORY_ELEMENT | SUBCATEGORY_ELEMENT | SPECIFIC_ELEMENT | EXTRA_ELEMENT
+                        if in_entity_types =>
+                    {
+                        if let Some(item) = current
+                            .as_ref()
+                            .and_then(|current| extract_entity_type_row(element, reader, current))
+                        {
+                            items.push(item.clone());
+                            current = Some(item);
+                        }
+                    }
+                    _ => (),
+                },
+                Ok(Event::End(ref element)) if element.name() == ENTITY_TYPES_ELEMENT => {
+                    in_entity_types = false;
+                    current = None;
+                }
+                Ok(Event::Eof) => break, // exits the loop when reaching end of file
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (), // There are several other `Event`s we do not consider here
+            }
+            buf.clear();
+        }
+        items
+    }
+
+    fn read_attribute<T: FromStr>(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        name: QName,
+    ) -> Option<T> {
+        let attr = element.try_get_attribute(name).ok()??;
+        T::from_str(&reader.decoder().decode(&attr.value).ok()?).ok()
+    }
+
+    fn extract_entity(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+    ) -> Option<EntityTypeItem> {
+        Some(EntityTypeItem {
+            kind: read_attribute(element, reader, ENTITY_ATTR_KIND)?,
+            domain: read_attribute(element, reader, ENTITY_ATTR_DOMAIN)?,
+            country: read_attribute(element, reader, ENTITY_ATTR_COUNTRY)?,
+            category: 0,
+            subcategory: 0,
+            specific: 0,
+            extra: 0,
+            description: String::new(),
+        })
+    }
+
+    /// Constructs the entity type of a category, subcategory, specific or extra row,
+    /// based on the entity type of the enclosing row.
+    fn extract_entity_type_row(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        parent: &EntityTypeItem,
+    ) -> Option<EntityTypeItem> {
+        let value: u8 = read_attribute(element, reader, ENUM_ROW_ATTR_VALUE)?;
+        let description = element
+            .try_get_attribute(ENUM_ROW_ATTR_DESC)
+            .ok()??
+            .decode_and_unescape_value(reader.decoder())
+            .ok()?
+            .to_string();
+
+        let mut item = EntityTypeItem {
+            description,
+            ..parent.clone()
+        };
+        match element.name() {
+            CATEGORY_ELEMENT => {
+                item.category = value;
+                item.subcategory = 0;
+                item.specific = 0;
+                item.extra = 0;
+            }
+            SUBCATEGORY_ELEMENT => {
+                item.subcategory = value;
+                item.specific = 0;
+                item.extra = 0;
+            }
+            SPECIFIC_ELEMENT => {
+                item.specific = value;
+                item.extra = 0;
+            }
+            _ => item.extra = value,
+        }
+        Some(item)
+    }
 }
 
 mod generation {
     use crate::{
-        Bitfield, BitfieldItem, Enum, EnumItem, GenerationItem, Ident, Literal, TokenStream,
-        format_field_name, format_name, format_name_postfix,
+        Bitfield, BitfieldItem, EntityTypeItem, Enum, EnumItem, GenerationItem, Ident, Literal,
+        TokenStream, format_field_name, format_name, format_name_postfix,
     };
     use quote::{format_ident, quote};
 
@@ -1316,4 +1470,45 @@ fn discriminant_literal(value: usize, data_size: usize) -> Literal {
             _ => Literal::u8_suffixed(value as u8),
         }
     }
+
+    /// Generates the lookup table of entity types, sorted on the fields of the entity type.
+    pub fn generate_entity_types(mut items: Vec<EntityTypeItem>) -> TokenStream {
+        let key = |item: &EntityTypeItem| {
+            (
+                item.kind,
+                item.domain,
+                item.country,
+                item.category,
+                item.subcategory,
+                item.specific,
+                item.extra,
+            )
+        };
+        // keep the first occurrence of duplicate entity types
+        items.sort_by_key(key);
+        items.dedup_by_key(|item| key(item));
+
+        let size = Literal::usize_unsuffixed(items.len());
+        let rows = items.iter().map(|item| {
+            let kind = Literal::u8_unsuffixed(item.kind);
+            let domain = Literal::u8_unsuffixed(item.domain);
+            let country = Literal::u16_unsuffixed(item.country);
+            let category = Literal::u8_unsuffixed(item.category);
+            let subcategory = Literal::u8_unsuffixed(item.subcategory);
+            let specific = Literal::u8_unsuffixed(item.specific);
+            let extra = Literal::u8_unsuffixed(item.extra);
+            let description = Literal::string(item.description.trim());
+            quote!(
+                ((#kind, #domain, #country, #category, #subcategory, #specific, #extra), #description)
+            )
+        });
+
+        quote!(
+            /// Entity types defined in SISO-REF-010, sorted on
+            /// (kind, domain, country, category, subcategory, specific, extra).
+            static ENTITY_TYPES: [(EntityTypeFields, &str); #size] = [
+                #(#rows),*
+            ];
+        )
+    }
 }
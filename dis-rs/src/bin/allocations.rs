@@ -34,22 +34,23 @@ fn main() {
 
     println!("Parsed {} PDUs.", pdus.len());
 
+    let views = view_dis(&bytes);
+    println!("Viewed {views} PDUs.");
+
     #[cfg(feature = "hotpath")]
     drop(guard);
 }
 
 #[cfg(feature = "pcap-file")]
 fn read_pcap_file(file_name: &str) -> Vec<u8> {
-    const NETWORK_STACK_HEADERS_LENGTH: usize = 42; // Ethernet/IP/UDP headers
     let file_in = std::fs::File::open(file_name).expect("Error opening .{FILE_EXT_PCAP} file");
-    let mut pcap_reader = pcap_file::pcap::PcapReader::new(file_in).unwrap();
+    let mut capture_reader = dis_rs::capture::CaptureReader::new(std::io::BufReader::new(file_in))
+        .expect("Error reading .{FILE_EXT_PCAP} file");
 
     let mut bytes = Vec::new();
-    while let Some(pkt) = pcap_reader.next_packet() {
-        let pkt = pkt.expect("Error opening packet");
-        let data = &pkt.data[NETWORK_STACK_HEADERS_LENGTH..];
-        // let mut parsed = dis_rs::parse(data).expect("Expected a well-formed PDU");
-        bytes.append(&mut data.to_vec());
+    while let Some(datagram) = capture_reader.next_datagram() {
+        let mut datagram = datagram.expect("Error reading packet");
+        bytes.append(&mut datagram.payload);
     }
     bytes
 }
@@ -66,3 +67,7 @@ fn read_xmsn_file(file_name: &str) -> Vec<u8> {
 fn parse_dis(bytes: &Vec<u8>) -> Vec<Pdu> {
     dis_rs::parse(bytes.as_slice()).expect("Expected well formed PDUs.")
 }
+
+fn view_dis(bytes: &[u8]) -> usize {
+    dis_rs::parse_views(bytes).flatten().count()
+}
@@ -0,0 +1,709 @@
+//! Reading and writing DIS traffic from and to packet capture files (pcap and pcapng).
+//!
+//! The [`CaptureReader`] iterates the packets of a capture, decapsulates the Ethernet, Linux cooked capture,
+//! IPv4/IPv6 and UDP layers, and parses the UDP payload into PDUs. Packets that are not UDP, are fragmented IPv4
+//! datagrams, or do not match the configured port filter, are skipped. PDUs that fail to parse are reported
+//! per PDU, so that the other PDUs in the same datagram are still available.
+//!
+//! The [`CaptureWriter`] writes PDUs as UDP datagrams in Ethernet frames to a pcap file,
+//! using synthetic MAC addresses.
+//!
+//! This module is available with the `pcap-file` feature.
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::parse_each;
+use bytes::BytesMut;
+use pcap_file::pcap::{PcapHeader, PcapPacket, PcapReader, PcapWriter};
+use pcap_file::pcapng::blocks::interface_description::InterfaceDescriptionOption;
+use pcap_file::pcapng::{Block, PcapNgReader};
+use pcap_file::{DataLink, PcapError};
+use std::io::{Chain, Cursor, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+use thiserror::Error;
+
+const PCAP_MAGIC_NUMBERS: [[u8; 4]; 4] = [
+    [0xA1, 0xB2, 0xC3, 0xD4],
+    [0xD4, 0xC3, 0xB2, 0xA1],
+    [0xA1, 0xB2, 0x3C, 0x4D],
+    [0x4D, 0x3C, 0xB2, 0xA1],
+];
+const PCAPNG_MAGIC_NUMBER: [u8; 4] = [0x0A, 0x0D, 0x0D, 0x0A];
+
+const ETHERNET_HEADER_LENGTH: usize = 14;
+const VLAN_TAG_LENGTH: usize = 4;
+const LINUX_SLL_HEADER_LENGTH: usize = 16;
+const LOOPBACK_HEADER_LENGTH: usize = 4;
+const IPV4_MIN_HEADER_LENGTH: usize = 20;
+const IPV6_HEADER_LENGTH: usize = 40;
+const UDP_HEADER_LENGTH: usize = 8;
+
+const ETHER_TYPE_IPV4: u16 = 0x0800;
+const ETHER_TYPE_IPV6: u16 = 0x86DD;
+const ETHER_TYPE_VLAN: u16 = 0x8100;
+const ETHER_TYPE_QINQ: u16 = 0x88A8;
+const IP_PROTOCOL_UDP: u8 = 17;
+const IPV4_FLAG_MORE_FRAGMENTS: u16 = 0x2000;
+const IPV4_FLAG_DONT_FRAGMENT: u16 = 0x4000;
+const IPV4_FRAGMENT_OFFSET_MASK: u16 = 0x1FFF;
+const DEFAULT_TTL: u8 = 64;
+
+const WRITER_SOURCE_MAC: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+const WRITER_DESTINATION_MAC: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x02];
+const MAX_UDP_PAYLOAD_LENGTH: usize = u16::MAX as usize - UDP_HEADER_LENGTH;
+
+#[derive(Debug, Error)]
+pub enum CaptureError {
+    #[error("Error reading or writing the capture file: {0}")]
+    Pcap(#[from] PcapError),
+    #[error("The file is not a pcap or pcapng capture.")]
+    UnknownFormat,
+    #[error("Error parsing PDUs from a captured datagram: {0}")]
+    Pdu(#[from] DisError),
+    #[error("Source and destination addresses are of a different IP version.")]
+    AddressFamilyMismatch,
+    #[error("Datagram of {0} bytes is too large for a single UDP packet.")]
+    DatagramTooLarge(usize),
+}
+
+/// A UDP datagram read from a capture file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedDatagram {
+    /// Capture timestamp, as the time since the UNIX epoch
+    pub timestamp: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+/// The PDUs contained in a single UDP datagram of a capture file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedPdus {
+    /// Capture timestamp, as the time since the UNIX epoch
+    pub timestamp: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub pdus: Vec<Pdu>,
+    /// Byte offset in the datagram and error of each PDU that could not be parsed.
+    /// Ignored when writing.
+    pub errors: Vec<(usize, DisError)>,
+}
+
+type Source<R> = Chain<Cursor<[u8; 4]>, R>;
+/// A captured packet: the capture timestamp, link layer type and packet data.
+type Packet = (Duration, DataLink, Vec<u8>);
+
+enum Format<R: Read> {
+    Pcap(PcapReader<Source<R>>),
+    PcapNg(PcapNgReader<Source<R>>),
+}
+
+/// Reader for DIS traffic in pcap and pcapng capture files.
+///
+/// Iterating the reader yields the PDUs of each UDP datagram in the capture.
+/// The raw datagrams can be obtained using [`CaptureReader::next_datagram`].
+pub struct CaptureReader<R: Read> {
+    format: Format<R>,
+    ports: Vec<u16>,
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Creates a reader for a pcap or pcapng capture, detected from the first bytes of `reader`.
+    ///
+    /// # Errors
+    /// Returns a `CaptureError` when the file header cannot be read or the format is not recognised.
+    pub fn new(mut reader: R) -> Result<Self, CaptureError> {
+        let mut magic = [0u8; 4];
+        reader
+            .read_exact(&mut magic)
+            .map_err(|err| CaptureError::Pcap(PcapError::IoError(err)))?;
+        let source = Cursor::new(magic).chain(reader);
+
+        let format = if PCAP_MAGIC_NUMBERS.contains(&magic) {
+            Format::Pcap(PcapReader::new(source)?)
+        } else if magic == PCAPNG_MAGIC_NUMBER {
+            Format::PcapNg(PcapNgReader::new(source)?)
+        } else {
+            return Err(CaptureError::UnknownFormat);
+        };
+
+        Ok(Self {
+            format,
+            ports: Vec::new(),
+        })
+    }
+
+    /// Only yields datagrams of which the source or destination port is `port`.
+    /// Can be applied multiple times to accept several ports.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.ports.push(port);
+        self
+    }
+
+    /// Reads the next UDP datagram that matches the port filter, skipping all other packets.
+    pub fn next_datagram(&mut self) -> Option<Result<CapturedDatagram, CaptureError>> {
+        loop {
+            let (timestamp, datalink, data) = match self.next_packet()? {
+                Ok(packet) => packet,
+                Err(err) => return Some(Err(err)),
+            };
+            if let Some((source, destination, payload)) = decapsulate(datalink, &data)
+                && self.accepts(source, destination)
+            {
+                return Some(Ok(CapturedDatagram {
+                    timestamp,
+                    source,
+                    destination,
+                    payload: payload.to_vec(),
+                }));
+            }
+        }
+    }
+
+    fn accepts(&self, source: SocketAddr, destination: SocketAddr) -> bool {
+        self.ports.is_empty()
+            || self.ports.contains(&source.port())
+            || self.ports.contains(&destination.port())
+    }
+
+    fn next_packet(&mut self) -> Option<Result<Packet, CaptureError>> {
+        match &mut self.format {
+            Format::Pcap(reader) => {
+                let datalink = reader.header().datalink;
+                Some(
+                    reader
+                        .next_packet()?
+                        .map(|packet| (packet.timestamp, datalink, packet.data.into_owned()))
+                        .map_err(CaptureError::from),
+                )
+            }
+            Format::PcapNg(reader) => loop {
+                let block = match reader.next_block()? {
+                    Ok(Block::EnhancedPacket(block)) => block,
+                    Ok(Block::SimplePacket(block)) => {
+                        // Simple Packet Blocks do not have a timestamp, and always belong to the first interface
+                        let data = block.data.into_owned();
+                        let Some(interface) = reader.interfaces().first() else {
+                            return Some(Err(PcapError::InvalidInterfaceId(0).into()));
+                        };
+                        return Some(Ok((Duration::ZERO, interface.linktype, data)));
+                    }
+                    Ok(_) => continue,
+                    Err(err) => return Some(Err(err.into())),
+                };
+                let (interface_id, timestamp) = (block.interface_id, block.timestamp);
+                let data = block.data.into_owned();
+                let Some(interface) = reader.interfaces().get(interface_id as usize) else {
+                    return Some(Err(PcapError::InvalidInterfaceId(interface_id).into()));
+                };
+                let timestamp = pcapng_timestamp(timestamp, &interface.options);
+                return Some(Ok((timestamp, interface.linktype, data)));
+            },
+        }
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<CapturedPdus, CaptureError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let datagram = match self.next_datagram()? {
+            Ok(datagram) => datagram,
+            Err(err) => return Some(Err(err)),
+        };
+        let mut pdus = Vec::new();
+        let mut errors = Vec::new();
+        for parsed in parse_each(&datagram.payload) {
+            match parsed.result {
+                Ok(pdu) => pdus.push(pdu),
+                Err(err) => errors.push((parsed.offset, err)),
+            }
+        }
+        Some(Ok(CapturedPdus {
+            timestamp: datagram.timestamp,
+            source: datagram.source,
+            destination: datagram.destination,
+            pdus,
+            errors,
+        }))
+    }
+}
+
+/// Converts the timestamp of an Enhanced Packet Block using the resolution of the interface.
+///
+/// `pcap-file` reports the raw timestamp as nanoseconds, regardless of the `if_tsresol` option,
+/// of which the default is microseconds.
+fn pcapng_timestamp(raw: Duration, options: &[InterfaceDescriptionOption]) -> Duration {
+    const DEFAULT_RESOLUTION: u8 = 6;
+    const BASE_2_FLAG: u8 = 0x80;
+
+    let ticks = raw.as_nanos();
+    let resolution = options
+        .iter()
+        .find_map(|option| match option {
+            InterfaceDescriptionOption::IfTsResol(resolution) => Some(*resolution),
+            _ => None,
+        })
+        .unwrap_or(DEFAULT_RESOLUTION);
+
+    let nanos = if resolution & BASE_2_FLAG == 0 {
+        let exponent = u32::from(resolution);
+        if exponent <= 9 {
+            ticks * 10u128.pow(9 - exponent)
+        } else {
+            ticks / 10u128.pow(exponent - 9)
+        }
+    } else {
+        let exponent = u32::from(resolution & !BASE_2_FLAG);
+        (ticks * 1_000_000_000) >> exponent
+    };
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// Strips the link, network and transport layer headers from a captured packet,
+/// returning the source and destination addresses and the UDP payload.
+fn decapsulate(datalink: DataLink, data: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    match datalink {
+        DataLink::ETHERNET => {
+            let mut offset = ETHERNET_HEADER_LENGTH;
+            let mut ether_type = read_u16(data, offset - 2)?;
+            while ether_type == ETHER_TYPE_VLAN || ether_type == ETHER_TYPE_QINQ {
+                offset += VLAN_TAG_LENGTH;
+                ether_type = read_u16(data, offset - 2)?;
+            }
+            decapsulate_ether_type(ether_type, data.get(offset..)?)
+        }
+        DataLink::LINUX_SLL => decapsulate_ether_type(
+            read_u16(data, LINUX_SLL_HEADER_LENGTH - 2)?,
+            data.get(LINUX_SLL_HEADER_LENGTH..)?,
+        ),
+        DataLink::NULL | DataLink::LOOP => decapsulate_ip(data.get(LOOPBACK_HEADER_LENGTH..)?),
+        DataLink::RAW | DataLink::IPV4 | DataLink::IPV6 => decapsulate_ip(data),
+        _ => None,
+    }
+}
+
+fn decapsulate_ether_type(ether_type: u16, data: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    match ether_type {
+        ETHER_TYPE_IPV4 => decapsulate_ipv4(data),
+        ETHER_TYPE_IPV6 => decapsulate_ipv6(data),
+        _ => None,
+    }
+}
+
+fn decapsulate_ip(data: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    match data.first()? >> 4 {
+        4 => decapsulate_ipv4(data),
+        6 => decapsulate_ipv6(data),
+        _ => None,
+    }
+}
+
+fn decapsulate_ipv4(data: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    let header_length = usize::from(data.first()? & 0x0F) * 4;
+    let total_length = usize::from(read_u16(data, 2)?);
+    let fragment = read_u16(data, 6)?;
+    if header_length < IPV4_MIN_HEADER_LENGTH
+        || *data.get(9)? != IP_PROTOCOL_UDP
+        || fragment & (IPV4_FLAG_MORE_FRAGMENTS | IPV4_FRAGMENT_OFFSET_MASK) != 0
+    {
+        return None;
+    }
+    let source = IpAddr::from(<[u8; 4]>::try_from(data.get(12..16)?).ok()?);
+    let destination = IpAddr::from(<[u8; 4]>::try_from(data.get(16..20)?).ok()?);
+
+    // the total length excludes any padding of the link layer
+    decapsulate_udp(source, destination, data.get(header_length..total_length)?)
+}
+
+fn decapsulate_ipv6(data: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    let payload_length = usize::from(read_u16(data, 4)?);
+    if *data.get(6)? != IP_PROTOCOL_UDP {
+        return None;
+    }
+    let source = IpAddr::from(<[u8; 16]>::try_from(data.get(8..24)?).ok()?);
+    let destination = IpAddr::from(<[u8; 16]>::try_from(data.get(24..40)?).ok()?);
+
+    decapsulate_udp(
+        source,
+        destination,
+        data.get(IPV6_HEADER_LENGTH..IPV6_HEADER_LENGTH + payload_length)?,
+    )
+}
+
+fn decapsulate_udp(
+    source: IpAddr,
+    destination: IpAddr,
+    data: &[u8],
+) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    let source_port = read_u16(data, 0)?;
+    let destination_port = read_u16(data, 2)?;
+    let length = usize::from(read_u16(data, 4)?);
+    let payload = data.get(UDP_HEADER_LENGTH..length)?;
+
+    Some((
+        SocketAddr::new(source, source_port),
+        SocketAddr::new(destination, destination_port),
+        payload,
+    ))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+/// Writer for DIS traffic to pcap capture files.
+///
+/// Each call to [`CaptureWriter::write`] writes the PDUs as a single UDP datagram in an Ethernet frame.
+pub struct CaptureWriter<W: Write> {
+    writer: PcapWriter<W>,
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Creates a writer, writing the pcap file header to `writer`.
+    ///
+    /// # Errors
+    /// Returns a `CaptureError` when the file header cannot be written.
+    pub fn new(writer: W) -> Result<Self, CaptureError> {
+        let header = PcapHeader {
+            datalink: DataLink::ETHERNET,
+            ts_resolution: pcap_file::TsResolution::NanoSecond,
+            ..PcapHeader::default()
+        };
+        Ok(Self {
+            writer: PcapWriter::with_header(writer, header)?,
+        })
+    }
+
+    /// Writes the PDUs as a single UDP datagram from `captured.source` to `captured.destination`.
+    ///
+    /// # Errors
+    /// Returns a `CaptureError` when the PDUs cannot be serialized, the addresses are of different IP versions,
+    /// the PDUs do not fit a single datagram, or the packet cannot be written.
+    pub fn write(&mut self, captured: &CapturedPdus) -> Result<(), CaptureError> {
+        let length = captured
+            .pdus
+            .iter()
+            .map(|pdu| usize::from(pdu.pdu_length()))
+            .sum();
+        if length > MAX_UDP_PAYLOAD_LENGTH {
+            return Err(CaptureError::DatagramTooLarge(length));
+        }
+
+        let mut payload = BytesMut::with_capacity(length);
+        for pdu in &captured.pdus {
+            pdu.serialize(&mut payload)?;
+        }
+        let frame = encapsulate(captured.source, captured.destination, &payload)?;
+
+        self.writer.write_packet(&PcapPacket::new(
+            captured.timestamp,
+            frame.len() as u32,
+            &frame,
+        ))?;
+        Ok(())
+    }
+
+    /// Consumes the writer, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_writer()
+    }
+}
+
+/// Wraps a UDP payload in UDP, IP and Ethernet headers.
+fn encapsulate(
+    source: SocketAddr,
+    destination: SocketAddr,
+    payload: &[u8],
+) -> Result<Vec<u8>, CaptureError> {
+    let udp_length = UDP_HEADER_LENGTH + payload.len();
+    let (ether_type, ip_header, pseudo_header) = match (source.ip(), destination.ip()) {
+        (IpAddr::V4(src), IpAddr::V4(dst)) => {
+            let total_length = IPV4_MIN_HEADER_LENGTH + udp_length;
+            let total_length = u16::try_from(total_length)
+                .map_err(|_| CaptureError::DatagramTooLarge(total_length))?;
+            (
+                ETHER_TYPE_IPV4,
+                ipv4_header(src, dst, total_length),
+                pseudo_header(&src.octets(), &dst.octets(), udp_length),
+            )
+        }
+        (IpAddr::V6(src), IpAddr::V6(dst)) => (
+            ETHER_TYPE_IPV6,
+            ipv6_header(src, dst, udp_length as u16),
+            pseudo_header(&src.octets(), &dst.octets(), udp_length),
+        ),
+        _ => return Err(CaptureError::AddressFamilyMismatch),
+    };
+
+    let mut udp = Vec::with_capacity(udp_length);
+    udp.extend_from_slice(&source.port().to_be_bytes());
+    udp.extend_from_slice(&destination.port().to_be_bytes());
+    udp.extend_from_slice(&(udp_length as u16).to_be_bytes());
+    udp.extend_from_slice(&[0, 0]);
+    udp.extend_from_slice(payload);
+    let checksum = match checksum(&[&pseudo_header, &udp]) {
+        // a computed checksum of zero is transmitted as all ones
+        0 => 0xFFFF,
+        checksum => checksum,
+    };
+    udp[6..8].copy_from_slice(&checksum.to_be_bytes());
+
+    let mut frame = Vec::with_capacity(ETHERNET_HEADER_LENGTH + ip_header.len() + udp.len());
+    frame.extend_from_slice(&WRITER_DESTINATION_MAC);
+    frame.extend_from_slice(&WRITER_SOURCE_MAC);
+    frame.extend_from_slice(&ether_type.to_be_bytes());
+    frame.extend_from_slice(&ip_header);
+    frame.extend_from_slice(&udp);
+    Ok(frame)
+}
+
+fn ipv4_header(source: Ipv4Addr, destination: Ipv4Addr, total_length: u16) -> Vec<u8> {
+    let mut header = Vec::with_capacity(IPV4_MIN_HEADER_LENGTH);
+    header.extend_from_slice(&[0x45, 0x00]);
+    header.extend_from_slice(&total_length.to_be_bytes());
+    header.extend_from_slice(&[0x00, 0x00]);
+    header.extend_from_slice(&IPV4_FLAG_DONT_FRAGMENT.to_be_bytes());
+    header.extend_from_slice(&[DEFAULT_TTL, IP_PROTOCOL_UDP, 0x00, 0x00]);
+    header.extend_from_slice(&source.octets());
+    header.extend_from_slice(&destination.octets());
+    let checksum = checksum(&[&header]);
+    header[10..12].copy_from_slice(&checksum.to_be_bytes());
+    header
+}
+
+fn ipv6_header(source: Ipv6Addr, destination: Ipv6Addr, payload_length: u16) -> Vec<u8> {
+    let mut header = Vec::with_capacity(IPV6_HEADER_LENGTH);
+    header.extend_from_slice(&[0x60, 0x00, 0x00, 0x00]);
+    header.extend_from_slice(&payload_length.to_be_bytes());
+    header.extend_from_slice(&[IP_PROTOCOL_UDP, DEFAULT_TTL]);
+    header.extend_from_slice(&source.octets());
+    header.extend_from_slice(&destination.octets());
+    header
+}
+
+/// The pseudo header of the source and destination addresses, protocol and length, used in the UDP checksum.
+fn pseudo_header(source: &[u8], destination: &[u8], udp_length: usize) -> Vec<u8> {
+    let mut header = Vec::with_capacity(source.len() + destination.len() + 8);
+    header.extend_from_slice(source);
+    header.extend_from_slice(destination);
+    header.extend_from_slice(&(udp_length as u32).to_be_bytes());
+    header.extend_from_slice(&[0, 0, 0, IP_PROTOCOL_UDP]);
+    header
+}
+
+/// The Internet checksum (RFC 1071) over the concatenation of `parts`, which must be of even length except for the last.
+fn checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum = parts
+        .iter()
+        .flat_map(|part| part.chunks(2))
+        .map(|chunk| u32::from(u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)])))
+        .sum::<u32>();
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::model::{EntityId, PduHeader, TimeUnits, Timestamp};
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::PduType;
+    use pcap_file::pcapng::PcapNgWriter;
+    use pcap_file::pcapng::blocks::enhanced_packet::EnhancedPacketBlock;
+    use pcap_file::pcapng::blocks::interface_description::InterfaceDescriptionBlock;
+    use std::borrow::Cow;
+
+    fn entity_state(id: u16) -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 1, id))
+                .build()
+                .into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        )
+    }
+
+    fn captured(source: &str, destination: &str, timestamp: Duration) -> CapturedPdus {
+        CapturedPdus {
+            timestamp,
+            source: source.parse().unwrap(),
+            destination: destination.parse().unwrap(),
+            pdus: vec![entity_state(1), entity_state(2)],
+            errors: vec![],
+        }
+    }
+
+    fn write(captures: &[CapturedPdus]) -> Vec<u8> {
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        for captured in captures {
+            writer.write(captured).unwrap();
+        }
+        writer.into_inner()
+    }
+
+    fn pdu_ids(captured: &CapturedPdus) -> Vec<EntityId> {
+        captured
+            .pdus
+            .iter()
+            .map(|pdu| match &pdu.body {
+                crate::common::model::PduBody::EntityState(body) => body.entity_id,
+                _ => panic!("expected an Entity State PDU"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pcap_round_trip() {
+        let expected = vec![
+            captured(
+                "192.168.1.10:3000",
+                "192.168.1.255:3000",
+                Duration::from_millis(1_700_000_000_123),
+            ),
+            captured(
+                "[fe80::1]:3000",
+                "[ff02::1]:3000",
+                Duration::from_millis(1_700_000_001_456),
+            ),
+        ];
+        let file = write(&expected);
+
+        let read = CaptureReader::new(file.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(read.len(), 2);
+        for (read, expected) in read.iter().zip(&expected) {
+            assert_eq!(read.timestamp, expected.timestamp);
+            assert_eq!(read.source, expected.source);
+            assert_eq!(read.destination, expected.destination);
+            assert_eq!(pdu_ids(read), pdu_ids(expected));
+        }
+    }
+
+    #[test]
+    fn keep_valid_pdus_of_datagram_with_invalid_pdu() {
+        let captured = captured("10.0.0.1:3000", "10.0.0.2:3000", Duration::from_secs(1));
+        let first_length = usize::from(captured.pdus[0].pdu_length());
+        let mut file = write(&[captured]);
+
+        // Corrupt the PDU length of the second PDU, after the pcap, Ethernet, IPv4 and UDP headers
+        let second_pdu = 24
+            + 16
+            + ETHERNET_HEADER_LENGTH
+            + IPV4_MIN_HEADER_LENGTH
+            + UDP_HEADER_LENGTH
+            + first_length;
+        file[second_pdu + 8..second_pdu + 10].copy_from_slice(&1u16.to_be_bytes());
+
+        let read = CaptureReader::new(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(read.timestamp, Duration::from_secs(1));
+        assert_eq!(pdu_ids(&read), vec![EntityId::new(1, 1, 1)]);
+        assert_eq!(
+            read.errors,
+            vec![(first_length, DisError::InvalidPduLength(1))]
+        );
+    }
+
+    #[test]
+    fn filter_on_port() {
+        let file = write(&[
+            captured("10.0.0.1:3000", "10.0.0.2:3000", Duration::ZERO),
+            captured("10.0.0.1:4000", "10.0.0.2:4000", Duration::ZERO),
+            captured("10.0.0.1:5000", "10.0.0.2:3001", Duration::ZERO),
+        ]);
+
+        let read = CaptureReader::new(file.as_slice())
+            .unwrap()
+            .with_port(3001)
+            .with_port(4000)
+            .map(|captured| captured.unwrap().source.port())
+            .collect::<Vec<_>>();
+
+        assert_eq!(read, vec![4000, 5000]);
+    }
+
+    #[test]
+    fn read_pcapng() {
+        let frame = encapsulate(
+            "10.0.0.1:3000".parse().unwrap(),
+            "10.0.0.2:3000".parse().unwrap(),
+            &{
+                let pdu = entity_state(7);
+                let mut buf = BytesMut::with_capacity(usize::from(pdu.pdu_length()));
+                pdu.serialize(&mut buf).unwrap();
+                buf
+            },
+        )
+        .unwrap();
+
+        let mut writer = PcapNgWriter::new(Vec::new()).unwrap();
+        writer
+            .write_pcapng_block(InterfaceDescriptionBlock {
+                linktype: DataLink::ETHERNET,
+                snaplen: 0,
+                options: vec![],
+            })
+            .unwrap();
+        writer
+            .write_pcapng_block(EnhancedPacketBlock {
+                interface_id: 0,
+                // written as 1_500_000 raw ticks (nanoseconds), read in the default resolution of microseconds
+                timestamp: Duration::from_micros(1_500),
+                original_len: frame.len() as u32,
+                data: Cow::Borrowed(&frame),
+                options: vec![],
+            })
+            .unwrap();
+        let file = writer.into_inner();
+
+        let read = CaptureReader::new(file.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].timestamp, Duration::from_millis(1_500));
+        assert_eq!(pdu_ids(&read[0]), vec![EntityId::new(1, 1, 7)]);
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(matches!(
+            CaptureReader::new([0u8; 24].as_slice()),
+            Err(CaptureError::UnknownFormat)
+        ));
+    }
+
+    #[test]
+    fn valid_checksums() {
+        let frame = encapsulate(
+            "10.0.0.1:3000".parse().unwrap(),
+            "10.0.0.2:3000".parse().unwrap(),
+            &[1, 2, 3, 4, 5],
+        )
+        .unwrap();
+        let ip = &frame[ETHERNET_HEADER_LENGTH..];
+        let udp = &ip[IPV4_MIN_HEADER_LENGTH..];
+
+        assert_eq!(checksum(&[&ip[..IPV4_MIN_HEADER_LENGTH]]), 0);
+        assert_eq!(
+            checksum(&[&pseudo_header(&ip[12..16], &ip[16..20], udp.len()), udp]),
+            0
+        );
+    }
+}
@@ -1,17 +1,17 @@
 use crate::BodyRaw;
 use crate::acknowledge_r::model::AcknowledgeR;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::enumerations::{AcknowledgeFlag, ResponseFlag};
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u16, be_u32};
 
 pub(crate) fn acknowledge_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, acknowledge_flag) = be_u16(input)?;
-    let (input, response_flag) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, acknowledge_flag) = context("Acknowledge Flag", be_u16).parse(input)?;
+    let (input, response_flag) = context("Response Flag", be_u16).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
 
     let acknowledge_flag = AcknowledgeFlag::from(acknowledge_flag);
     let response_flag = ResponseFlag::from(response_flag);
@@ -1,24 +1,25 @@
 use crate::BodyRaw;
 use crate::action_request_r::model::ActionRequestR;
 use crate::common::model::PduBody;
-use crate::common::parser::{datum_specification, entity_id};
+use crate::common::parser::{IResult, context, datum_specification, entity_id};
 use crate::enumerations::{ActionId, RequiredReliabilityService};
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u8, be_u16, be_u32};
 
 pub(crate) fn action_request_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, required_reliability_service) = be_u8(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, required_reliability_service) =
+        context("Required Reliability Service", be_u8).parse(input)?;
     let required_reliability_service =
         RequiredReliabilityService::from(required_reliability_service);
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, action_id) = be_u32(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, action_id) = context("Action ID", be_u32).parse(input)?;
     let action_id = ActionId::from(action_id);
-    let (input, _padding) = be_u32(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification).parse(input)?;
 
     let body = ActionRequestR::builder()
         .with_origination_id(originating_id)
@@ -1,18 +1,18 @@
 use crate::BodyRaw;
 use crate::common::action_response::model::ActionResponse;
 use crate::common::model::PduBody;
-use crate::common::parser::{datum_specification, entity_id};
+use crate::common::parser::{IResult, context, datum_specification, entity_id};
 use crate::enumerations::RequestStatus;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u32;
 
 pub(crate) fn action_response_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, request_status) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, request_status) = context("Request Status", be_u32).parse(input)?;
     let request_status = RequestStatus::from(request_status);
-    let (input, datums) = datum_specification(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification).parse(input)?;
 
     let body = ActionResponse::builder()
         .with_origination_id(originating_id)
@@ -155,7 +155,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
                 marking_string: s.to_string(),
             })
         } else {
-            Err(DisError::ParseError(format!(
+            Err(DisError::StringFormatError(format!(
                 "String is too long for AggregateMarking. Found {}, max 31 allowed.",
                 s.len()
             )))
@@ -249,7 +249,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         const NUM_DIGITS: usize = 7;
         let ss = s.split(':').collect::<Vec<&str>>();
         if ss.len() != NUM_DIGITS {
-            return Err(DisError::ParseError(format!(
+            return Err(DisError::StringFormatError(format!(
                 "AggregateType string pattern does contain not precisely {NUM_DIGITS} digits"
             )));
         }
@@ -258,42 +258,42 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
                 .get(0)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid kind digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid kind digit".to_string()))?
                 .into(),
             domain: ss
                 .get(1)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid domain digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid domain digit".to_string()))?
                 .into(),
             country: ss
                 .get(2)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u16>()
-                .map_err(|_| DisError::ParseError("Invalid country digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid country digit".to_string()))?
                 .into(),
             category: ss
                 .get(3)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid category digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid category digit".to_string()))?,
             subcategory: ss
                 .get(4)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid subcategory digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid subcategory digit".to_string()))?
                 .into(),
             specific: ss
                 .get(5)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid specific digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid specific digit".to_string()))?
                 .into(),
             extra: ss
                 .get(6)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid extra digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid extra digit".to_string()))?,
         })
     }
 }
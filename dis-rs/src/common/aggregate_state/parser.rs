@@ -4,7 +4,8 @@
     aggregate_state_intermediate_length_padding,
 };
 use crate::common::parser::{
-    entity_id, entity_type, location, orientation, sanitize_marking, variable_datum, vec3_f32,
+    IResult, context, entity_id, entity_type, location, orientation, sanitize_marking,
+    variable_datum, vec3_f32,
 };
 use crate::entity_state::parser::{entity_appearance, force_id};
 use crate::enumerations::{
@@ -13,46 +14,62 @@
     PlatformDomain,
 };
 use crate::model::PduBody;
+use nom::Parser;
 use nom::bytes::complete::take;
+use nom::combinator::map;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn aggregate_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, aggregate_id) = entity_id(input)?;
-    let (input, force_id) = force_id(input)?;
-    let (input, aggregate_state) = be_u8(input)?;
+    let (input, aggregate_id) = context("Aggregate ID", entity_id).parse(input)?;
+    let (input, force_id) = context("Force ID", force_id).parse(input)?;
+    let (input, aggregate_state) = context("Aggregate State", be_u8).parse(input)?;
     let aggregate_state = AggregateStateAggregateState::from(aggregate_state);
-    let (input, aggregate_type) = aggregate_type(input)?;
-    let (input, formation) = be_u32(input)?;
+    let (input, aggregate_type) = context("Aggregate Type", aggregate_type).parse(input)?;
+    let (input, formation) = context("Formation", be_u32).parse(input)?;
     let formation = AggregateStateFormation::from(formation);
-    let (input, aggregate_marking) = aggregate_marking(input)?;
-    let (input, dimensions) = vec3_f32(input)?;
-    let (input, orientation) = orientation(input)?;
-    let (input, center_of_mass) = location(input)?;
-    let (input, velocity) = vec3_f32(input)?;
-
-    let (input, number_of_aggregates) = be_u16(input)?;
-    let (input, number_of_entities) = be_u16(input)?;
-    let (input, number_of_silent_aggregates) = be_u16(input)?;
-    let (input, number_of_silent_entities) = be_u16(input)?;
-
-    let (input, aggregates) = count(entity_id, number_of_aggregates.into()).parse(input)?;
-    let (input, entities) = count(entity_id, number_of_entities.into()).parse(input)?;
+    let (input, aggregate_marking) =
+        context("Aggregate Marking", aggregate_marking).parse(input)?;
+    let (input, dimensions) = context("Dimensions", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, center_of_mass) = context("Center of Mass", location).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+
+    let (input, number_of_aggregates) = context("Number of Aggregates", be_u16).parse(input)?;
+    let (input, number_of_entities) = context("Number of Entities", be_u16).parse(input)?;
+    let (input, number_of_silent_aggregates) =
+        context("Number of Silent Aggregates", be_u16).parse(input)?;
+    let (input, number_of_silent_entities) =
+        context("Number of Silent Entities", be_u16).parse(input)?;
+
+    let (input, aggregates) =
+        count(context("Entity ID", entity_id), number_of_aggregates.into()).parse(input)?;
+    let (input, entities) =
+        count(context("Entity ID", entity_id), number_of_entities.into()).parse(input)?;
 
     let (_intermediate_length, padding_length) =
         aggregate_state_intermediate_length_padding(&aggregates, &entities);
 
-    let (input, _padding) = take(padding_length)(input)?;
+    let (input, _padding) = context("Padding", take(padding_length)).parse(input)?;
 
-    let (input, silent_aggregate_systems) =
-        count(silent_aggregate_system, number_of_silent_aggregates.into()).parse(input)?;
-    let (input, silent_entity_systems) =
-        count(silent_entity_system, number_of_silent_entities.into()).parse(input)?;
+    let (input, silent_aggregate_systems) = count(
+        context("Silent Aggregate System", silent_aggregate_system),
+        number_of_silent_aggregates.into(),
+    )
+    .parse(input)?;
+    let (input, silent_entity_systems) = count(
+        context("Silent Entity System", silent_entity_system),
+        number_of_silent_entities.into(),
+    )
+    .parse(input)?;
 
-    let (input, number_of_variable_datums) = be_u32(input)?;
-    let (input, variable_datums) =
-        count(variable_datum, number_of_variable_datums as usize).parse(input)?;
+    let (input, number_of_variable_datums) =
+        context("Number of Variable Datums", be_u32).parse(input)?;
+    let (input, variable_datums) = count(
+        context("Variable Datum", variable_datum),
+        number_of_variable_datums as usize,
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -78,18 +95,18 @@ pub(crate) fn aggregate_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 fn aggregate_type(input: &[u8]) -> IResult<&[u8], AggregateType> {
-    let (input, aggregate_kind) = be_u8(input)?;
+    let (input, aggregate_kind) = context("Aggregate Kind", be_u8).parse(input)?;
     let aggregate_kind = AggregateStateAggregateKind::from(aggregate_kind);
-    let (input, domain) = be_u8(input)?;
+    let (input, domain) = context("Domain", be_u8).parse(input)?;
     let domain = PlatformDomain::from(domain);
-    let (input, country) = be_u16(input)?;
+    let (input, country) = context("Country", be_u16).parse(input)?;
     let country = Country::from(country);
-    let (input, category) = be_u8(input)?;
-    let (input, subcategory) = be_u8(input)?;
+    let (input, category) = context("Category", be_u8).parse(input)?;
+    let (input, subcategory) = context("Subcategory", be_u8).parse(input)?;
     let subcategory = AggregateStateSubcategory::from(subcategory);
-    let (input, specific) = be_u8(input)?;
+    let (input, specific) = context("Specific", be_u8).parse(input)?;
     let specific = AggregateStateSpecific::from(specific);
-    let (input, extra) = be_u8(input)?;
+    let (input, extra) = context("Extra", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -106,12 +123,10 @@ fn aggregate_type(input: &[u8]) -> IResult<&[u8], AggregateType> {
 }
 
 fn aggregate_marking(input: &[u8]) -> IResult<&[u8], AggregateMarking> {
-    let mut buf: [u8; 31] = [0; 31];
-    let (input, marking_character_set) = be_u8(input)?;
+    let (input, marking_character_set) = context("Marking Character Set", be_u8).parse(input)?;
     let marking_character_set = EntityMarkingCharacterSet::from(marking_character_set);
-    let (input, ()) = nom::multi::fill(be_u8, &mut buf).parse(input)?;
-
-    let marking_string = sanitize_marking(&buf[..]);
+    let (input, marking_string) =
+        context("Marking String", map(take(31usize), sanitize_marking)).parse(input)?;
 
     Ok((
         input,
@@ -123,9 +138,9 @@ fn aggregate_marking(input: &[u8]) -> IResult<&[u8], AggregateMarking> {
 }
 
 fn silent_aggregate_system(input: &[u8]) -> IResult<&[u8], SilentAggregateSystem> {
-    let (input, number_of_aggregates) = be_u16(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, aggregate_type) = aggregate_type(input)?;
+    let (input, number_of_aggregates) = context("Number of Aggregates", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, aggregate_type) = context("Aggregate Type", aggregate_type).parse(input)?;
 
     Ok((
         input,
@@ -136,11 +151,12 @@ fn silent_aggregate_system(input: &[u8]) -> IResult<&[u8], SilentAggregateSystem
 }
 
 fn silent_entity_system(input: &[u8]) -> IResult<&[u8], SilentEntitySystem> {
-    let (input, number_of_entities) = be_u16(input)?;
-    let (input, number_of_appearance_records) = be_u16(input)?;
-    let (input, entity_type) = entity_type(input)?;
+    let (input, number_of_entities) = context("Number of Entities", be_u16).parse(input)?;
+    let (input, number_of_appearance_records) =
+        context("Number of Appearance Records", be_u16).parse(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
     let (input, appearances) = count(
-        entity_appearance(entity_type),
+        context("Appearance", entity_appearance(entity_type)),
         number_of_appearance_records.into(),
     )
     .parse(input)?;
@@ -0,0 +1,89 @@
+use crate::appearance::model::Appearance;
+use crate::common::model::{EntityType, LiveEntityId};
+use crate::entity_state::model::EntityMarking;
+use crate::enumerations::ForceId;
+
+pub struct AppearanceBuilder(Appearance);
+
+impl Default for AppearanceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppearanceBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        AppearanceBuilder(Appearance::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: Appearance) -> Self {
+        AppearanceBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> Appearance {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = Some(force_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_type(mut self, entity_type: EntityType) -> Self {
+        self.0.entity_type = Some(entity_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_alternate_entity_type(mut self, alternate_entity_type: EntityType) -> Self {
+        self.0.alternate_entity_type = Some(alternate_entity_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_marking(mut self, entity_marking: EntityMarking) -> Self {
+        self.0.entity_marking = Some(entity_marking);
+        self
+    }
+
+    #[must_use]
+    pub fn with_capabilities(mut self, capabilities: u32) -> Self {
+        self.0.capabilities = Some(capabilities);
+        self
+    }
+
+    #[must_use]
+    pub fn with_appearance_visual(mut self, appearance_visual: u32) -> Self {
+        self.0.appearance_visual = Some(appearance_visual);
+        self
+    }
+
+    #[must_use]
+    pub fn with_appearance_ir(mut self, appearance_ir: u32) -> Self {
+        self.0.appearance_ir = Some(appearance_ir);
+        self
+    }
+
+    #[must_use]
+    pub fn with_appearance_em(mut self, appearance_em: u32) -> Self {
+        self.0.appearance_em = Some(appearance_em);
+        self
+    }
+
+    #[must_use]
+    pub fn with_appearance_audio(mut self, appearance_audio: u32) -> Self {
+        self.0.appearance_audio = Some(appearance_audio);
+        self
+    }
+}
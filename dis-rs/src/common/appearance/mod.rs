@@ -0,0 +1,71 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::appearance::model::Appearance;
+    use crate::common::model::{EntityType, LiveEntityId, Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::entity_state::model::EntityMarking;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, EntityKind, ForceId, LvcIndicator, PduType,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    fn round_trip(body: Appearance) {
+        let header = PduHeader::new_v7(1, PduType::Appearance).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(serialized_length, pdu_length);
+        assert_eq!(buf.len(), pdu_length as usize);
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(original_pdu, parsed);
+    }
+
+    #[test]
+    fn appearance_internal_consistency() {
+        let body = Appearance::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 1, 42))
+            .with_force_id(ForceId::Friendly)
+            .with_entity_type(EntityType::default().with_kind(EntityKind::Platform))
+            .with_entity_marking(EntityMarking::new_ascii("LIVE 1"))
+            .with_appearance_visual(0x0040_0001)
+            .build();
+        assert_eq!(body.appearance_flag_1(), 0b0010_1011);
+        assert_eq!(body.appearance_flag_2(), None);
+
+        round_trip(body);
+    }
+
+    #[test]
+    fn appearance_with_flag_2_internal_consistency() {
+        let body = Appearance::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 1, 42))
+            .with_alternate_entity_type(EntityType::default().with_kind(EntityKind::LifeForm))
+            .with_capabilities(0x0000_0003)
+            .with_appearance_ir(1)
+            .with_appearance_audio(2)
+            .build();
+        assert_eq!(body.appearance_flag_1(), 0b1101_0100);
+        assert_eq!(body.appearance_flag_2(), Some(0b0000_0010));
+
+        round_trip(body);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::BodyRaw;
+use crate::appearance::builder::AppearanceBuilder;
+use crate::common::model::{EntityId, EntityType, LiveEntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::entity_state::model::EntityMarking;
+use crate::enumerations::{ForceId, PduType};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_APPEARANCE_BODY_LENGTH: u16 = 5;
+
+pub(crate) const APPEARANCE_FLAG_1_FORCE_ID: u8 = 0x01;
+pub(crate) const APPEARANCE_FLAG_1_ENTITY_TYPE: u8 = 0x02;
+pub(crate) const APPEARANCE_FLAG_1_ALTERNATE_ENTITY_TYPE: u8 = 0x04;
+pub(crate) const APPEARANCE_FLAG_1_ENTITY_MARKING: u8 = 0x08;
+pub(crate) const APPEARANCE_FLAG_1_CAPABILITIES: u8 = 0x10;
+pub(crate) const APPEARANCE_FLAG_1_VISUAL: u8 = 0x20;
+pub(crate) const APPEARANCE_FLAG_1_IR: u8 = 0x40;
+pub(crate) const APPEARANCE_FLAG_1_FLAG_2_INCLUDED: u8 = 0x80;
+pub(crate) const APPEARANCE_FLAG_2_EM: u8 = 0x01;
+pub(crate) const APPEARANCE_FLAG_2_AUDIO: u8 = 0x02;
+
+/// 9.4.3 Appearance PDU
+///
+/// The optional fields are present on the wire when they are `Some`; the Appearance Flag fields are derived from them.
+/// Capabilities and appearances are kept as raw values, because their interpretation depends on the entity type
+/// (see `EntityAppearance::from_bytes` and `entity_capabilities_from_bytes`).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Appearance {
+    pub live_entity_id: LiveEntityId,
+    pub force_id: Option<ForceId>,
+    pub entity_type: Option<EntityType>,
+    pub alternate_entity_type: Option<EntityType>,
+    pub entity_marking: Option<EntityMarking>,
+    pub capabilities: Option<u32>,
+    pub appearance_visual: Option<u32>,
+    pub appearance_ir: Option<u32>,
+    pub appearance_em: Option<u32>,
+    pub appearance_audio: Option<u32>,
+}
+
+impl Appearance {
+    /// Computes the Appearance Flag 1 field from the optional fields that are present.
+    #[must_use]
+    pub fn appearance_flag_1(&self) -> u8 {
+        let mut flag = 0u8;
+        if self.force_id.is_some() {
+            flag |= APPEARANCE_FLAG_1_FORCE_ID;
+        }
+        if self.entity_type.is_some() {
+            flag |= APPEARANCE_FLAG_1_ENTITY_TYPE;
+        }
+        if self.alternate_entity_type.is_some() {
+            flag |= APPEARANCE_FLAG_1_ALTERNATE_ENTITY_TYPE;
+        }
+        if self.entity_marking.is_some() {
+            flag |= APPEARANCE_FLAG_1_ENTITY_MARKING;
+        }
+        if self.capabilities.is_some() {
+            flag |= APPEARANCE_FLAG_1_CAPABILITIES;
+        }
+        if self.appearance_visual.is_some() {
+            flag |= APPEARANCE_FLAG_1_VISUAL;
+        }
+        if self.appearance_ir.is_some() {
+            flag |= APPEARANCE_FLAG_1_IR;
+        }
+        if self.appearance_flag_2().is_some() {
+            flag |= APPEARANCE_FLAG_1_FLAG_2_INCLUDED;
+        }
+        flag
+    }
+
+    /// Computes the Appearance Flag 2 field, which is only present when an EM or audio appearance is included.
+    #[must_use]
+    pub fn appearance_flag_2(&self) -> Option<u8> {
+        let mut flag = 0u8;
+        if self.appearance_em.is_some() {
+            flag |= APPEARANCE_FLAG_2_EM;
+        }
+        if self.appearance_audio.is_some() {
+            flag |= APPEARANCE_FLAG_2_AUDIO;
+        }
+        if flag == 0 { None } else { Some(flag) }
+    }
+}
+
+impl BodyRaw for Appearance {
+    type Builder = AppearanceBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::Appearance(self)
+    }
+}
+
+impl BodyInfo for Appearance {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        let appearances = [
+            self.capabilities,
+            self.appearance_visual,
+            self.appearance_ir,
+            self.appearance_em,
+            self.appearance_audio,
+        ]
+        .iter()
+        .filter(|field| field.is_some())
+        .count() as u16;
+
+        BASE_APPEARANCE_BODY_LENGTH
+            + self.appearance_flag_2().map_or(0, |_| 1)
+            + self.force_id.map_or(0, |_| 1)
+            + self
+                .entity_type
+                .map_or(0, |entity_type| entity_type.record_length())
+            + self
+                .alternate_entity_type
+                .map_or(0, |entity_type| entity_type.record_length())
+            + self
+                .entity_marking
+                .as_ref()
+                .map_or(0, EntityMarking::record_length)
+            + appearances * FOUR_OCTETS as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::Appearance
+    }
+}
+
+/// Live Entity PDUs identify entities by a `LiveEntityId`, which cannot be expressed as an `EntityId`.
+impl Interaction for Appearance {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
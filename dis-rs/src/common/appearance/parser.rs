@@ -0,0 +1,84 @@
+use crate::BodyRaw;
+use crate::appearance::model::{
+    APPEARANCE_FLAG_1_ALTERNATE_ENTITY_TYPE, APPEARANCE_FLAG_1_CAPABILITIES,
+    APPEARANCE_FLAG_1_ENTITY_MARKING, APPEARANCE_FLAG_1_ENTITY_TYPE,
+    APPEARANCE_FLAG_1_FLAG_2_INCLUDED, APPEARANCE_FLAG_1_FORCE_ID, APPEARANCE_FLAG_1_IR,
+    APPEARANCE_FLAG_1_VISUAL, APPEARANCE_FLAG_2_AUDIO, APPEARANCE_FLAG_2_EM, Appearance,
+};
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_type, live_entity_id};
+use crate::entity_state::parser::{entity_marking, force_id};
+use nom::Parser;
+use nom::combinator::cond;
+use nom::number::complete::{be_u8, be_u32};
+
+pub(crate) fn appearance_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id).parse(input)?;
+    let (input, flag_1) = context("Flag 1", be_u8).parse(input)?;
+    let (input, flag_2) = cond(
+        flag_1 & APPEARANCE_FLAG_1_FLAG_2_INCLUDED != 0,
+        context("Flag 2", be_u8),
+    )
+    .parse(input)?;
+    let flag_2 = flag_2.unwrap_or_default();
+    let (input, force_id) = cond(
+        flag_1 & APPEARANCE_FLAG_1_FORCE_ID != 0,
+        context("Force ID", force_id),
+    )
+    .parse(input)?;
+    let (input, primary_entity_type) = cond(
+        flag_1 & APPEARANCE_FLAG_1_ENTITY_TYPE != 0,
+        context("Primary Entity Type", entity_type),
+    )
+    .parse(input)?;
+    let (input, alternate_entity_type) = cond(
+        flag_1 & APPEARANCE_FLAG_1_ALTERNATE_ENTITY_TYPE != 0,
+        context("Alternate Entity Type", entity_type),
+    )
+    .parse(input)?;
+    let (input, entity_marking) = cond(
+        flag_1 & APPEARANCE_FLAG_1_ENTITY_MARKING != 0,
+        context("Entity Marking", entity_marking),
+    )
+    .parse(input)?;
+    let (input, capabilities) = cond(
+        flag_1 & APPEARANCE_FLAG_1_CAPABILITIES != 0,
+        context("Capabilities", be_u32),
+    )
+    .parse(input)?;
+    let (input, appearance_visual) = cond(
+        flag_1 & APPEARANCE_FLAG_1_VISUAL != 0,
+        context("Appearance Visual", be_u32),
+    )
+    .parse(input)?;
+    let (input, appearance_ir) = cond(
+        flag_1 & APPEARANCE_FLAG_1_IR != 0,
+        context("Appearance IR", be_u32),
+    )
+    .parse(input)?;
+    let (input, appearance_em) = cond(
+        flag_2 & APPEARANCE_FLAG_2_EM != 0,
+        context("Appearance EM", be_u32),
+    )
+    .parse(input)?;
+    let (input, appearance_audio) = cond(
+        flag_2 & APPEARANCE_FLAG_2_AUDIO != 0,
+        context("Appearance Audio", be_u32),
+    )
+    .parse(input)?;
+
+    let body = Appearance {
+        live_entity_id,
+        force_id,
+        entity_type: primary_entity_type,
+        alternate_entity_type,
+        entity_marking,
+        capabilities,
+        appearance_visual,
+        appearance_ir,
+        appearance_em,
+        appearance_audio,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
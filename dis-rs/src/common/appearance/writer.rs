@@ -0,0 +1,39 @@
+use crate::appearance::model::Appearance;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for Appearance {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u8(self.appearance_flag_1());
+        if let Some(flag_2) = self.appearance_flag_2() {
+            buf.put_u8(flag_2);
+        }
+        if let Some(force_id) = self.force_id {
+            buf.put_u8(force_id.into());
+        }
+        if let Some(entity_type) = &self.entity_type {
+            entity_type.serialize(buf);
+        }
+        if let Some(alternate_entity_type) = &self.alternate_entity_type {
+            alternate_entity_type.serialize(buf);
+        }
+        if let Some(entity_marking) = &self.entity_marking {
+            entity_marking.serialize(buf);
+        }
+        for value in [
+            self.capabilities,
+            self.appearance_visual,
+            self.appearance_ir,
+            self.appearance_em,
+            self.appearance_audio,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            buf.put_u32(value);
+        }
+
+        self.body_length()
+    }
+}
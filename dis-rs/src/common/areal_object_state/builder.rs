@@ -0,0 +1,106 @@
+use crate::areal_object_state::model::ArealObjectState;
+use crate::common::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType,
+    SimulationAddress,
+};
+use crate::enumerations::ForceId;
+
+pub struct ArealObjectStateBuilder(ArealObjectState);
+
+impl Default for ArealObjectStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArealObjectStateBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        ArealObjectStateBuilder(ArealObjectState::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: ArealObjectState) -> Self {
+        ArealObjectStateBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> ArealObjectState {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_object_id(mut self, object_id: EntityId) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_referenced_object_id(mut self, referenced_object_id: EntityId) -> Self {
+        self.0.referenced_object_id = referenced_object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_update_number(mut self, update_number: u16) -> Self {
+        self.0.update_number = update_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_modifications(mut self, modifications: ObjectStateModification) -> Self {
+        self.0.modifications = modifications;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_type(mut self, object_type: ObjectType) -> Self {
+        self.0.object_type = object_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_specific_object_appearance(mut self, specific_object_appearance: u32) -> Self {
+        self.0.specific_object_appearance = specific_object_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_general_object_appearance(
+        mut self,
+        general_object_appearance: GeneralObjectAppearance,
+    ) -> Self {
+        self.0.general_object_appearance = general_object_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requester_id(mut self, requester_id: SimulationAddress) -> Self {
+        self.0.requester_id = requester_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_receiving_id(mut self, receiving_id: SimulationAddress) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_location(mut self, object_location: Location) -> Self {
+        self.0.object_locations.push(object_location);
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_locations(mut self, object_locations: Vec<Location>) -> Self {
+        self.0.object_locations = object_locations;
+        self
+    }
+}
@@ -0,0 +1,68 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::areal_object_state::model::ArealObjectState;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        AppearanceObjectSpecificBreachState, ForceId, PduType, PlatformDomain,
+    };
+    use crate::model::{
+        BreachableObjectAppearance, EntityId, GeneralObjectAppearance, Location, ObjectKind,
+        ObjectStateModification, ObjectType,
+    };
+    use bytes::BytesMut;
+
+    #[test]
+    fn areal_object_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::ArealObjectState);
+
+        let breach = BreachableObjectAppearance::new(AppearanceObjectSpecificBreachState::Breached);
+        let body = ArealObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 300))
+            .with_update_number(2)
+            .with_force_id(ForceId::Opposing)
+            .with_modifications(ObjectStateModification::new(true, false))
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::Obstacle,
+                1,
+                1,
+            ))
+            .with_specific_object_appearance((&breach).into())
+            .with_general_object_appearance(GeneralObjectAppearance::default())
+            .with_object_location(Location::new(0.0, 0.0, 0.0))
+            .with_object_location(Location::new(100.0, 0.0, 0.0))
+            .with_object_location(Location::new(100.0, 100.0, 0.0))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        assert_eq!(pdu_length, 48 + 3 * 24);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let _ = original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(original_pdu, parsed);
+
+        if let crate::common::model::PduBody::ArealObjectState(body) = parsed.body {
+            assert_eq!(
+                BreachableObjectAppearance::from(body.specific_object_appearance),
+                breach
+            );
+        } else {
+            panic!("Expected an Areal Object State PDU");
+        }
+    }
+}
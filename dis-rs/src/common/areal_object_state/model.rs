@@ -0,0 +1,74 @@
+use crate::BodyRaw;
+use crate::areal_object_state::builder::ArealObjectStateBuilder;
+use crate::common::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, PduBody,
+    SimulationAddress,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::TWENTY_FOUR_OCTETS;
+use crate::enumerations::{ForceId, PduType};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_AREAL_OBJECT_STATE_BODY_LENGTH: u16 = 36;
+
+/// 5.11.3.4 Areal Object State PDU
+///
+/// 7.10.6 Areal Object State PDU
+///
+/// Only the `is_location_modified` flag of the `modifications` field applies to areal objects.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArealObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    /// The object specific part of the Object Appearance, which is interpreted according to the `object_type`.
+    /// See `BreachableObjectAppearance`.
+    pub specific_object_appearance: u32,
+    pub general_object_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_locations: Vec<Location>,
+}
+
+impl BodyRaw for ArealObjectState {
+    type Builder = ArealObjectStateBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::ArealObjectState(self)
+    }
+}
+
+impl BodyInfo for ArealObjectState {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        BASE_AREAL_OBJECT_STATE_BODY_LENGTH
+            + (self.object_locations.len() * TWENTY_FOUR_OCTETS) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArealObjectState
+    }
+}
+
+impl Interaction for ArealObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
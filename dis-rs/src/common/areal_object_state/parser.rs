@@ -0,0 +1,49 @@
+use crate::BodyRaw;
+use crate::areal_object_state::model::ArealObjectState;
+use crate::common::model::{GeneralObjectAppearance, ObjectStateModification, PduBody};
+use crate::common::parser::{
+    IResult, context, entity_id, location, object_type, simulation_address,
+};
+use crate::enumerations::ForceId;
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+pub(crate) fn areal_object_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, object_id) = context("Object ID", entity_id).parse(input)?;
+    let (input, referenced_object_id) = context("Referenced Object ID", entity_id).parse(input)?;
+    let (input, update_number) = context("Update Number", be_u16).parse(input)?;
+    let (input, force_id) = context("Force ID", be_u8).parse(input)?;
+    let force_id = ForceId::from(force_id);
+    let (input, modifications) = context("Modifications", be_u8).parse(input)?;
+    let modifications = ObjectStateModification::from(modifications);
+    let (input, object_type) = context("Object Type", object_type).parse(input)?;
+    let (input, specific_object_appearance) =
+        context("Specific Object Appearance", be_u32).parse(input)?;
+    let (input, general_object_appearance) =
+        context("General Object Appearance", be_u16).parse(input)?;
+    let general_object_appearance = GeneralObjectAppearance::from(general_object_appearance);
+    let (input, number_of_points) = context("Number of Points", be_u16).parse(input)?;
+    let (input, requester_id) = context("Requester ID", simulation_address).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", simulation_address).parse(input)?;
+    let (input, object_locations) =
+        count(context("Location", location), number_of_points.into()).parse(input)?;
+
+    Ok((
+        input,
+        ArealObjectState::builder()
+            .with_object_id(object_id)
+            .with_referenced_object_id(referenced_object_id)
+            .with_update_number(update_number)
+            .with_force_id(force_id)
+            .with_modifications(modifications)
+            .with_object_type(object_type)
+            .with_specific_object_appearance(specific_object_appearance)
+            .with_general_object_appearance(general_object_appearance)
+            .with_requester_id(requester_id)
+            .with_receiving_id(receiving_id)
+            .with_object_locations(object_locations)
+            .build()
+            .into_pdu_body(),
+    ))
+}
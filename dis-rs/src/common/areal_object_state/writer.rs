@@ -0,0 +1,26 @@
+use crate::areal_object_state::model::ArealObjectState;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for ArealObjectState {
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8((&self.modifications).into());
+        self.object_type.serialize(buf);
+        buf.put_u32(self.specific_object_appearance);
+        buf.put_u16((&self.general_object_appearance).into());
+        buf.put_u16(self.object_locations.len() as u16);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        self.object_locations
+            .iter()
+            .map(|location| location.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::common::model::{LiveEntityId, VariableParameter};
+
+pub struct ArticulatedPartsBuilder(ArticulatedParts);
+
+impl Default for ArticulatedPartsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArticulatedPartsBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        ArticulatedPartsBuilder(ArticulatedParts::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: ArticulatedParts) -> Self {
+        ArticulatedPartsBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> ArticulatedParts {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_variable_parameter(mut self, parameter: VariableParameter) -> Self {
+        self.0.variable_parameters.push(parameter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_variable_parameters(mut self, parameters: Vec<VariableParameter>) -> Self {
+        self.0.variable_parameters = parameters;
+        self
+    }
+}
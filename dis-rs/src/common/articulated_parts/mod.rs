@@ -0,0 +1,69 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::articulated_parts::model::ArticulatedParts;
+    use crate::common::model::{
+        ArticulatedPart, LiveEntityId, Pdu, PduHeader, TimeUnits, Timestamp, VariableParameter,
+    };
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, CoupledExtensionIndicator,
+        LvcIndicator, PduType,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn articulated_parts_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::ArticulatedParts).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = ArticulatedParts::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 1, 42))
+            .with_variable_parameter(VariableParameter::Articulated(ArticulatedPart {
+                change_indicator: 1,
+                attachment_id: 0,
+                type_class: ArticulatedPartsTypeClass::PrimaryTurretNumber1,
+                type_metric: ArticulatedPartsTypeMetric::Azimuth,
+                parameter_value: 1.5,
+            }))
+            .with_variable_parameter(VariableParameter::Articulated(ArticulatedPart {
+                change_indicator: 1,
+                attachment_id: 1,
+                type_class: ArticulatedPartsTypeClass::PrimaryGunNumber1,
+                type_metric: ArticulatedPartsTypeMetric::Elevation,
+                parameter_value: 0.25,
+            }))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        assert_eq!(pdu_length, 12 + 5 + 2 * 16);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let _ = original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::BodyRaw;
+use crate::articulated_parts::builder::ArticulatedPartsBuilder;
+use crate::common::model::{EntityId, LiveEntityId, PduBody, VariableParameter};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
+use crate::enumerations::PduType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_ARTICULATED_PARTS_BODY_LENGTH: u16 = 5;
+
+/// 9.4.4 Articulated Parts PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArticulatedParts {
+    pub live_entity_id: LiveEntityId,
+    pub variable_parameters: Vec<VariableParameter>,
+}
+
+impl BodyRaw for ArticulatedParts {
+    type Builder = ArticulatedPartsBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::ArticulatedParts(self)
+    }
+}
+
+impl BodyInfo for ArticulatedParts {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        BASE_ARTICULATED_PARTS_BODY_LENGTH
+            + self.variable_parameters.len() as u16 * VARIABLE_PARAMETER_RECORD_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArticulatedParts
+    }
+}
+
+/// Live Entity PDUs identify entities by a `LiveEntityId`, which cannot be expressed as an `EntityId`.
+impl Interaction for ArticulatedParts {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,27 @@
+use crate::BodyRaw;
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, live_entity_id, variable_parameter};
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+
+pub(crate) fn articulated_parts_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id).parse(input)?;
+    let (input, number_of_variable_parameters) =
+        context("Number of Variable Parameters", be_u8).parse(input)?;
+    let (input, variable_parameters) = count(
+        context("Variable Parameter", variable_parameter),
+        number_of_variable_parameters.into(),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        ArticulatedParts::builder()
+            .with_live_entity_id(live_entity_id)
+            .with_variable_parameters(variable_parameters)
+            .build()
+            .into_pdu_body(),
+    ))
+}
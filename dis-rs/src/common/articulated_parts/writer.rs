@@ -0,0 +1,16 @@
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for ArticulatedParts {
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u8(self.variable_parameters.len() as u8);
+        for parameter in &self.variable_parameters {
+            parameter.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
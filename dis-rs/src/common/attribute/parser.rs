@@ -3,27 +3,35 @@
     Attribute, AttributeRecord, AttributeRecordSet, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS,
 };
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, pdu_type, protocol_version, simulation_address};
+use crate::common::parser::{
+    IResult, context, entity_id, pdu_type, protocol_version, simulation_address,
+};
 use crate::enumerations::{AttributeActionCode, VariableRecordType};
+use nom::Parser;
 use nom::bytes::complete::take;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn attribute_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, origination_simulation_address) = simulation_address(input)?;
-    let (input, _padding) = be_u32(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, record_pdu_type) = pdu_type(input)?;
-    let (input, record_protocol_version) = protocol_version(input)?;
-    let (input, master_attribute_record_type) = be_u32(input)?;
+    let (input, origination_simulation_address) =
+        context("Origination Simulation Address", simulation_address).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, record_pdu_type) = context("Record PDU Type", pdu_type).parse(input)?;
+    let (input, record_protocol_version) =
+        context("Record Protocol Version", protocol_version).parse(input)?;
+    let (input, master_attribute_record_type) =
+        context("Master Attribute Record Type", be_u32).parse(input)?;
     let master_attribute_record_type = VariableRecordType::from(master_attribute_record_type);
-    let (input, action_code) = be_u8(input)?;
+    let (input, action_code) = context("Action Code", be_u8).parse(input)?;
     let action_code = AttributeActionCode::from(action_code);
-    let (input, _padding) = be_u8(input)?;
-    let (input, number_of_record_sets) = be_u16(input)?;
-    let (input, attribute_record_sets) =
-        count(attribute_record_set, number_of_record_sets.into()).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, number_of_record_sets) = context("Number of Record Sets", be_u16).parse(input)?;
+    let (input, attribute_record_sets) = count(
+        context("Attribute Record Set", attribute_record_set),
+        number_of_record_sets.into(),
+    )
+    .parse(input)?;
 
     let body = Attribute::builder()
         .with_originating_simulation_address(origination_simulation_address)
@@ -38,10 +46,13 @@ pub(crate) fn attribute_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 pub(crate) fn attribute_record_set(input: &[u8]) -> IResult<&[u8], AttributeRecordSet> {
-    let (input, entity_id) = entity_id(input)?;
-    let (input, number_of_records) = be_u16(input)?;
-    let (input, attribute_records) =
-        count(attribute_record, number_of_records.into()).parse(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, number_of_records) = context("Number of Records", be_u16).parse(input)?;
+    let (input, attribute_records) = count(
+        context("Attribute Record", attribute_record),
+        number_of_records.into(),
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -52,11 +63,16 @@ pub(crate) fn attribute_record_set(input: &[u8]) -> IResult<&[u8], AttributeReco
 }
 
 pub(crate) fn attribute_record(input: &[u8]) -> IResult<&[u8], AttributeRecord> {
-    let (input, record_type) = be_u32(input)?;
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
     let record_type = VariableRecordType::from(record_type);
-    let (input, record_length_octets) = be_u16(input)?;
-    let (input, fields) =
-        take(record_length_octets.saturating_sub(BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS))(input)?;
+    let (input, record_length_octets) = context("Record Length Octets", be_u16).parse(input)?;
+    let (input, fields) = context(
+        "Fields",
+        take::<_, &[u8], _>(
+            record_length_octets.saturating_sub(BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS),
+        ),
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -1,21 +1,21 @@
 use crate::BodyRaw;
 use crate::common::collision::model::Collision;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, event_id, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, event_id, vec3_f32};
 use crate::enumerations::CollisionType;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_f32, be_u8};
 
 pub(crate) fn collision_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, issuing_entity_id) = entity_id(input)?;
-    let (input, colliding_entity_id) = entity_id(input)?;
-    let (input, event_id) = event_id(input)?;
-    let (input, collision_type) = be_u8(input)?;
+    let (input, issuing_entity_id) = context("Issuing Entity ID", entity_id).parse(input)?;
+    let (input, colliding_entity_id) = context("Colliding Entity ID", entity_id).parse(input)?;
+    let (input, event_id) = context("Event ID", event_id).parse(input)?;
+    let (input, collision_type) = context("Collision Type", be_u8).parse(input)?;
     let collision_type = CollisionType::from(collision_type);
-    let (input, _padding) = be_u8(input)?;
-    let (input, velocity) = vec3_f32(input)?;
-    let (input, mass) = be_f32(input)?;
-    let (input, location) = vec3_f32(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, mass) = context("Mass", be_f32).parse(input)?;
+    let (input, location) = context("Location", vec3_f32).parse(input)?;
 
     let body = Collision::builder()
         .with_issuing_entity_id(issuing_entity_id)
@@ -1,27 +1,28 @@
 use crate::BodyRaw;
 use crate::common::collision_elastic::model::CollisionElastic;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, event_id, vec3_f32};
-use nom::IResult;
+use crate::common::parser::{IResult, context, entity_id, event_id, vec3_f32};
+use nom::Parser;
 use nom::number::complete::{be_f32, be_u16};
 
 #[allow(clippy::similar_names)]
 pub(crate) fn collision_elastic_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, issuing_entity_id) = entity_id(input)?;
-    let (input, colliding_entity_id) = entity_id(input)?;
-    let (input, event_id) = event_id(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, velocity) = vec3_f32(input)?;
-    let (input, mass) = be_f32(input)?;
-    let (input, location) = vec3_f32(input)?;
-    let (input, intermediate_result_xx) = be_f32(input)?;
-    let (input, intermediate_result_xy) = be_f32(input)?;
-    let (input, intermediate_result_xz) = be_f32(input)?;
-    let (input, intermediate_result_yy) = be_f32(input)?;
-    let (input, intermediate_result_yz) = be_f32(input)?;
-    let (input, intermediate_result_zz) = be_f32(input)?;
-    let (input, unit_surface_normal) = vec3_f32(input)?;
-    let (input, coefficient_of_restitution) = be_f32(input)?;
+    let (input, issuing_entity_id) = context("Issuing Entity ID", entity_id).parse(input)?;
+    let (input, colliding_entity_id) = context("Colliding Entity ID", entity_id).parse(input)?;
+    let (input, event_id) = context("Event ID", event_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, mass) = context("Mass", be_f32).parse(input)?;
+    let (input, location) = context("Location", vec3_f32).parse(input)?;
+    let (input, intermediate_result_xx) = context("Intermediate Result XX", be_f32).parse(input)?;
+    let (input, intermediate_result_xy) = context("Intermediate Result XY", be_f32).parse(input)?;
+    let (input, intermediate_result_xz) = context("Intermediate Result XZ", be_f32).parse(input)?;
+    let (input, intermediate_result_yy) = context("Intermediate Result YY", be_f32).parse(input)?;
+    let (input, intermediate_result_yz) = context("Intermediate Result YZ", be_f32).parse(input)?;
+    let (input, intermediate_result_zz) = context("Intermediate Result ZZ", be_f32).parse(input)?;
+    let (input, unit_surface_normal) = context("Unit Surface Normal", vec3_f32).parse(input)?;
+    let (input, coefficient_of_restitution) =
+        context("Coefficient of Restitution", be_f32).parse(input)?;
 
     let body = CollisionElastic::builder()
         .with_issuing_entity_id(issuing_entity_id)
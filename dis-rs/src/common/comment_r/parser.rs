@@ -1,13 +1,13 @@
 use crate::BodyRaw;
 use crate::comment_r::model::CommentR;
 use crate::common::model::PduBody;
-use crate::common::parser::{datum_specification, entity_id};
-use nom::IResult;
+use crate::common::parser::{IResult, context, datum_specification, entity_id};
+use nom::Parser;
 
 pub(crate) fn comment_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification).parse(input)?;
 
     let body = CommentR::builder()
         .with_origination_id(originating_id)
@@ -0,0 +1,431 @@
+//! Conversion of PDUs between DIS v6 (IEEE 1278.1a-1998) and DIS v7 (IEEE 1278.1-2012).
+use crate::common::BodyInfo;
+use crate::common::detonation::model::DetonationDescriptor;
+use crate::common::entity_state::model::EntityAppearance;
+use crate::common::errors::DisError;
+use crate::common::fire::model::FireDescriptor;
+use crate::common::model::{EntityType, Pdu, PduBody};
+use crate::common::validation::{LAST_V6_PDU_TYPE, v7_only_variable_parameter};
+use crate::enumerations::{
+    DetonationTypeIndicator, EntityCapabilities, FireTypeIndicator, PduType, ProtocolVersion,
+};
+use crate::v6::entity_state::model::EntityCapabilities as EntityCapabilitiesV6;
+use crate::v7::entity_state::entity_capabilities_from_bytes;
+use crate::v7::model::PduStatus;
+use crate::v7::parser::parse_pdu_status_fields;
+
+impl Pdu {
+    /// Converts the PDU to the given protocol version, either DIS v6 or DIS v7.
+    ///
+    /// Converting to v7 adds a `PduStatus` record to the header, with the indicators that
+    /// can be derived from the body (such as the Fire and Detonation Type Indicators), and
+    /// reinterprets the Entity Capabilities for the kind of entity.
+    /// Converting to v6 strips the `PduStatus` record, which only holds indicators about the
+    /// PDU that v6 does not define, and reduces the Entity Capabilities to the fields defined in v6.
+    /// In both directions the Entity Appearance is reinterpreted for the entity type.
+    ///
+    /// # Errors
+    /// Returns `DisError::UnsupportedProtocolVersion` when the target version is not v6 or v7,
+    /// and `DisError::IncompatibleProtocolVersion` when the PDU type or one of its records
+    /// cannot be represented in v6, or when Entity Capabilities that are set cannot be
+    /// represented in the target version.
+    pub fn to_version(&self, version: ProtocolVersion) -> Result<Pdu, DisError> {
+        match version {
+            ProtocolVersion::IEEE1278_1A1998 => self.to_v6(),
+            ProtocolVersion::IEEE1278_12012 => self.to_v7(),
+            _ => Err(DisError::UnsupportedProtocolVersion(version)),
+        }
+    }
+
+    fn to_v6(&self) -> Result<Pdu, DisError> {
+        if let Some(field) = v7_only_record(self) {
+            return Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_1A1998,
+                field,
+            });
+        }
+
+        let mut pdu = self.clone();
+        pdu.header.protocol_version = ProtocolVersion::IEEE1278_1A1998;
+        pdu.header.pdu_status = None;
+        pdu.header.padding = 0;
+        convert_body(&mut pdu.body, ProtocolVersion::IEEE1278_1A1998)?;
+        Ok(pdu)
+    }
+
+    fn to_v7(&self) -> Result<Pdu, DisError> {
+        let mut pdu = self.clone();
+        if pdu.header.protocol_version != ProtocolVersion::IEEE1278_12012 {
+            pdu.header.protocol_version = ProtocolVersion::IEEE1278_12012;
+            pdu.header.pdu_status = Some(pdu_status(&pdu.body));
+            pdu.header.padding = 0;
+            convert_body(&mut pdu.body, ProtocolVersion::IEEE1278_12012)?;
+        }
+        Ok(pdu)
+    }
+}
+
+/// Returns the name of the first PDU type or record of the PDU that is only defined in DIS v7.
+fn v7_only_record(pdu: &Pdu) -> Option<&'static str> {
+    if !matches!(pdu.header.pdu_type, PduType::Unspecified(_))
+        && u8::from(pdu.header.pdu_type) > LAST_V6_PDU_TYPE
+    {
+        return Some("PDU Type");
+    }
+    let variable_parameters = match &pdu.body {
+        PduBody::EntityState(body) => body.variable_parameters.as_slice(),
+        PduBody::EntityStateUpdate(body) => body.variable_parameters.as_slice(),
+        PduBody::Fire(body) => {
+            return match body.descriptor {
+                FireDescriptor::Munition(_) => None,
+                FireDescriptor::Expendable(_) => Some("Expendable Descriptor"),
+            };
+        }
+        PduBody::Detonation(body) => match body.descriptor {
+            DetonationDescriptor::Munition(_) => body.variable_parameters.as_slice(),
+            DetonationDescriptor::Expendable(_) => return Some("Expendable Descriptor"),
+            DetonationDescriptor::Explosion(_) => return Some("Explosion Descriptor"),
+        },
+        _ => return None,
+    };
+    variable_parameters
+        .iter()
+        .find_map(v7_only_variable_parameter)
+}
+
+/// Builds the `PduStatus` record for a body converted to v7, with the indicators
+/// that are applicable to the PDU type, and values derived from the body where possible.
+fn pdu_status(body: &PduBody) -> PduStatus {
+    let status = parse_pdu_status_fields(u8::from(body.body_type()), 0);
+    match body {
+        PduBody::Fire(fire) => status.with_fire_type_indicator(match fire.descriptor {
+            FireDescriptor::Munition(_) => FireTypeIndicator::Munition,
+            FireDescriptor::Expendable(_) => FireTypeIndicator::Expendable,
+        }),
+        PduBody::Detonation(detonation) => {
+            status.with_detonation_type_indicator(match detonation.descriptor {
+                DetonationDescriptor::Munition(_) => DetonationTypeIndicator::Munition,
+                DetonationDescriptor::Expendable(_) => DetonationTypeIndicator::Expendable,
+                DetonationDescriptor::Explosion(_) => DetonationTypeIndicator::NonmunitionExplosion,
+            })
+        }
+        _ => status,
+    }
+}
+
+/// Reinterprets the version dependent records of an Entity State body.
+///
+/// The Entity State Update PDU does not carry the entity type, so its appearance is left as is.
+fn convert_body(body: &mut PduBody, version: ProtocolVersion) -> Result<(), DisError> {
+    if let PduBody::EntityState(body) = body {
+        body.entity_capabilities =
+            convert_capabilities(body.entity_capabilities, &body.entity_type, version)?;
+        body.entity_appearance = convert_appearance(&body.entity_appearance, &body.entity_type);
+    }
+    Ok(())
+}
+
+/// Converts the Entity Capabilities to the form used in the target version.
+///
+/// DIS v6 defines a single set of capabilities for all entity kinds, which this crate models as
+/// `LandPlatformEntityCapabilities`. DIS v7 defines capabilities per kind (and domain) of entity,
+/// of which only some contain the v6 fields.
+///
+/// # Errors
+/// Returns `DisError::IncompatibleProtocolVersion` when a capability that is set would be lost.
+fn convert_capabilities(
+    capabilities: EntityCapabilities,
+    entity_type: &EntityType,
+    version: ProtocolVersion,
+) -> Result<EntityCapabilities, DisError> {
+    let v6 = EntityCapabilitiesV6::from(capabilities);
+    let (converted, lossless) = if version == ProtocolVersion::IEEE1278_1A1998 {
+        let converted = EntityCapabilities::from(v6);
+        (converted, u32::from(converted) == u32::from(capabilities))
+    } else {
+        let converted =
+            entity_capabilities_from_bytes(u32::from(EntityCapabilities::from(v6)), entity_type);
+        (
+            converted,
+            EntityCapabilitiesV6::from(converted) == EntityCapabilitiesV6::from(capabilities),
+        )
+    };
+    if lossless {
+        Ok(converted)
+    } else {
+        Err(DisError::IncompatibleProtocolVersion {
+            version,
+            field: "Entity Capabilities",
+        })
+    }
+}
+
+fn convert_appearance(appearance: &EntityAppearance, entity_type: &EntityType) -> EntityAppearance {
+    EntityAppearance::from_bytes(u32::from(appearance), entity_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::directed_energy_fire::model::DirectedEnergyFire;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::fire::model::Fire;
+    use crate::common::model::{ExpendableDescriptor, PduHeader, TimeUnits, Timestamp};
+    use crate::common::model::{SeparationParameter, VariableParameter};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{EntityKind, LvcIndicator, PlatformDomain};
+    use bytes::BytesMut;
+
+    fn pdu(header: PduHeader, body: PduBody) -> Pdu {
+        Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        )
+    }
+
+    fn air_platform() -> EntityType {
+        EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Air)
+    }
+
+    #[test]
+    fn entity_state_v6_to_v7() {
+        let entity_type = air_platform();
+        let pdu = pdu(
+            PduHeader::new_v6(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(entity_type)
+                .with_appearance(EntityAppearance::from_bytes(0, &entity_type))
+                .build()
+                .into_pdu_body(),
+        );
+
+        let converted = pdu.to_version(ProtocolVersion::IEEE1278_12012).unwrap();
+
+        assert_eq!(
+            converted.header.protocol_version,
+            ProtocolVersion::IEEE1278_12012
+        );
+        assert!(converted.header.pdu_status.is_some());
+        let PduBody::EntityState(body) = converted.body else {
+            panic!("expected an Entity State body");
+        };
+        assert!(matches!(
+            body.entity_capabilities,
+            EntityCapabilities::AirPlatformEntityCapabilities(_)
+        ));
+        assert!(matches!(
+            body.entity_appearance,
+            EntityAppearance::AirPlatform(_)
+        ));
+    }
+
+    #[test]
+    fn entity_state_v7_to_v6() {
+        let pdu = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(air_platform())
+                .build()
+                .into_pdu_body(),
+        );
+
+        let converted = pdu.to_version(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert!(converted.header.pdu_status.is_none());
+
+        let mut buf = BytesMut::with_capacity(1024);
+        converted.serialize(&mut buf).unwrap();
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(
+            parsed.header.protocol_version,
+            ProtocolVersion::IEEE1278_1A1998
+        );
+        assert_eq!(parsed.body, converted.body);
+    }
+
+    #[test]
+    fn fire_v6_to_v7_sets_fire_type_indicator() {
+        let pdu = pdu(
+            PduHeader::new_v6(1, PduType::Fire),
+            Fire::builder().build().into_pdu_body(),
+        );
+
+        let converted = pdu.to_version(ProtocolVersion::IEEE1278_12012).unwrap();
+
+        assert_eq!(
+            converted.header.pdu_status.unwrap().fire_type_indicator,
+            Some(FireTypeIndicator::Munition)
+        );
+    }
+
+    #[test]
+    fn v7_only_records_to_v6() {
+        let fire = pdu(
+            PduHeader::new_v7(1, PduType::Fire),
+            Fire::builder()
+                .with_descriptor(FireDescriptor::Expendable(ExpendableDescriptor::default()))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_eq!(
+            fire.to_version(ProtocolVersion::IEEE1278_1A1998),
+            Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_1A1998,
+                field: "Expendable Descriptor",
+            })
+        );
+
+        let directed_energy_fire = pdu(
+            PduHeader::new_v7(1, PduType::DirectedEnergyFire),
+            DirectedEnergyFire::builder().build().into_pdu_body(),
+        );
+        assert_eq!(
+            directed_energy_fire.to_version(ProtocolVersion::IEEE1278_1A1998),
+            Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_1A1998,
+                field: "PDU Type",
+            })
+        );
+    }
+
+    #[test]
+    fn v7_only_variable_parameter_to_v6() {
+        let pdu = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_variable_parameter(VariableParameter::Separation(
+                    SeparationParameter::default(),
+                ))
+                .build()
+                .into_pdu_body(),
+        );
+
+        assert_eq!(
+            pdu.to_version(ProtocolVersion::IEEE1278_1A1998),
+            Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_1A1998,
+                field: "Separation Variable Parameter",
+            })
+        );
+    }
+
+    #[test]
+    fn status_indicators_are_stripped_to_v6() {
+        let status = parse_pdu_status_fields(u8::from(PduType::EntityState), 0)
+            .with_lvc_indicator(LvcIndicator::Live);
+        let pdu = pdu(
+            PduHeader::new_v7(1, PduType::EntityState).with_pdu_status(status),
+            EntityState::builder().build().into_pdu_body(),
+        );
+
+        let converted = pdu.to_version(ProtocolVersion::IEEE1278_1A1998).unwrap();
+
+        assert!(converted.header.pdu_status.is_none());
+        assert_eq!(converted.header.padding, 0);
+    }
+
+    #[test]
+    fn capabilities_lost_to_v6() {
+        let munition = EntityType::default().with_kind(EntityKind::Munition);
+        let capabilities = entity_capabilities_from_bytes(0b1, &munition);
+        let set = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(munition)
+                .with_capabilities(capabilities)
+                .build()
+                .into_pdu_body(),
+        );
+
+        assert_eq!(
+            set.to_version(ProtocolVersion::IEEE1278_1A1998),
+            Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_1A1998,
+                field: "Entity Capabilities",
+            })
+        );
+
+        let unset = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(munition)
+                .with_capabilities(entity_capabilities_from_bytes(0, &munition))
+                .build()
+                .into_pdu_body(),
+        );
+        assert!(unset.to_version(ProtocolVersion::IEEE1278_1A1998).is_ok());
+    }
+
+    #[test]
+    fn capabilities_lost_to_v7() {
+        let munition = EntityType::default().with_kind(EntityKind::Munition);
+        let pdu = pdu(
+            PduHeader::new_v6(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(munition)
+                .with_capabilities(EntityCapabilities::from(
+                    EntityCapabilitiesV6::default().fuel_supply(),
+                ))
+                .build()
+                .into_pdu_body(),
+        );
+
+        assert_eq!(
+            pdu.to_version(ProtocolVersion::IEEE1278_12012),
+            Err(DisError::IncompatibleProtocolVersion {
+                version: ProtocolVersion::IEEE1278_12012,
+                field: "Entity Capabilities",
+            })
+        );
+    }
+
+    #[test]
+    fn capabilities_kept_between_versions() {
+        let supply = EntityType::default().with_kind(EntityKind::Supply);
+        let pdu = pdu(
+            PduHeader::new_v6(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(supply)
+                .with_capabilities(EntityCapabilities::from(
+                    EntityCapabilitiesV6::default().fuel_supply(),
+                ))
+                .build()
+                .into_pdu_body(),
+        );
+
+        let v7 = pdu.to_version(ProtocolVersion::IEEE1278_12012).unwrap();
+        let PduBody::EntityState(body) = &v7.body else {
+            panic!("expected an Entity State body");
+        };
+        let EntityCapabilities::SupplyEntityCapabilities(capabilities) = body.entity_capabilities
+        else {
+            panic!("expected Supply capabilities");
+        };
+        assert!(capabilities.fuel_supply);
+
+        let v6 = v7.to_version(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        let (PduBody::EntityState(v6), PduBody::EntityState(original)) = (&v6.body, &pdu.body)
+        else {
+            panic!("expected Entity State bodies");
+        };
+        assert_eq!(v6.entity_capabilities, original.entity_capabilities);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let pdu = pdu(
+            PduHeader::new_v7(1, PduType::Fire),
+            Fire::builder().build().into_pdu_body(),
+        );
+
+        assert_eq!(
+            pdu.to_version(ProtocolVersion::IEEE1278_11995),
+            Err(DisError::UnsupportedProtocolVersion(
+                ProtocolVersion::IEEE1278_11995
+            ))
+        );
+    }
+}
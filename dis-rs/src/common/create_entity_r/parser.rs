@@ -1,20 +1,21 @@
 use crate::BodyRaw;
 use crate::common::create_entity_r::model::CreateEntityR;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::enumerations::RequiredReliabilityService;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u8, be_u16, be_u32};
 
 pub(crate) fn create_entity_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, required_reliability_service) = be_u8(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, required_reliability_service) =
+        context("Required Reliability Service", be_u8).parse(input)?;
     let required_reliability_service =
         RequiredReliabilityService::from(required_reliability_service);
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
 
     let body = CreateEntityR::builder()
         .with_origination_id(originating_id)
@@ -1,27 +1,35 @@
 use crate::BodyRaw;
 use crate::common::data_query::model::DataQuery;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, timestamp};
+use crate::common::parser::{IResult, context, entity_id, timestamp};
 use crate::enumerations::VariableRecordType;
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::be_u32;
-use nom::{IResult, Parser};
 
 pub(crate) fn data_query_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, time_interval) = timestamp(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, time_interval) = context("Time Interval", timestamp).parse(input)?;
 
-    let (input, num_of_fixed_datums) = be_u32(input)?;
-    let (input, num_of_variable_datums) = be_u32(input)?;
-    let (input, fixed_datum_ids) = count(be_u32, num_of_fixed_datums as usize).parse(input)?;
+    let (input, num_of_fixed_datums) = context("Number of Fixed Datums", be_u32).parse(input)?;
+    let (input, num_of_variable_datums) =
+        context("Number of Variable Datums", be_u32).parse(input)?;
+    let (input, fixed_datum_ids) = count(
+        context("Fixed Datum ID", be_u32),
+        num_of_fixed_datums as usize,
+    )
+    .parse(input)?;
     let fixed_datum_ids = fixed_datum_ids
         .iter()
         .map(|id| VariableRecordType::from(*id))
         .collect();
-    let (input, variable_datum_ids) =
-        count(be_u32, num_of_variable_datums as usize).parse(input)?;
+    let (input, variable_datum_ids) = count(
+        context("Variable Datum ID", be_u32),
+        num_of_variable_datums as usize,
+    )
+    .parse(input)?;
     let variable_datum_ids = variable_datum_ids
         .iter()
         .map(|id| VariableRecordType::from(*id))
@@ -1,32 +1,41 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::data_query_r::model::DataQueryR;
 use crate::enumerations::{RequiredReliabilityService, VariableRecordType};
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn data_query_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, required_reliability_service) = be_u8(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, required_reliability_service) =
+        context("Required Reliability Service", be_u8).parse(input)?;
     let required_reliability_service =
         RequiredReliabilityService::from(required_reliability_service);
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, time_interval) = be_u32(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, time_interval) = context("Time Interval", be_u32).parse(input)?;
 
-    let (input, num_of_fixed_datums) = be_u32(input)?;
-    let (input, num_of_variable_datums) = be_u32(input)?;
-    let (input, fixed_datum_ids) = count(be_u32, num_of_fixed_datums as usize).parse(input)?;
+    let (input, num_of_fixed_datums) = context("Number of Fixed Datums", be_u32).parse(input)?;
+    let (input, num_of_variable_datums) =
+        context("Number of Variable Datums", be_u32).parse(input)?;
+    let (input, fixed_datum_ids) = count(
+        context("Fixed Datum ID", be_u32),
+        num_of_fixed_datums as usize,
+    )
+    .parse(input)?;
     let fixed_datum_ids = fixed_datum_ids
         .iter()
         .map(|id| VariableRecordType::from(*id))
         .collect();
-    let (input, variable_datum_ids) =
-        count(be_u32, num_of_variable_datums as usize).parse(input)?;
+    let (input, variable_datum_ids) = count(
+        context("Variable Datum ID", be_u32),
+        num_of_variable_datums as usize,
+    )
+    .parse(input)?;
     let variable_datum_ids = variable_datum_ids
         .iter()
         .map(|id| VariableRecordType::from(*id))
@@ -1,27 +1,30 @@
 use crate::BodyRaw;
 use crate::common::designator::model::Designator;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, location, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, location, vec3_f32};
 use crate::enumerations::{DeadReckoningAlgorithm, DesignatorCode, DesignatorSystemName};
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_f32, be_u8, be_u16};
 
 pub(crate) fn designator_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, designating_entity_id) = entity_id(input)?;
-    let (input, system_name) = be_u16(input)?;
+    let (input, designating_entity_id) =
+        context("Designating Entity ID", entity_id).parse(input)?;
+    let (input, system_name) = context("System Name", be_u16).parse(input)?;
     let system_name = DesignatorSystemName::from(system_name);
-    let (input, designated_entity_id) = entity_id(input)?;
-    let (input, code) = be_u16(input)?;
+    let (input, designated_entity_id) = context("Designated Entity ID", entity_id).parse(input)?;
+    let (input, code) = context("Code", be_u16).parse(input)?;
     let code = DesignatorCode::from(code);
-    let (input, power) = be_f32(input)?;
-    let (input, wavelength) = be_f32(input)?;
-    let (input, spot_wrt_designated_entity) = vec3_f32(input)?;
-    let (input, spot_location) = location(input)?;
-    let (input, dead_reckoning_algorithm) = be_u8(input)?;
+    let (input, power) = context("Power", be_f32).parse(input)?;
+    let (input, wavelength) = context("Wavelength", be_f32).parse(input)?;
+    let (input, spot_wrt_designated_entity) =
+        context("Spot WRT Designated Entity", vec3_f32).parse(input)?;
+    let (input, spot_location) = context("Spot Location", location).parse(input)?;
+    let (input, dead_reckoning_algorithm) =
+        context("Dead Reckoning Algorithm", be_u8).parse(input)?;
     let dead_reckoning_algorithm = DeadReckoningAlgorithm::from(dead_reckoning_algorithm);
-    let (input, _padding_8) = be_u8(input)?;
-    let (input, _padding_16) = be_u16(input)?;
-    let (input, linear_acceleration) = vec3_f32(input)?;
+    let (input, _padding_8) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding_16) = context("Padding", be_u16).parse(input)?;
+    let (input, linear_acceleration) = context("Linear Acceleration", vec3_f32).parse(input)?;
 
     let body = Designator::builder()
         .with_designating_entity_id(designating_entity_id)
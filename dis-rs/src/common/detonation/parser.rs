@@ -2,8 +2,8 @@
 use crate::common::detonation::model::{Detonation, DetonationDescriptor};
 use crate::common::model::{PduBody, PduHeader};
 use crate::common::parser;
+use crate::common::parser::{IResult, context};
 use crate::enumerations::{DetonationResult, DetonationTypeIndicator};
-use nom::IResult;
 use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16};
@@ -17,19 +17,27 @@ pub(crate) fn detonation_body(
             .unwrap_or_default()
             .detonation_type_indicator
             .unwrap_or(DetonationTypeIndicator::Munition);
-        let (input, source_entity_id) = parser::entity_id(input)?;
-        let (input, target_entity_id) = parser::entity_id(input)?;
-        let (input, exploding_entity_id) = parser::entity_id(input)?;
-        let (input, event_it) = parser::event_id(input)?;
-        let (input, velocity) = parser::vec3_f32(input)?;
-        let (input, world_location) = parser::location(input)?;
-        let (input, descriptor) = detonation_descriptor(dti)(input)?;
-        let (input, entity_location) = parser::vec3_f32(input)?;
-        let (input, detonation_result) = be_u8(input)?;
-        let (input, variable_parameters_no) = be_u8(input)?;
-        let (input, _padding) = be_u16(input)?;
+        let (input, source_entity_id) =
+            context("Source Entity ID", parser::entity_id).parse(input)?;
+        let (input, target_entity_id) =
+            context("Target Entity ID", parser::entity_id).parse(input)?;
+        let (input, exploding_entity_id) =
+            context("Exploding Entity ID", parser::entity_id).parse(input)?;
+        let (input, event_it) = context("Event ID", parser::event_id).parse(input)?;
+        let (input, velocity) = context("Velocity", parser::vec3_f32).parse(input)?;
+        let (input, world_location) = context("World Location", parser::location).parse(input)?;
+        let (input, descriptor) = context("Descriptor", detonation_descriptor(dti)).parse(input)?;
+        let (input, entity_location) = context("Entity Location", parser::vec3_f32).parse(input)?;
+        let (input, detonation_result) = context("Detonation Result", be_u8).parse(input)?;
+        let (input, variable_parameters_no) =
+            context("Number of Variable Parameters", be_u8).parse(input)?;
+        let (input, _padding) = context("Padding", be_u16).parse(input)?;
         let (input, articulation_parameters) = if variable_parameters_no > 0 {
-            count(parser::variable_parameter, variable_parameters_no as usize).parse(input)?
+            count(
+                context("Variable Parameter", parser::variable_parameter),
+                variable_parameters_no as usize,
+            )
+            .parse(input)?
         } else {
             (input, vec![])
         };
@@ -56,16 +64,19 @@ pub(crate) fn detonation_descriptor(
 ) -> impl Fn(&[u8]) -> IResult<&[u8], DetonationDescriptor> {
     move |input: &[u8]| match detonation_type_indicator {
         DetonationTypeIndicator::Munition => {
-            let (input, munition) = parser::munition_descriptor(input)?;
+            let (input, munition) =
+                context("Munition", parser::munition_descriptor).parse(input)?;
             Ok((input, DetonationDescriptor::Munition(munition)))
         }
         DetonationTypeIndicator::NonmunitionExplosion => {
-            let (input, explosion) = parser::explosion_descriptor(input)?;
+            let (input, explosion) =
+                context("Explosion", parser::explosion_descriptor).parse(input)?;
             Ok((input, DetonationDescriptor::Explosion(explosion)))
         }
         // FIXME: DetonationTypeIndicator::Unspecified(_) should be an error; for now parse as Expendable, which has no data
         DetonationTypeIndicator::Expendable | DetonationTypeIndicator::Unspecified(_) => {
-            let (input, expendable) = parser::expendable_descriptor(input)?;
+            let (input, expendable) =
+                context("Expendable", parser::expendable_descriptor).parse(input)?;
             Ok((input, DetonationDescriptor::Expendable(expendable)))
         }
     }
@@ -0,0 +1,119 @@
+use crate::directed_energy_fire::model::{
+    DEFireFlags, DEFirePulseShape, DERecord, DirectedEnergyFire,
+};
+use crate::model::{ClockTime, EntityId, EntityType, EventId, VectorF32};
+
+pub struct DirectedEnergyFireBuilder(DirectedEnergyFire);
+
+impl Default for DirectedEnergyFireBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DirectedEnergyFireBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        DirectedEnergyFireBuilder(DirectedEnergyFire::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: DirectedEnergyFire) -> Self {
+        DirectedEnergyFireBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> DirectedEnergyFire {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_firing_entity_id(mut self, firing_entity_id: EntityId) -> Self {
+        self.0.firing_entity_id = firing_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_event_id(mut self, event_id: EventId) -> Self {
+        self.0.event_id = event_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_type(mut self, munition_type: EntityType) -> Self {
+        self.0.munition_type = munition_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_shot_start_time(mut self, shot_start_time: ClockTime) -> Self {
+        self.0.shot_start_time = shot_start_time;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cumulative_shot_time(mut self, cumulative_shot_time: f32) -> Self {
+        self.0.cumulative_shot_time = cumulative_shot_time;
+        self
+    }
+
+    #[must_use]
+    pub fn with_aperture_emitter_location(mut self, aperture_emitter_location: VectorF32) -> Self {
+        self.0.aperture_emitter_location = aperture_emitter_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_aperture_diameter(mut self, aperture_diameter: f32) -> Self {
+        self.0.aperture_diameter = aperture_diameter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_wavelength(mut self, wavelength: f32) -> Self {
+        self.0.wavelength = wavelength;
+        self
+    }
+
+    #[must_use]
+    pub fn with_peak_irradiance(mut self, peak_irradiance: f32) -> Self {
+        self.0.peak_irradiance = peak_irradiance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pulse_repetition_frequency(mut self, pulse_repetition_frequency: f32) -> Self {
+        self.0.pulse_repetition_frequency = pulse_repetition_frequency;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pulse_width(mut self, pulse_width: f32) -> Self {
+        self.0.pulse_width = pulse_width;
+        self
+    }
+
+    #[must_use]
+    pub fn with_flags(mut self, flags: DEFireFlags) -> Self {
+        self.0.flags = flags;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pulse_shape(mut self, pulse_shape: DEFirePulseShape) -> Self {
+        self.0.pulse_shape = pulse_shape;
+        self
+    }
+
+    #[must_use]
+    pub fn with_de_record(mut self, de_record: DERecord) -> Self {
+        self.0.de_records.push(de_record);
+        self
+    }
+
+    #[must_use]
+    pub fn with_de_records(mut self, de_records: Vec<DERecord>) -> Self {
+        self.0.de_records = de_records;
+        self
+    }
+}
@@ -0,0 +1,149 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::Interaction;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::directed_energy_fire::model::{
+        DEAreaAimpoint, DEBeamSpotType, DEFireFlags, DEFirePulseShape, DEPrecisionAimpoint,
+        DERecord, DETargetEnergyDeposition, DirectedEnergyFire,
+    };
+    use crate::enumerations::{
+        CoupledExtensionIndicator, EntityKind, LvcIndicator, PduType,
+        TransmitterAntennaPatternReferenceSystem,
+    };
+    use crate::model::{
+        ClockTime, EntityId, EntityType, EventId, Location, Orientation, VectorF32,
+    };
+    use crate::transmitter::model::BeamAntennaPattern;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    fn directed_energy_fire_body() -> DirectedEnergyFire {
+        DirectedEnergyFire::builder()
+            .with_firing_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(1, 1, 100))
+            .with_munition_type(EntityType::default().with_kind(EntityKind::Munition))
+            .with_shot_start_time(ClockTime::new(12, 3600))
+            .with_cumulative_shot_time(1.5)
+            .with_aperture_emitter_location(VectorF32::new(1.0, 0.0, -2.0))
+            .with_aperture_diameter(0.3)
+            .with_wavelength(1.064e-6)
+            .with_peak_irradiance(5000.0)
+            .with_pulse_repetition_frequency(0.0)
+            .with_pulse_width(0.0)
+            .with_flags(
+                DEFireFlags::new()
+                    .with_weapon_on(true)
+                    .with_state_update(true),
+            )
+            .with_pulse_shape(DEFirePulseShape::ContinuousWave)
+            .with_de_record(DERecord::PrecisionAimpoint(
+                DEPrecisionAimpoint::new()
+                    .with_target_spot_location(Location::new(3_919_999.0, 330_000.0, 5_000_000.0))
+                    .with_target_spot_entity_location(VectorF32::new(0.5, 0.0, 0.0))
+                    .with_target_entity_id(EntityId::new(1, 1, 2))
+                    .with_target_component_id(3)
+                    .with_beam_spot_type(DEBeamSpotType::Gaussian)
+                    .with_beam_spot_cross_section_semi_major_axis(0.1)
+                    .with_beam_spot_cross_section_semi_minor_axis(0.05)
+                    .with_peak_irradiance(4500.0),
+            ))
+            .with_de_record(DERecord::AreaAimpoint(
+                DEAreaAimpoint::new()
+                    .with_beam_antenna_pattern(
+                        BeamAntennaPattern::new()
+                            .with_beam_direction(Orientation::new(0.1, 0.2, 0.0))
+                            .with_azimuth_beamwidth(0.05)
+                            .with_elevation_beamwidth(0.05)
+                            .with_reference_system(
+                                TransmitterAntennaPatternReferenceSystem::EntityCoordinates,
+                            ),
+                    )
+                    .with_target_energy_deposition(DETargetEnergyDeposition::new(
+                        EntityId::new(1, 1, 3),
+                        100.0,
+                    ))
+                    .with_target_energy_deposition(DETargetEnergyDeposition::new(
+                        EntityId::new(1, 1, 4),
+                        50.0,
+                    )),
+            ))
+            .with_de_record(DERecord::Unspecified {
+                record_type: 4999,
+                fields: vec![0, 0, 1, 2, 3, 4],
+            })
+            .build()
+    }
+
+    #[test]
+    fn directed_energy_fire_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::DirectedEnergyFire).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Virtual)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = directed_energy_fire_body().into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+        assert_eq!(buf.len(), pdu_length as usize);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn directed_energy_fire_record_lengths() {
+        let body = directed_energy_fire_body();
+        let lengths: Vec<u16> = body
+            .de_records
+            .iter()
+            .map(DERecord::record_length)
+            .collect();
+
+        // area aimpoint: 12 + 40 + 2 * 12 = 76 octets, padded to 80
+        assert_eq!(lengths, vec![96, 80, 12]);
+    }
+
+    #[test]
+    fn directed_energy_fire_interaction() {
+        let body = directed_energy_fire_body();
+
+        assert_eq!(body.originator(), Some(&EntityId::new(1, 1, 1)));
+        assert_eq!(body.receiver(), Some(&EntityId::new(1, 1, 2)));
+
+        let area_only =
+            body.into_builder()
+                .with_de_records(vec![DERecord::AreaAimpoint(
+                    DEAreaAimpoint::new().with_target_energy_deposition(
+                        DETargetEnergyDeposition::new(EntityId::new(1, 1, 3), 100.0),
+                    ),
+                )])
+                .build();
+        assert_eq!(area_only.receiver(), Some(&EntityId::new(1, 1, 3)));
+    }
+}
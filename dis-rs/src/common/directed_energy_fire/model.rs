@@ -0,0 +1,637 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    ClockTime, EntityId, EntityType, EventId, Location, PduBody, VectorF32, length_padded_to_num,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::EIGHT_OCTETS;
+use crate::directed_energy_fire::builder::DirectedEnergyFireBuilder;
+use crate::enumerations::PduType;
+use crate::transmitter::model::{BEAM_ANTENNA_PATTERN_OCTETS, BeamAntennaPattern};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH: u16 = 76;
+pub const DE_RECORD_HEADER_LENGTH: u16 = 6;
+pub const DE_PRECISION_AIMPOINT_RECORD_LENGTH: u16 = 96;
+pub const BASE_DE_AREA_AIMPOINT_RECORD_LENGTH: u16 = 12;
+pub const DE_TARGET_ENERGY_DEPOSITION_RECORD_LENGTH: u16 = 12;
+pub const DE_DAMAGE_DESCRIPTION_RECORD_LENGTH: u16 = 40;
+
+/// Variable Record Type of the DE Precision Aimpoint record (SISO-REF-010 UID 66)
+pub const DE_PRECISION_AIMPOINT_RECORD_TYPE: u32 = 4000;
+/// Variable Record Type of the DE Area Aimpoint record (SISO-REF-010 UID 66)
+pub const DE_AREA_AIMPOINT_RECORD_TYPE: u32 = 4001;
+/// Variable Record Type of the DE Damage Description record (SISO-REF-010 UID 66)
+pub const DE_DAMAGE_DESCRIPTION_RECORD_TYPE: u32 = 4500;
+
+/// 5.4.5 Directed Energy Fire PDU
+///
+/// 7.3.4 Directed Energy Fire PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DirectedEnergyFire {
+    pub firing_entity_id: EntityId,
+    pub event_id: EventId,
+    pub munition_type: EntityType,
+    pub shot_start_time: ClockTime,
+    pub cumulative_shot_time: f32,
+    pub aperture_emitter_location: VectorF32,
+    pub aperture_diameter: f32,
+    pub wavelength: f32,
+    pub peak_irradiance: f32,
+    pub pulse_repetition_frequency: f32,
+    pub pulse_width: f32,
+    pub flags: DEFireFlags,
+    pub pulse_shape: DEFirePulseShape,
+    pub de_records: Vec<DERecord>,
+}
+
+impl BodyRaw for DirectedEnergyFire {
+    type Builder = DirectedEnergyFireBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::DirectedEnergyFire(self)
+    }
+}
+
+impl BodyInfo for DirectedEnergyFire {
+    fn body_length(&self) -> u16 {
+        BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH
+            + self
+                .de_records
+                .iter()
+                .map(DERecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::DirectedEnergyFire
+    }
+}
+
+impl Interaction for DirectedEnergyFire {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.firing_entity_id)
+    }
+
+    /// The PDU has no dedicated target field; the target is taken from the first aimpoint record that identifies one.
+    fn receiver(&self) -> Option<&EntityId> {
+        self.de_records.iter().find_map(|record| match record {
+            DERecord::PrecisionAimpoint(aimpoint) => Some(&aimpoint.target_entity_id),
+            DERecord::AreaAimpoint(aimpoint) => aimpoint
+                .target_energy_depositions
+                .first()
+                .map(|deposition| &deposition.target_entity_id),
+            _ => None,
+        })
+    }
+}
+
+/// Flags field of the Directed Energy Fire PDU (SISO-REF-010 UID 313)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEFireFlags {
+    pub weapon_on: bool,
+    pub state_update: bool,
+}
+
+impl DEFireFlags {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_weapon_on(mut self, weapon_on: bool) -> Self {
+        self.weapon_on = weapon_on;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state_update(mut self, state_update: bool) -> Self {
+        self.state_update = state_update;
+        self
+    }
+}
+
+impl From<u16> for DEFireFlags {
+    fn from(value: u16) -> Self {
+        Self {
+            weapon_on: (value & 0x0001) != 0,
+            state_update: ((value >> 1) & 0x0001) != 0,
+        }
+    }
+}
+
+impl From<&DEFireFlags> for u16 {
+    fn from(value: &DEFireFlags) -> Self {
+        let weapon_on = u16::from(value.weapon_on);
+        let state_update = u16::from(value.state_update) << 1;
+
+        weapon_on | state_update
+    }
+}
+
+/// Custom defined enum to model the Pulse Shape field (SISO-REF-010 UID 312)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEFirePulseShape {
+    #[default]
+    Other, // 0
+    SquareWave,     // 1
+    ContinuousWave, // 2
+    Gaussian,       // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for DEFirePulseShape {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::SquareWave,
+            2 => Self::ContinuousWave,
+            3 => Self::Gaussian,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<DEFirePulseShape> for u8 {
+    fn from(value: DEFirePulseShape) -> Self {
+        match value {
+            DEFirePulseShape::Other => 0,
+            DEFirePulseShape::SquareWave => 1,
+            DEFirePulseShape::ContinuousWave => 2,
+            DEFirePulseShape::Gaussian => 3,
+            DEFirePulseShape::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Directed Energy records carried by the Directed Energy Fire PDU and the Entity Damage Status PDU.
+///
+/// Records with an unknown Record Type are kept as the raw bytes following the record type and length fields.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DERecord {
+    PrecisionAimpoint(DEPrecisionAimpoint),
+    AreaAimpoint(DEAreaAimpoint),
+    DamageDescription(DEDamageDescription),
+    Unspecified { record_type: u32, fields: Vec<u8> },
+}
+
+impl Default for DERecord {
+    fn default() -> Self {
+        Self::PrecisionAimpoint(DEPrecisionAimpoint::default())
+    }
+}
+
+impl DERecord {
+    #[must_use]
+    pub fn record_type(&self) -> u32 {
+        match self {
+            DERecord::PrecisionAimpoint(_) => DE_PRECISION_AIMPOINT_RECORD_TYPE,
+            DERecord::AreaAimpoint(_) => DE_AREA_AIMPOINT_RECORD_TYPE,
+            DERecord::DamageDescription(_) => DE_DAMAGE_DESCRIPTION_RECORD_TYPE,
+            DERecord::Unspecified { record_type, .. } => *record_type,
+        }
+    }
+
+    /// Length in octets of the complete record, including the record type and length fields
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            DERecord::PrecisionAimpoint(_) => DE_PRECISION_AIMPOINT_RECORD_LENGTH,
+            DERecord::AreaAimpoint(aimpoint) => aimpoint.record_length(),
+            DERecord::DamageDescription(_) => DE_DAMAGE_DESCRIPTION_RECORD_LENGTH,
+            DERecord::Unspecified { fields, .. } => DE_RECORD_HEADER_LENGTH + fields.len() as u16,
+        }
+    }
+}
+
+/// 6.2.20.3 DE Precision Aimpoint record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEPrecisionAimpoint {
+    pub target_spot_location: Location,
+    pub target_spot_entity_location: VectorF32,
+    pub target_spot_velocity: VectorF32,
+    pub target_spot_acceleration: VectorF32,
+    pub target_entity_id: EntityId,
+    pub target_component_id: u8,
+    pub beam_spot_type: DEBeamSpotType,
+    pub beam_spot_cross_section_semi_major_axis: f32,
+    pub beam_spot_cross_section_semi_minor_axis: f32,
+    pub beam_spot_cross_section_orientation_angle: f32,
+    pub peak_irradiance: f32,
+}
+
+impl DEPrecisionAimpoint {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_target_spot_location(mut self, target_spot_location: Location) -> Self {
+        self.target_spot_location = target_spot_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_entity_location(
+        mut self,
+        target_spot_entity_location: VectorF32,
+    ) -> Self {
+        self.target_spot_entity_location = target_spot_entity_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_velocity(mut self, target_spot_velocity: VectorF32) -> Self {
+        self.target_spot_velocity = target_spot_velocity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_acceleration(mut self, target_spot_acceleration: VectorF32) -> Self {
+        self.target_spot_acceleration = target_spot_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_entity_id(mut self, target_entity_id: EntityId) -> Self {
+        self.target_entity_id = target_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_component_id(mut self, target_component_id: u8) -> Self {
+        self.target_component_id = target_component_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_type(mut self, beam_spot_type: DEBeamSpotType) -> Self {
+        self.beam_spot_type = beam_spot_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_semi_major_axis(mut self, semi_major_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_major_axis = semi_major_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_semi_minor_axis(mut self, semi_minor_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_minor_axis = semi_minor_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_orientation_angle(
+        mut self,
+        orientation_angle: f32,
+    ) -> Self {
+        self.beam_spot_cross_section_orientation_angle = orientation_angle;
+        self
+    }
+
+    #[must_use]
+    pub fn with_peak_irradiance(mut self, peak_irradiance: f32) -> Self {
+        self.peak_irradiance = peak_irradiance;
+        self
+    }
+}
+
+/// 6.2.20.2 DE Area Aimpoint record
+///
+/// The record is padded to a 64-bit boundary.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEAreaAimpoint {
+    pub beam_antenna_patterns: Vec<BeamAntennaPattern>,
+    pub target_energy_depositions: Vec<DETargetEnergyDeposition>,
+}
+
+impl DEAreaAimpoint {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_beam_antenna_pattern(mut self, beam_antenna_pattern: BeamAntennaPattern) -> Self {
+        self.beam_antenna_patterns.push(beam_antenna_pattern);
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_antenna_patterns(
+        mut self,
+        beam_antenna_patterns: Vec<BeamAntennaPattern>,
+    ) -> Self {
+        self.beam_antenna_patterns = beam_antenna_patterns;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_energy_deposition(
+        mut self,
+        target_energy_deposition: DETargetEnergyDeposition,
+    ) -> Self {
+        self.target_energy_depositions
+            .push(target_energy_deposition);
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_energy_depositions(
+        mut self,
+        target_energy_depositions: Vec<DETargetEnergyDeposition>,
+    ) -> Self {
+        self.target_energy_depositions = target_energy_depositions;
+        self
+    }
+
+    /// Length in octets of the record, including the record type and length fields and the trailing padding
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        let unpadded_length = BASE_DE_AREA_AIMPOINT_RECORD_LENGTH as usize
+            + self.beam_antenna_patterns.len() * BEAM_ANTENNA_PATTERN_OCTETS as usize
+            + self.target_energy_depositions.len()
+                * DE_TARGET_ENERGY_DEPOSITION_RECORD_LENGTH as usize;
+        length_padded_to_num(unpadded_length, EIGHT_OCTETS).record_length as u16
+    }
+}
+
+/// Directed Energy Target Energy Deposition record, part of the DE Area Aimpoint record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DETargetEnergyDeposition {
+    pub target_entity_id: EntityId,
+    pub peak_irradiance: f32,
+}
+
+impl DETargetEnergyDeposition {
+    #[must_use]
+    pub fn new(target_entity_id: EntityId, peak_irradiance: f32) -> Self {
+        Self {
+            target_entity_id,
+            peak_irradiance,
+        }
+    }
+}
+
+/// 6.2.20.4 DE Damage Description record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEDamageDescription {
+    pub damage_location: VectorF32,
+    pub damage_diameter: f32,
+    pub temperature: f32,
+    pub component_identification: DEComponentIdentification,
+    pub component_damage_status: DEComponentDamageStatus,
+    /// Component Visual Damage Status bitfield (SISO-REF-010 UID 317)
+    pub component_visual_damage_status: u8,
+    pub component_visual_smoke_color: DEComponentVisualSmokeColor,
+    pub fire_event_id: EventId,
+}
+
+impl DEDamageDescription {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_damage_location(mut self, damage_location: VectorF32) -> Self {
+        self.damage_location = damage_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage_diameter(mut self, damage_diameter: f32) -> Self {
+        self.damage_diameter = damage_diameter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_identification(
+        mut self,
+        component_identification: DEComponentIdentification,
+    ) -> Self {
+        self.component_identification = component_identification;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_damage_status(
+        mut self,
+        component_damage_status: DEComponentDamageStatus,
+    ) -> Self {
+        self.component_damage_status = component_damage_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_visual_damage_status(
+        mut self,
+        component_visual_damage_status: u8,
+    ) -> Self {
+        self.component_visual_damage_status = component_visual_damage_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_visual_smoke_color(
+        mut self,
+        component_visual_smoke_color: DEComponentVisualSmokeColor,
+    ) -> Self {
+        self.component_visual_smoke_color = component_visual_smoke_color;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fire_event_id(mut self, fire_event_id: EventId) -> Self {
+        self.fire_event_id = fire_event_id;
+        self
+    }
+}
+
+/// Custom defined enum to model the Beam Spot Type field (SISO-REF-010 UID 311)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEBeamSpotType {
+    #[default]
+    Other, // 0
+    Gaussian, // 1
+    TopHat,   // 2
+    Unspecified(u8),
+}
+
+impl From<u8> for DEBeamSpotType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::Gaussian,
+            2 => Self::TopHat,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<DEBeamSpotType> for u8 {
+    fn from(value: DEBeamSpotType) -> Self {
+        match value {
+            DEBeamSpotType::Other => 0,
+            DEBeamSpotType::Gaussian => 1,
+            DEBeamSpotType::TopHat => 2,
+            DEBeamSpotType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Component Identification field (SISO-REF-010 UID 314)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEComponentIdentification {
+    #[default]
+    EntityCenter, // 0
+    EntityStructure,          // 1
+    ControlSystem,            // 2
+    ControlSurface,           // 3
+    EnginePropulsionSystem,   // 4
+    CrewMember,               // 5
+    Fuse,                     // 6
+    AcquisitionSensor,        // 7
+    TrackingSensor,           // 8
+    FuelTankSolidRocketMotor, // 9
+    Unspecified(u8),
+}
+
+impl From<u8> for DEComponentIdentification {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::EntityCenter,
+            1 => Self::EntityStructure,
+            2 => Self::ControlSystem,
+            3 => Self::ControlSurface,
+            4 => Self::EnginePropulsionSystem,
+            5 => Self::CrewMember,
+            6 => Self::Fuse,
+            7 => Self::AcquisitionSensor,
+            8 => Self::TrackingSensor,
+            9 => Self::FuelTankSolidRocketMotor,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<DEComponentIdentification> for u8 {
+    fn from(value: DEComponentIdentification) -> Self {
+        match value {
+            DEComponentIdentification::EntityCenter => 0,
+            DEComponentIdentification::EntityStructure => 1,
+            DEComponentIdentification::ControlSystem => 2,
+            DEComponentIdentification::ControlSurface => 3,
+            DEComponentIdentification::EnginePropulsionSystem => 4,
+            DEComponentIdentification::CrewMember => 5,
+            DEComponentIdentification::Fuse => 6,
+            DEComponentIdentification::AcquisitionSensor => 7,
+            DEComponentIdentification::TrackingSensor => 8,
+            DEComponentIdentification::FuelTankSolidRocketMotor => 9,
+            DEComponentIdentification::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Component Damage Status field (SISO-REF-010 UID 315)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEComponentDamageStatus {
+    #[default]
+    NoDamage, // 0
+    MinorDamage,  // 1
+    MediumDamage, // 2
+    MajorDamage,  // 3
+    Destroyed,    // 4
+    Unspecified(u8),
+}
+
+impl From<u8> for DEComponentDamageStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoDamage,
+            1 => Self::MinorDamage,
+            2 => Self::MediumDamage,
+            3 => Self::MajorDamage,
+            4 => Self::Destroyed,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<DEComponentDamageStatus> for u8 {
+    fn from(value: DEComponentDamageStatus) -> Self {
+        match value {
+            DEComponentDamageStatus::NoDamage => 0,
+            DEComponentDamageStatus::MinorDamage => 1,
+            DEComponentDamageStatus::MediumDamage => 2,
+            DEComponentDamageStatus::MajorDamage => 3,
+            DEComponentDamageStatus::Destroyed => 4,
+            DEComponentDamageStatus::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Component Visual Smoke Color field (SISO-REF-010 UID 316)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEComponentVisualSmokeColor {
+    #[default]
+    NoSmoke, // 0
+    White, // 1
+    Gray,  // 2
+    Black, // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for DEComponentVisualSmokeColor {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoSmoke,
+            1 => Self::White,
+            2 => Self::Gray,
+            3 => Self::Black,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<DEComponentVisualSmokeColor> for u8 {
+    fn from(value: DEComponentVisualSmokeColor) -> Self {
+        match value {
+            DEComponentVisualSmokeColor::NoSmoke => 0,
+            DEComponentVisualSmokeColor::White => 1,
+            DEComponentVisualSmokeColor::Gray => 2,
+            DEComponentVisualSmokeColor::Black => 3,
+            DEComponentVisualSmokeColor::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
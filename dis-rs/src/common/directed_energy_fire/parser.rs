@@ -0,0 +1,204 @@
+use crate::BodyRaw;
+use crate::common::directed_energy_fire::model::{
+    DE_AREA_AIMPOINT_RECORD_TYPE, DE_DAMAGE_DESCRIPTION_RECORD_TYPE,
+    DE_PRECISION_AIMPOINT_RECORD_TYPE, DE_RECORD_HEADER_LENGTH, DEAreaAimpoint, DEBeamSpotType,
+    DEComponentDamageStatus, DEComponentIdentification, DEComponentVisualSmokeColor,
+    DEDamageDescription, DEFireFlags, DEFirePulseShape, DEPrecisionAimpoint, DERecord,
+    DETargetEnergyDeposition, DirectedEnergyFire,
+};
+use crate::common::model::PduBody;
+use crate::common::parser::{
+    IResult, clock_time, context, entity_id, entity_type, event_id, location, vec3_f32,
+};
+use crate::common::transmitter::parser::beam_antenna_pattern;
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn directed_energy_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_entity_id) = context("Firing Entity ID", entity_id).parse(input)?;
+    let (input, event_id) = context("Event ID", event_id).parse(input)?;
+    let (input, munition_type) = context("Munition Type", entity_type).parse(input)?;
+    let (input, shot_start_time) = context("Shot Start Time", clock_time).parse(input)?;
+    let (input, cumulative_shot_time) = context("Cumulative Shot Time", be_f32).parse(input)?;
+    let (input, aperture_emitter_location) =
+        context("Aperture Emitter Location", vec3_f32).parse(input)?;
+    let (input, aperture_diameter) = context("Aperture Diameter", be_f32).parse(input)?;
+    let (input, wavelength) = context("Wavelength", be_f32).parse(input)?;
+    let (input, peak_irradiance) = context("Peak Irradiance", be_f32).parse(input)?;
+    let (input, pulse_repetition_frequency) =
+        context("Pulse Repetition Frequency", be_f32).parse(input)?;
+    let (input, pulse_width) = context("Pulse Width", be_f32).parse(input)?;
+    let (input, flags) = context("Flags", be_u16).parse(input)?;
+    let (input, pulse_shape) = context("Pulse Shape", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, number_of_de_records) = context("Number of DE Records", be_u16).parse(input)?;
+    let (input, de_records) = count(
+        context("DE Record", de_record),
+        number_of_de_records as usize,
+    )
+    .parse(input)?;
+
+    let body = DirectedEnergyFire::builder()
+        .with_firing_entity_id(firing_entity_id)
+        .with_event_id(event_id)
+        .with_munition_type(munition_type)
+        .with_shot_start_time(shot_start_time)
+        .with_cumulative_shot_time(cumulative_shot_time)
+        .with_aperture_emitter_location(aperture_emitter_location)
+        .with_aperture_diameter(aperture_diameter)
+        .with_wavelength(wavelength)
+        .with_peak_irradiance(peak_irradiance)
+        .with_pulse_repetition_frequency(pulse_repetition_frequency)
+        .with_pulse_width(pulse_width)
+        .with_flags(DEFireFlags::from(flags))
+        .with_pulse_shape(DEFirePulseShape::from(pulse_shape))
+        .with_de_records(de_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+/// Parses a single DE record, consuming exactly the number of octets stated in its Record Length field.
+pub(crate) fn de_record(input: &[u8]) -> IResult<&[u8], DERecord> {
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
+    let (input, record_length) = context("Record Length", be_u16).parse(input)?;
+    let (input, fields) = context(
+        "Fields",
+        take(record_length.saturating_sub(DE_RECORD_HEADER_LENGTH)),
+    )
+    .parse(input)?;
+
+    let record = match record_type {
+        DE_PRECISION_AIMPOINT_RECORD_TYPE => {
+            let (_, record) = de_precision_aimpoint(fields)?;
+            DERecord::PrecisionAimpoint(record)
+        }
+        DE_AREA_AIMPOINT_RECORD_TYPE => {
+            let (_, record) = de_area_aimpoint(fields)?;
+            DERecord::AreaAimpoint(record)
+        }
+        DE_DAMAGE_DESCRIPTION_RECORD_TYPE => {
+            let (_, record) = de_damage_description(fields)?;
+            DERecord::DamageDescription(record)
+        }
+        _ => DERecord::Unspecified {
+            record_type,
+            fields: fields.to_vec(),
+        },
+    };
+
+    Ok((input, record))
+}
+
+fn de_precision_aimpoint(input: &[u8]) -> IResult<&[u8], DEPrecisionAimpoint> {
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, target_spot_location) = context("Target Spot Location", location).parse(input)?;
+    let (input, target_spot_entity_location) =
+        context("Target Spot Entity Location", vec3_f32).parse(input)?;
+    let (input, target_spot_velocity) = context("Target Spot Velocity", vec3_f32).parse(input)?;
+    let (input, target_spot_acceleration) =
+        context("Target Spot Acceleration", vec3_f32).parse(input)?;
+    let (input, target_entity_id) = context("Target Entity ID", entity_id).parse(input)?;
+    let (input, target_component_id) = context("Target Component ID", be_u8).parse(input)?;
+    let (input, beam_spot_type) = context("Beam Spot Type", be_u8).parse(input)?;
+    let (input, semi_major_axis) = context("Semi Major Axis", be_f32).parse(input)?;
+    let (input, semi_minor_axis) = context("Semi Minor Axis", be_f32).parse(input)?;
+    let (input, orientation_angle) = context("Orientation Angle", be_f32).parse(input)?;
+    let (input, peak_irradiance) = context("Peak Irradiance", be_f32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+
+    Ok((
+        input,
+        DEPrecisionAimpoint::new()
+            .with_target_spot_location(target_spot_location)
+            .with_target_spot_entity_location(target_spot_entity_location)
+            .with_target_spot_velocity(target_spot_velocity)
+            .with_target_spot_acceleration(target_spot_acceleration)
+            .with_target_entity_id(target_entity_id)
+            .with_target_component_id(target_component_id)
+            .with_beam_spot_type(DEBeamSpotType::from(beam_spot_type))
+            .with_beam_spot_cross_section_semi_major_axis(semi_major_axis)
+            .with_beam_spot_cross_section_semi_minor_axis(semi_minor_axis)
+            .with_beam_spot_cross_section_orientation_angle(orientation_angle)
+            .with_peak_irradiance(peak_irradiance),
+    ))
+}
+
+/// Trailing padding of the record is covered by the Record Length and already consumed by `de_record`.
+fn de_area_aimpoint(input: &[u8]) -> IResult<&[u8], DEAreaAimpoint> {
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, beam_antenna_pattern_count) =
+        context("Beam Antenna Pattern Count", be_u16).parse(input)?;
+    let (input, target_energy_deposition_count) =
+        context("Target Energy Deposition Count", be_u16).parse(input)?;
+    let (input, beam_antenna_patterns) = count(
+        context("Beam Antenna Pattern", beam_antenna_pattern),
+        beam_antenna_pattern_count as usize,
+    )
+    .parse(input)?;
+    let (input, target_energy_depositions) = count(
+        context("DE Target Energy Deposition", de_target_energy_deposition),
+        target_energy_deposition_count as usize,
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        DEAreaAimpoint::new()
+            .with_beam_antenna_patterns(beam_antenna_patterns)
+            .with_target_energy_depositions(target_energy_depositions),
+    ))
+}
+
+fn de_target_energy_deposition(input: &[u8]) -> IResult<&[u8], DETargetEnergyDeposition> {
+    let (input, target_entity_id) = context("Target Entity ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, peak_irradiance) = context("Peak Irradiance", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        DETargetEnergyDeposition::new(target_entity_id, peak_irradiance),
+    ))
+}
+
+fn de_damage_description(input: &[u8]) -> IResult<&[u8], DEDamageDescription> {
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, damage_location) = context("Damage Location", vec3_f32).parse(input)?;
+    let (input, damage_diameter) = context("Damage Diameter", be_f32).parse(input)?;
+    let (input, temperature) = context("Temperature", be_f32).parse(input)?;
+    let (input, component_identification) =
+        context("Component Identification", be_u8).parse(input)?;
+    let (input, component_damage_status) =
+        context("Component Damage Status", be_u8).parse(input)?;
+    let (input, component_visual_damage_status) =
+        context("Component Visual Damage Status", be_u8).parse(input)?;
+    let (input, component_visual_smoke_color) =
+        context("Component Visual Smoke Color", be_u8).parse(input)?;
+    let (input, fire_event_id) = context("Fire Event ID", event_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        DEDamageDescription::new()
+            .with_damage_location(damage_location)
+            .with_damage_diameter(damage_diameter)
+            .with_temperature(temperature)
+            .with_component_identification(DEComponentIdentification::from(
+                component_identification,
+            ))
+            .with_component_damage_status(DEComponentDamageStatus::from(component_damage_status))
+            .with_component_visual_damage_status(component_visual_damage_status)
+            .with_component_visual_smoke_color(DEComponentVisualSmokeColor::from(
+                component_visual_smoke_color,
+            ))
+            .with_fire_event_id(fire_event_id),
+    ))
+}
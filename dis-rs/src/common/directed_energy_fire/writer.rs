@@ -0,0 +1,131 @@
+use crate::common::directed_energy_fire::model::{
+    BASE_DE_AREA_AIMPOINT_RECORD_LENGTH, DE_DAMAGE_DESCRIPTION_RECORD_LENGTH,
+    DE_PRECISION_AIMPOINT_RECORD_LENGTH, DE_RECORD_HEADER_LENGTH, DEAreaAimpoint,
+    DEDamageDescription, DEPrecisionAimpoint, DERecord, DETargetEnergyDeposition,
+    DirectedEnergyFire,
+};
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for DirectedEnergyFire {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.firing_entity_id.serialize(buf);
+        self.event_id.serialize(buf);
+        self.munition_type.serialize(buf);
+        self.shot_start_time.serialize(buf);
+        buf.put_f32(self.cumulative_shot_time);
+        self.aperture_emitter_location.serialize(buf);
+        buf.put_f32(self.aperture_diameter);
+        buf.put_f32(self.wavelength);
+        buf.put_f32(self.peak_irradiance);
+        buf.put_f32(self.pulse_repetition_frequency);
+        buf.put_f32(self.pulse_width);
+        buf.put_u16((&self.flags).into());
+        buf.put_u8(self.pulse_shape.into());
+        buf.put_u8(0u8);
+        buf.put_u32(0u32);
+        buf.put_u16(0u16);
+        buf.put_u16(self.de_records.len() as u16);
+        for record in &self.de_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for DERecord {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.record_type());
+        buf.put_u16(self.record_length());
+        match self {
+            DERecord::PrecisionAimpoint(record) => {
+                record.serialize(buf);
+            }
+            DERecord::AreaAimpoint(record) => {
+                record.serialize(buf);
+            }
+            DERecord::DamageDescription(record) => {
+                record.serialize(buf);
+            }
+            DERecord::Unspecified { fields, .. } => {
+                buf.put_slice(fields);
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for DEPrecisionAimpoint {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(0u16);
+        self.target_spot_location.serialize(buf);
+        self.target_spot_entity_location.serialize(buf);
+        self.target_spot_velocity.serialize(buf);
+        self.target_spot_acceleration.serialize(buf);
+        self.target_entity_id.serialize(buf);
+        buf.put_u8(self.target_component_id);
+        buf.put_u8(self.beam_spot_type.into());
+        buf.put_f32(self.beam_spot_cross_section_semi_major_axis);
+        buf.put_f32(self.beam_spot_cross_section_semi_minor_axis);
+        buf.put_f32(self.beam_spot_cross_section_orientation_angle);
+        buf.put_f32(self.peak_irradiance);
+        buf.put_u32(0u32);
+
+        DE_PRECISION_AIMPOINT_RECORD_LENGTH - DE_RECORD_HEADER_LENGTH
+    }
+}
+
+impl Serialize for DEAreaAimpoint {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(0u16);
+        buf.put_u16(self.beam_antenna_patterns.len() as u16);
+        buf.put_u16(self.target_energy_depositions.len() as u16);
+        let patterns_bytes: u16 = self
+            .beam_antenna_patterns
+            .iter()
+            .map(|pattern| pattern.serialize(buf))
+            .sum();
+        let depositions_bytes: u16 = self
+            .target_energy_depositions
+            .iter()
+            .map(|deposition| deposition.serialize(buf))
+            .sum();
+
+        let padding_length = self.record_length()
+            - BASE_DE_AREA_AIMPOINT_RECORD_LENGTH
+            - patterns_bytes
+            - depositions_bytes;
+        buf.put_bytes(0u8, padding_length as usize);
+
+        self.record_length() - DE_RECORD_HEADER_LENGTH
+    }
+}
+
+impl Serialize for DETargetEnergyDeposition {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let id_bytes = self.target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_f32(self.peak_irradiance);
+
+        id_bytes + 6
+    }
+}
+
+impl Serialize for DEDamageDescription {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(0u16);
+        self.damage_location.serialize(buf);
+        buf.put_f32(self.damage_diameter);
+        buf.put_f32(self.temperature);
+        buf.put_u8(self.component_identification.into());
+        buf.put_u8(self.component_damage_status.into());
+        buf.put_u8(self.component_visual_damage_status);
+        buf.put_u8(self.component_visual_smoke_color.into());
+        self.fire_event_id.serialize(buf);
+        buf.put_u16(0u16);
+
+        DE_DAMAGE_DESCRIPTION_RECORD_LENGTH - DE_RECORD_HEADER_LENGTH
+    }
+}
@@ -5,27 +5,30 @@
 };
 use crate::common::model::{PduBody, PduHeader};
 use crate::common::parser;
-use crate::common::parser::{entity_id, event_id, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, event_id, vec3_f32};
 use crate::enumerations::{
     BeamStatusBeamState, ElectromagneticEmissionBeamFunction,
     ElectromagneticEmissionStateUpdateIndicator, EmitterName, EmitterSystemFunction,
     HighDensityTrackJam,
 };
-use nom::IResult;
 use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u8, be_u16};
 
 pub(crate) fn emission_body(_header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
-    move |input| {
-        let (input, emitting_entity_id) = entity_id(input)?;
-        let (input, event_id) = event_id(input)?;
-        let (input, status_update_indicator) = be_u8(input)?;
-        let (input, no_of_systems) = be_u8(input)?;
-        let (input, _pad_16) = be_u16(input)?;
+    move |input: &[u8]| {
+        let (input, emitting_entity_id) = context("Emitting Entity ID", entity_id).parse(input)?;
+        let (input, event_id) = context("Event ID", event_id).parse(input)?;
+        let (input, status_update_indicator) =
+            context("Status Update Indicator", be_u8).parse(input)?;
+        let (input, no_of_systems) = context("Number of Systems", be_u8).parse(input)?;
+        let (input, _pad_16) = context("Padding", be_u16).parse(input)?;
 
-        let (input, mut emitter_systems) =
-            count(emitter_system, no_of_systems as usize).parse(input)?;
+        let (input, mut emitter_systems) = count(
+            context("Emitter System", emitter_system),
+            no_of_systems as usize,
+        )
+        .parse(input)?;
 
         let body = ElectromagneticEmission::builder()
             .with_emitting_entity_id(emitting_entity_id)
@@ -41,15 +44,15 @@ pub(crate) fn emission_body(_header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[
 }
 
 pub(crate) fn emitter_system(input: &[u8]) -> IResult<&[u8], EmitterSystem> {
-    let (input, _system_data_length) = be_u8(input)?;
-    let (input, no_of_beams) = be_u8(input)?;
-    let (input, _pad_16) = be_u16(input)?;
-    let (input, name) = be_u16(input)?;
-    let (input, function) = be_u8(input)?;
-    let (input, number) = be_u8(input)?;
-    let (input, location) = vec3_f32(input)?;
+    let (input, _system_data_length) = context("System Data Length", be_u8).parse(input)?;
+    let (input, no_of_beams) = context("Number of Beams", be_u8).parse(input)?;
+    let (input, _pad_16) = context("Padding", be_u16).parse(input)?;
+    let (input, name) = context("Name", be_u16).parse(input)?;
+    let (input, function) = context("Function", be_u8).parse(input)?;
+    let (input, number) = context("Number", be_u8).parse(input)?;
+    let (input, location) = context("Location", vec3_f32).parse(input)?;
 
-    let (input, mut beams) = count(beam, no_of_beams as usize).parse(input)?;
+    let (input, mut beams) = count(context("Beam", beam), no_of_beams as usize).parse(input)?;
 
     let system = EmitterSystem::new()
         .with_name(EmitterName::from(name))
@@ -62,17 +65,20 @@ pub(crate) fn emitter_system(input: &[u8]) -> IResult<&[u8], EmitterSystem> {
 }
 
 pub(crate) fn beam(input: &[u8]) -> IResult<&[u8], Beam> {
-    let (input, _data_length) = be_u8(input)?;
-    let (input, number) = be_u8(input)?;
-    let (input, parameter_index) = be_u16(input)?;
-    let (input, fundamental_parameter_data) = fundamental_parameter_data(input)?;
-    let (input, beam_data) = parser::beam_data(input)?;
-    let (input, function) = be_u8(input)?;
-    let (input, no_of_targets) = be_u8(input)?;
-    let (input, high_density_track_jam) = be_u8(input)?;
-    let (input, status) = be_u8(input)?;
-    let (input, jamming_technique) = jamming_technique(input)?;
-    let (input, mut track_jams) = count(track_jam, no_of_targets as usize).parse(input)?;
+    let (input, _data_length) = context("Data Length", be_u8).parse(input)?;
+    let (input, number) = context("Number", be_u8).parse(input)?;
+    let (input, parameter_index) = context("Parameter Index", be_u16).parse(input)?;
+    let (input, fundamental_parameter_data) =
+        context("Fundamental Parameter Data", fundamental_parameter_data).parse(input)?;
+    let (input, beam_data) = context("Beam Data", parser::beam_data).parse(input)?;
+    let (input, function) = context("Function", be_u8).parse(input)?;
+    let (input, no_of_targets) = context("Number of Targets", be_u8).parse(input)?;
+    let (input, high_density_track_jam) = context("High Density Track Jam", be_u8).parse(input)?;
+    let (input, status) = context("Status", be_u8).parse(input)?;
+    let (input, jamming_technique) =
+        context("Jamming Technique", jamming_technique).parse(input)?;
+    let (input, mut track_jams) =
+        count(context("Track/Jam Data", track_jam), no_of_targets as usize).parse(input)?;
 
     let beam = Beam::new()
         .with_number(number)
@@ -89,11 +95,12 @@ pub(crate) fn beam(input: &[u8]) -> IResult<&[u8], Beam> {
 }
 
 pub(crate) fn fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], FundamentalParameterData> {
-    let (input, frequency) = be_f32(input)?;
-    let (input, frequency_range) = be_f32(input)?;
-    let (input, effective_power) = be_f32(input)?;
-    let (input, pulse_repetition_frequency) = be_f32(input)?;
-    let (input, pulse_width) = be_f32(input)?;
+    let (input, frequency) = context("Frequency", be_f32).parse(input)?;
+    let (input, frequency_range) = context("Frequency Range", be_f32).parse(input)?;
+    let (input, effective_power) = context("Effective Power", be_f32).parse(input)?;
+    let (input, pulse_repetition_frequency) =
+        context("Pulse Repetition Frequency", be_f32).parse(input)?;
+    let (input, pulse_width) = context("Pulse Width", be_f32).parse(input)?;
 
     let data = FundamentalParameterData::new()
         .with_frequency(frequency)
@@ -106,10 +113,10 @@ pub(crate) fn fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], Fundame
 }
 
 pub(crate) fn jamming_technique(input: &[u8]) -> IResult<&[u8], JammingTechnique> {
-    let (input, kind) = be_u8(input)?;
-    let (input, category) = be_u8(input)?;
-    let (input, subcategory) = be_u8(input)?;
-    let (input, specific) = be_u8(input)?;
+    let (input, kind) = context("Kind", be_u8).parse(input)?;
+    let (input, category) = context("Category", be_u8).parse(input)?;
+    let (input, subcategory) = context("Subcategory", be_u8).parse(input)?;
+    let (input, specific) = context("Specific", be_u8).parse(input)?;
 
     let technique = JammingTechnique::new()
         .with_kind(kind)
@@ -121,9 +128,9 @@ pub(crate) fn jamming_technique(input: &[u8]) -> IResult<&[u8], JammingTechnique
 }
 
 pub(crate) fn track_jam(input: &[u8]) -> IResult<&[u8], TrackJam> {
-    let (input, entity_id) = entity_id(input)?;
-    let (input, emitter_number) = be_u8(input)?;
-    let (input, beam_number) = be_u8(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, emitter_number) = context("Emitter Number", be_u8).parse(input)?;
+    let (input, beam_number) = context("Beam Number", be_u8).parse(input)?;
 
     let track = TrackJam::new()
         .with_entity_id(entity_id)
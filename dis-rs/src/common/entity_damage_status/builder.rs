@@ -0,0 +1,46 @@
+use crate::directed_energy_fire::model::DERecord;
+use crate::entity_damage_status::model::EntityDamageStatus;
+use crate::model::EntityId;
+
+pub struct EntityDamageStatusBuilder(EntityDamageStatus);
+
+impl Default for EntityDamageStatusBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityDamageStatusBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        EntityDamageStatusBuilder(EntityDamageStatus::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: EntityDamageStatus) -> Self {
+        EntityDamageStatusBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> EntityDamageStatus {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_damaged_entity_id(mut self, damaged_entity_id: EntityId) -> Self {
+        self.0.damaged_entity_id = damaged_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage_description_record(mut self, record: DERecord) -> Self {
+        self.0.damage_description_records.push(record);
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage_description_records(mut self, records: Vec<DERecord>) -> Self {
+        self.0.damage_description_records = records;
+        self
+    }
+}
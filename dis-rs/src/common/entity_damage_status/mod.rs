@@ -0,0 +1,70 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::directed_energy_fire::model::{
+        DEComponentDamageStatus, DEComponentIdentification, DEComponentVisualSmokeColor,
+        DEDamageDescription, DERecord,
+    };
+    use crate::entity_damage_status::model::EntityDamageStatus;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::model::{EntityId, EventId, VectorF32};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn entity_damage_status_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::EntityDamageStatus).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Virtual)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = EntityDamageStatus::builder()
+            .with_damaged_entity_id(EntityId::new(1, 1, 2))
+            .with_damage_description_record(DERecord::DamageDescription(
+                DEDamageDescription::new()
+                    .with_damage_location(VectorF32::new(0.5, 0.0, -0.2))
+                    .with_damage_diameter(0.1)
+                    .with_temperature(450.0)
+                    .with_component_identification(DEComponentIdentification::AcquisitionSensor)
+                    .with_component_damage_status(DEComponentDamageStatus::MajorDamage)
+                    .with_component_visual_damage_status(0x01)
+                    .with_component_visual_smoke_color(DEComponentVisualSmokeColor::Gray)
+                    .with_fire_event_id(EventId::new(1, 1, 100)),
+            ))
+            .build();
+        assert_eq!(body.damage_description_records[0].record_length(), 40);
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+        assert_eq!(buf.len(), 64);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
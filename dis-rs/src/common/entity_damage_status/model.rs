@@ -0,0 +1,63 @@
+use crate::BodyRaw;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::directed_energy_fire::model::DERecord;
+use crate::entity_damage_status::builder::EntityDamageStatusBuilder;
+use crate::enumerations::PduType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH: u16 = 12;
+
+/// 5.4.6 Entity Damage Status PDU
+///
+/// 7.3.5 Entity Damage Status PDU
+///
+/// The damage description records are normally `DERecord::DamageDescription` records.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntityDamageStatus {
+    pub damaged_entity_id: EntityId,
+    pub damage_description_records: Vec<DERecord>,
+}
+
+impl BodyRaw for EntityDamageStatus {
+    type Builder = EntityDamageStatusBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::EntityDamageStatus(self)
+    }
+}
+
+impl BodyInfo for EntityDamageStatus {
+    fn body_length(&self) -> u16 {
+        BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH
+            + self
+                .damage_description_records
+                .iter()
+                .map(DERecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EntityDamageStatus
+    }
+}
+
+impl Interaction for EntityDamageStatus {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.damaged_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
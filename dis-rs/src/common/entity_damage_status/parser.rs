@@ -0,0 +1,28 @@
+use crate::BodyRaw;
+use crate::common::directed_energy_fire::parser::de_record;
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_id};
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::be_u16;
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn entity_damage_status_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, damaged_entity_id) = context("Damaged Entity ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, number_of_records) = context("Number of Records", be_u16).parse(input)?;
+    let (input, records) =
+        count(context("DE Record", de_record), number_of_records as usize).parse(input)?;
+
+    let body = EntityDamageStatus::builder()
+        .with_damaged_entity_id(damaged_entity_id)
+        .with_damage_description_records(records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
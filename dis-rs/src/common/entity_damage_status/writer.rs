@@ -0,0 +1,17 @@
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for EntityDamageStatus {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.damaged_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u16(0u16);
+        buf.put_u16(self.damage_description_records.len() as u16);
+        for record in &self.damage_description_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
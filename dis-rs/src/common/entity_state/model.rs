@@ -5,12 +5,12 @@
 use crate::constants::{FOUR_OCTETS, TWELVE_OCTETS, VARIABLE_PARAMETER_RECORD_LENGTH};
 use crate::entity_state::builder::EntityStateBuilder;
 use crate::enumerations::{
-    AirPlatformAppearance, AppearanceEntityOrObjectState, CulturalFeatureAppearance,
-    DeadReckoningAlgorithm, EntityCapabilities, EntityKind, EntityMarkingCharacterSet,
-    EnvironmentalAppearance, ExpendableAppearance, ForceId, LandPlatformAppearance,
-    LifeFormsAppearance, MunitionAppearance, PduType, PlatformDomain, RadioAppearance,
-    SensorEmitterAppearance, SpacePlatformAppearance, SubsurfacePlatformAppearance,
-    SupplyAppearance, SurfacePlatformAppearance,
+    AirPlatformAppearance, AppearanceDamage, AppearanceEntityOrObjectState,
+    CulturalFeatureAppearance, DeadReckoningAlgorithm, EntityCapabilities, EntityKind,
+    EntityMarkingCharacterSet, EnvironmentalAppearance, ExpendableAppearance, ForceId,
+    LandPlatformAppearance, LifeFormsAppearance, MunitionAppearance, PduType, PlatformDomain,
+    RadioAppearance, SensorEmitterAppearance, SpacePlatformAppearance,
+    SubsurfacePlatformAppearance, SupplyAppearance, SurfacePlatformAppearance,
 };
 use crate::{BodyRaw, DisError};
 #[cfg(feature = "serde")]
@@ -19,12 +19,6 @@
 
 const BASE_ENTITY_STATE_BODY_LENGTH: u16 = 132;
 
-// TODO sensible errors for EntityState
-#[allow(dead_code)]
-pub enum EntityStateValidationError {
-    SomeFieldNotOkError,
-}
-
 /// 5.3.2 Entity State PDU
 ///
 /// 7.2.2 Entity State PDU
@@ -197,6 +191,25 @@ pub fn is_frozen(&self) -> Option<bool> {
             EntityAppearance::Unspecified(_) => None,
         }
     }
+    /// The damage state of the entity, for the appearance records that define a Damage field.
+    #[must_use]
+    pub fn damage(&self) -> Option<AppearanceDamage> {
+        match self {
+            EntityAppearance::LandPlatform(appearance) => Some(appearance.damage),
+            EntityAppearance::AirPlatform(appearance) => Some(appearance.damage),
+            EntityAppearance::SurfacePlatform(appearance) => Some(appearance.damage),
+            EntityAppearance::SubsurfacePlatform(appearance) => Some(appearance.damage),
+            EntityAppearance::SpacePlatform(appearance) => Some(appearance.damage),
+            EntityAppearance::Munition(appearance) => Some(appearance.damage),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` when the appearance indicates the entity is destroyed.
+    #[must_use]
+    pub fn is_destroyed(&self) -> bool {
+        self.damage() == Some(AppearanceDamage::Destroyed)
+    }
 }
 
 impl From<&EntityAppearance> for u32 {
@@ -272,7 +285,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
                 marking_string: s.to_string(),
             })
         } else {
-            Err(DisError::ParseError(format!(
+            Err(DisError::StringFormatError(format!(
                 "String is too long for EntityMarking. Found {}, max 11 allowed.",
                 s.len()
             )))
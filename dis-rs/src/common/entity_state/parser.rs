@@ -5,14 +5,14 @@
 };
 use crate::common::model::{EntityType, PduBody, PduHeader};
 use crate::common::parser;
-use crate::common::parser::{entity_id, entity_type, sanitize_marking, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, entity_type, sanitize_marking, vec3_f32};
 use crate::enumerations::{
     DeadReckoningAlgorithm, EntityMarkingCharacterSet, ForceId, ProtocolVersion,
 };
 use crate::v6::entity_state::parser::entity_capabilities;
-use nom::IResult;
 use nom::Parser;
 use nom::bytes::complete::take;
+use nom::combinator::map;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
 
@@ -20,30 +20,45 @@ pub(crate) fn entity_state_body(
     header: &PduHeader,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
-        let (input, entity_id_val) = entity_id(input)?;
-        let (input, force_id_val) = force_id(input)?;
-        let (input, variable_parameters_no) = be_u8(input)?;
-        let (input, entity_type_val) = entity_type(input)?;
-        let (input, alternative_entity_type) = entity_type(input)?;
-        let (input, entity_linear_velocity) = vec3_f32(input)?;
-        let (input, entity_location) = parser::location(input)?;
-        let (input, entity_orientation) = parser::orientation(input)?;
-        let (input, entity_appearance) = entity_appearance(entity_type_val)(input)?;
-        let (input, dead_reckoning_parameters) = dr_parameters(input)?;
-        let (input, entity_marking) = entity_marking(input)?;
+        let (input, entity_id_val) = context("Entity ID", entity_id).parse(input)?;
+        let (input, force_id_val) = context("Force ID", force_id).parse(input)?;
+        let (input, variable_parameters_no) =
+            context("Number of Variable Parameters", be_u8).parse(input)?;
+        let (input, entity_type_val) = context("Entity Type", entity_type).parse(input)?;
+        let (input, alternative_entity_type) =
+            context("Alternative Entity Type", entity_type).parse(input)?;
+        let (input, entity_linear_velocity) =
+            context("Entity Linear Velocity", vec3_f32).parse(input)?;
+        let (input, entity_location) = context("Entity Location", parser::location).parse(input)?;
+        let (input, entity_orientation) =
+            context("Entity Orientation", parser::orientation).parse(input)?;
+        let (input, entity_appearance) =
+            context("Entity Appearance", entity_appearance(entity_type_val)).parse(input)?;
+        let (input, dead_reckoning_parameters) =
+            context("Dead Reckoning Parameters", dr_parameters).parse(input)?;
+        let (input, entity_marking) = context("Entity Marking", entity_marking).parse(input)?;
         #[allow(clippy::wildcard_in_or_patterns)]
         let (input, entity_capabilities) =
             if header.protocol_version == ProtocolVersion::IEEE1278_12012 {
-                crate::v7::entity_state::parser::entity_capabilities(entity_type_val)(input)?
+                context(
+                    "Entity Capabilities",
+                    crate::v7::entity_state::parser::entity_capabilities(entity_type_val),
+                )
+                .parse(input)?
             } else {
-                let (input, entity_capabilities) = entity_capabilities(input)?;
+                let (input, entity_capabilities) =
+                    context("Entity Capabilities", entity_capabilities).parse(input)?;
                 (
                     input,
                     crate::enumerations::EntityCapabilities::from(entity_capabilities),
                 )
             };
         let (input, variable_parameters) = if variable_parameters_no > 0 {
-            count(parser::variable_parameter, variable_parameters_no as usize).parse(input)?
+            count(
+                context("Variable Parameter", parser::variable_parameter),
+                variable_parameters_no as usize,
+            )
+            .parse(input)?
         } else {
             (input, vec![])
         };
@@ -90,12 +105,11 @@ pub(crate) fn entity_appearance(
 /// strip trailing whitespace and any trailing non-alphanumeric characters. In case the marking is less
 /// than 11 characters, the trailing bytes are typically 0x00 in the PDU, which in UTF-8 is a control character.
 pub(crate) fn entity_marking(input: &[u8]) -> IResult<&[u8], EntityMarking> {
-    let mut buf: [u8; 11] = [0; 11];
-    let (input, marking_character_set) = be_u8(input)?;
-    let (input, ()) = nom::multi::fill(be_u8, &mut buf).parse(input)?;
+    let (input, marking_character_set) = context("Marking Character Set", be_u8).parse(input)?;
+    let (input, marking_string) =
+        context("Marking String", map(take(11usize), sanitize_marking)).parse(input)?;
 
     let marking_character_set = EntityMarkingCharacterSet::from(marking_character_set);
-    let marking_string = sanitize_marking(&buf[..]);
 
     Ok((
         input,
@@ -107,10 +121,13 @@ pub(crate) fn entity_marking(input: &[u8]) -> IResult<&[u8], EntityMarking> {
 }
 
 pub(crate) fn dr_parameters(input: &[u8]) -> IResult<&[u8], DrParameters> {
-    let (input, algorithm) = be_u8(input)?;
+    let (input, algorithm) = context("Algorithm", be_u8).parse(input)?;
     let algorithm = DeadReckoningAlgorithm::from(algorithm);
 
-    let (input, other_parameters) = dr_other_parameters(input, algorithm)?;
+    let (input, other_parameters) = context("Other Parameters", |input| {
+        dr_other_parameters(input, algorithm)
+    })
+    .parse(input)?;
 
     // // This match statement basically determines the value of the DrParametersType field for Euler and Quaternion variants
     // let (input, other_parameters) = match algorithm {
@@ -135,8 +152,8 @@ pub(crate) fn dr_parameters(input: &[u8]) -> IResult<&[u8], DrParameters> {
     //     }
     // };
 
-    let (input, acceleration) = vec3_f32(input)?;
-    let (input, velocity) = vec3_f32(input)?;
+    let (input, acceleration) = context("Acceleration", vec3_f32).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
 
     Ok((
         input,
@@ -183,11 +200,11 @@ pub(crate) fn dr_other_parameters_none(input: &[u8]) -> IResult<&[u8], DrOtherPa
 }
 
 pub(crate) fn dr_other_parameters_euler(input: &[u8]) -> IResult<&[u8], DrOtherParameters> {
-    let (input, _param_type) = be_u8(input)?;
-    let (input, _unused) = be_u16(input)?;
-    let (input, local_yaw) = be_f32(input)?;
-    let (input, local_pitch) = be_f32(input)?;
-    let (input, local_roll) = be_f32(input)?;
+    let (input, _param_type) = context("Parameter Type", be_u8).parse(input)?;
+    let (input, _unused) = context("Padding", be_u16).parse(input)?;
+    let (input, local_yaw) = context("Local Yaw", be_f32).parse(input)?;
+    let (input, local_pitch) = context("Local Pitch", be_f32).parse(input)?;
+    let (input, local_roll) = context("Local Roll", be_f32).parse(input)?;
     Ok((
         input,
         DrOtherParameters::LocalEulerAngles(DrEulerAngles {
@@ -199,11 +216,11 @@ pub(crate) fn dr_other_parameters_euler(input: &[u8]) -> IResult<&[u8], DrOtherP
 }
 
 pub(crate) fn dr_other_parameters_quaternion(input: &[u8]) -> IResult<&[u8], DrOtherParameters> {
-    let (input, _param_type) = be_u8(input)?;
-    let (input, nil) = be_u16(input)?;
-    let (input, x) = be_f32(input)?;
-    let (input, y) = be_f32(input)?;
-    let (input, z) = be_f32(input)?;
+    let (input, _param_type) = context("Parameter Type", be_u8).parse(input)?;
+    let (input, nil) = context("Nil", be_u16).parse(input)?;
+    let (input, x) = context("X", be_f32).parse(input)?;
+    let (input, y) = context("Y", be_f32).parse(input)?;
+    let (input, z) = context("Z", be_f32).parse(input)?;
     Ok((
         input,
         DrOtherParameters::WorldOrientationQuaternion(DrWorldOrientationQuaternion {
@@ -2,23 +2,34 @@
 use crate::common::entity_state::parser::entity_appearance;
 use crate::common::entity_state_update::model::EntityStateUpdate;
 use crate::common::model::{EntityType, PduBody};
-use crate::common::parser::{entity_id, location, orientation, variable_parameter, vec3_f32};
+use crate::common::parser::{
+    IResult, context, entity_id, location, orientation, variable_parameter, vec3_f32,
+};
 use crate::enumerations::EntityKind;
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::be_u8;
-use nom::{IResult, Parser};
 
 pub(crate) fn entity_state_update_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, entity_id_val) = entity_id(input)?;
-    let (input, _padding) = be_u8(input)?;
-    let (input, variable_parameters_no) = be_u8(input)?;
-    let (input, entity_linear_velocity) = vec3_f32(input)?;
-    let (input, entity_location) = location(input)?;
-    let (input, entity_orientation) = orientation(input)?;
-    let (input, entity_appearance) =
-        entity_appearance(EntityType::default().with_kind(EntityKind::Other))(input)?;
+    let (input, entity_id_val) = context("Entity ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, variable_parameters_no) =
+        context("Number of Variable Parameters", be_u8).parse(input)?;
+    let (input, entity_linear_velocity) =
+        context("Entity Linear Velocity", vec3_f32).parse(input)?;
+    let (input, entity_location) = context("Entity Location", location).parse(input)?;
+    let (input, entity_orientation) = context("Entity Orientation", orientation).parse(input)?;
+    let (input, entity_appearance) = context(
+        "Entity Appearance",
+        entity_appearance(EntityType::default().with_kind(EntityKind::Other)),
+    )
+    .parse(input)?;
     let (input, variable_parameters) = if variable_parameters_no > 0 {
-        count(variable_parameter, variable_parameters_no as usize).parse(input)?
+        count(
+            context("Variable Parameter", variable_parameter),
+            variable_parameters_no as usize,
+        )
+        .parse(input)?
     } else {
         (input, vec![])
     };
@@ -0,0 +1,150 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, EntityKind, LvcIndicator, PduType, TransferredEntityIndicator,
+    };
+    use crate::environmental_process::model::{
+        BoundingSphere, CombicState, ConeRecord1, EnvironmentRecord, EnvironmentRecordData,
+        EnvironmentStatus, EnvironmentalProcess, FlareState, RectangularVolumeRecord3,
+    };
+    use crate::model::{EntityId, EntityType, Location, Orientation, VectorF32};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn environmental_process_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::EnvironmentalProcess).with_pdu_status(
+            PduStatus::default()
+                .with_transferred_entity_indicator(TransferredEntityIndicator::NoDifference)
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = EnvironmentalProcess::builder()
+            .with_environmental_process_id(EntityId::new(1, 2, 3))
+            .with_environment_type(EntityType::default().with_kind(EntityKind::Environmental))
+            .with_model_type(1)
+            .with_environment_status(EnvironmentStatus::new(true, true))
+            .with_sequence_number(7)
+            .with_environment_record(EnvironmentRecord::new(
+                1,
+                EnvironmentRecordData::CombicState(CombicState {
+                    time_since_creation: 1000,
+                    number_of_sources: 2,
+                    geometry_index: 3,
+                    source_type: 1,
+                    barrage_rate: 1.5,
+                    barrage_duration: 30.0,
+                    barrage_crosswind_length: 10.0,
+                    barrage_downwind_length: 20.0,
+                    detonation_velocity: VectorF32::new(1.0, 2.0, 3.0),
+                    ..Default::default()
+                }),
+            ))
+            .with_environment_record(EnvironmentRecord::new(
+                2,
+                EnvironmentRecordData::FlareState(FlareState {
+                    time_since_creation: 500,
+                    number_of_intensity: 3,
+                    number_of_sources: 1,
+                    geometry_index: 4,
+                    ..Default::default()
+                }),
+            ))
+            .with_environment_record(EnvironmentRecord::new(
+                3,
+                EnvironmentRecordData::BoundingSphere(BoundingSphere {
+                    centroid_location: Location::new(100.0, 200.0, 300.0),
+                    radius: 50.0,
+                }),
+            ))
+            .with_environment_record(EnvironmentRecord::new(
+                4,
+                EnvironmentRecordData::ConeRecord1(ConeRecord1 {
+                    vertex_location: Location::new(1.0, 2.0, 3.0),
+                    orientation: Orientation::new(0.1, 0.2, 0.3),
+                    height: 10.0,
+                    peak_angle: 0.5,
+                }),
+            ))
+            .with_environment_record(EnvironmentRecord::new(
+                5,
+                EnvironmentRecordData::RectangularVolumeRecord3(RectangularVolumeRecord3 {
+                    center_location: Location::new(4.0, 5.0, 6.0),
+                    lengths: VectorF32::new(10.0, 20.0, 30.0),
+                    orientation: Orientation::new(0.0, 0.0, 1.0),
+                }),
+            ))
+            .with_environment_record(EnvironmentRecord::new(
+                6,
+                EnvironmentRecordData::Unspecified(12_345, vec![1, 2, 3]),
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn environmental_process_v6_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::EnvironmentalProcess);
+
+        let body = EnvironmentalProcess::builder()
+            .with_environmental_process_id(EntityId::new(1, 2, 3))
+            .with_environment_record(EnvironmentRecord::new(
+                0,
+                EnvironmentRecordData::UniformGeometry,
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let _ = original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(original_pdu, parsed);
+    }
+
+    #[test]
+    fn environment_status_bits() {
+        let status = EnvironmentStatus::from(0b0000_0010);
+        assert!(!status.is_last);
+        assert!(status.is_active);
+        assert_eq!(u8::from(&EnvironmentStatus::new(true, false)), 0b0000_0001);
+    }
+}
@@ -0,0 +1,427 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, EntityType, Location, Orientation, PduBody, VectorF32, length_padded_to_num,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::EIGHT_OCTETS;
+use crate::enumerations::PduType;
+use crate::environmental_process::builder::EnvironmentalProcessBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH: u16 = 20;
+pub(crate) const BASE_ENVIRONMENT_RECORD_LENGTH: u16 = 8;
+
+/// 5.11.2.2 Environmental Process PDU
+///
+/// 7.10.2 Environmental Process PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentalProcess {
+    pub environmental_process_id: EntityId,
+    pub environment_type: EntityType,
+    pub model_type: u8,
+    pub environment_status: EnvironmentStatus,
+    pub sequence_number: u16,
+    pub environment_records: Vec<EnvironmentRecord>,
+}
+
+impl BodyRaw for EnvironmentalProcess {
+    type Builder = EnvironmentalProcessBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::EnvironmentalProcess(self)
+    }
+}
+
+impl BodyInfo for EnvironmentalProcess {
+    fn body_length(&self) -> u16 {
+        BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH
+            + self
+                .environment_records
+                .iter()
+                .map(EnvironmentRecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EnvironmentalProcess
+    }
+}
+
+impl Interaction for EnvironmentalProcess {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_process_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Environment Status field of the Environmental Process PDU (SISO-REF-010 UID 248)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentStatus {
+    pub is_last: bool,
+    pub is_active: bool,
+}
+
+impl EnvironmentStatus {
+    #[must_use]
+    pub fn new(is_last: bool, is_active: bool) -> Self {
+        Self { is_last, is_active }
+    }
+}
+
+impl From<u8> for EnvironmentStatus {
+    fn from(value: u8) -> Self {
+        Self {
+            is_last: (value & 0x01) != 0,
+            is_active: ((value >> 1) & 0x01) != 0,
+        }
+    }
+}
+
+impl From<&EnvironmentStatus> for u8 {
+    fn from(value: &EnvironmentStatus) -> Self {
+        u8::from(value.is_last) | (u8::from(value.is_active) << 1)
+    }
+}
+
+/// 6.2.31 Environment record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentRecord {
+    pub index: u8,
+    pub data: EnvironmentRecordData,
+}
+
+impl EnvironmentRecord {
+    #[must_use]
+    pub fn new(index: u8, data: EnvironmentRecordData) -> Self {
+        Self { index, data }
+    }
+
+    /// Length of the record-specific fields in bits, excluding padding, as carried in the Record Length field.
+    #[must_use]
+    pub fn data_length_bits(&self) -> u16 {
+        self.data.data_length() * 8
+    }
+
+    /// Total length of the record in octets, including the padding to a 64-bit boundary.
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        length_padded_to_num(
+            (BASE_ENVIRONMENT_RECORD_LENGTH + self.data.data_length()).into(),
+            EIGHT_OCTETS,
+        )
+        .record_length as u16
+    }
+}
+
+/// The record-specific fields of an Environment record, selected by the Record Type (SISO-REF-010 UID 250).
+///
+/// Record types that are not modeled are kept as raw data in the `Unspecified` variant.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum EnvironmentRecordData {
+    CombicState(CombicState),
+    FlareState(FlareState),
+    BoundingSphere(BoundingSphere),
+    #[default]
+    UniformGeometry,
+    PointRecord1(PointRecord1),
+    LineRecord1(LineRecord1),
+    SphereRecord1(SphereRecord1),
+    EllipsoidRecord1(EllipsoidRecord1),
+    ConeRecord1(ConeRecord1),
+    RectangularVolumeRecord1(RectangularVolumeRecord1),
+    RectangularVolumeRecord3(RectangularVolumeRecord3),
+    PointRecord2(PointRecord2),
+    LineRecord2(LineRecord2),
+    SphereRecord2(SphereRecord2),
+    EllipsoidRecord2(EllipsoidRecord2),
+    ConeRecord2(ConeRecord2),
+    RectangularVolumeRecord2(RectangularVolumeRecord2),
+    GaussianPlume(GaussianPlume),
+    GaussianPuff(GaussianPuff),
+    Unspecified(u32, Vec<u8>),
+}
+
+pub(crate) const RECORD_TYPE_COMBIC_STATE: u32 = 256;
+pub(crate) const RECORD_TYPE_FLARE_STATE: u32 = 259;
+pub(crate) const RECORD_TYPE_BOUNDING_SPHERE: u32 = 65_536;
+pub(crate) const RECORD_TYPE_UNIFORM_GEOMETRY: u32 = 327_680;
+pub(crate) const RECORD_TYPE_POINT_1: u32 = 655_360;
+pub(crate) const RECORD_TYPE_LINE_1: u32 = 786_432;
+pub(crate) const RECORD_TYPE_SPHERE_1: u32 = 851_968;
+pub(crate) const RECORD_TYPE_ELLIPSOID_1: u32 = 1_048_576;
+pub(crate) const RECORD_TYPE_CONE_1: u32 = 3_145_728;
+pub(crate) const RECORD_TYPE_RECTANGULAR_VOLUME_1: u32 = 5_242_880;
+pub(crate) const RECORD_TYPE_RECTANGULAR_VOLUME_3: u32 = 83_886_080;
+pub(crate) const RECORD_TYPE_POINT_2: u32 = 167_772_160;
+pub(crate) const RECORD_TYPE_LINE_2: u32 = 201_326_592;
+pub(crate) const RECORD_TYPE_SPHERE_2: u32 = 218_103_808;
+pub(crate) const RECORD_TYPE_ELLIPSOID_2: u32 = 268_435_456;
+pub(crate) const RECORD_TYPE_CONE_2: u32 = 805_306_368;
+pub(crate) const RECORD_TYPE_RECTANGULAR_VOLUME_2: u32 = 1_342_177_280;
+pub(crate) const RECORD_TYPE_GAUSSIAN_PLUME: u32 = 1_610_612_736;
+pub(crate) const RECORD_TYPE_GAUSSIAN_PUFF: u32 = 1_879_048_192;
+
+impl EnvironmentRecordData {
+    /// The Record Type value (SISO-REF-010 UID 250) of the record.
+    #[must_use]
+    pub fn record_type(&self) -> u32 {
+        match self {
+            EnvironmentRecordData::CombicState(_) => RECORD_TYPE_COMBIC_STATE,
+            EnvironmentRecordData::FlareState(_) => RECORD_TYPE_FLARE_STATE,
+            EnvironmentRecordData::BoundingSphere(_) => RECORD_TYPE_BOUNDING_SPHERE,
+            EnvironmentRecordData::UniformGeometry => RECORD_TYPE_UNIFORM_GEOMETRY,
+            EnvironmentRecordData::PointRecord1(_) => RECORD_TYPE_POINT_1,
+            EnvironmentRecordData::LineRecord1(_) => RECORD_TYPE_LINE_1,
+            EnvironmentRecordData::SphereRecord1(_) => RECORD_TYPE_SPHERE_1,
+            EnvironmentRecordData::EllipsoidRecord1(_) => RECORD_TYPE_ELLIPSOID_1,
+            EnvironmentRecordData::ConeRecord1(_) => RECORD_TYPE_CONE_1,
+            EnvironmentRecordData::RectangularVolumeRecord1(_) => RECORD_TYPE_RECTANGULAR_VOLUME_1,
+            EnvironmentRecordData::RectangularVolumeRecord3(_) => RECORD_TYPE_RECTANGULAR_VOLUME_3,
+            EnvironmentRecordData::PointRecord2(_) => RECORD_TYPE_POINT_2,
+            EnvironmentRecordData::LineRecord2(_) => RECORD_TYPE_LINE_2,
+            EnvironmentRecordData::SphereRecord2(_) => RECORD_TYPE_SPHERE_2,
+            EnvironmentRecordData::EllipsoidRecord2(_) => RECORD_TYPE_ELLIPSOID_2,
+            EnvironmentRecordData::ConeRecord2(_) => RECORD_TYPE_CONE_2,
+            EnvironmentRecordData::RectangularVolumeRecord2(_) => RECORD_TYPE_RECTANGULAR_VOLUME_2,
+            EnvironmentRecordData::GaussianPlume(_) => RECORD_TYPE_GAUSSIAN_PLUME,
+            EnvironmentRecordData::GaussianPuff(_) => RECORD_TYPE_GAUSSIAN_PUFF,
+            EnvironmentRecordData::Unspecified(record_type, _) => *record_type,
+        }
+    }
+
+    /// Length of the record-specific fields in octets, excluding padding.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    pub fn data_length(&self) -> u16 {
+        match self {
+            EnvironmentRecordData::CombicState(_) => 52,
+            EnvironmentRecordData::FlareState(_) => 24,
+            EnvironmentRecordData::BoundingSphere(_) => 28,
+            EnvironmentRecordData::UniformGeometry => 0,
+            EnvironmentRecordData::PointRecord1(_) => 24,
+            EnvironmentRecordData::LineRecord1(_) => 48,
+            EnvironmentRecordData::SphereRecord1(_) => 28,
+            EnvironmentRecordData::EllipsoidRecord1(_) => 48,
+            EnvironmentRecordData::ConeRecord1(_) => 44,
+            EnvironmentRecordData::RectangularVolumeRecord1(_) => 36,
+            EnvironmentRecordData::RectangularVolumeRecord3(_) => 48,
+            EnvironmentRecordData::PointRecord2(_) => 36,
+            EnvironmentRecordData::LineRecord2(_) => 72,
+            EnvironmentRecordData::SphereRecord2(_) => 56,
+            EnvironmentRecordData::EllipsoidRecord2(_) => 84,
+            EnvironmentRecordData::ConeRecord2(_) => 76,
+            EnvironmentRecordData::RectangularVolumeRecord2(_) => 84,
+            EnvironmentRecordData::GaussianPlume(_) => 76,
+            EnvironmentRecordData::GaussianPuff(_) => 112,
+            EnvironmentRecordData::Unspecified(_, data) => data.len() as u16,
+        }
+    }
+}
+
+/// COMBIC State record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CombicState {
+    pub time_since_creation: u32,
+    pub munition_source: EntityType,
+    pub number_of_sources: u32,
+    pub geometry_index: u16,
+    pub source_type: u32,
+    pub barrage_rate: f32,
+    pub barrage_duration: f32,
+    pub barrage_crosswind_length: f32,
+    pub barrage_downwind_length: f32,
+    pub detonation_velocity: VectorF32,
+}
+
+/// Flare State record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlareState {
+    pub time_since_creation: u32,
+    pub munition_source: EntityType,
+    pub number_of_intensity: u32,
+    pub number_of_sources: u32,
+    pub geometry_index: u16,
+}
+
+/// Bounding Sphere record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoundingSphere {
+    pub centroid_location: Location,
+    pub radius: f32,
+}
+
+/// Point record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointRecord1 {
+    pub location: Location,
+}
+
+/// Line record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineRecord1 {
+    pub start_point_location: Location,
+    pub end_point_location: Location,
+}
+
+/// Sphere record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphereRecord1 {
+    pub centroid_location: Location,
+    pub radius: f32,
+}
+
+/// Ellipsoid record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EllipsoidRecord1 {
+    pub centroid_location: Location,
+    pub sigma: VectorF32,
+    pub orientation: Orientation,
+}
+
+/// Cone record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConeRecord1 {
+    pub vertex_location: Location,
+    pub orientation: Orientation,
+    pub height: f32,
+    pub peak_angle: f32,
+}
+
+/// Rectangular Volume record 1
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord1 {
+    pub corner_location: Location,
+    pub lengths: VectorF32,
+}
+
+/// Rectangular Volume record 3
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord3 {
+    pub center_location: Location,
+    pub lengths: VectorF32,
+    pub orientation: Orientation,
+}
+
+/// Point record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointRecord2 {
+    pub location: Location,
+    pub velocity: VectorF32,
+}
+
+/// Line record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineRecord2 {
+    pub start_point_location: Location,
+    pub end_point_location: Location,
+    pub start_point_velocity: VectorF32,
+    pub end_point_velocity: VectorF32,
+}
+
+/// Sphere record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphereRecord2 {
+    pub centroid_location: Location,
+    pub radius: f32,
+    pub radius_rate: f32,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// Ellipsoid record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EllipsoidRecord2 {
+    pub centroid_location: Location,
+    pub sigma: VectorF32,
+    pub sigma_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// Cone record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConeRecord2 {
+    pub vertex_location: Location,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+    pub height: f32,
+    pub height_rate: f32,
+    pub peak_angle: f32,
+    pub peak_angle_rate: f32,
+}
+
+/// Rectangular Volume record 2
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord2 {
+    pub corner_location: Location,
+    pub lengths: VectorF32,
+    pub lengths_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// Gaussian Plume record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GaussianPlume {
+    pub source_location: Location,
+    pub orientation: Orientation,
+    pub plume_length: f32,
+    pub plume_width: f32,
+    pub plume_top_height: f32,
+    pub plume_length_rate: f32,
+    pub plume_width_rate: f32,
+    pub plume_top_height_rate: f32,
+    pub leading_edge_centroid_height: f32,
+    pub leading_edge_velocity: VectorF32,
+}
+
+/// Gaussian Puff record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GaussianPuff {
+    pub puff_location: Location,
+    pub origination_location: Location,
+    pub sigma: VectorF32,
+    pub sigma_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+    pub centroid_height: f32,
+}
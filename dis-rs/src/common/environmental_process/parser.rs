@@ -0,0 +1,435 @@
+use crate::BodyRaw;
+use crate::common::model::{PduBody, length_padded_to_num};
+use crate::common::parser::{
+    IResult, context, entity_id, entity_type, location, orientation, vec3_f32,
+};
+use crate::constants::EIGHT_OCTETS;
+use crate::environmental_process::model::{
+    BASE_ENVIRONMENT_RECORD_LENGTH, BoundingSphere, CombicState, ConeRecord1, ConeRecord2,
+    EllipsoidRecord1, EllipsoidRecord2, EnvironmentRecord, EnvironmentRecordData,
+    EnvironmentStatus, EnvironmentalProcess, FlareState, GaussianPlume, GaussianPuff, LineRecord1,
+    LineRecord2, PointRecord1, PointRecord2, RECORD_TYPE_BOUNDING_SPHERE, RECORD_TYPE_COMBIC_STATE,
+    RECORD_TYPE_CONE_1, RECORD_TYPE_CONE_2, RECORD_TYPE_ELLIPSOID_1, RECORD_TYPE_ELLIPSOID_2,
+    RECORD_TYPE_FLARE_STATE, RECORD_TYPE_GAUSSIAN_PLUME, RECORD_TYPE_GAUSSIAN_PUFF,
+    RECORD_TYPE_LINE_1, RECORD_TYPE_LINE_2, RECORD_TYPE_POINT_1, RECORD_TYPE_POINT_2,
+    RECORD_TYPE_RECTANGULAR_VOLUME_1, RECORD_TYPE_RECTANGULAR_VOLUME_2,
+    RECORD_TYPE_RECTANGULAR_VOLUME_3, RECORD_TYPE_SPHERE_1, RECORD_TYPE_SPHERE_2,
+    RECORD_TYPE_UNIFORM_GEOMETRY, RectangularVolumeRecord1, RectangularVolumeRecord2,
+    RectangularVolumeRecord3, SphereRecord1, SphereRecord2,
+};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
+
+pub(crate) fn environmental_process_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, environmental_process_id) =
+        context("Environmental Process ID", entity_id).parse(input)?;
+    let (input, environment_type) = context("Environment Type", entity_type).parse(input)?;
+    let (input, model_type) = context("Model Type", be_u8).parse(input)?;
+    let (input, environment_status) = context("Environment Status", be_u8).parse(input)?;
+    let environment_status = EnvironmentStatus::from(environment_status);
+    let (input, number_of_environment_records) =
+        context("Number of Environment Records", be_u16).parse(input)?;
+    let (input, sequence_number) = context("Sequence Number", be_u16).parse(input)?;
+    let (input, environment_records) = count(
+        context("Environment Record", environment_record),
+        number_of_environment_records.into(),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentalProcess::builder()
+            .with_environmental_process_id(environmental_process_id)
+            .with_environment_type(environment_type)
+            .with_model_type(model_type)
+            .with_environment_status(environment_status)
+            .with_sequence_number(sequence_number)
+            .with_environment_records(environment_records)
+            .build()
+            .into_pdu_body(),
+    ))
+}
+
+pub(crate) fn environment_record(input: &[u8]) -> IResult<&[u8], EnvironmentRecord> {
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
+    let (input, record_length_bits) = context("Record Length Bits", be_u16).parse(input)?;
+    let (input, index) = context("Index", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let data_length = record_length_bits / 8;
+    let (input, data) = match record_type {
+        RECORD_TYPE_COMBIC_STATE => context("Combic State", combic_state).parse(input)?,
+        RECORD_TYPE_FLARE_STATE => context("Flare State", flare_state).parse(input)?,
+        RECORD_TYPE_BOUNDING_SPHERE => context("Bounding Sphere", bounding_sphere).parse(input)?,
+        RECORD_TYPE_UNIFORM_GEOMETRY => (input, EnvironmentRecordData::UniformGeometry),
+        RECORD_TYPE_POINT_1 => context("Point Record 1", point_record_1).parse(input)?,
+        RECORD_TYPE_LINE_1 => context("Line Record 1", line_record_1).parse(input)?,
+        RECORD_TYPE_SPHERE_1 => context("Sphere Record 1", sphere_record_1).parse(input)?,
+        RECORD_TYPE_ELLIPSOID_1 => {
+            context("Ellipsoid Record 1", ellipsoid_record_1).parse(input)?
+        }
+        RECORD_TYPE_CONE_1 => context("Cone Record 1", cone_record_1).parse(input)?,
+        RECORD_TYPE_RECTANGULAR_VOLUME_1 => {
+            context("Rectangular Volume Record 1", rectangular_volume_record_1).parse(input)?
+        }
+        RECORD_TYPE_RECTANGULAR_VOLUME_3 => {
+            context("Rectangular Volume Record 3", rectangular_volume_record_3).parse(input)?
+        }
+        RECORD_TYPE_POINT_2 => context("Point Record 2", point_record_2).parse(input)?,
+        RECORD_TYPE_LINE_2 => context("Line Record 2", line_record_2).parse(input)?,
+        RECORD_TYPE_SPHERE_2 => context("Sphere Record 2", sphere_record_2).parse(input)?,
+        RECORD_TYPE_ELLIPSOID_2 => {
+            context("Ellipsoid Record 2", ellipsoid_record_2).parse(input)?
+        }
+        RECORD_TYPE_CONE_2 => context("Cone Record 2", cone_record_2).parse(input)?,
+        RECORD_TYPE_RECTANGULAR_VOLUME_2 => {
+            context("Rectangular Volume Record 2", rectangular_volume_record_2).parse(input)?
+        }
+        RECORD_TYPE_GAUSSIAN_PLUME => context("Gaussian Plume", gaussian_plume).parse(input)?,
+        RECORD_TYPE_GAUSSIAN_PUFF => context("Gaussian Puff", gaussian_puff).parse(input)?,
+        _ => {
+            let (input, data) = context("Record Data", take(data_length)).parse(input)?;
+            (
+                input,
+                EnvironmentRecordData::Unspecified(record_type, data.to_vec()),
+            )
+        }
+    };
+    let padding = length_padded_to_num(
+        (BASE_ENVIRONMENT_RECORD_LENGTH + data.data_length()).into(),
+        EIGHT_OCTETS,
+    )
+    .padding_length;
+    let (input, _padding) = context("Padding", take(padding)).parse(input)?;
+
+    Ok((input, EnvironmentRecord::new(index, data)))
+}
+
+fn combic_state(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, time_since_creation) = context("Time since Creation", be_u32).parse(input)?;
+    let (input, munition_source) = context("Munition Source", entity_type).parse(input)?;
+    let (input, number_of_sources) = context("Number of Sources", be_u32).parse(input)?;
+    let (input, geometry_index) = context("Geometry Index", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, source_type) = context("Source Type", be_u32).parse(input)?;
+    let (input, barrage_rate) = context("Barrage Rate", be_f32).parse(input)?;
+    let (input, barrage_duration) = context("Barrage Duration", be_f32).parse(input)?;
+    let (input, barrage_crosswind_length) =
+        context("Barrage Crosswind Length", be_f32).parse(input)?;
+    let (input, barrage_downwind_length) =
+        context("Barrage Downwind Length", be_f32).parse(input)?;
+    let (input, detonation_velocity) = context("Detonation Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::CombicState(CombicState {
+            time_since_creation,
+            munition_source,
+            number_of_sources,
+            geometry_index,
+            source_type,
+            barrage_rate,
+            barrage_duration,
+            barrage_crosswind_length,
+            barrage_downwind_length,
+            detonation_velocity,
+        }),
+    ))
+}
+
+fn flare_state(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, time_since_creation) = context("Time since Creation", be_u32).parse(input)?;
+    let (input, munition_source) = context("Munition Source", entity_type).parse(input)?;
+    let (input, number_of_intensity) = context("Number of Intensity", be_u32).parse(input)?;
+    let (input, number_of_sources) = context("Number of Sources", be_u32).parse(input)?;
+    let (input, geometry_index) = context("Geometry Index", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::FlareState(FlareState {
+            time_since_creation,
+            munition_source,
+            number_of_intensity,
+            number_of_sources,
+            geometry_index,
+        }),
+    ))
+}
+
+fn bounding_sphere(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, centroid_location) = context("Centroid Location", location).parse(input)?;
+    let (input, radius) = context("Radius", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::BoundingSphere(BoundingSphere {
+            centroid_location,
+            radius,
+        }),
+    ))
+}
+
+fn point_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, location) = context("Location", location).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::PointRecord1(PointRecord1 { location }),
+    ))
+}
+
+fn line_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, start_point_location) = context("Start Point Location", location).parse(input)?;
+    let (input, end_point_location) = context("End Point Location", location).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::LineRecord1(LineRecord1 {
+            start_point_location,
+            end_point_location,
+        }),
+    ))
+}
+
+fn sphere_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, centroid_location) = context("Centroid Location", location).parse(input)?;
+    let (input, radius) = context("Radius", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::SphereRecord1(SphereRecord1 {
+            centroid_location,
+            radius,
+        }),
+    ))
+}
+
+fn ellipsoid_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, centroid_location) = context("Centroid Location", location).parse(input)?;
+    let (input, sigma) = context("Sigma", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::EllipsoidRecord1(EllipsoidRecord1 {
+            centroid_location,
+            sigma,
+            orientation,
+        }),
+    ))
+}
+
+fn cone_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, vertex_location) = context("Vertex Location", location).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, height) = context("Height", be_f32).parse(input)?;
+    let (input, peak_angle) = context("Peak Angle", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::ConeRecord1(ConeRecord1 {
+            vertex_location,
+            orientation,
+            height,
+            peak_angle,
+        }),
+    ))
+}
+
+fn rectangular_volume_record_1(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, corner_location) = context("Corner Location", location).parse(input)?;
+    let (input, lengths) = context("Lengths", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::RectangularVolumeRecord1(RectangularVolumeRecord1 {
+            corner_location,
+            lengths,
+        }),
+    ))
+}
+
+fn rectangular_volume_record_3(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, center_location) = context("Center Location", location).parse(input)?;
+    let (input, lengths) = context("Lengths", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::RectangularVolumeRecord3(RectangularVolumeRecord3 {
+            center_location,
+            lengths,
+            orientation,
+        }),
+    ))
+}
+
+fn point_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, location) = context("Location", location).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::PointRecord2(PointRecord2 { location, velocity }),
+    ))
+}
+
+fn line_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, start_point_location) = context("Start Point Location", location).parse(input)?;
+    let (input, end_point_location) = context("End Point Location", location).parse(input)?;
+    let (input, start_point_velocity) = context("Start Point Velocity", vec3_f32).parse(input)?;
+    let (input, end_point_velocity) = context("End Point Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::LineRecord2(LineRecord2 {
+            start_point_location,
+            end_point_location,
+            start_point_velocity,
+            end_point_velocity,
+        }),
+    ))
+}
+
+fn sphere_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, centroid_location) = context("Centroid Location", location).parse(input)?;
+    let (input, radius) = context("Radius", be_f32).parse(input)?;
+    let (input, radius_rate) = context("Radius Rate", be_f32).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::SphereRecord2(SphereRecord2 {
+            centroid_location,
+            radius,
+            radius_rate,
+            velocity,
+            angular_velocity,
+        }),
+    ))
+}
+
+fn ellipsoid_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, centroid_location) = context("Centroid Location", location).parse(input)?;
+    let (input, sigma) = context("Sigma", vec3_f32).parse(input)?;
+    let (input, sigma_rate) = context("Sigma Rate", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::EllipsoidRecord2(EllipsoidRecord2 {
+            centroid_location,
+            sigma,
+            sigma_rate,
+            orientation,
+            velocity,
+            angular_velocity,
+        }),
+    ))
+}
+
+fn cone_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, vertex_location) = context("Vertex Location", location).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_f32).parse(input)?;
+    let (input, height) = context("Height", be_f32).parse(input)?;
+    let (input, height_rate) = context("Height Rate", be_f32).parse(input)?;
+    let (input, peak_angle) = context("Peak Angle", be_f32).parse(input)?;
+    let (input, peak_angle_rate) = context("Peak Angle Rate", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::ConeRecord2(ConeRecord2 {
+            vertex_location,
+            orientation,
+            velocity,
+            angular_velocity,
+            height,
+            height_rate,
+            peak_angle,
+            peak_angle_rate,
+        }),
+    ))
+}
+
+fn rectangular_volume_record_2(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, corner_location) = context("Corner Location", location).parse(input)?;
+    let (input, lengths) = context("Lengths", vec3_f32).parse(input)?;
+    let (input, lengths_rate) = context("Lengths Rate", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::RectangularVolumeRecord2(RectangularVolumeRecord2 {
+            corner_location,
+            lengths,
+            lengths_rate,
+            orientation,
+            velocity,
+            angular_velocity,
+        }),
+    ))
+}
+
+fn gaussian_plume(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, source_location) = context("Source Location", location).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, plume_length) = context("Plume Length", be_f32).parse(input)?;
+    let (input, plume_width) = context("Plume Width", be_f32).parse(input)?;
+    let (input, plume_top_height) = context("Plume Top Height", be_f32).parse(input)?;
+    let (input, plume_length_rate) = context("Plume Length Rate", be_f32).parse(input)?;
+    let (input, plume_width_rate) = context("Plume Width Rate", be_f32).parse(input)?;
+    let (input, plume_top_height_rate) = context("Plume Top Height Rate", be_f32).parse(input)?;
+    let (input, leading_edge_centroid_height) =
+        context("Leading Edge Centroid Height", be_f32).parse(input)?;
+    let (input, leading_edge_velocity) = context("Leading Edge Velocity", vec3_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::GaussianPlume(GaussianPlume {
+            source_location,
+            orientation,
+            plume_length,
+            plume_width,
+            plume_top_height,
+            plume_length_rate,
+            plume_width_rate,
+            plume_top_height_rate,
+            leading_edge_centroid_height,
+            leading_edge_velocity,
+        }),
+    ))
+}
+
+fn gaussian_puff(input: &[u8]) -> IResult<&[u8], EnvironmentRecordData> {
+    let (input, puff_location) = context("Puff Location", location).parse(input)?;
+    let (input, origination_location) = context("Origination Location", location).parse(input)?;
+    let (input, sigma) = context("Sigma", vec3_f32).parse(input)?;
+    let (input, sigma_rate) = context("Sigma Rate", vec3_f32).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_f32).parse(input)?;
+    let (input, centroid_height) = context("Centroid Height", be_f32).parse(input)?;
+
+    Ok((
+        input,
+        EnvironmentRecordData::GaussianPuff(GaussianPuff {
+            puff_location,
+            origination_location,
+            sigma,
+            sigma_rate,
+            orientation,
+            velocity,
+            angular_velocity,
+            centroid_height,
+        }),
+    ))
+}
@@ -0,0 +1,264 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::EIGHT_OCTETS;
+use crate::environmental_process::model::{
+    BASE_ENVIRONMENT_RECORD_LENGTH, BoundingSphere, CombicState, ConeRecord1, ConeRecord2,
+    EllipsoidRecord1, EllipsoidRecord2, EnvironmentRecord, EnvironmentRecordData,
+    EnvironmentalProcess, FlareState, GaussianPlume, GaussianPuff, LineRecord1, LineRecord2,
+    PointRecord1, PointRecord2, RectangularVolumeRecord1, RectangularVolumeRecord2,
+    RectangularVolumeRecord3, SphereRecord1, SphereRecord2,
+};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for EnvironmentalProcess {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.environmental_process_id.serialize(buf);
+        self.environment_type.serialize(buf);
+        buf.put_u8(self.model_type);
+        buf.put_u8((&self.environment_status).into());
+        buf.put_u16(self.environment_records.len() as u16);
+        buf.put_u16(self.sequence_number);
+        self.environment_records
+            .iter()
+            .map(|record| record.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
+
+impl Serialize for EnvironmentRecord {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.data.record_type());
+        buf.put_u16(self.data_length_bits());
+        buf.put_u8(self.index);
+        buf.put_u8(0u8);
+        let data_bytes = self.data.serialize(buf);
+        let padding = length_padded_to_num(
+            (BASE_ENVIRONMENT_RECORD_LENGTH + data_bytes).into(),
+            EIGHT_OCTETS,
+        )
+        .padding_length;
+        buf.put_bytes(0u8, padding);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for EnvironmentRecordData {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            EnvironmentRecordData::CombicState(record) => record.serialize(buf),
+            EnvironmentRecordData::FlareState(record) => record.serialize(buf),
+            EnvironmentRecordData::BoundingSphere(record) => record.serialize(buf),
+            EnvironmentRecordData::UniformGeometry => 0,
+            EnvironmentRecordData::PointRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::LineRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::SphereRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::EllipsoidRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::ConeRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::RectangularVolumeRecord1(record) => record.serialize(buf),
+            EnvironmentRecordData::RectangularVolumeRecord3(record) => record.serialize(buf),
+            EnvironmentRecordData::PointRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::LineRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::SphereRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::EllipsoidRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::ConeRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::RectangularVolumeRecord2(record) => record.serialize(buf),
+            EnvironmentRecordData::GaussianPlume(record) => record.serialize(buf),
+            EnvironmentRecordData::GaussianPuff(record) => record.serialize(buf),
+            EnvironmentRecordData::Unspecified(_, data) => {
+                buf.put_slice(data);
+                data.len() as u16
+            }
+        }
+    }
+}
+
+impl Serialize for CombicState {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.time_since_creation);
+        self.munition_source.serialize(buf);
+        buf.put_u32(self.number_of_sources);
+        buf.put_u16(self.geometry_index);
+        buf.put_u16(0u16);
+        buf.put_u32(self.source_type);
+        buf.put_f32(self.barrage_rate);
+        buf.put_f32(self.barrage_duration);
+        buf.put_f32(self.barrage_crosswind_length);
+        buf.put_f32(self.barrage_downwind_length);
+        self.detonation_velocity.serialize(buf);
+
+        52
+    }
+}
+
+impl Serialize for FlareState {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.time_since_creation);
+        self.munition_source.serialize(buf);
+        buf.put_u32(self.number_of_intensity);
+        buf.put_u32(self.number_of_sources);
+        buf.put_u16(self.geometry_index);
+        buf.put_u16(0u16);
+
+        24
+    }
+}
+
+impl Serialize for BoundingSphere {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.centroid_location.serialize(buf);
+        buf.put_f32(self.radius);
+
+        location_bytes + 4
+    }
+}
+
+impl Serialize for PointRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.location.serialize(buf)
+    }
+}
+
+impl Serialize for LineRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.start_point_location.serialize(buf) + self.end_point_location.serialize(buf)
+    }
+}
+
+impl Serialize for SphereRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.centroid_location.serialize(buf);
+        buf.put_f32(self.radius);
+
+        location_bytes + 4
+    }
+}
+
+impl Serialize for EllipsoidRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.centroid_location.serialize(buf)
+            + self.sigma.serialize(buf)
+            + self.orientation.serialize(buf)
+    }
+}
+
+impl Serialize for ConeRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.vertex_location.serialize(buf);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_f32(self.height);
+        buf.put_f32(self.peak_angle);
+
+        location_bytes + orientation_bytes + 8
+    }
+}
+
+impl Serialize for RectangularVolumeRecord1 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.corner_location.serialize(buf) + self.lengths.serialize(buf)
+    }
+}
+
+impl Serialize for RectangularVolumeRecord3 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.center_location.serialize(buf)
+            + self.lengths.serialize(buf)
+            + self.orientation.serialize(buf)
+    }
+}
+
+impl Serialize for PointRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.location.serialize(buf) + self.velocity.serialize(buf)
+    }
+}
+
+impl Serialize for LineRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.start_point_location.serialize(buf)
+            + self.end_point_location.serialize(buf)
+            + self.start_point_velocity.serialize(buf)
+            + self.end_point_velocity.serialize(buf)
+    }
+}
+
+impl Serialize for SphereRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.centroid_location.serialize(buf);
+        buf.put_f32(self.radius);
+        buf.put_f32(self.radius_rate);
+
+        location_bytes + 8 + self.velocity.serialize(buf) + self.angular_velocity.serialize(buf)
+    }
+}
+
+impl Serialize for EllipsoidRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.centroid_location.serialize(buf)
+            + self.sigma.serialize(buf)
+            + self.sigma_rate.serialize(buf)
+            + self.orientation.serialize(buf)
+            + self.velocity.serialize(buf)
+            + self.angular_velocity.serialize(buf)
+    }
+}
+
+impl Serialize for ConeRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.vertex_location.serialize(buf);
+        let orientation_bytes = self.orientation.serialize(buf);
+        let velocity_bytes = self.velocity.serialize(buf);
+        let angular_velocity_bytes = self.angular_velocity.serialize(buf);
+        buf.put_f32(self.height);
+        buf.put_f32(self.height_rate);
+        buf.put_f32(self.peak_angle);
+        buf.put_f32(self.peak_angle_rate);
+
+        location_bytes + orientation_bytes + velocity_bytes + angular_velocity_bytes + 16
+    }
+}
+
+impl Serialize for RectangularVolumeRecord2 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        self.corner_location.serialize(buf)
+            + self.lengths.serialize(buf)
+            + self.lengths_rate.serialize(buf)
+            + self.orientation.serialize(buf)
+            + self.velocity.serialize(buf)
+            + self.angular_velocity.serialize(buf)
+    }
+}
+
+impl Serialize for GaussianPlume {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let location_bytes = self.source_location.serialize(buf);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_f32(self.plume_length);
+        buf.put_f32(self.plume_width);
+        buf.put_f32(self.plume_top_height);
+        buf.put_f32(self.plume_length_rate);
+        buf.put_f32(self.plume_width_rate);
+        buf.put_f32(self.plume_top_height_rate);
+        buf.put_f32(self.leading_edge_centroid_height);
+        let velocity_bytes = self.leading_edge_velocity.serialize(buf);
+
+        location_bytes + orientation_bytes + 28 + velocity_bytes
+    }
+}
+
+impl Serialize for GaussianPuff {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let bytes = self.puff_location.serialize(buf)
+            + self.origination_location.serialize(buf)
+            + self.sigma.serialize(buf)
+            + self.sigma_rate.serialize(buf)
+            + self.orientation.serialize(buf)
+            + self.velocity.serialize(buf)
+            + self.angular_velocity.serialize(buf);
+        buf.put_f32(self.centroid_height);
+
+        bytes + 4
+    }
+}
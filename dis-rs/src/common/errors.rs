@@ -1,12 +1,43 @@
+use nom::ErrorConvert;
+use nom::error::{ContextError, ErrorKind, ParseError};
 use thiserror::Error;
 
 use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{PduType, ProtocolVersion};
 
-#[derive(Debug, PartialEq, Eq, Error)]
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
 pub enum DisError {
-    // UnsupportedProtocolVersion,
+    #[error("Protocol version {0:?} is not supported.")]
+    UnsupportedProtocolVersion(ProtocolVersion), // the requested protocol version is not supported by the crate
+    #[error("{field} cannot be represented in protocol version {version:?}.")]
+    IncompatibleProtocolVersion {
+        version: ProtocolVersion,
+        field: &'static str,
+    }, // the PDU contains a type or record that is not defined in the target protocol version
+    #[error("Malformed PDU header, failed to parse {record} at byte {offset}: {kind:?}")]
+    MalformedHeader {
+        offset: usize,
+        record: &'static str,
+        kind: ErrorKind,
+    }, // the header of a PDU could not be parsed
+    #[error(
+        "Malformed {pdu_type:?} PDU ({protocol_version:?}), failed to parse {record} at byte {offset}: {kind:?}. Body length expected {expected}, found {found}"
+    )]
+    MalformedPdu {
+        pdu_type: PduType,
+        protocol_version: ProtocolVersion,
+        offset: usize,
+        record: &'static str,
+        kind: ErrorKind,
+        expected: u16,
+        found: u16,
+    }, // the body of a PDU could not be parsed; offset is relative to the start of the PDU, expected and found are the body lengths according to the header and the input
+    #[error("PDU length {0} is smaller than the PDU header length.")]
+    InvalidPduLength(u16), // the PDU length in the header is too small to be valid; (u16 PDU length)
+    #[error("Discarded {0} bytes to resynchronise on a PDU header.")]
+    DiscardedBytes(usize), // bytes in a stream were skipped because they did not form a valid PDU header; (usize discarded)
     #[error("{0}")]
-    ParseError(String), // the parsing of a PDU resulted in an error
+    StringFormatError(String), // the String value to convert from is not formatted correctly for the data structure
     #[error(
         "The buffer does not contain enough bytes for a valid DIS header. {0} bytes available, needed {PDU_HEADER_LEN_BYTES}"
     )]
@@ -26,3 +57,41 @@ pub enum DisError {
     #[error("IFF PDU - Undetermined System Time.")]
     IffUndeterminedSystemType, // the System Type in an IFF PDU does not determine whether it is an Interrogator or a Transponder
 }
+
+/// Error type of the nom parsers in the crate.
+///
+/// Records the remaining input at the point where parsing failed, and the innermost
+/// record (named using the `context` parser) that was being parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordError<I> {
+    pub input: I,
+    pub kind: ErrorKind,
+    pub record: Option<&'static str>,
+}
+
+impl<I> ParseError<I> for RecordError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            record: None,
+        }
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> ContextError<I> for RecordError<I> {
+    fn add_context(_input: I, record: &'static str, mut other: Self) -> Self {
+        other.record.get_or_insert(record);
+        other
+    }
+}
+
+impl<I> ErrorConvert<RecordError<I>> for nom::error::Error<(I, usize)> {
+    fn convert(self) -> RecordError<I> {
+        RecordError::from_error_kind(self.input.0, self.code)
+    }
+}
@@ -1,18 +1,18 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::{datum_specification, entity_id};
+use crate::common::parser::{IResult, context, datum_specification, entity_id};
 use crate::enumerations::EventType;
 use crate::event_report_r::model::EventReportR;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u32;
 
 pub(crate) fn event_report_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, event_type) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, event_type) = context("Event Type", be_u32).parse(input)?;
     let event_type = EventType::from(event_type);
-    let (input, _padding) = be_u32(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification).parse(input)?;
 
     let body = EventReportR::builder()
         .with_origination_id(originating_id)
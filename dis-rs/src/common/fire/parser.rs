@@ -2,8 +2,9 @@
 use crate::common::fire::model::{Fire, FireDescriptor};
 use crate::common::model::{PduBody, PduHeader};
 use crate::common::parser;
+use crate::common::parser::{IResult, context};
 use crate::enumerations::FireTypeIndicator;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_f32, be_u32};
 
 pub(crate) fn fire_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
@@ -17,15 +18,18 @@ pub(crate) fn fire_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8],
             .unwrap_or_default()
             .fire_type_indicator
             .unwrap_or(FireTypeIndicator::Munition);
-        let (input, firing_entity_id) = parser::entity_id(input)?;
-        let (input, target_entity_id) = parser::entity_id(input)?;
-        let (input, munition_id) = parser::entity_id(input)?;
-        let (input, event_id) = parser::event_id(input)?;
-        let (input, fire_mission_index) = be_u32(input)?;
-        let (input, location_in_world) = parser::location(input)?;
-        let (input, descriptor) = fire_descriptor(fti)(input)?;
-        let (input, velocity) = parser::vec3_f32(input)?;
-        let (input, range) = be_f32(input)?;
+        let (input, firing_entity_id) =
+            context("Firing Entity ID", parser::entity_id).parse(input)?;
+        let (input, target_entity_id) =
+            context("Target Entity ID", parser::entity_id).parse(input)?;
+        let (input, munition_id) = context("Munition ID", parser::entity_id).parse(input)?;
+        let (input, event_id) = context("Event ID", parser::event_id).parse(input)?;
+        let (input, fire_mission_index) = context("Fire Mission Index", be_u32).parse(input)?;
+        let (input, location_in_world) =
+            context("Location in World", parser::location).parse(input)?;
+        let (input, descriptor) = context("Descriptor", fire_descriptor(fti)).parse(input)?;
+        let (input, velocity) = context("Velocity", parser::vec3_f32).parse(input)?;
+        let (input, range) = context("Range", be_f32).parse(input)?;
 
         let body = Fire {
             firing_entity_id,
@@ -48,12 +52,14 @@ pub(crate) fn fire_descriptor(
 ) -> impl Fn(&[u8]) -> IResult<&[u8], FireDescriptor> {
     move |input: &[u8]| match fire_type_indicator {
         FireTypeIndicator::Munition => {
-            let (input, munition) = parser::munition_descriptor(input)?;
+            let (input, munition) =
+                context("Munition", parser::munition_descriptor).parse(input)?;
             Ok((input, FireDescriptor::Munition(munition)))
         }
         // FIXME: FireTypeIndicator::Unspecified(_) should be an error; for now parse as Expendable, which has no data
         FireTypeIndicator::Expendable | FireTypeIndicator::Unspecified(_) => {
-            let (input, expendable) = parser::expendable_descriptor(input)?;
+            let (input, expendable) =
+                context("Expendable", parser::expendable_descriptor).parse(input)?;
             Ok((input, FireDescriptor::Expendable(expendable)))
         }
     }
@@ -0,0 +1,122 @@
+use crate::common::model::{ClockTime, EntityId, EntityType, Orientation};
+use crate::gridded_data::model::{
+    GridAxisDescriptor, GridData, GriddedData, GriddedDataConstantGrid, GriddedDataCoordinateSystem,
+};
+
+pub struct GriddedDataBuilder(GriddedData);
+
+impl Default for GriddedDataBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GriddedDataBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        GriddedDataBuilder(GriddedData::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: GriddedData) -> Self {
+        GriddedDataBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> GriddedData {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_environmental_simulation_id(
+        mut self,
+        environmental_simulation_id: EntityId,
+    ) -> Self {
+        self.0.environmental_simulation_id = environmental_simulation_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_field_number(mut self, field_number: u16) -> Self {
+        self.0.field_number = field_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pdu_number(mut self, pdu_number: u16) -> Self {
+        self.0.pdu_number = pdu_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pdu_total(mut self, pdu_total: u16) -> Self {
+        self.0.pdu_total = pdu_total;
+        self
+    }
+
+    #[must_use]
+    pub fn with_coordinate_system(
+        mut self,
+        coordinate_system: GriddedDataCoordinateSystem,
+    ) -> Self {
+        self.0.coordinate_system = coordinate_system;
+        self
+    }
+
+    #[must_use]
+    pub fn with_constant_grid(mut self, constant_grid: GriddedDataConstantGrid) -> Self {
+        self.0.constant_grid = constant_grid;
+        self
+    }
+
+    #[must_use]
+    pub fn with_environment_type(mut self, environment_type: EntityType) -> Self {
+        self.0.environment_type = environment_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.0.orientation = orientation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sample_time(mut self, sample_time: ClockTime) -> Self {
+        self.0.sample_time = sample_time;
+        self
+    }
+
+    #[must_use]
+    pub fn with_total_values(mut self, total_values: u32) -> Self {
+        self.0.total_values = total_values;
+        self
+    }
+
+    #[must_use]
+    pub fn with_grid_axis_descriptor(mut self, grid_axis_descriptor: GridAxisDescriptor) -> Self {
+        self.0.grid_axis_descriptors.push(grid_axis_descriptor);
+        self
+    }
+
+    #[must_use]
+    pub fn with_grid_axis_descriptors(
+        mut self,
+        grid_axis_descriptors: Vec<GridAxisDescriptor>,
+    ) -> Self {
+        self.0.grid_axis_descriptors = grid_axis_descriptors;
+        self
+    }
+
+    #[must_use]
+    pub fn with_grid_data(mut self, grid_data: GridData) -> Self {
+        self.0.grid_data.push(grid_data);
+        self
+    }
+
+    #[must_use]
+    pub fn with_grid_data_records(mut self, grid_data: Vec<GridData>) -> Self {
+        self.0.grid_data = grid_data;
+        self
+    }
+}
@@ -0,0 +1,124 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::gridded_data::model::{
+        GridAxisDescriptor, GridAxisIrregular, GridAxisRegular, GridData, GridDataRepresentation,
+        GriddedData, GriddedDataConstantGrid, GriddedDataCoordinateSystem,
+    };
+    use crate::model::{ClockTime, EntityId, Orientation};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn gridded_data_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::GriddedData).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = GriddedData::builder()
+            .with_environmental_simulation_id(EntityId::new(1, 2, 3))
+            .with_field_number(1)
+            .with_pdu_number(1)
+            .with_pdu_total(1)
+            .with_coordinate_system(GriddedDataCoordinateSystem::LatitudeLongitudeHeight)
+            .with_constant_grid(GriddedDataConstantGrid::UpdatedGrid)
+            .with_orientation(Orientation::new(0.0, 0.5, 1.0))
+            .with_sample_time(ClockTime::new(10, 2000))
+            .with_total_values(9)
+            .with_grid_axis_descriptor(GridAxisDescriptor::Regular(GridAxisRegular {
+                domain_initial_x: 0.0,
+                domain_final_x: 100.0,
+                domain_points_x: 3,
+                interleaf_factor: 1,
+                number_of_points_on_x_axis: 3,
+                initial_index_x: 0,
+            }))
+            .with_grid_axis_descriptor(GridAxisDescriptor::Irregular(GridAxisIrregular {
+                domain_initial_x: 10.0,
+                domain_final_x: 30.0,
+                domain_points_x: 3,
+                interleaf_factor: 1,
+                initial_index_x: 0,
+                coordinate_scale_x: 0.5,
+                coordinate_offset_x: 10.0,
+                x_values: vec![0, 20, 40],
+            }))
+            .with_grid_data(GridData::new(
+                1,
+                GridDataRepresentation::Type0 {
+                    values: vec![1, 2, 3, 4, 5],
+                },
+            ))
+            .with_grid_data(GridData::new(
+                2,
+                GridDataRepresentation::Type1 {
+                    field_scale: 0.1,
+                    field_offset: -5.0,
+                    values: vec![100, 200, 300],
+                },
+            ))
+            .with_grid_data(GridData::new(
+                3,
+                GridDataRepresentation::Type2 {
+                    values: vec![1.5, 2.5, 3.5],
+                },
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn grid_record_lengths() {
+        let irregular = GridAxisDescriptor::Irregular(GridAxisIrregular {
+            x_values: vec![1, 2, 3],
+            ..Default::default()
+        });
+        assert_eq!(irregular.record_length(), 48);
+        assert_eq!(GridAxisDescriptor::default().record_length(), 24);
+
+        let type0 = GridData::new(0, GridDataRepresentation::Type0 { values: vec![1] });
+        assert_eq!(type0.record_length(), 8);
+        let type1 = GridData::new(
+            0,
+            GridDataRepresentation::Type1 {
+                field_scale: 1.0,
+                field_offset: 0.0,
+                values: vec![1],
+            },
+        );
+        assert_eq!(type1.record_length(), 16);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    ClockTime, EntityId, EntityType, Orientation, PduBody, length_padded_to_num,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, TWO_OCTETS};
+use crate::enumerations::PduType;
+use crate::gridded_data::builder::GriddedDataBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_GRIDDED_DATA_BODY_LENGTH: u16 = 52;
+pub(crate) const BASE_GRID_AXIS_DESCRIPTOR_LENGTH: usize = 24;
+pub(crate) const BASE_GRID_AXIS_IRREGULAR_LENGTH: usize = 40;
+pub(crate) const BASE_GRID_DATA_LENGTH: usize = 4;
+
+/// 5.11.2.3 Gridded Data PDU
+///
+/// 7.10.3 Gridded Data PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GriddedData {
+    pub environmental_simulation_id: EntityId,
+    pub field_number: u16,
+    pub pdu_number: u16,
+    pub pdu_total: u16,
+    pub coordinate_system: GriddedDataCoordinateSystem,
+    pub constant_grid: GriddedDataConstantGrid,
+    pub environment_type: EntityType,
+    pub orientation: Orientation,
+    pub sample_time: ClockTime,
+    pub total_values: u32,
+    pub grid_axis_descriptors: Vec<GridAxisDescriptor>,
+    pub grid_data: Vec<GridData>,
+}
+
+impl BodyRaw for GriddedData {
+    type Builder = GriddedDataBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::GriddedData(self)
+    }
+}
+
+impl BodyInfo for GriddedData {
+    fn body_length(&self) -> u16 {
+        BASE_GRIDDED_DATA_BODY_LENGTH
+            + self
+                .grid_axis_descriptors
+                .iter()
+                .map(GridAxisDescriptor::record_length)
+                .sum::<u16>()
+            + self
+                .grid_data
+                .iter()
+                .map(GridData::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::GriddedData
+    }
+}
+
+impl Interaction for GriddedData {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Coordinate System field of the Gridded Data PDU (SISO-REF-010 UID 244)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GriddedDataCoordinateSystem {
+    #[default]
+    RightHandedCartesian, // 0
+    LeftHandedCartesian,     // 1
+    LatitudeLongitudeHeight, // 2
+    LatitudeLongitudeDepth,  // 3
+    Unspecified(u16),
+}
+
+impl From<u16> for GriddedDataCoordinateSystem {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::RightHandedCartesian,
+            1 => Self::LeftHandedCartesian,
+            2 => Self::LatitudeLongitudeHeight,
+            3 => Self::LatitudeLongitudeDepth,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<GriddedDataCoordinateSystem> for u16 {
+    fn from(value: GriddedDataCoordinateSystem) -> Self {
+        match value {
+            GriddedDataCoordinateSystem::RightHandedCartesian => 0,
+            GriddedDataCoordinateSystem::LeftHandedCartesian => 1,
+            GriddedDataCoordinateSystem::LatitudeLongitudeHeight => 2,
+            GriddedDataCoordinateSystem::LatitudeLongitudeDepth => 3,
+            GriddedDataCoordinateSystem::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Constant Grid field of the Gridded Data PDU (SISO-REF-010 UID 245)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GriddedDataConstantGrid {
+    #[default]
+    ConstantGrid, // 0
+    UpdatedGrid, // 1
+    Unspecified(u8),
+}
+
+impl From<u8> for GriddedDataConstantGrid {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::ConstantGrid,
+            1 => Self::UpdatedGrid,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<GriddedDataConstantGrid> for u8 {
+    fn from(value: GriddedDataConstantGrid) -> Self {
+        match value {
+            GriddedDataConstantGrid::ConstantGrid => 0,
+            GriddedDataConstantGrid::UpdatedGrid => 1,
+            GriddedDataConstantGrid::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// 6.2.41 Grid Axis Descriptor record
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GridAxisDescriptor {
+    Regular(GridAxisRegular),
+    Irregular(GridAxisIrregular),
+}
+
+impl Default for GridAxisDescriptor {
+    fn default() -> Self {
+        Self::Regular(GridAxisRegular::default())
+    }
+}
+
+impl GridAxisDescriptor {
+    /// The Axis Type field value; 0 for a regular axis and 1 for an irregular axis.
+    #[must_use]
+    pub fn axis_type(&self) -> u8 {
+        match self {
+            GridAxisDescriptor::Regular(_) => 0,
+            GridAxisDescriptor::Irregular(_) => 1,
+        }
+    }
+
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(_) => BASE_GRID_AXIS_DESCRIPTOR_LENGTH as u16,
+            GridAxisDescriptor::Irregular(axis) => {
+                length_padded_to_num(
+                    BASE_GRID_AXIS_IRREGULAR_LENGTH + axis.x_values.len() * TWO_OCTETS,
+                    EIGHT_OCTETS,
+                )
+                .record_length as u16
+            }
+        }
+    }
+}
+
+/// 6.2.41.2 Grid Axis Descriptor record, regular axis
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridAxisRegular {
+    pub domain_initial_x: f64,
+    pub domain_final_x: f64,
+    pub domain_points_x: u16,
+    pub interleaf_factor: u8,
+    pub number_of_points_on_x_axis: u16,
+    pub initial_index_x: u16,
+}
+
+/// 6.2.41.3 Grid Axis Descriptor record, irregular axis
+///
+/// The Number of Points on Xi Axis field is derived from the number of `x_values`.
+#[derive(Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridAxisIrregular {
+    pub domain_initial_x: f64,
+    pub domain_final_x: f64,
+    pub domain_points_x: u16,
+    pub interleaf_factor: u8,
+    pub initial_index_x: u16,
+    pub coordinate_scale_x: f64,
+    pub coordinate_offset_x: f64,
+    pub x_values: Vec<u16>,
+}
+
+/// 6.2.42 Grid Data record
+#[derive(Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridData {
+    pub sample_type: u16,
+    pub data: GridDataRepresentation,
+}
+
+impl GridData {
+    #[must_use]
+    pub fn new(sample_type: u16, data: GridDataRepresentation) -> Self {
+        Self { sample_type, data }
+    }
+
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        let length = match &self.data {
+            GridDataRepresentation::Type0 { values } => {
+                length_padded_to_num(
+                    BASE_GRID_DATA_LENGTH + TWO_OCTETS + values.len(),
+                    FOUR_OCTETS,
+                )
+                .record_length
+            }
+            GridDataRepresentation::Type1 { values, .. } => {
+                length_padded_to_num(
+                    BASE_GRID_DATA_LENGTH + EIGHT_OCTETS + TWO_OCTETS + values.len() * TWO_OCTETS,
+                    FOUR_OCTETS,
+                )
+                .record_length
+            }
+            GridDataRepresentation::Type2 { values } => {
+                BASE_GRID_DATA_LENGTH + FOUR_OCTETS + values.len() * FOUR_OCTETS
+            }
+        };
+        length as u16
+    }
+}
+
+/// The data values of a Grid Data record, by Data Representation type (SISO-REF-010 UID 249)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GridDataRepresentation {
+    /// 6.2.42.2 Grid Data record, representation type 0: raw octets
+    Type0 { values: Vec<u8> },
+    /// 6.2.42.3 Grid Data record, representation type 1: scaled 16-bit values
+    Type1 {
+        field_scale: f32,
+        field_offset: f32,
+        values: Vec<u16>,
+    },
+    /// 6.2.42.4 Grid Data record, representation type 2: 32-bit floating point values
+    Type2 { values: Vec<f32> },
+}
+
+impl Default for GridDataRepresentation {
+    fn default() -> Self {
+        Self::Type2 { values: vec![] }
+    }
+}
+
+impl GridDataRepresentation {
+    /// The Data Representation field value of the record.
+    #[must_use]
+    pub fn data_representation(&self) -> u16 {
+        match self {
+            GridDataRepresentation::Type0 { .. } => 0,
+            GridDataRepresentation::Type1 { .. } => 1,
+            GridDataRepresentation::Type2 { .. } => 2,
+        }
+    }
+}
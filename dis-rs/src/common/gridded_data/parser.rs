@@ -0,0 +1,194 @@
+use crate::BodyRaw;
+use crate::common::errors::RecordError;
+use crate::common::model::{PduBody, length_padded_to_num};
+use crate::common::parser::{IResult, clock_time, context, entity_id, entity_type, orientation};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, TWO_OCTETS};
+use crate::gridded_data::model::{
+    BASE_GRID_AXIS_IRREGULAR_LENGTH, BASE_GRID_DATA_LENGTH, GridAxisDescriptor, GridAxisIrregular,
+    GridAxisRegular, GridData, GridDataRepresentation, GriddedData, GriddedDataConstantGrid,
+    GriddedDataCoordinateSystem,
+};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::error::{ErrorKind, ParseError};
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_f64, be_u8, be_u16, be_u32};
+
+pub(crate) fn gridded_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, environmental_simulation_id) =
+        context("Environmental Simulation ID", entity_id).parse(input)?;
+    let (input, field_number) = context("Field Number", be_u16).parse(input)?;
+    let (input, pdu_number) = context("PDU Number", be_u16).parse(input)?;
+    let (input, pdu_total) = context("PDU Total", be_u16).parse(input)?;
+    let (input, coordinate_system) = context("Coordinate System", be_u16).parse(input)?;
+    let coordinate_system = GriddedDataCoordinateSystem::from(coordinate_system);
+    let (input, number_of_grid_axes) = context("Number of Grid Axes", be_u8).parse(input)?;
+    let (input, constant_grid) = context("Constant Grid", be_u8).parse(input)?;
+    let constant_grid = GriddedDataConstantGrid::from(constant_grid);
+    let (input, environment_type) = context("Environment Type", entity_type).parse(input)?;
+    let (input, orientation) = context("Orientation", orientation).parse(input)?;
+    let (input, sample_time) = context("Sample Time", clock_time).parse(input)?;
+    let (input, total_values) = context("Total Values", be_u32).parse(input)?;
+    let (input, vector_dimension) = context("Vector Dimension", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, grid_axis_descriptors) = count(
+        context("Grid Axis Descriptor", grid_axis_descriptor),
+        number_of_grid_axes.into(),
+    )
+    .parse(input)?;
+    let (input, grid_data) =
+        count(context("Grid Data", grid_data), vector_dimension.into()).parse(input)?;
+
+    Ok((
+        input,
+        GriddedData::builder()
+            .with_environmental_simulation_id(environmental_simulation_id)
+            .with_field_number(field_number)
+            .with_pdu_number(pdu_number)
+            .with_pdu_total(pdu_total)
+            .with_coordinate_system(coordinate_system)
+            .with_constant_grid(constant_grid)
+            .with_environment_type(environment_type)
+            .with_orientation(orientation)
+            .with_sample_time(sample_time)
+            .with_total_values(total_values)
+            .with_grid_axis_descriptors(grid_axis_descriptors)
+            .with_grid_data_records(grid_data)
+            .build()
+            .into_pdu_body(),
+    ))
+}
+
+pub(crate) fn grid_axis_descriptor(input: &[u8]) -> IResult<&[u8], GridAxisDescriptor> {
+    let (input, domain_initial_x) = context("Domain Initial X", be_f64).parse(input)?;
+    let (input, domain_final_x) = context("Domain Final X", be_f64).parse(input)?;
+    let (input, domain_points_x) = context("Domain Points X", be_u16).parse(input)?;
+    let (input, interleaf_factor) = context("Interleaf Factor", be_u8).parse(input)?;
+    let (input, axis_type) = context("Axis Type", be_u8).parse(input)?;
+    let (input, number_of_points_on_x_axis) =
+        context("Number of Points on X Axis", be_u16).parse(input)?;
+    let (input, initial_index_x) = context("Initial Index X", be_u16).parse(input)?;
+
+    match axis_type {
+        0 => Ok((
+            input,
+            GridAxisDescriptor::Regular(GridAxisRegular {
+                domain_initial_x,
+                domain_final_x,
+                domain_points_x,
+                interleaf_factor,
+                number_of_points_on_x_axis,
+                initial_index_x,
+            }),
+        )),
+        1 => {
+            let (input, coordinate_scale_x) = context("Coordinate Scale X", be_f64).parse(input)?;
+            let (input, coordinate_offset_x) =
+                context("Coordinate Offset X", be_f64).parse(input)?;
+            let (input, x_values) = count(
+                context("X Value", be_u16),
+                number_of_points_on_x_axis.into(),
+            )
+            .parse(input)?;
+            let (input, _padding) = context(
+                "Padding",
+                take(
+                    length_padded_to_num(
+                        BASE_GRID_AXIS_IRREGULAR_LENGTH + x_values.len() * TWO_OCTETS,
+                        EIGHT_OCTETS,
+                    )
+                    .padding_length,
+                ),
+            )
+            .parse(input)?;
+
+            Ok((
+                input,
+                GridAxisDescriptor::Irregular(GridAxisIrregular {
+                    domain_initial_x,
+                    domain_final_x,
+                    domain_points_x,
+                    interleaf_factor,
+                    initial_index_x,
+                    coordinate_scale_x,
+                    coordinate_offset_x,
+                    x_values,
+                }),
+            ))
+        }
+        _ => Err(nom::Err::Error(RecordError::from_error_kind(
+            input,
+            ErrorKind::Switch,
+        ))),
+    }
+}
+
+pub(crate) fn grid_data(input: &[u8]) -> IResult<&[u8], GridData> {
+    let (input, sample_type) = context("Sample Type", be_u16).parse(input)?;
+    let (input, data_representation) = context("Data Representation", be_u16).parse(input)?;
+
+    let (input, data) = match data_representation {
+        0 => {
+            let (input, number_of_octets) = context("Number of Octets", be_u16).parse(input)?;
+            let (input, values) =
+                context("Values", count(be_u8, number_of_octets.into())).parse(input)?;
+            let (input, _padding) = context(
+                "Padding",
+                take(
+                    length_padded_to_num(
+                        BASE_GRID_DATA_LENGTH + TWO_OCTETS + values.len(),
+                        FOUR_OCTETS,
+                    )
+                    .padding_length,
+                ),
+            )
+            .parse(input)?;
+            (input, GridDataRepresentation::Type0 { values })
+        }
+        1 => {
+            let (input, field_scale) = context("Field Scale", be_f32).parse(input)?;
+            let (input, field_offset) = context("Field Offset", be_f32).parse(input)?;
+            let (input, number_of_values) = context("Number of Values", be_u16).parse(input)?;
+            let (input, values) =
+                context("Values", count(be_u16, number_of_values.into())).parse(input)?;
+            let (input, _padding) = context(
+                "Padding",
+                take(
+                    length_padded_to_num(
+                        BASE_GRID_DATA_LENGTH
+                            + EIGHT_OCTETS
+                            + TWO_OCTETS
+                            + values.len() * TWO_OCTETS,
+                        FOUR_OCTETS,
+                    )
+                    .padding_length,
+                ),
+            )
+            .parse(input)?;
+            (
+                input,
+                GridDataRepresentation::Type1 {
+                    field_scale,
+                    field_offset,
+                    values,
+                },
+            )
+        }
+        2 => {
+            let (input, number_of_values) = context("Number of Values", be_u16).parse(input)?;
+            let (input, _padding) = context("Padding", be_u16).parse(input)?;
+            let (input, values) =
+                context("Values", count(be_f32, number_of_values.into())).parse(input)?;
+            (input, GridDataRepresentation::Type2 { values })
+        }
+        _ => {
+            return Err(nom::Err::Error(RecordError::from_error_kind(
+                input,
+                ErrorKind::Switch,
+            )));
+        }
+    };
+
+    Ok((input, GridData::new(sample_type, data)))
+}
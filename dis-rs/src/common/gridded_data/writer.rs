@@ -0,0 +1,130 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, TWO_OCTETS};
+use crate::gridded_data::model::{
+    BASE_GRID_AXIS_IRREGULAR_LENGTH, BASE_GRID_DATA_LENGTH, GridAxisDescriptor, GridData,
+    GridDataRepresentation, GriddedData,
+};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for GriddedData {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.environmental_simulation_id.serialize(buf);
+        buf.put_u16(self.field_number);
+        buf.put_u16(self.pdu_number);
+        buf.put_u16(self.pdu_total);
+        buf.put_u16(self.coordinate_system.into());
+        buf.put_u8(self.grid_axis_descriptors.len() as u8);
+        buf.put_u8(self.constant_grid.into());
+        self.environment_type.serialize(buf);
+        self.orientation.serialize(buf);
+        self.sample_time.serialize(buf);
+        buf.put_u32(self.total_values);
+        buf.put_u8(self.grid_data.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        self.grid_axis_descriptors
+            .iter()
+            .map(|axis| axis.serialize(buf))
+            .sum::<u16>();
+        self.grid_data
+            .iter()
+            .map(|data| data.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
+
+impl Serialize for GridAxisDescriptor {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(axis) => {
+                buf.put_f64(axis.domain_initial_x);
+                buf.put_f64(axis.domain_final_x);
+                buf.put_u16(axis.domain_points_x);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(self.axis_type());
+                buf.put_u16(axis.number_of_points_on_x_axis);
+                buf.put_u16(axis.initial_index_x);
+            }
+            GridAxisDescriptor::Irregular(axis) => {
+                buf.put_f64(axis.domain_initial_x);
+                buf.put_f64(axis.domain_final_x);
+                buf.put_u16(axis.domain_points_x);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(self.axis_type());
+                buf.put_u16(axis.x_values.len() as u16);
+                buf.put_u16(axis.initial_index_x);
+                buf.put_f64(axis.coordinate_scale_x);
+                buf.put_f64(axis.coordinate_offset_x);
+                for &value in &axis.x_values {
+                    buf.put_u16(value);
+                }
+                buf.put_bytes(
+                    0u8,
+                    length_padded_to_num(
+                        BASE_GRID_AXIS_IRREGULAR_LENGTH + axis.x_values.len() * TWO_OCTETS,
+                        EIGHT_OCTETS,
+                    )
+                    .padding_length,
+                );
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for GridData {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(self.sample_type);
+        buf.put_u16(self.data.data_representation());
+        match &self.data {
+            GridDataRepresentation::Type0 { values } => {
+                buf.put_u16(values.len() as u16);
+                buf.put_slice(values);
+                buf.put_bytes(
+                    0u8,
+                    length_padded_to_num(
+                        BASE_GRID_DATA_LENGTH + TWO_OCTETS + values.len(),
+                        FOUR_OCTETS,
+                    )
+                    .padding_length,
+                );
+            }
+            GridDataRepresentation::Type1 {
+                field_scale,
+                field_offset,
+                values,
+            } => {
+                buf.put_f32(*field_scale);
+                buf.put_f32(*field_offset);
+                buf.put_u16(values.len() as u16);
+                for &value in values {
+                    buf.put_u16(value);
+                }
+                buf.put_bytes(
+                    0u8,
+                    length_padded_to_num(
+                        BASE_GRID_DATA_LENGTH
+                            + EIGHT_OCTETS
+                            + TWO_OCTETS
+                            + values.len() * TWO_OCTETS,
+                        FOUR_OCTETS,
+                    )
+                    .padding_length,
+                );
+            }
+            GridDataRepresentation::Type2 { values } => {
+                buf.put_u16(values.len() as u16);
+                buf.put_u16(0u16);
+                for &value in values {
+                    buf.put_f32(value);
+                }
+            }
+        }
+
+        self.record_length()
+    }
+}
@@ -13,33 +13,38 @@
     SystemStatus,
 };
 use crate::common::model::PduBody;
-use crate::common::parser::{beam_data, entity_id, event_id, simulation_address, vec3_f32};
+use crate::common::parser::{
+    IResult, beam_data, context, entity_id, event_id, sanitize_marking, simulation_address,
+    vec3_f32,
+};
 use crate::constants::EIGHT_OCTETS;
 use crate::enumerations::{
     AircraftIdentificationType, AircraftPresentDomain, CapabilityReport, DataCategory,
     IffApplicableModes, IffSystemMode, IffSystemName, IffSystemType, NavigationSource,
     VariableRecordType,
 };
+use nom::Parser;
 use nom::bytes::complete::take;
+use nom::combinator::map;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, entity_id) = entity_id(input)?;
-    let (input, event_id) = event_id(input)?;
-    let (input, antenna_location) = vec3_f32(input)?;
-    let (input, system_id) = system_id(input)?;
-    let (input, system_designator) = be_u8(input)?;
-    let (input, system_specific_data) = be_u8(input)?;
-    let (input, fundamental_data) = fundamental_operational_data(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, event_id) = context("Event ID", event_id).parse(input)?;
+    let (input, antenna_location) = context("Antenna Location", vec3_f32).parse(input)?;
+    let (input, system_id) = context("System ID", system_id).parse(input)?;
+    let (input, system_designator) = context("System Designator", be_u8).parse(input)?;
+    let (input, system_specific_data) = context("System Specific Data", be_u8).parse(input)?;
+    let (input, fundamental_data) =
+        context("Fundamental Operational Data", fundamental_operational_data).parse(input)?;
 
     let builder = Iff::builder();
 
     let (input, builder) = if fundamental_data.information_layers.layer_2
         == LayersPresenceApplicability::PresentApplicable
     {
-        let (input, layer_2) = iff_layer_2(input)?;
+        let (input, layer_2) = context("IFF Layer 2", iff_layer_2).parse(input)?;
         (input, builder.with_layer_2(layer_2))
     } else {
         (input, builder)
@@ -47,7 +52,8 @@ pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     let (input, builder) = if fundamental_data.information_layers.layer_3
         == LayersPresenceApplicability::PresentApplicable
     {
-        let (input, layer_3) = iff_layer_3(&system_id.system_type)(input)?;
+        let (input, layer_3) =
+            context("IFF Layer 3", iff_layer_3(&system_id.system_type)).parse(input)?;
         (input, builder.with_layer_3(layer_3))
     } else {
         (input, builder)
@@ -55,7 +61,8 @@ pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     let (input, builder) = if fundamental_data.information_layers.layer_4
         == LayersPresenceApplicability::PresentApplicable
     {
-        let (input, layer_4) = iff_layer_4(&system_id.system_type)(input)?;
+        let (input, layer_4) =
+            context("IFF Layer 4", iff_layer_4(&system_id.system_type)).parse(input)?;
         (input, builder.with_layer_4(layer_4))
     } else {
         (input, builder)
@@ -63,7 +70,7 @@ pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     let (input, builder) = if fundamental_data.information_layers.layer_5
         == LayersPresenceApplicability::PresentApplicable
     {
-        let (input, layer_5) = iff_layer_5(input)?;
+        let (input, layer_5) = context("IFF Layer 5", iff_layer_5).parse(input)?;
         (input, builder.with_layer_5(layer_5))
     } else {
         (input, builder)
@@ -82,13 +89,21 @@ pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 fn iff_layer_2(input: &[u8]) -> IResult<&[u8], IffLayer2> {
-    let (input, layer_header) = layer_header(input)?;
-    let (input, beam_data) = beam_data(input)?;
-    let (input, operational_parameter_1) = be_u8(input)?;
-    let (input, operational_parameter_2) = be_u8(input)?;
-    let (input, num_params) = be_u16(input)?;
-    let (input, fundamental_parameters) =
-        count(iff_fundamental_parameter_data, num_params.into()).parse(input)?;
+    let (input, layer_header) = context("Layer Header", layer_header).parse(input)?;
+    let (input, beam_data) = context("Beam Data", beam_data).parse(input)?;
+    let (input, operational_parameter_1) =
+        context("Operational Parameter 1", be_u8).parse(input)?;
+    let (input, operational_parameter_2) =
+        context("Operational Parameter 2", be_u8).parse(input)?;
+    let (input, num_params) = context("Number of Params", be_u16).parse(input)?;
+    let (input, fundamental_parameters) = count(
+        context(
+            "IFF Fundamental Parameter Data",
+            iff_fundamental_parameter_data,
+        ),
+        num_params.into(),
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -104,11 +119,14 @@ fn iff_layer_2(input: &[u8]) -> IResult<&[u8], IffLayer2> {
 
 fn iff_layer_3(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer3> + '_ {
     move |input: &[u8]| {
-        let (input, layer_header) = layer_header(input)?;
-        let (input, reporting_simulation) = simulation_address(input)?;
-        let (input, basic_data) = mode_5_basic_data(system_type)(input)?;
-        let (input, _padding) = be_u16(input)?;
-        let (input, data_specification) = iff_data_specification(input)?;
+        let (input, layer_header) = context("Layer Header", layer_header).parse(input)?;
+        let (input, reporting_simulation) =
+            context("Reporting Simulation", simulation_address).parse(input)?;
+        let (input, basic_data) =
+            context("Basic Data", mode_5_basic_data(system_type)).parse(input)?;
+        let (input, _padding) = context("Padding", be_u16).parse(input)?;
+        let (input, data_specification) =
+            context("IFF Data Specification", iff_data_specification).parse(input)?;
 
         Ok((
             input,
@@ -127,11 +145,14 @@ fn iff_layer_3(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8],
 
 fn iff_layer_4(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer4> + '_ {
     move |input: &[u8]| {
-        let (input, layer_header) = layer_header(input)?;
-        let (input, reporting_simulation) = simulation_address(input)?;
-        let (input, basic_data) = mode_s_basic_data(system_type)(input)?;
-        let (input, _padding) = be_u16(input)?;
-        let (input, data_specification) = iff_data_specification(input)?;
+        let (input, layer_header) = context("Layer Header", layer_header).parse(input)?;
+        let (input, reporting_simulation) =
+            context("Reporting Simulation", simulation_address).parse(input)?;
+        let (input, basic_data) =
+            context("Basic Data", mode_s_basic_data(system_type)).parse(input)?;
+        let (input, _padding) = context("Padding", be_u16).parse(input)?;
+        let (input, data_specification) =
+            context("IFF Data Specification", iff_data_specification).parse(input)?;
 
         Ok((
             input,
@@ -149,14 +170,17 @@ fn iff_layer_4(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8],
 }
 
 fn iff_layer_5(input: &[u8]) -> IResult<&[u8], IffLayer5> {
-    let (input, layer_header) = layer_header(input)?;
-    let (input, reporting_simulation) = simulation_address(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, applicable_layers) = information_layers(input)?;
-    let (input, data_category) = be_u8(input)?;
+    let (input, layer_header) = context("Layer Header", layer_header).parse(input)?;
+    let (input, reporting_simulation) =
+        context("Reporting Simulation", simulation_address).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, applicable_layers) =
+        context("Information Layers", information_layers).parse(input)?;
+    let (input, data_category) = context("Data Category", be_u8).parse(input)?;
     let data_category = DataCategory::from(data_category);
-    let (input, _padding) = be_u16(input)?;
-    let (input, data_specification) = iff_data_specification(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, data_specification) =
+        context("IFF Data Specification", iff_data_specification).parse(input)?;
 
     Ok((
         input,
@@ -177,16 +201,17 @@ fn change_options_record(input: &[u8]) -> IResult<&[u8], ChangeOptionsRecord> {
 }
 
 fn fundamental_operational_data(input: &[u8]) -> IResult<&[u8], FundamentalOperationalData> {
-    let (input, system_status) = system_status(input)?;
-    let (input, data_field_1) = be_u8(input)?;
-    let (input, information_layers) = information_layers(input)?;
-    let (input, data_field_2) = be_u8(input)?;
-    let (input, parameter_1) = be_u16(input)?;
-    let (input, parameter_2) = be_u16(input)?;
-    let (input, parameter_3) = be_u16(input)?;
-    let (input, parameter_4) = be_u16(input)?;
-    let (input, parameter_5) = be_u16(input)?;
-    let (input, parameter_6) = be_u16(input)?;
+    let (input, system_status) = context("System Status", system_status).parse(input)?;
+    let (input, data_field_1) = context("Data Field 1", be_u8).parse(input)?;
+    let (input, information_layers) =
+        context("Information Layers", information_layers).parse(input)?;
+    let (input, data_field_2) = context("Data Field 2", be_u8).parse(input)?;
+    let (input, parameter_1) = context("Parameter 1", be_u16).parse(input)?;
+    let (input, parameter_2) = context("Parameter 2", be_u16).parse(input)?;
+    let (input, parameter_3) = context("Parameter 3", be_u16).parse(input)?;
+    let (input, parameter_4) = context("Parameter 4", be_u16).parse(input)?;
+    let (input, parameter_5) = context("Parameter 5", be_u16).parse(input)?;
+    let (input, parameter_6) = context("Parameter 6", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -206,11 +231,14 @@ fn fundamental_operational_data(input: &[u8]) -> IResult<&[u8], FundamentalOpera
 }
 
 fn iff_data_record(input: &[u8]) -> IResult<&[u8], IffDataRecord> {
-    let (input, record_type) = be_u32(input)?;
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
     let record_type = VariableRecordType::from(record_type);
-    let (input, record_length) = be_u16(input)?;
-    let (input, field) =
-        take(record_length.saturating_sub(BASE_IFF_DATA_RECORD_LENGTH_OCTETS))(input)?;
+    let (input, record_length) = context("Record Length", be_u16).parse(input)?;
+    let (input, field) = context(
+        "Field",
+        take::<_, &[u8], _>(record_length.saturating_sub(BASE_IFF_DATA_RECORD_LENGTH_OCTETS)),
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -222,8 +250,12 @@ fn iff_data_record(input: &[u8]) -> IResult<&[u8], IffDataRecord> {
 }
 
 fn iff_data_specification(input: &[u8]) -> IResult<&[u8], IffDataSpecification> {
-    let (input, num_records) = be_u16(input)?;
-    let (input, records) = count(iff_data_record, num_records.into()).parse(input)?;
+    let (input, num_records) = context("Number of Records", be_u16).parse(input)?;
+    let (input, records) = count(
+        context("IFF Data Record", iff_data_record),
+        num_records.into(),
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -240,14 +272,15 @@ fn information_layers(input: &[u8]) -> IResult<&[u8], InformationLayers> {
 }
 
 fn iff_fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], IffFundamentalParameterData> {
-    let (input, erp) = be_f32(input)?;
-    let (input, frequency) = be_f32(input)?;
-    let (input, pgrf) = be_f32(input)?;
-    let (input, pulse_width) = be_f32(input)?;
-    let (input, burst_length) = be_f32(input)?;
-    let (input, applicable_modes) = be_u8(input)?;
+    let (input, erp) = context("ERP", be_f32).parse(input)?;
+    let (input, frequency) = context("Frequency", be_f32).parse(input)?;
+    let (input, pgrf) = context("PGRF", be_f32).parse(input)?;
+    let (input, pulse_width) = context("Pulse Width", be_f32).parse(input)?;
+    let (input, burst_length) = context("Burst Length", be_f32).parse(input)?;
+    let (input, applicable_modes) = context("Applicable Modes", be_u8).parse(input)?;
     let applicable_modes = IffApplicableModes::from(applicable_modes);
-    let (input, system_specific_data) = system_specific_data(input)?;
+    let (input, system_specific_data) =
+        context("System Specific Data", system_specific_data).parse(input)?;
 
     Ok((
         input,
@@ -264,9 +297,10 @@ fn iff_fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], IffFundamental
 }
 
 fn layer_header(input: &[u8]) -> IResult<&[u8], LayerHeader> {
-    let (input, layer_number) = be_u8(input)?;
-    let (input, layer_specific_information) = be_u8(input)?;
-    let (input, length) = be_u16(input)?;
+    let (input, layer_number) = context("Layer Number", be_u8).parse(input)?;
+    let (input, layer_specific_information) =
+        context("Layer Specific Information", be_u8).parse(input)?;
+    let (input, length) = context("Length", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -279,9 +313,9 @@ fn layer_header(input: &[u8]) -> IResult<&[u8], LayerHeader> {
 }
 
 fn system_specific_data(input: &[u8]) -> IResult<&[u8], SystemSpecificData> {
-    let (input, part_1) = be_u8(input)?;
-    let (input, part_2) = be_u8(input)?;
-    let (input, part_3) = be_u8(input)?;
+    let (input, part_1) = context("Part 1", be_u8).parse(input)?;
+    let (input, part_2) = context("Part 2", be_u8).parse(input)?;
+    let (input, part_3) = context("Part 3", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -294,13 +328,14 @@ fn system_specific_data(input: &[u8]) -> IResult<&[u8], SystemSpecificData> {
 }
 
 fn system_id(input: &[u8]) -> IResult<&[u8], SystemId> {
-    let (input, system_type) = be_u16(input)?;
+    let (input, system_type) = context("System Type", be_u16).parse(input)?;
     let system_type = IffSystemType::from(system_type);
-    let (input, system_name) = be_u16(input)?;
+    let (input, system_name) = context("System Name", be_u16).parse(input)?;
     let system_name = IffSystemName::from(system_name);
-    let (input, system_mode) = be_u8(input)?;
+    let (input, system_mode) = context("System Mode", be_u8).parse(input)?;
     let system_mode = IffSystemMode::from(system_mode);
-    let (input, change_options_record) = change_options_record(input)?;
+    let (input, change_options_record) =
+        context("Change Options", change_options_record).parse(input)?;
 
     Ok((
         input,
@@ -345,14 +380,18 @@ fn system_status(input: &[u8]) -> IResult<&[u8], SystemStatus> {
 fn mode_5_basic_data(
     system_type: &IffSystemType,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], Result<Mode5BasicData, DisError>> + '_ {
-    move |input: &[u8]| match system_type {
+    move |input| match system_type {
         IffSystemType::MarkXXIIATCRBSTransponder
         | IffSystemType::SovietTransponder
         | IffSystemType::RRBTransponder
         | IffSystemType::MarkXIIATransponder
         | IffSystemType::Mode5Transponder
         | IffSystemType::ModeSTransponder => {
-            let (input, basic_data) = mode_5_transponder_basic_data(input)?;
+            let (input, basic_data) = context(
+                "Mode 5 Transponder Basic Data",
+                mode_5_transponder_basic_data,
+            )
+            .parse(input)?;
             Ok((input, Ok(Mode5BasicData::Transponder(basic_data))))
         }
         IffSystemType::MarkXXIIATCRBSInterrogator
@@ -360,7 +399,11 @@ fn mode_5_basic_data(
         | IffSystemType::MarkXIIAInterrogator
         | IffSystemType::Mode5Interrogator
         | IffSystemType::ModeSInterrogator => {
-            let (input, basic_data) = mode_5_interrogator_basic_data(input)?;
+            let (input, basic_data) = context(
+                "Mode 5 Interrogator Basic Data",
+                mode_5_interrogator_basic_data,
+            )
+            .parse(input)?;
             Ok((input, Ok(Mode5BasicData::Interrogator(basic_data))))
         }
         IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
@@ -374,12 +417,14 @@ fn mode_5_basic_data(
 }
 
 fn mode_5_interrogator_basic_data(input: &[u8]) -> IResult<&[u8], Mode5InterrogatorBasicData> {
-    let (input, status) = mode_5_interrogator_status(input)?;
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, message_formats) = mode_5_message_formats(input)?;
-    let (input, entity_id) = entity_id(input)?;
-    let (input, _padding) = be_u16(input)?;
+    let (input, status) =
+        context("Mode 5 Interrogator Status", mode_5_interrogator_status).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, message_formats) =
+        context("Mode 5 Message Formats", mode_5_message_formats).parse(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -404,16 +449,23 @@ fn mode_5_message_formats(input: &[u8]) -> IResult<&[u8], Mode5MessageFormats> {
 }
 
 fn mode_5_transponder_basic_data(input: &[u8]) -> IResult<&[u8], Mode5TransponderBasicData> {
-    let (input, status) = mode_5_transponder_status(input)?;
-    let (input, pin) = be_u16(input)?;
-    let (input, message_formats_present) = mode_5_message_formats(input)?;
-    let (input, enhanced_mode_1) = enhanced_mode_1_code(input)?;
-    let (input, national_origin) = be_u16(input)?;
-    let (input, supplemental_data) = mode_5_transponder_supplemental_data(input)?;
-    let (input, navigation_source) = be_u8(input)?;
+    let (input, status) =
+        context("Mode 5 Transponder Status", mode_5_transponder_status).parse(input)?;
+    let (input, pin) = context("PIN", be_u16).parse(input)?;
+    let (input, message_formats_present) =
+        context("Mode 5 Message Formats", mode_5_message_formats).parse(input)?;
+    let (input, enhanced_mode_1) =
+        context("Enhanced Mode 1 Code", enhanced_mode_1_code).parse(input)?;
+    let (input, national_origin) = context("National Origin", be_u16).parse(input)?;
+    let (input, supplemental_data) = context(
+        "Mode 5 Transponder Supplemental Data",
+        mode_5_transponder_supplemental_data,
+    )
+    .parse(input)?;
+    let (input, navigation_source) = context("Navigation Source", be_u8).parse(input)?;
     let navigation_source = NavigationSource::from(navigation_source);
-    let (input, figure_of_merit) = be_u8(input)?;
-    let (input, _padding) = be_u8(input)?;
+    let (input, figure_of_merit) = context("Figure of Merit", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -455,14 +507,18 @@ fn mode_s_altitude(input: &[u8]) -> IResult<&[u8], ModeSAltitude> {
 fn mode_s_basic_data(
     system_type: &IffSystemType,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], Result<ModeSBasicData, DisError>> + '_ {
-    move |input: &[u8]| match system_type {
+    move |input| match system_type {
         IffSystemType::MarkXXIIATCRBSTransponder
         | IffSystemType::SovietTransponder
         | IffSystemType::RRBTransponder
         | IffSystemType::MarkXIIATransponder
         | IffSystemType::Mode5Transponder
         | IffSystemType::ModeSTransponder => {
-            let (input, basic_data) = mode_s_transponder_basic_data(input)?;
+            let (input, basic_data) = context(
+                "Mode S Transponder Basic Data",
+                mode_s_transponder_basic_data,
+            )
+            .parse(input)?;
             Ok((input, Ok(ModeSBasicData::Transponder(basic_data))))
         }
         IffSystemType::MarkXXIIATCRBSInterrogator
@@ -470,7 +526,11 @@ fn mode_s_basic_data(
         | IffSystemType::MarkXIIAInterrogator
         | IffSystemType::Mode5Interrogator
         | IffSystemType::ModeSInterrogator => {
-            let (input, basic_data) = mode_s_interrogator_basic_data(input)?;
+            let (input, basic_data) = context(
+                "Mode S Interrogator Basic Data",
+                mode_s_interrogator_basic_data,
+            )
+            .parse(input)?;
             Ok((input, Ok(ModeSBasicData::Interrogator(basic_data))))
         }
         IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
@@ -486,10 +546,13 @@ fn mode_s_basic_data(
 fn mode_s_interrogator_basic_data(input: &[u8]) -> IResult<&[u8], ModeSInterrogatorBasicData> {
     const PAD_168_BITS_IN_OCTETS: usize = 21;
 
-    let (input, status) = mode_s_interrogator_status(input)?;
-    let (input, _padding_1_octet) = be_u8(input)?;
-    let (input, levels_present) = mode_s_levels_present(input)?;
-    let (input, _padding_21_octets) = take(PAD_168_BITS_IN_OCTETS)(input)?;
+    let (input, status) =
+        context("Mode S Interrogator Status", mode_s_interrogator_status).parse(input)?;
+    let (input, _padding_1_octet) = context("Padding", be_u8).parse(input)?;
+    let (input, levels_present) =
+        context("Mode S Levels Present", mode_s_levels_present).parse(input)?;
+    let (input, _padding_21_octets) =
+        context("Padding", take(PAD_168_BITS_IN_OCTETS)).parse(input)?;
 
     Ok((
         input,
@@ -513,29 +576,28 @@ fn mode_s_levels_present(input: &[u8]) -> IResult<&[u8], ModeSLevelsPresent> {
 }
 
 fn mode_s_transponder_basic_data(input: &[u8]) -> IResult<&[u8], ModeSTransponderBasicData> {
-    let (input, status) = mode_s_transponder_status(input)?;
-    let (input, levels_present) = mode_s_levels_present(input)?;
-    let (input, aircraft_present_domain) = be_u8(input)?;
+    let (input, status) =
+        context("Mode S Transponder Status", mode_s_transponder_status).parse(input)?;
+    let (input, levels_present) =
+        context("Mode S Levels Present", mode_s_levels_present).parse(input)?;
+    let (input, aircraft_present_domain) =
+        context("Aircraft Present Domain", be_u8).parse(input)?;
     let aircraft_present_domain = AircraftPresentDomain::from(aircraft_present_domain);
 
-    let mut buf: [u8; EIGHT_OCTETS] = [0; EIGHT_OCTETS];
-    let (input, ()) = nom::multi::fill(be_u8, &mut buf).parse(input)?;
-
-    let mut aircraft_id = String::from_utf8_lossy(&buf[..]).into_owned();
-    aircraft_id.truncate(
-        aircraft_id
-            .trim_end()
-            .trim_end_matches(|c: char| !c.is_alphanumeric())
-            .len(),
-    );
+    let (input, aircraft_id) = context(
+        "Aircraft Identification",
+        map(take(EIGHT_OCTETS), sanitize_marking),
+    )
+    .parse(input)?;
 
-    let (input, aircraft_address) = be_u32(input)?;
-    let (input, aircraft_identification_type) = be_u8(input)?;
+    let (input, aircraft_address) = context("Aircraft Address", be_u32).parse(input)?;
+    let (input, aircraft_identification_type) =
+        context("Aircraft Identification Type", be_u8).parse(input)?;
     let aircraft_identification_type =
         AircraftIdentificationType::from(aircraft_identification_type);
-    let (input, dap_source) = dap_source(input)?;
-    let (input, altitude) = mode_s_altitude(input)?;
-    let (input, capability_report) = be_u8(input)?;
+    let (input, dap_source) = context("DAP Source", dap_source).parse(input)?;
+    let (input, altitude) = context("Mode S Altitude", mode_s_altitude).parse(input)?;
+    let (input, capability_report) = context("Capability Report", be_u8).parse(input)?;
     let capability_report = CapabilityReport::from(capability_report);
 
     Ok((
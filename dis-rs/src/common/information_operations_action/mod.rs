@@ -0,0 +1,96 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::information_operations_action::model::{
+        CommunicationsNodeId, IOActionPhase, IOActionType, IOCommunicationsNode,
+        IOCommunicationsNodeType, IOEffect, IOEffectType, IOLinkType, IORecord, IOSimulationSource,
+        IOStatus, IOWarfareType, InformationOperationsAction,
+    };
+    use crate::model::EntityId;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    fn information_operations_action_body() -> InformationOperationsAction {
+        InformationOperationsAction::builder()
+            .with_originating_simulation_id(EntityId::new(1, 1, 0))
+            .with_receiving_simulation_id(EntityId::new(2, 1, 0))
+            .with_request_id(42)
+            .with_io_warfare_type(IOWarfareType::ElectronicWarfare)
+            .with_io_simulation_source(IOSimulationSource::Unspecified(7))
+            .with_io_action_type(IOActionType::IOAttackComputedEffects)
+            .with_io_action_phase(IOActionPhase::StartAttackEffects)
+            .with_io_attacker_id(EntityId::new(1, 1, 10))
+            .with_io_primary_target_id(EntityId::new(2, 1, 20))
+            .with_io_record(IORecord::CommunicationsNode(IOCommunicationsNode::new(
+                IOCommunicationsNodeType::ReceiverNodeId,
+                CommunicationsNodeId::new(EntityId::new(2, 1, 20), 3),
+            )))
+            .with_io_record(IORecord::Effect(
+                IOEffect::new()
+                    .with_io_status(IOStatus::EffectOnReceiver)
+                    .with_io_link_type(IOLinkType::PhysicalLink)
+                    .with_io_effect(IOEffectType::Degraded)
+                    .with_io_effect_duty_cycle(50)
+                    .with_io_effect_duration(120),
+            ))
+            .with_io_record(IORecord::Unspecified {
+                record_type: 5999,
+                fields: vec![0, 0, 1, 2, 3, 4, 5, 6, 7, 8],
+            })
+            .build()
+    }
+
+    #[test]
+    fn information_operations_action_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::InformationOperationsAction).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Constructive)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = information_operations_action_body().into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+        assert_eq!(buf.len(), 56 + 16 + 16 + 16);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn information_operations_action_serde_roundtrip() {
+        let body = information_operations_action_body();
+
+        let json = serde_json::to_value(&body).unwrap();
+        let body_de = serde_json::from_value::<InformationOperationsAction>(json).unwrap();
+
+        assert_eq!(body_de, body);
+    }
+}
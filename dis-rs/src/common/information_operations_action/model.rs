@@ -0,0 +1,588 @@
+use crate::BodyRaw;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::PduType;
+use crate::information_operations_action::builder::InformationOperationsActionBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH: u16 = 44;
+pub const IO_RECORD_HEADER_LENGTH: u16 = 6;
+pub const IO_COMMUNICATIONS_NODE_RECORD_LENGTH: u16 = 16;
+pub const IO_EFFECT_RECORD_LENGTH: u16 = 16;
+
+/// Variable Record Type of the IO Communications Node record (SISO-REF-010 UID 66)
+pub const IO_COMMUNICATIONS_NODE_RECORD_TYPE: u32 = 5001;
+/// Variable Record Type of the IO Effect record (SISO-REF-010 UID 66)
+pub const IO_EFFECT_RECORD_TYPE: u32 = 5500;
+
+/// 5.13.2 Information Operations Action PDU
+///
+/// 7.12.2 Information Operations Action PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InformationOperationsAction {
+    pub originating_simulation_id: EntityId,
+    pub receiving_simulation_id: EntityId,
+    pub request_id: u32,
+    pub io_warfare_type: IOWarfareType,
+    pub io_simulation_source: IOSimulationSource,
+    pub io_action_type: IOActionType,
+    pub io_action_phase: IOActionPhase,
+    pub io_attacker_id: EntityId,
+    pub io_primary_target_id: EntityId,
+    pub io_records: Vec<IORecord>,
+}
+
+impl BodyRaw for InformationOperationsAction {
+    type Builder = InformationOperationsActionBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsAction(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsAction {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH
+            + self
+                .io_records
+                .iter()
+                .map(IORecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsAction
+    }
+}
+
+impl Interaction for InformationOperationsAction {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_simulation_id)
+    }
+}
+
+/// Standard Variable records carried by the Information Operations Action and Report PDUs.
+///
+/// Records with an unknown Record Type are kept as the raw bytes following the record type and length fields.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IORecord {
+    CommunicationsNode(IOCommunicationsNode),
+    Effect(IOEffect),
+    Unspecified { record_type: u32, fields: Vec<u8> },
+}
+
+impl Default for IORecord {
+    fn default() -> Self {
+        Self::Effect(IOEffect::default())
+    }
+}
+
+impl IORecord {
+    #[must_use]
+    pub fn record_type(&self) -> u32 {
+        match self {
+            IORecord::CommunicationsNode(_) => IO_COMMUNICATIONS_NODE_RECORD_TYPE,
+            IORecord::Effect(_) => IO_EFFECT_RECORD_TYPE,
+            IORecord::Unspecified { record_type, .. } => *record_type,
+        }
+    }
+
+    /// Length in octets of the complete record, including the record type and length fields
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            IORecord::CommunicationsNode(_) => IO_COMMUNICATIONS_NODE_RECORD_LENGTH,
+            IORecord::Effect(_) => IO_EFFECT_RECORD_LENGTH,
+            IORecord::Unspecified { fields, .. } => IO_RECORD_HEADER_LENGTH + fields.len() as u16,
+        }
+    }
+}
+
+/// IO Communications Node record (Standard Variable record type 5001)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IOCommunicationsNode {
+    pub communications_node_type: IOCommunicationsNodeType,
+    pub communications_node_id: CommunicationsNodeId,
+}
+
+impl IOCommunicationsNode {
+    #[must_use]
+    pub fn new(
+        communications_node_type: IOCommunicationsNodeType,
+        communications_node_id: CommunicationsNodeId,
+    ) -> Self {
+        Self {
+            communications_node_type,
+            communications_node_id,
+        }
+    }
+}
+
+/// Communications Node ID record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationsNodeId {
+    pub entity_id: EntityId,
+    pub element_id: u16,
+}
+
+impl CommunicationsNodeId {
+    #[must_use]
+    pub fn new(entity_id: EntityId, element_id: u16) -> Self {
+        Self {
+            entity_id,
+            element_id,
+        }
+    }
+}
+
+/// IO Effect record (Standard Variable record type 5500)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IOEffect {
+    pub io_status: IOStatus,
+    pub io_link_type: IOLinkType,
+    pub io_effect: IOEffectType,
+    /// Percentage of time the effect is applied, 0-100
+    pub io_effect_duty_cycle: u8,
+    /// Duration of the effect in seconds
+    pub io_effect_duration: u16,
+    pub io_process: IOProcess,
+}
+
+impl IOEffect {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_io_status(mut self, io_status: IOStatus) -> Self {
+        self.io_status = io_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_link_type(mut self, io_link_type: IOLinkType) -> Self {
+        self.io_link_type = io_link_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_effect(mut self, io_effect: IOEffectType) -> Self {
+        self.io_effect = io_effect;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_effect_duty_cycle(mut self, io_effect_duty_cycle: u8) -> Self {
+        self.io_effect_duty_cycle = io_effect_duty_cycle;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_effect_duration(mut self, io_effect_duration: u16) -> Self {
+        self.io_effect_duration = io_effect_duration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_process(mut self, io_process: IOProcess) -> Self {
+        self.io_process = io_process;
+        self
+    }
+}
+
+/// Custom defined enum to model the IO Warfare Type field (SISO-REF-010 UID 285)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOWarfareType {
+    #[default]
+    NoStatement, // 0
+    ElectronicWarfare,         // 1
+    ComputerNetworkOperations, // 2
+    PsychologicalOperations,   // 3
+    MilitaryDeception,         // 4
+    OperationsSecurity,        // 5
+    PhysicalAttack,            // 6
+    Unspecified(u16),
+}
+
+impl From<u16> for IOWarfareType {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::ElectronicWarfare,
+            2 => Self::ComputerNetworkOperations,
+            3 => Self::PsychologicalOperations,
+            4 => Self::MilitaryDeception,
+            5 => Self::OperationsSecurity,
+            6 => Self::PhysicalAttack,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOWarfareType> for u16 {
+    fn from(value: IOWarfareType) -> Self {
+        match value {
+            IOWarfareType::NoStatement => 0,
+            IOWarfareType::ElectronicWarfare => 1,
+            IOWarfareType::ComputerNetworkOperations => 2,
+            IOWarfareType::PsychologicalOperations => 3,
+            IOWarfareType::MilitaryDeception => 4,
+            IOWarfareType::OperationsSecurity => 5,
+            IOWarfareType::PhysicalAttack => 6,
+            IOWarfareType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Simulation Source field (SISO-REF-010 UID 286)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOSimulationSource {
+    #[default]
+    NoStatement, // 0
+    Unspecified(u16),
+}
+
+impl From<u16> for IOSimulationSource {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOSimulationSource> for u16 {
+    fn from(value: IOSimulationSource) -> Self {
+        match value {
+            IOSimulationSource::NoStatement => 0,
+            IOSimulationSource::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Action Type field (SISO-REF-010 UID 287)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOActionType {
+    #[default]
+    NoStatement, // 0
+    IOAttackProfileData,          // 1
+    IOAttackComputedEffects,      // 2
+    IntentBasedEW,                // 3
+    IntentBasedEWComputedEffects, // 4
+    Unspecified(u16),
+}
+
+impl From<u16> for IOActionType {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::IOAttackProfileData,
+            2 => Self::IOAttackComputedEffects,
+            3 => Self::IntentBasedEW,
+            4 => Self::IntentBasedEWComputedEffects,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOActionType> for u16 {
+    fn from(value: IOActionType) -> Self {
+        match value {
+            IOActionType::NoStatement => 0,
+            IOActionType::IOAttackProfileData => 1,
+            IOActionType::IOAttackComputedEffects => 2,
+            IOActionType::IntentBasedEW => 3,
+            IOActionType::IntentBasedEWComputedEffects => 4,
+            IOActionType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Action Phase field (SISO-REF-010 UID 288)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOActionPhase {
+    #[default]
+    NoStatement, // 0
+    StartAttackProfile,               // 1
+    EndAttackProfile,                 // 2
+    ContinueAttackProfileWithChanges, // 3
+    StartAttackEffects,               // 4
+    EndAttackedEffects,               // 5
+    ContinueAttackEffectsWithChanges, // 6
+    Unspecified(u16),
+}
+
+impl From<u16> for IOActionPhase {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::StartAttackProfile,
+            2 => Self::EndAttackProfile,
+            3 => Self::ContinueAttackProfileWithChanges,
+            4 => Self::StartAttackEffects,
+            5 => Self::EndAttackedEffects,
+            6 => Self::ContinueAttackEffectsWithChanges,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOActionPhase> for u16 {
+    fn from(value: IOActionPhase) -> Self {
+        match value {
+            IOActionPhase::NoStatement => 0,
+            IOActionPhase::StartAttackProfile => 1,
+            IOActionPhase::EndAttackProfile => 2,
+            IOActionPhase::ContinueAttackProfileWithChanges => 3,
+            IOActionPhase::StartAttackEffects => 4,
+            IOActionPhase::EndAttackedEffects => 5,
+            IOActionPhase::ContinueAttackEffectsWithChanges => 6,
+            IOActionPhase::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Report Type field (SISO-REF-010 UID 289)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOReportType {
+    #[default]
+    NoStatement, // 0
+    InitialReport, // 1
+    UpdateReport,  // 2
+    FinalReport,   // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for IOReportType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::InitialReport,
+            2 => Self::UpdateReport,
+            3 => Self::FinalReport,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOReportType> for u8 {
+    fn from(value: IOReportType) -> Self {
+        match value {
+            IOReportType::NoStatement => 0,
+            IOReportType::InitialReport => 1,
+            IOReportType::UpdateReport => 2,
+            IOReportType::FinalReport => 3,
+            IOReportType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Status field of the IO Effect record (SISO-REF-010 UID 290)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOStatus {
+    #[default]
+    NoStatement, // 0
+    EffectOnSender,                   // 1
+    EffectOnReceiver,                 // 2
+    EffectOnSenderAndReceiver,        // 3
+    EffectOnMessage,                  // 4
+    EffectOnSenderAndMessage,         // 5
+    EffectOnReceiverAndMessage,       // 6
+    EffectOnSenderReceiverAndMessage, // 7
+    Unspecified(u8),
+}
+
+impl From<u8> for IOStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::EffectOnSender,
+            2 => Self::EffectOnReceiver,
+            3 => Self::EffectOnSenderAndReceiver,
+            4 => Self::EffectOnMessage,
+            5 => Self::EffectOnSenderAndMessage,
+            6 => Self::EffectOnReceiverAndMessage,
+            7 => Self::EffectOnSenderReceiverAndMessage,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOStatus> for u8 {
+    fn from(value: IOStatus) -> Self {
+        match value {
+            IOStatus::NoStatement => 0,
+            IOStatus::EffectOnSender => 1,
+            IOStatus::EffectOnReceiver => 2,
+            IOStatus::EffectOnSenderAndReceiver => 3,
+            IOStatus::EffectOnMessage => 4,
+            IOStatus::EffectOnSenderAndMessage => 5,
+            IOStatus::EffectOnReceiverAndMessage => 6,
+            IOStatus::EffectOnSenderReceiverAndMessage => 7,
+            IOStatus::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Link Type field of the IO Effect record (SISO-REF-010 UID 291)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOLinkType {
+    #[default]
+    NoStatement, // 0
+    LogicalLink,  // 1
+    PhysicalNode, // 2
+    PhysicalLink, // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for IOLinkType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::LogicalLink,
+            2 => Self::PhysicalNode,
+            3 => Self::PhysicalLink,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOLinkType> for u8 {
+    fn from(value: IOLinkType) -> Self {
+        match value {
+            IOLinkType::NoStatement => 0,
+            IOLinkType::LogicalLink => 1,
+            IOLinkType::PhysicalNode => 2,
+            IOLinkType::PhysicalLink => 3,
+            IOLinkType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Effect field of the IO Effect record (SISO-REF-010 UID 292)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOEffectType {
+    #[default]
+    NoStatement, // 0
+    Denial,          // 1
+    Degraded,        // 2
+    Disrupt,         // 3
+    TerminateEffect, // 255
+    Unspecified(u8),
+}
+
+impl From<u8> for IOEffectType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::Denial,
+            2 => Self::Degraded,
+            3 => Self::Disrupt,
+            255 => Self::TerminateEffect,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOEffectType> for u8 {
+    fn from(value: IOEffectType) -> Self {
+        match value {
+            IOEffectType::NoStatement => 0,
+            IOEffectType::Denial => 1,
+            IOEffectType::Degraded => 2,
+            IOEffectType::Disrupt => 3,
+            IOEffectType::TerminateEffect => 255,
+            IOEffectType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the IO Process field of the IO Effect record (SISO-REF-010 UID 293)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOProcess {
+    #[default]
+    NoStatement, // 0
+    Unspecified(u16),
+}
+
+impl From<u16> for IOProcess {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOProcess> for u16 {
+    fn from(value: IOProcess) -> Self {
+        match value {
+            IOProcess::NoStatement => 0,
+            IOProcess::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Communications Node Type field of the IO Communications Node record (SISO-REF-010 UID 294)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IOCommunicationsNodeType {
+    #[default]
+    NoStatement, // 0
+    SenderNodeId,         // 1
+    ReceiverNodeId,       // 2
+    SenderReceiverNodeId, // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for IOCommunicationsNodeType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoStatement,
+            1 => Self::SenderNodeId,
+            2 => Self::ReceiverNodeId,
+            3 => Self::SenderReceiverNodeId,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IOCommunicationsNodeType> for u8 {
+    fn from(value: IOCommunicationsNodeType) -> Self {
+        match value {
+            IOCommunicationsNodeType::NoStatement => 0,
+            IOCommunicationsNodeType::SenderNodeId => 1,
+            IOCommunicationsNodeType::ReceiverNodeId => 2,
+            IOCommunicationsNodeType::SenderReceiverNodeId => 3,
+            IOCommunicationsNodeType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
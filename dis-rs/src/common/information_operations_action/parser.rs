@@ -0,0 +1,119 @@
+use crate::BodyRaw;
+use crate::common::information_operations_action::model::{
+    CommunicationsNodeId, IO_COMMUNICATIONS_NODE_RECORD_TYPE, IO_EFFECT_RECORD_TYPE,
+    IO_RECORD_HEADER_LENGTH, IOActionPhase, IOActionType, IOCommunicationsNode,
+    IOCommunicationsNodeType, IOEffect, IOEffectType, IOLinkType, IOProcess, IORecord,
+    IOSimulationSource, IOStatus, IOWarfareType, InformationOperationsAction,
+};
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_id};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn information_operations_action_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_simulation_id) =
+        context("Originating Simulation ID", entity_id).parse(input)?;
+    let (input, receiving_simulation_id) =
+        context("Receiving Simulation ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, io_warfare_type) = context("IO Warfare Type", be_u16).parse(input)?;
+    let (input, io_simulation_source) = context("IO Simulation Source", be_u16).parse(input)?;
+    let (input, io_action_type) = context("IO Action Type", be_u16).parse(input)?;
+    let (input, io_action_phase) = context("IO Action Phase", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, io_attacker_id) = context("IO Attacker ID", entity_id).parse(input)?;
+    let (input, io_primary_target_id) = context("IO Primary Target ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, number_of_io_records) = context("Number of IO Records", be_u16).parse(input)?;
+    let (input, io_records) = count(
+        context("IO Record", io_record),
+        number_of_io_records as usize,
+    )
+    .parse(input)?;
+
+    let body = InformationOperationsAction::builder()
+        .with_originating_simulation_id(originating_simulation_id)
+        .with_receiving_simulation_id(receiving_simulation_id)
+        .with_request_id(request_id)
+        .with_io_warfare_type(IOWarfareType::from(io_warfare_type))
+        .with_io_simulation_source(IOSimulationSource::from(io_simulation_source))
+        .with_io_action_type(IOActionType::from(io_action_type))
+        .with_io_action_phase(IOActionPhase::from(io_action_phase))
+        .with_io_attacker_id(io_attacker_id)
+        .with_io_primary_target_id(io_primary_target_id)
+        .with_io_records(io_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+/// Parses a single IO record, consuming exactly the number of octets stated in its Record Length field.
+pub(crate) fn io_record(input: &[u8]) -> IResult<&[u8], IORecord> {
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
+    let (input, record_length) = context("Record Length", be_u16).parse(input)?;
+    let (input, fields) = context(
+        "Fields",
+        take(record_length.saturating_sub(IO_RECORD_HEADER_LENGTH)),
+    )
+    .parse(input)?;
+
+    let record = match record_type {
+        IO_COMMUNICATIONS_NODE_RECORD_TYPE => {
+            let (_, record) = io_communications_node(fields)?;
+            IORecord::CommunicationsNode(record)
+        }
+        IO_EFFECT_RECORD_TYPE => {
+            let (_, record) = io_effect(fields)?;
+            IORecord::Effect(record)
+        }
+        _ => IORecord::Unspecified {
+            record_type,
+            fields: fields.to_vec(),
+        },
+    };
+
+    Ok((input, record))
+}
+
+fn io_communications_node(input: &[u8]) -> IResult<&[u8], IOCommunicationsNode> {
+    let (input, communications_node_type) =
+        context("Communications Node Type", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, element_id) = context("Element ID", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        IOCommunicationsNode::new(
+            IOCommunicationsNodeType::from(communications_node_type),
+            CommunicationsNodeId::new(entity_id, element_id),
+        ),
+    ))
+}
+
+fn io_effect(input: &[u8]) -> IResult<&[u8], IOEffect> {
+    let (input, io_status) = context("IO Status", be_u8).parse(input)?;
+    let (input, io_link_type) = context("IO Link Type", be_u8).parse(input)?;
+    let (input, io_effect) = context("IO Effect", be_u8).parse(input)?;
+    let (input, io_effect_duty_cycle) = context("IO Effect Duty Cycle", be_u8).parse(input)?;
+    let (input, io_effect_duration) = context("IO Effect Duration", be_u16).parse(input)?;
+    let (input, io_process) = context("IO Process", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        IOEffect::new()
+            .with_io_status(IOStatus::from(io_status))
+            .with_io_link_type(IOLinkType::from(io_link_type))
+            .with_io_effect(IOEffectType::from(io_effect))
+            .with_io_effect_duty_cycle(io_effect_duty_cycle)
+            .with_io_effect_duration(io_effect_duration)
+            .with_io_process(IOProcess::from(io_process)),
+    ))
+}
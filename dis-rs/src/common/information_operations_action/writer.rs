@@ -0,0 +1,73 @@
+use crate::common::information_operations_action::model::{
+    IO_COMMUNICATIONS_NODE_RECORD_LENGTH, IO_EFFECT_RECORD_LENGTH, IO_RECORD_HEADER_LENGTH,
+    IOCommunicationsNode, IOEffect, IORecord, InformationOperationsAction,
+};
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for InformationOperationsAction {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.originating_simulation_id.serialize(buf);
+        self.receiving_simulation_id.serialize(buf);
+        buf.put_u32(self.request_id);
+        buf.put_u16(self.io_warfare_type.into());
+        buf.put_u16(self.io_simulation_source.into());
+        buf.put_u16(self.io_action_type.into());
+        buf.put_u16(self.io_action_phase.into());
+        buf.put_u32(0u32);
+        self.io_attacker_id.serialize(buf);
+        self.io_primary_target_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u16(self.io_records.len() as u16);
+        for record in &self.io_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for IORecord {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.record_type());
+        buf.put_u16(self.record_length());
+        match self {
+            IORecord::CommunicationsNode(record) => {
+                record.serialize(buf);
+            }
+            IORecord::Effect(record) => {
+                record.serialize(buf);
+            }
+            IORecord::Unspecified { fields, .. } => {
+                buf.put_slice(fields);
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for IOCommunicationsNode {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.communications_node_type.into());
+        buf.put_u8(0u8);
+        self.communications_node_id.entity_id.serialize(buf);
+        buf.put_u16(self.communications_node_id.element_id);
+
+        IO_COMMUNICATIONS_NODE_RECORD_LENGTH - IO_RECORD_HEADER_LENGTH
+    }
+}
+
+impl Serialize for IOEffect {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.io_status.into());
+        buf.put_u8(self.io_link_type.into());
+        buf.put_u8(self.io_effect.into());
+        buf.put_u8(self.io_effect_duty_cycle);
+        buf.put_u16(self.io_effect_duration);
+        buf.put_u16(self.io_process.into());
+        buf.put_u16(0u16);
+
+        IO_EFFECT_RECORD_LENGTH - IO_RECORD_HEADER_LENGTH
+    }
+}
@@ -0,0 +1,76 @@
+use crate::information_operations_action::model::{IORecord, IOReportType, IOSimulationSource};
+use crate::information_operations_report::model::InformationOperationsReport;
+use crate::model::EntityId;
+
+pub struct InformationOperationsReportBuilder(InformationOperationsReport);
+
+impl Default for InformationOperationsReportBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InformationOperationsReportBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        InformationOperationsReportBuilder(InformationOperationsReport::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: InformationOperationsReport) -> Self {
+        InformationOperationsReportBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> InformationOperationsReport {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_originating_simulation_id(mut self, originating_simulation_id: EntityId) -> Self {
+        self.0.originating_simulation_id = originating_simulation_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_receiving_simulation_id(mut self, receiving_simulation_id: EntityId) -> Self {
+        self.0.receiving_simulation_id = receiving_simulation_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_simulation_source(mut self, io_simulation_source: IOSimulationSource) -> Self {
+        self.0.io_simulation_source = io_simulation_source;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_report_type(mut self, io_report_type: IOReportType) -> Self {
+        self.0.io_report_type = io_report_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_attacker_id(mut self, io_attacker_id: EntityId) -> Self {
+        self.0.io_attacker_id = io_attacker_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_primary_target_id(mut self, io_primary_target_id: EntityId) -> Self {
+        self.0.io_primary_target_id = io_primary_target_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_record(mut self, io_record: IORecord) -> Self {
+        self.0.io_records.push(io_record);
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_records(mut self, io_records: Vec<IORecord>) -> Self {
+        self.0.io_records = io_records;
+        self
+    }
+}
@@ -0,0 +1,63 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::PduType;
+    use crate::information_operations_action::model::{
+        IOEffect, IOEffectType, IOLinkType, IORecord, IOReportType, IOSimulationSource, IOStatus,
+    };
+    use crate::information_operations_report::model::InformationOperationsReport;
+    use crate::model::EntityId;
+    use bytes::BytesMut;
+
+    #[test]
+    fn information_operations_report_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::InformationOperationsReport);
+
+        let body = InformationOperationsReport::builder()
+            .with_originating_simulation_id(EntityId::new(2, 1, 0))
+            .with_receiving_simulation_id(EntityId::new(1, 1, 0))
+            .with_io_simulation_source(IOSimulationSource::NoStatement)
+            .with_io_report_type(IOReportType::FinalReport)
+            .with_io_attacker_id(EntityId::new(1, 1, 10))
+            .with_io_primary_target_id(EntityId::new(2, 1, 20))
+            .with_io_record(IORecord::Effect(
+                IOEffect::new()
+                    .with_io_status(IOStatus::EffectOnSenderAndReceiver)
+                    .with_io_link_type(IOLinkType::LogicalLink)
+                    .with_io_effect(IOEffectType::TerminateEffect),
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+        assert_eq!(buf.len(), 48 + 16);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
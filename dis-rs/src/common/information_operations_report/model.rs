@@ -0,0 +1,66 @@
+use crate::BodyRaw;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::PduType;
+use crate::information_operations_action::model::{IORecord, IOReportType, IOSimulationSource};
+use crate::information_operations_report::builder::InformationOperationsReportBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH: u16 = 36;
+
+/// 5.13.3 Information Operations Report PDU
+///
+/// 7.12.3 Information Operations Report PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InformationOperationsReport {
+    pub originating_simulation_id: EntityId,
+    pub receiving_simulation_id: EntityId,
+    pub io_simulation_source: IOSimulationSource,
+    pub io_report_type: IOReportType,
+    pub io_attacker_id: EntityId,
+    pub io_primary_target_id: EntityId,
+    pub io_records: Vec<IORecord>,
+}
+
+impl BodyRaw for InformationOperationsReport {
+    type Builder = InformationOperationsReportBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsReport(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsReport {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH
+            + self
+                .io_records
+                .iter()
+                .map(IORecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsReport
+    }
+}
+
+impl Interaction for InformationOperationsReport {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_simulation_id)
+    }
+}
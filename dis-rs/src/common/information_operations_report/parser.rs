@@ -0,0 +1,45 @@
+use crate::BodyRaw;
+use crate::common::information_operations_action::model::{IOReportType, IOSimulationSource};
+use crate::common::information_operations_action::parser::io_record;
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_id};
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn information_operations_report_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_simulation_id) =
+        context("Originating Simulation ID", entity_id).parse(input)?;
+    let (input, receiving_simulation_id) =
+        context("Receiving Simulation ID", entity_id).parse(input)?;
+    let (input, io_simulation_source) = context("IO Simulation Source", be_u16).parse(input)?;
+    let (input, io_report_type) = context("IO Report Type", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, io_attacker_id) = context("IO Attacker ID", entity_id).parse(input)?;
+    let (input, io_primary_target_id) = context("IO Primary Target ID", entity_id).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, number_of_io_records) = context("Number of IO Records", be_u16).parse(input)?;
+    let (input, io_records) = count(
+        context("IO Record", io_record),
+        number_of_io_records as usize,
+    )
+    .parse(input)?;
+
+    let body = InformationOperationsReport::builder()
+        .with_originating_simulation_id(originating_simulation_id)
+        .with_receiving_simulation_id(receiving_simulation_id)
+        .with_io_simulation_source(IOSimulationSource::from(io_simulation_source))
+        .with_io_report_type(IOReportType::from(io_report_type))
+        .with_io_attacker_id(io_attacker_id)
+        .with_io_primary_target_id(io_primary_target_id)
+        .with_io_records(io_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,114 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, IntercomAttachedIndicator, LvcIndicator, PduType,
+        TransferredEntityIndicator,
+    };
+    use crate::intercom_control::model::{
+        CommunicationsChannelType, GroupAssignment, GroupDestination, IntercomChannelClass,
+        IntercomCommand, IntercomCommunicationsParameters, IntercomCommunicationsType,
+        IntercomControl, IntercomControlType, IntercomDestinationLineStateCommand,
+        IntercomTransmitLineState, SpecificDestination,
+    };
+    use crate::model::EntityId;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn intercom_control_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::IntercomControl).with_pdu_status(
+            PduStatus::default()
+                .with_transferred_entity_indicator(TransferredEntityIndicator::NoDifference)
+                .with_lvc_indicator(LvcIndicator::Virtual)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled)
+                .with_intercom_attached_indicator(IntercomAttachedIndicator::NoStatement),
+        );
+
+        let body = IntercomControl::builder()
+            .with_control_type(IntercomControlType::RequestAcknowledgeRequired)
+            .with_communications_channel_type(CommunicationsChannelType::new(
+                IntercomCommunicationsType::ConnectionHDX,
+                IntercomChannelClass::VirtualIntercom,
+            ))
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_source_intercom_number(3)
+            .with_source_line_id(4)
+            .with_transmit_priority(5)
+            .with_transmit_line_state(IntercomTransmitLineState::Transmitting)
+            .with_command(IntercomCommand::Connect)
+            .with_master_intercom_reference_id(EntityId::new(1, 1, 2))
+            .with_master_intercom_number(6)
+            .with_master_channel_id(7)
+            .with_intercom_parameter(IntercomCommunicationsParameters::SpecificDestination(
+                SpecificDestination::new()
+                    .with_destination_intercom_reference_id(EntityId::new(1, 1, 3))
+                    .with_destination_intercom_number(8)
+                    .with_destination_line_id(9)
+                    .with_destination_priority(10)
+                    .with_destination_line_state_command(
+                        IntercomDestinationLineStateCommand::SetLineStateTransmitting,
+                    ),
+            ))
+            .with_intercom_parameter(IntercomCommunicationsParameters::GroupDestination(
+                GroupDestination::new()
+                    .with_group_bit_field(0x0000_00F0)
+                    .with_destination_priority(1)
+                    .with_destination_line_state_command(
+                        IntercomDestinationLineStateCommand::ReturnToLocalLineStateControl,
+                    ),
+            ))
+            .with_intercom_parameter(IntercomCommunicationsParameters::GroupAssignment(
+                GroupAssignment::new()
+                    .with_group_bit_field(0x0000_0001)
+                    .with_destination_intercom_reference_id(EntityId::new(1, 1, 4))
+                    .with_destination_intercom_number(11),
+            ))
+            .with_intercom_parameter(IntercomCommunicationsParameters::Unspecified {
+                record_type: 99,
+                record_specific_field: vec![1, 2, 3, 4],
+            })
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn communications_channel_type_bits() {
+        let channel_type = CommunicationsChannelType::new(
+            IntercomCommunicationsType::ConnectionFDX,
+            IntercomChannelClass::VirtualIntercom,
+        );
+        assert_eq!(u8::from(&channel_type), 0x81);
+        assert_eq!(CommunicationsChannelType::from(0x81), channel_type);
+    }
+}
@@ -0,0 +1,517 @@
+use crate::BodyRaw;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::PduType;
+use crate::intercom_control::builder::IntercomControlBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const BASE_INTERCOM_CONTROL_BODY_LENGTH: u16 = 28;
+pub const BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH: u16 = 4;
+pub const SPECIFIC_DESTINATION_RECORD_LENGTH: u16 = 12;
+pub const GROUP_DESTINATION_RECORD_LENGTH: u16 = 8;
+pub const GROUP_ASSIGNMENT_RECORD_LENGTH: u16 = 12;
+
+/// 5.8.7 Intercom Control PDU
+///
+/// 7.7.6 Intercom Control PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntercomControl {
+    pub control_type: IntercomControlType,
+    pub communications_channel_type: CommunicationsChannelType,
+    pub source_entity_id: EntityId,
+    pub source_intercom_number: u16,
+    pub source_line_id: u8,
+    pub transmit_priority: u8,
+    pub transmit_line_state: IntercomTransmitLineState,
+    pub command: IntercomCommand,
+    pub master_intercom_reference_id: EntityId,
+    pub master_intercom_number: u16,
+    pub master_channel_id: u16,
+    pub intercom_parameters: Vec<IntercomCommunicationsParameters>,
+}
+
+impl BodyRaw for IntercomControl {
+    type Builder = IntercomControlBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::IntercomControl(self)
+    }
+}
+
+impl BodyInfo for IntercomControl {
+    fn body_length(&self) -> u16 {
+        BASE_INTERCOM_CONTROL_BODY_LENGTH
+            + self
+                .intercom_parameters
+                .iter()
+                .map(IntercomCommunicationsParameters::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IntercomControl
+    }
+}
+
+impl Interaction for IntercomControl {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.source_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.master_intercom_reference_id)
+    }
+}
+
+/// Communications Channel Type field of the Intercom Control PDU.
+///
+/// Bits 0-6 hold the communications type (SISO-REF-010 UID 181), bit 7 the channel class.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationsChannelType {
+    pub communications_type: IntercomCommunicationsType,
+    pub channel_class: IntercomChannelClass,
+}
+
+impl CommunicationsChannelType {
+    #[must_use]
+    pub fn new(
+        communications_type: IntercomCommunicationsType,
+        channel_class: IntercomChannelClass,
+    ) -> Self {
+        Self {
+            communications_type,
+            channel_class,
+        }
+    }
+}
+
+impl From<u8> for CommunicationsChannelType {
+    fn from(value: u8) -> Self {
+        Self {
+            communications_type: IntercomCommunicationsType::from(value & 0x7F),
+            channel_class: IntercomChannelClass::from(value >> 7),
+        }
+    }
+}
+
+impl From<&CommunicationsChannelType> for u8 {
+    fn from(value: &CommunicationsChannelType) -> Self {
+        let communications_type = u8::from(value.communications_type) & 0x7F;
+        let channel_class = u8::from(value.channel_class) << 7;
+
+        communications_type | channel_class
+    }
+}
+
+/// 6.2.46 Intercom Communications Parameters record
+///
+/// The record-specific field is modelled per Record Type (SISO-REF-010 UID 185).
+/// Records with an unknown type are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommunicationsParameters {
+    SpecificDestination(SpecificDestination),
+    GroupDestination(GroupDestination),
+    GroupAssignment(GroupAssignment),
+    Unspecified {
+        record_type: u16,
+        record_specific_field: Vec<u8>,
+    },
+}
+
+impl Default for IntercomCommunicationsParameters {
+    fn default() -> Self {
+        Self::SpecificDestination(SpecificDestination::default())
+    }
+}
+
+impl IntercomCommunicationsParameters {
+    #[must_use]
+    pub fn record_type(&self) -> u16 {
+        match self {
+            IntercomCommunicationsParameters::SpecificDestination(_) => 1,
+            IntercomCommunicationsParameters::GroupDestination(_) => 2,
+            IntercomCommunicationsParameters::GroupAssignment(_) => 3,
+            IntercomCommunicationsParameters::Unspecified { record_type, .. } => *record_type,
+        }
+    }
+
+    /// Length in octets of the record-specific field
+    #[must_use]
+    pub fn record_specific_length(&self) -> u16 {
+        match self {
+            IntercomCommunicationsParameters::SpecificDestination(_) => {
+                SPECIFIC_DESTINATION_RECORD_LENGTH
+            }
+            IntercomCommunicationsParameters::GroupDestination(_) => {
+                GROUP_DESTINATION_RECORD_LENGTH
+            }
+            IntercomCommunicationsParameters::GroupAssignment(_) => GROUP_ASSIGNMENT_RECORD_LENGTH,
+            IntercomCommunicationsParameters::Unspecified {
+                record_specific_field,
+                ..
+            } => record_specific_field.len() as u16,
+        }
+    }
+
+    /// Length in octets of the complete record, including the type and length fields
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH + self.record_specific_length()
+    }
+}
+
+/// Specific Destination record (Intercom Communications Parameters record type 1)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpecificDestination {
+    pub destination_intercom_reference_id: EntityId,
+    pub destination_intercom_number: u16,
+    pub destination_line_id: u8,
+    pub destination_priority: u8,
+    pub destination_line_state_command: IntercomDestinationLineStateCommand,
+}
+
+impl SpecificDestination {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_destination_intercom_reference_id(mut self, id: EntityId) -> Self {
+        self.destination_intercom_reference_id = id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_intercom_number(mut self, number: u16) -> Self {
+        self.destination_intercom_number = number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_line_id(mut self, line_id: u8) -> Self {
+        self.destination_line_id = line_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_priority(mut self, priority: u8) -> Self {
+        self.destination_priority = priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_line_state_command(
+        mut self,
+        command: IntercomDestinationLineStateCommand,
+    ) -> Self {
+        self.destination_line_state_command = command;
+        self
+    }
+}
+
+/// Group Destination record (Intercom Communications Parameters record type 2)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupDestination {
+    pub group_bit_field: u32,
+    pub destination_priority: u8,
+    pub destination_line_state_command: IntercomDestinationLineStateCommand,
+}
+
+impl GroupDestination {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_group_bit_field(mut self, group_bit_field: u32) -> Self {
+        self.group_bit_field = group_bit_field;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_priority(mut self, priority: u8) -> Self {
+        self.destination_priority = priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_line_state_command(
+        mut self,
+        command: IntercomDestinationLineStateCommand,
+    ) -> Self {
+        self.destination_line_state_command = command;
+        self
+    }
+}
+
+/// Group Assignment record (Intercom Communications Parameters record type 3)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupAssignment {
+    pub group_bit_field: u32,
+    pub destination_intercom_reference_id: EntityId,
+    pub destination_intercom_number: u16,
+}
+
+impl GroupAssignment {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_group_bit_field(mut self, group_bit_field: u32) -> Self {
+        self.group_bit_field = group_bit_field;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_intercom_reference_id(mut self, id: EntityId) -> Self {
+        self.destination_intercom_reference_id = id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_intercom_number(mut self, number: u16) -> Self {
+        self.destination_intercom_number = number;
+        self
+    }
+}
+
+/// Custom defined enum to model the Control Type field (SISO-REF-010 UID 180)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomControlType {
+    #[default]
+    Reserved, // 0
+    Status,                     // 1
+    RequestAcknowledgeRequired, // 2
+    RequestNoAcknowledge,       // 3
+    AckRequestGranted,          // 4
+    NackRequestDenied,          // 5
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomControlType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Reserved,
+            1 => Self::Status,
+            2 => Self::RequestAcknowledgeRequired,
+            3 => Self::RequestNoAcknowledge,
+            4 => Self::AckRequestGranted,
+            5 => Self::NackRequestDenied,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IntercomControlType> for u8 {
+    fn from(value: IntercomControlType) -> Self {
+        match value {
+            IntercomControlType::Reserved => 0,
+            IntercomControlType::Status => 1,
+            IntercomControlType::RequestAcknowledgeRequired => 2,
+            IntercomControlType::RequestNoAcknowledge => 3,
+            IntercomControlType::AckRequestGranted => 4,
+            IntercomControlType::NackRequestDenied => 5,
+            IntercomControlType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the communications type of the Communications Channel Type field (SISO-REF-010 UID 181)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommunicationsType {
+    #[default]
+    Reserved, // 0
+    ConnectionFDX,                          // 1
+    ConnectionHDXDestinationIsReceiveOnly,  // 2
+    ConnectionHDXDestinationIsTransmitOnly, // 3
+    ConnectionHDX,                          // 4
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomCommunicationsType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Reserved,
+            1 => Self::ConnectionFDX,
+            2 => Self::ConnectionHDXDestinationIsReceiveOnly,
+            3 => Self::ConnectionHDXDestinationIsTransmitOnly,
+            4 => Self::ConnectionHDX,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IntercomCommunicationsType> for u8 {
+    fn from(value: IntercomCommunicationsType) -> Self {
+        match value {
+            IntercomCommunicationsType::Reserved => 0,
+            IntercomCommunicationsType::ConnectionFDX => 1,
+            IntercomCommunicationsType::ConnectionHDXDestinationIsReceiveOnly => 2,
+            IntercomCommunicationsType::ConnectionHDXDestinationIsTransmitOnly => 3,
+            IntercomCommunicationsType::ConnectionHDX => 4,
+            IntercomCommunicationsType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the class bit of the Communications Channel Type field
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomChannelClass {
+    #[default]
+    SimulatedAudio, // 0
+    VirtualIntercom, // 1
+}
+
+impl From<u8> for IntercomChannelClass {
+    fn from(value: u8) -> Self {
+        match value & 0x01 {
+            0 => Self::SimulatedAudio,
+            _ => Self::VirtualIntercom,
+        }
+    }
+}
+
+impl From<IntercomChannelClass> for u8 {
+    fn from(value: IntercomChannelClass) -> Self {
+        match value {
+            IntercomChannelClass::SimulatedAudio => 0,
+            IntercomChannelClass::VirtualIntercom => 1,
+        }
+    }
+}
+
+/// Custom defined enum to model the Command field (SISO-REF-010 UID 182)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommand {
+    #[default]
+    NoCommand, // 0
+    Status,     // 1
+    Connect,    // 2
+    Disconnect, // 3
+    Reset,      // 4
+    On,         // 5
+    Off,        // 6
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomCommand {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoCommand,
+            1 => Self::Status,
+            2 => Self::Connect,
+            3 => Self::Disconnect,
+            4 => Self::Reset,
+            5 => Self::On,
+            6 => Self::Off,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IntercomCommand> for u8 {
+    fn from(value: IntercomCommand) -> Self {
+        match value {
+            IntercomCommand::NoCommand => 0,
+            IntercomCommand::Status => 1,
+            IntercomCommand::Connect => 2,
+            IntercomCommand::Disconnect => 3,
+            IntercomCommand::Reset => 4,
+            IntercomCommand::On => 5,
+            IntercomCommand::Off => 6,
+            IntercomCommand::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Transmit Line State field (SISO-REF-010 UID 183)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomTransmitLineState {
+    #[default]
+    TransmitLineStateNotApplicable, // 0
+    NotTransmitting, // 1
+    Transmitting,    // 2
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomTransmitLineState {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::TransmitLineStateNotApplicable,
+            1 => Self::NotTransmitting,
+            2 => Self::Transmitting,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IntercomTransmitLineState> for u8 {
+    fn from(value: IntercomTransmitLineState) -> Self {
+        match value {
+            IntercomTransmitLineState::TransmitLineStateNotApplicable => 0,
+            IntercomTransmitLineState::NotTransmitting => 1,
+            IntercomTransmitLineState::Transmitting => 2,
+            IntercomTransmitLineState::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Destination Line State Command field (SISO-REF-010 UID 184)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomDestinationLineStateCommand {
+    #[default]
+    None, // 0
+    SetLineStateTransmitting,      // 1
+    SetLineStateNotTransmitting,   // 2
+    ReturnToLocalLineStateControl, // 3
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomDestinationLineStateCommand {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::SetLineStateTransmitting,
+            2 => Self::SetLineStateNotTransmitting,
+            3 => Self::ReturnToLocalLineStateControl,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<IntercomDestinationLineStateCommand> for u8 {
+    fn from(value: IntercomDestinationLineStateCommand) -> Self {
+        match value {
+            IntercomDestinationLineStateCommand::None => 0,
+            IntercomDestinationLineStateCommand::SetLineStateTransmitting => 1,
+            IntercomDestinationLineStateCommand::SetLineStateNotTransmitting => 2,
+            IntercomDestinationLineStateCommand::ReturnToLocalLineStateControl => 3,
+            IntercomDestinationLineStateCommand::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::BodyRaw;
+use crate::common::intercom_control::model::{
+    CommunicationsChannelType, GroupAssignment, GroupDestination, IntercomCommand,
+    IntercomCommunicationsParameters, IntercomControl, IntercomControlType,
+    IntercomDestinationLineStateCommand, IntercomTransmitLineState, SpecificDestination,
+};
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_id};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn intercom_control_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, control_type) = context("Control Type", be_u8).parse(input)?;
+    let (input, communications_channel_type) =
+        context("Communications Channel Type", be_u8).parse(input)?;
+    let (input, source_entity_id) = context("Source Entity ID", entity_id).parse(input)?;
+    let (input, source_intercom_number) = context("Source Intercom Number", be_u16).parse(input)?;
+    let (input, source_line_id) = context("Source Line ID", be_u8).parse(input)?;
+    let (input, transmit_priority) = context("Transmit Priority", be_u8).parse(input)?;
+    let (input, transmit_line_state) = context("Transmit Line State", be_u8).parse(input)?;
+    let (input, command) = context("Command", be_u8).parse(input)?;
+    let (input, master_intercom_reference_id) =
+        context("Master Intercom Reference ID", entity_id).parse(input)?;
+    let (input, master_intercom_number) = context("Master Intercom Number", be_u16).parse(input)?;
+    let (input, master_channel_id) = context("Master Channel ID", be_u16).parse(input)?;
+    let (input, intercom_parameters_length) =
+        context("Intercom Parameters Length", be_u32).parse(input)?;
+    let (input, intercom_parameters) = count(
+        context(
+            "Intercom Communications Parameters",
+            intercom_communications_parameters,
+        ),
+        intercom_parameters_length as usize,
+    )
+    .parse(input)?;
+
+    let body = IntercomControl::builder()
+        .with_control_type(IntercomControlType::from(control_type))
+        .with_communications_channel_type(CommunicationsChannelType::from(
+            communications_channel_type,
+        ))
+        .with_source_entity_id(source_entity_id)
+        .with_source_intercom_number(source_intercom_number)
+        .with_source_line_id(source_line_id)
+        .with_transmit_priority(transmit_priority)
+        .with_transmit_line_state(IntercomTransmitLineState::from(transmit_line_state))
+        .with_command(IntercomCommand::from(command))
+        .with_master_intercom_reference_id(master_intercom_reference_id)
+        .with_master_intercom_number(master_intercom_number)
+        .with_master_channel_id(master_channel_id)
+        .with_intercom_parameters(intercom_parameters)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn intercom_communications_parameters(
+    input: &[u8],
+) -> IResult<&[u8], IntercomCommunicationsParameters> {
+    let (input, record_type) = context("Record Type", be_u16).parse(input)?;
+    let (input, record_length) = context("Record Length", be_u16).parse(input)?;
+    let (input, record_specific_field) =
+        context("Record Specific Field", take(record_length)).parse(input)?;
+
+    let record = match record_type {
+        1 => {
+            let (_, record) = specific_destination(record_specific_field)?;
+            IntercomCommunicationsParameters::SpecificDestination(record)
+        }
+        2 => {
+            let (_, record) = group_destination(record_specific_field)?;
+            IntercomCommunicationsParameters::GroupDestination(record)
+        }
+        3 => {
+            let (_, record) = group_assignment(record_specific_field)?;
+            IntercomCommunicationsParameters::GroupAssignment(record)
+        }
+        _ => IntercomCommunicationsParameters::Unspecified {
+            record_type,
+            record_specific_field: record_specific_field.to_vec(),
+        },
+    };
+
+    Ok((input, record))
+}
+
+fn specific_destination(input: &[u8]) -> IResult<&[u8], SpecificDestination> {
+    let (input, destination_intercom_reference_id) =
+        context("Destination Intercom Reference ID", entity_id).parse(input)?;
+    let (input, destination_intercom_number) =
+        context("Destination Intercom Number", be_u16).parse(input)?;
+    let (input, destination_line_id) = context("Destination Line ID", be_u8).parse(input)?;
+    let (input, destination_priority) = context("Destination Priority", be_u8).parse(input)?;
+    let (input, destination_line_state_command) =
+        context("Destination Line State Command", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+
+    Ok((
+        input,
+        SpecificDestination::new()
+            .with_destination_intercom_reference_id(destination_intercom_reference_id)
+            .with_destination_intercom_number(destination_intercom_number)
+            .with_destination_line_id(destination_line_id)
+            .with_destination_priority(destination_priority)
+            .with_destination_line_state_command(IntercomDestinationLineStateCommand::from(
+                destination_line_state_command,
+            )),
+    ))
+}
+
+fn group_destination(input: &[u8]) -> IResult<&[u8], GroupDestination> {
+    let (input, group_bit_field) = context("Group Bit Field", be_u32).parse(input)?;
+    let (input, destination_priority) = context("Destination Priority", be_u8).parse(input)?;
+    let (input, destination_line_state_command) =
+        context("Destination Line State Command", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        GroupDestination::new()
+            .with_group_bit_field(group_bit_field)
+            .with_destination_priority(destination_priority)
+            .with_destination_line_state_command(IntercomDestinationLineStateCommand::from(
+                destination_line_state_command,
+            )),
+    ))
+}
+
+fn group_assignment(input: &[u8]) -> IResult<&[u8], GroupAssignment> {
+    let (input, group_bit_field) = context("Group Bit Field", be_u32).parse(input)?;
+    let (input, destination_intercom_reference_id) =
+        context("Destination Intercom Reference ID", entity_id).parse(input)?;
+    let (input, destination_intercom_number) =
+        context("Destination Intercom Number", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        GroupAssignment::new()
+            .with_group_bit_field(group_bit_field)
+            .with_destination_intercom_reference_id(destination_intercom_reference_id)
+            .with_destination_intercom_number(destination_intercom_number),
+    ))
+}
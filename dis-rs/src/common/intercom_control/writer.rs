@@ -0,0 +1,88 @@
+use crate::common::intercom_control::model::{
+    GroupAssignment, GroupDestination, IntercomCommunicationsParameters, IntercomControl,
+    SpecificDestination,
+};
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for IntercomControl {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.control_type.into());
+        buf.put_u8((&self.communications_channel_type).into());
+        self.source_entity_id.serialize(buf);
+        buf.put_u16(self.source_intercom_number);
+        buf.put_u8(self.source_line_id);
+        buf.put_u8(self.transmit_priority);
+        buf.put_u8(self.transmit_line_state.into());
+        buf.put_u8(self.command.into());
+        self.master_intercom_reference_id.serialize(buf);
+        buf.put_u16(self.master_intercom_number);
+        buf.put_u16(self.master_channel_id);
+        buf.put_u32(self.intercom_parameters.len() as u32);
+        for parameter in &self.intercom_parameters {
+            parameter.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for IntercomCommunicationsParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(self.record_type());
+        buf.put_u16(self.record_specific_length());
+        match self {
+            IntercomCommunicationsParameters::SpecificDestination(record) => {
+                record.serialize(buf);
+            }
+            IntercomCommunicationsParameters::GroupDestination(record) => {
+                record.serialize(buf);
+            }
+            IntercomCommunicationsParameters::GroupAssignment(record) => {
+                record.serialize(buf);
+            }
+            IntercomCommunicationsParameters::Unspecified {
+                record_specific_field,
+                ..
+            } => {
+                buf.put_slice(record_specific_field);
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for SpecificDestination {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let id_bytes = self.destination_intercom_reference_id.serialize(buf);
+        buf.put_u16(self.destination_intercom_number);
+        buf.put_u8(self.destination_line_id);
+        buf.put_u8(self.destination_priority);
+        buf.put_u8(self.destination_line_state_command.into());
+        buf.put_u8(0u8);
+
+        id_bytes + 6
+    }
+}
+
+impl Serialize for GroupDestination {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.group_bit_field);
+        buf.put_u8(self.destination_priority);
+        buf.put_u8(self.destination_line_state_command.into());
+        buf.put_u16(0u16);
+
+        8
+    }
+}
+
+impl Serialize for GroupAssignment {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.group_bit_field);
+        let id_bytes = self.destination_intercom_reference_id.serialize(buf);
+        buf.put_u16(self.destination_intercom_number);
+
+        4 + id_bytes + 2
+    }
+}
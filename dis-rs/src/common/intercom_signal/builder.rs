@@ -0,0 +1,71 @@
+use crate::enumerations::SignalTdlType;
+use crate::intercom_signal::model::IntercomSignal;
+use crate::model::EntityId;
+use crate::signal::model::EncodingScheme;
+
+pub struct IntercomSignalBuilder(IntercomSignal);
+
+impl Default for IntercomSignalBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntercomSignalBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        IntercomSignalBuilder(IntercomSignal::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: IntercomSignal) -> Self {
+        IntercomSignalBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> IntercomSignal {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_intercom_reference_id(mut self, intercom_reference_id: EntityId) -> Self {
+        self.0.intercom_reference_id = intercom_reference_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_intercom_number(mut self, intercom_number: u16) -> Self {
+        self.0.intercom_number = intercom_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_encoding_scheme(mut self, encoding_scheme: EncodingScheme) -> Self {
+        self.0.encoding_scheme = encoding_scheme;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tdl_type(mut self, tdl_type: SignalTdlType) -> Self {
+        self.0.tdl_type = tdl_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.0.sample_rate = sample_rate;
+        self
+    }
+
+    #[must_use]
+    pub fn with_samples(mut self, samples: u16) -> Self {
+        self.0.samples = samples;
+        self
+    }
+
+    #[must_use]
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.0.data = data;
+        self
+    }
+}
@@ -0,0 +1,51 @@
+use crate::BodyRaw;
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::model::PduBody;
+use crate::common::parser::{IResult, context, entity_id};
+use crate::common::signal::parser::parse_encoding_scheme;
+use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
+use crate::enumerations::SignalTdlType;
+use crate::model::length_padded_to_num;
+use nom::Parser;
+use nom::number::complete::{be_u16, be_u32};
+
+#[cfg_attr(
+    all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+    hotpath::measure
+)]
+pub(crate) fn intercom_signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, intercom_reference_id) =
+        context("Intercom Reference ID", entity_id).parse(input)?;
+    let (input, intercom_number) = context("Intercom Number", be_u16).parse(input)?;
+    let (input, encoding_scheme) = context("Encoding Scheme", be_u16).parse(input)?;
+    let (input, tdl_type) = context("TDL Type", be_u16).parse(input)?;
+    let tdl_type = SignalTdlType::from(tdl_type);
+    let (input, sample_rate) = context("Sample Rate", be_u32).parse(input)?;
+
+    let (input, data_length_in_bits) = context("Data Length in Bits", be_u16).parse(input)?;
+    let (input, samples) = context("Samples", be_u16).parse(input)?;
+    let data_length_in_bytes = data_length_in_bits / ONE_BYTE_IN_BITS as u16;
+    let (input, data) =
+        context("Data", nom::bytes::complete::take(data_length_in_bytes)).parse(input)?;
+
+    let padded_record = length_padded_to_num(data_length_in_bytes as usize, FOUR_OCTETS);
+    let (input, _padding) = context(
+        "Padding",
+        nom::bytes::complete::take(padded_record.padding_length),
+    )
+    .parse(input)?;
+
+    let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
+
+    let body = IntercomSignal::builder()
+        .with_intercom_reference_id(intercom_reference_id)
+        .with_intercom_number(intercom_number)
+        .with_encoding_scheme(encoding_scheme)
+        .with_tdl_type(tdl_type)
+        .with_sample_rate(sample_rate)
+        .with_samples(samples)
+        .with_data(data.to_vec())
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,26 @@
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::model::length_padded_to_num;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for IntercomSignal {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        let intercom_ref_id_bytes = self.intercom_reference_id.serialize(buf);
+        buf.put_u16(self.intercom_number);
+        let encoding_scheme_bytes = self.encoding_scheme.serialize(buf);
+        buf.put_u16(self.tdl_type.into());
+        buf.put_u32(self.sample_rate);
+        buf.put_u16((self.data.len() * ONE_BYTE_IN_BITS) as u16);
+        buf.put_u16(self.samples);
+        buf.put(&self.data[..]);
+        let padded_record_lengths = length_padded_to_num(self.data.len(), FOUR_OCTETS);
+        buf.put_bytes(0u8, padded_record_lengths.padding_length);
+
+        intercom_ref_id_bytes
+            + 2
+            + encoding_scheme_bytes
+            + 10
+            + padded_record_lengths.record_length as u16
+    }
+}
@@ -1,5 +1,5 @@
 use crate::BodyRaw;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::entity_state::parser::entity_appearance;
 use crate::enumerations::{EntityKind, IsGroupOfGroupedEntityCategory, PlatformDomain};
 use crate::is_group_of::model::{
@@ -8,19 +8,23 @@
     GroupReferencePoint, IsGroupOf,
 };
 use crate::model::{EntityType, PduBody};
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_f64, be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn is_group_of_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, group_id) = entity_id(input)?;
-    let (input, category) = be_u8(input)?;
+    let (input, group_id) = context("Group ID", entity_id).parse(input)?;
+    let (input, category) = context("Category", be_u8).parse(input)?;
     let category = IsGroupOfGroupedEntityCategory::from(category);
-    let (input, number_of_entities) = be_u8(input)?;
-    let (input, _padding) = be_u32(input)?;
-    let (input, ref_point) = group_reference_point(input)?;
+    let (input, number_of_entities) = context("Number of Entities", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, ref_point) =
+        context("Group Reference Point", group_reference_point).parse(input)?;
     let (input, descriptions) = count(
-        group_entity_description(&category),
+        context(
+            "Group Entity Description",
+            group_entity_description(&category),
+        ),
         number_of_entities.into(),
     )
     .parse(input)?;
@@ -38,8 +42,8 @@ pub(crate) fn is_group_of_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 fn group_reference_point(input: &[u8]) -> IResult<&[u8], GroupReferencePoint> {
-    let (input, lat) = be_f64(input)?;
-    let (input, lon) = be_f64(input)?;
+    let (input, lat) = context("Latitude", be_f64).parse(input)?;
+    let (input, lon) = context("Longitude", be_f64).parse(input)?;
 
     Ok((
         input,
@@ -74,19 +78,24 @@ fn group_entity_description(
 }
 
 fn ged_record_1(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, entity_id) = be_u16(input)?;
-    let (input, location) = ged_entity_location(input)?;
-    let (input, appearance) = entity_appearance(
-        EntityType::default()
-            .with_kind(EntityKind::Platform)
-            .with_domain(PlatformDomain::Land),
-    )(input)?;
-    let (input, orientation) = ged_entity_orientation(input)?;
-    let (input, speed) = be_u8(input)?;
-    let (input, turret_azimuth) = be_u8(input)?;
-    let (input, gun_elevation) = be_u8(input)?;
-    let (input, turret_slew_rate) = be_u8(input)?;
-    let (input, gun_elevation_rate) = be_u8(input)?;
+    let (input, entity_id) = context("Entity ID", be_u16).parse(input)?;
+    let (input, location) = context("GED Entity Location", ged_entity_location).parse(input)?;
+    let (input, appearance) = context(
+        "Appearance",
+        entity_appearance(
+            EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Land),
+        ),
+    )
+    .parse(input)?;
+    let (input, orientation) =
+        context("GED Entity Orientation", ged_entity_orientation).parse(input)?;
+    let (input, speed) = context("Speed", be_u8).parse(input)?;
+    let (input, turret_azimuth) = context("Turret Azimuth", be_u8).parse(input)?;
+    let (input, gun_elevation) = context("Gun Elevation", be_u8).parse(input)?;
+    let (input, turret_slew_rate) = context("Turret Slew Rate", be_u8).parse(input)?;
+    let (input, gun_elevation_rate) = context("Gun Elevation Rate", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -105,17 +114,18 @@ fn ged_record_1(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_2(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, ged_record) = ged_record_1(input)?;
+    let (input, ged_record) = context("GED Record 1", ged_record_1).parse(input)?;
     let ged_record =
         if let GroupEntityDescription::BasicGroundCombatVehicle(ged_record) = ged_record {
             ged_record
         } else {
             GEDRecord1::default()
         };
-    let (input, fuel_status) = be_u8(input)?;
-    let (input, ground_maintenance_status) = be_u8(input)?;
-    let (input, primary_ammunition) = be_u8(input)?;
-    let (input, secondary_ammunition) = be_u8(input)?;
+    let (input, fuel_status) = context("Fuel Status", be_u8).parse(input)?;
+    let (input, ground_maintenance_status) =
+        context("Ground Maintenance Status", be_u8).parse(input)?;
+    let (input, primary_ammunition) = context("Primary Ammunition", be_u8).parse(input)?;
+    let (input, secondary_ammunition) = context("Secondary Ammunition", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -130,19 +140,24 @@ fn ged_record_2(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_3(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, entity_id) = be_u16(input)?;
-    let (input, location) = ged_entity_location(input)?;
-    let (input, appearance) = entity_appearance(
-        EntityType::default()
-            .with_kind(EntityKind::Platform)
-            .with_domain(PlatformDomain::Land),
-    )(input)?;
-    let (input, orientation) = ged_entity_orientation(input)?;
-    let (input, speed) = be_u8(input)?;
-    let (input, head_azimuth) = be_u8(input)?;
-    let (input, head_elevation) = be_u8(input)?;
-    let (input, head_scan_rate) = be_u8(input)?;
-    let (input, head_elevation_rate) = be_u8(input)?;
+    let (input, entity_id) = context("Entity ID", be_u16).parse(input)?;
+    let (input, location) = context("GED Entity Location", ged_entity_location).parse(input)?;
+    let (input, appearance) = context(
+        "Appearance",
+        entity_appearance(
+            EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Land),
+        ),
+    )
+    .parse(input)?;
+    let (input, orientation) =
+        context("GED Entity Orientation", ged_entity_orientation).parse(input)?;
+    let (input, speed) = context("Speed", be_u8).parse(input)?;
+    let (input, head_azimuth) = context("Head Azimuth", be_u8).parse(input)?;
+    let (input, head_elevation) = context("Head Elevation", be_u8).parse(input)?;
+    let (input, head_scan_rate) = context("Head Scan Rate", be_u8).parse(input)?;
+    let (input, head_elevation_rate) = context("Head Elevation Rate", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -161,17 +176,17 @@ fn ged_record_3(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_4(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, ged_record) = ged_record_3(input)?;
+    let (input, ged_record) = context("GED Record 3", ged_record_3).parse(input)?;
     let ged_record =
         if let GroupEntityDescription::BasicGroundCombatSoldier(ged_record) = ged_record {
             ged_record
         } else {
             GEDRecord3::default()
         };
-    let (input, water_status) = be_u8(input)?;
-    let (input, reset_status) = be_u8(input)?;
-    let (input, primary_ammunition) = be_u8(input)?;
-    let (input, secondary_ammunition) = be_u8(input)?;
+    let (input, water_status) = context("Water Status", be_u8).parse(input)?;
+    let (input, reset_status) = context("Reset Status", be_u8).parse(input)?;
+    let (input, primary_ammunition) = context("Primary Ammunition", be_u8).parse(input)?;
+    let (input, secondary_ammunition) = context("Secondary Ammunition", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -186,22 +201,29 @@ fn ged_record_4(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_5(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, entity_id) = be_u16(input)?;
-    let (input, location) = ged_entity_location(input)?;
-    let (input, appearance) = entity_appearance(
-        EntityType::default()
-            .with_kind(EntityKind::Platform)
-            .with_domain(PlatformDomain::Air),
-    )(input)?;
-    let (input, orientation) = ged_entity_orientation(input)?;
-    let (input, fuel_status) = be_u8(input)?;
-    let (input, movement_horizontal_deviation) = be_u8(input)?;
-    let (input, movement_vertical_deviation) = be_u8(input)?;
-    let (input, movement_speed) = be_u16(input)?;
-    let (input, turret_azimuth) = be_u8(input)?;
-    let (input, gun_elevation) = be_u8(input)?;
-    let (input, turret_scan_rate) = be_u8(input)?;
-    let (input, gun_elevation_rate) = be_u8(input)?;
+    let (input, entity_id) = context("Entity ID", be_u16).parse(input)?;
+    let (input, location) = context("GED Entity Location", ged_entity_location).parse(input)?;
+    let (input, appearance) = context(
+        "Appearance",
+        entity_appearance(
+            EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Air),
+        ),
+    )
+    .parse(input)?;
+    let (input, orientation) =
+        context("GED Entity Orientation", ged_entity_orientation).parse(input)?;
+    let (input, fuel_status) = context("Fuel Status", be_u8).parse(input)?;
+    let (input, movement_horizontal_deviation) =
+        context("Movement Horizontal Deviation", be_u8).parse(input)?;
+    let (input, movement_vertical_deviation) =
+        context("Movement Vertical Deviation", be_u8).parse(input)?;
+    let (input, movement_speed) = context("Movement Speed", be_u16).parse(input)?;
+    let (input, turret_azimuth) = context("Turret Azimuth", be_u8).parse(input)?;
+    let (input, gun_elevation) = context("Gun Elevation", be_u8).parse(input)?;
+    let (input, turret_scan_rate) = context("Turret Scan Rate", be_u8).parse(input)?;
+    let (input, gun_elevation_rate) = context("Gun Elevation Rate", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -223,17 +245,18 @@ fn ged_record_5(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_6(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, ged_record) = ged_record_5(input)?;
+    let (input, ged_record) = context("GED Record 5", ged_record_5).parse(input)?;
     let ged_record = if let GroupEntityDescription::BasicRotorWingAircraft(ged_record) = ged_record
     {
         ged_record
     } else {
         GEDRecord5::default()
     };
-    let (input, supplemental_fuel_status) = be_u8(input)?;
-    let (input, air_maintenance_status) = be_u8(input)?;
-    let (input, primary_ammunition) = be_u8(input)?;
-    let (input, secondary_ammunition) = be_u8(input)?;
+    let (input, supplemental_fuel_status) =
+        context("Supplemental Fuel Status", be_u8).parse(input)?;
+    let (input, air_maintenance_status) = context("Air Maintenance Status", be_u8).parse(input)?;
+    let (input, primary_ammunition) = context("Primary Ammunition", be_u8).parse(input)?;
+    let (input, secondary_ammunition) = context("Secondary Ammunition", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -248,18 +271,25 @@ fn ged_record_6(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_7(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, entity_id) = be_u16(input)?;
-    let (input, location) = ged_entity_location(input)?;
-    let (input, appearance) = entity_appearance(
-        EntityType::default()
-            .with_kind(EntityKind::Platform)
-            .with_domain(PlatformDomain::Air),
-    )(input)?;
-    let (input, orientation) = ged_entity_orientation(input)?;
-    let (input, fuel_status) = be_u8(input)?;
-    let (input, movement_horizontal_deviation) = be_u8(input)?;
-    let (input, movement_vertical_deviation) = be_u8(input)?;
-    let (input, movement_speed) = be_u16(input)?;
+    let (input, entity_id) = context("Entity ID", be_u16).parse(input)?;
+    let (input, location) = context("GED Entity Location", ged_entity_location).parse(input)?;
+    let (input, appearance) = context(
+        "Appearance",
+        entity_appearance(
+            EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Air),
+        ),
+    )
+    .parse(input)?;
+    let (input, orientation) =
+        context("GED Entity Orientation", ged_entity_orientation).parse(input)?;
+    let (input, fuel_status) = context("Fuel Status", be_u8).parse(input)?;
+    let (input, movement_horizontal_deviation) =
+        context("Movement Horizontal Deviation", be_u8).parse(input)?;
+    let (input, movement_vertical_deviation) =
+        context("Movement Vertical Deviation", be_u8).parse(input)?;
+    let (input, movement_speed) = context("Movement Speed", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -277,17 +307,18 @@ fn ged_record_7(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_8(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, ged_record) = ged_record_7(input)?;
+    let (input, ged_record) = context("GED Record 7", ged_record_7).parse(input)?;
     let ged_record = if let GroupEntityDescription::BasicFixedWingAircraft(ged_record) = ged_record
     {
         ged_record
     } else {
         GEDRecord7::default()
     };
-    let (input, supplemental_fuel_status) = be_u8(input)?;
-    let (input, air_maintenance_status) = be_u8(input)?;
-    let (input, primary_ammunition) = be_u8(input)?;
-    let (input, secondary_ammunition) = be_u8(input)?;
+    let (input, supplemental_fuel_status) =
+        context("Supplemental Fuel Status", be_u8).parse(input)?;
+    let (input, air_maintenance_status) = context("Air Maintenance Status", be_u8).parse(input)?;
+    let (input, primary_ammunition) = context("Primary Ammunition", be_u8).parse(input)?;
+    let (input, secondary_ammunition) = context("Secondary Ammunition", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -302,15 +333,20 @@ fn ged_record_8(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_record_9(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
-    let (input, entity_id) = be_u16(input)?;
-    let (input, location) = ged_entity_location(input)?;
-    let (input, appearance) = entity_appearance(
-        EntityType::default()
-            .with_kind(EntityKind::Platform)
-            .with_domain(PlatformDomain::Land),
-    )(input)?;
-    let (input, orientation) = ged_entity_orientation(input)?;
-    let (input, speed) = be_u16(input)?;
+    let (input, entity_id) = context("Entity ID", be_u16).parse(input)?;
+    let (input, location) = context("GED Entity Location", ged_entity_location).parse(input)?;
+    let (input, appearance) = context(
+        "Appearance",
+        entity_appearance(
+            EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Land),
+        ),
+    )
+    .parse(input)?;
+    let (input, orientation) =
+        context("GED Entity Orientation", ged_entity_orientation).parse(input)?;
+    let (input, speed) = context("Speed", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -325,9 +361,9 @@ fn ged_record_9(input: &[u8]) -> IResult<&[u8], GroupEntityDescription> {
 }
 
 fn ged_entity_location(input: &[u8]) -> IResult<&[u8], GEDEntityLocation> {
-    let (input, x_offset) = be_u16(input)?;
-    let (input, y_offset) = be_u16(input)?;
-    let (input, z_offset) = be_u16(input)?;
+    let (input, x_offset) = context("X Offset", be_u16).parse(input)?;
+    let (input, y_offset) = context("Y Offset", be_u16).parse(input)?;
+    let (input, z_offset) = context("Z Offset", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -341,9 +377,9 @@ fn ged_entity_location(input: &[u8]) -> IResult<&[u8], GEDEntityLocation> {
 
 #[allow(clippy::similar_names)]
 fn ged_entity_orientation(input: &[u8]) -> IResult<&[u8], GEDEntityOrientation> {
-    let (input, psi) = be_u8(input)?;
-    let (input, theta) = be_u8(input)?;
-    let (input, phi) = be_u8(input)?;
+    let (input, psi) = context("Psi", be_u8).parse(input)?;
+    let (input, theta) = context("Theta", be_u8).parse(input)?;
+    let (input, phi) = context("Phi", be_u8).parse(input)?;
 
     Ok((input, GEDEntityOrientation { psi, theta, phi }))
 }
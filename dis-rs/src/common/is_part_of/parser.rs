@@ -1,18 +1,20 @@
 use crate::BodyRaw;
-use crate::common::parser::{entity_id, entity_type, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, entity_type, vec3_f32};
 use crate::enumerations::{IsPartOfNature, IsPartOfPosition, StationName};
 use crate::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
 use crate::model::PduBody;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u16;
 
 pub(crate) fn is_part_of_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_sim_id) = entity_id(input)?;
-    let (input, receiving_entity_id) = entity_id(input)?;
-    let (input, relationship) = relationship(input)?;
-    let (input, part_location) = vec3_f32(input)?;
-    let (input, named_location_id) = named_location_id(input)?;
-    let (input, part_type) = entity_type(input)?;
+    let (input, originating_sim_id) =
+        context("Originating Simulation ID", entity_id).parse(input)?;
+    let (input, receiving_entity_id) = context("Receiving Entity ID", entity_id).parse(input)?;
+    let (input, relationship) = context("Relationship", relationship).parse(input)?;
+    let (input, part_location) = context("Part Location", vec3_f32).parse(input)?;
+    let (input, named_location_id) =
+        context("Named Location ID", named_location_id).parse(input)?;
+    let (input, part_type) = context("Part Type", entity_type).parse(input)?;
 
     Ok((
         input,
@@ -29,9 +31,9 @@ pub(crate) fn is_part_of_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 fn relationship(input: &[u8]) -> IResult<&[u8], Relationship> {
-    let (input, nature) = be_u16(input)?;
+    let (input, nature) = context("Nature", be_u16).parse(input)?;
     let nature = IsPartOfNature::from(nature);
-    let (input, position) = be_u16(input)?;
+    let (input, position) = context("Position", be_u16).parse(input)?;
     let position = IsPartOfPosition::from(position);
 
     Ok((
@@ -43,9 +45,9 @@ fn relationship(input: &[u8]) -> IResult<&[u8], Relationship> {
 }
 
 fn named_location_id(input: &[u8]) -> IResult<&[u8], NamedLocationId> {
-    let (input, name) = be_u16(input)?;
+    let (input, name) = context("Name", be_u16).parse(input)?;
     let name = StationName::from(name);
-    let (input, number) = be_u16(input)?;
+    let (input, number) = context("Number", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -0,0 +1,98 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{
+        EntityType, LiveEntityId, LiveOrientation, MunitionDescriptor, Pdu, PduHeader,
+        RelativeWorldCoordinates, TimeUnits, Timestamp, VectorI16,
+    };
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, DetonationResult, EntityKind, LvcIndicator, PduType,
+    };
+    use crate::le_detonation::model::LeDetonation;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn le_detonation_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LEDetonation).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = LeDetonation::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 1, 10))
+            .with_target_live_entity_id(LiveEntityId::new(1, 1, 20))
+            .with_munition_live_entity_id(LiveEntityId::new(1, 1, 30))
+            .with_world_location(RelativeWorldCoordinates::new(2, 2510, 20, 1))
+            .with_velocity(VectorI16::new(7500, 0, -300))
+            .with_munition_orientation(LiveOrientation::new(10, 250, 0))
+            .with_munition_descriptor(
+                MunitionDescriptor::default()
+                    .with_entity_type(EntityType::default().with_kind(EntityKind::Munition))
+                    .with_quantity(1),
+            )
+            .with_entity_location(VectorI16::new(-50, 10, 120))
+            .with_detonation_result(DetonationResult::EntityImpact)
+            .build();
+        assert_eq!(body.detonation_flag_1(), 0b0011_1011);
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        assert_eq!(pdu_length, 12 + 20 + 8 + 3 + 16 + 6);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+        assert_eq!(serialized_length, pdu_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn le_detonation_skips_flag_2() {
+        let header = PduHeader::new_v7(1, PduType::LEDetonation).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+        let body = LeDetonation::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 1, 10))
+            .with_detonation_result(DetonationResult::GroundImpact)
+            .build();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        let _ = original_pdu.serialize(&mut buf).unwrap();
+
+        // Insert a Detonation Flag 2 octet after Flag 1 and fix up the PDU length
+        let mut bytes = buf.to_vec();
+        bytes[16] |= 0x80;
+        bytes.insert(17, 0);
+        let length = u16::from_be_bytes([bytes[8], bytes[9]]) + 1;
+        bytes[8..10].copy_from_slice(&length.to_be_bytes());
+
+        let parsed = parse_pdu(&bytes).unwrap();
+        assert_eq!(parsed.body, original_pdu.body);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, LiveEntityId, LiveEventId, LiveOrientation, MunitionDescriptor, PduBody,
+    RelativeWorldCoordinates, VectorI16,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::{DetonationResult, PduType};
+use crate::le_detonation::builder::LeDetonationBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LE_DETONATION_BODY_LENGTH: u16 = 20;
+const MUNITION_DESCRIPTOR_LENGTH: u16 = 16;
+
+pub(crate) const LE_DETONATION_FLAG_1_TARGET_ID: u8 = 0x01;
+pub(crate) const LE_DETONATION_FLAG_1_MUNITION_ID: u8 = 0x02;
+pub(crate) const LE_DETONATION_FLAG_1_EVENT_ID: u8 = 0x04;
+pub(crate) const LE_DETONATION_FLAG_1_MUNITION_ORIENTATION: u8 = 0x08;
+pub(crate) const LE_DETONATION_FLAG_1_MUNITION_DESCRIPTOR: u8 = 0x10;
+pub(crate) const LE_DETONATION_FLAG_1_ENTITY_LOCATION: u8 = 0x20;
+pub(crate) const LE_DETONATION_FLAG_1_FLAG_2_INCLUDED: u8 = 0x80;
+
+/// 9.4.6 LE Detonation PDU
+///
+/// The optional fields are present on the wire when they are `Some`; the Detonation Flag fields are derived from them.
+/// Detonation Flag 2 defines no fields of its own; when received it is skipped and it is never written.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LeDetonation {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: Option<LiveEntityId>,
+    pub event_id: Option<LiveEventId>,
+    pub world_location: RelativeWorldCoordinates,
+    /// Velocity in decimeters per second
+    pub velocity: VectorI16,
+    pub munition_orientation: Option<LiveOrientation>,
+    pub munition_descriptor: Option<MunitionDescriptor>,
+    /// Location of the detonation relative to the target entity, in centimeters
+    pub entity_location: Option<VectorI16>,
+    pub detonation_result: DetonationResult,
+}
+
+impl LeDetonation {
+    /// Computes the Detonation Flag 1 field from the optional fields that are present.
+    #[must_use]
+    pub fn detonation_flag_1(&self) -> u8 {
+        let mut flag = 0u8;
+        if self.target_live_entity_id.is_some() {
+            flag |= LE_DETONATION_FLAG_1_TARGET_ID;
+        }
+        if self.munition_live_entity_id.is_some() {
+            flag |= LE_DETONATION_FLAG_1_MUNITION_ID;
+        }
+        if self.event_id.is_some() {
+            flag |= LE_DETONATION_FLAG_1_EVENT_ID;
+        }
+        if self.munition_orientation.is_some() {
+            flag |= LE_DETONATION_FLAG_1_MUNITION_ORIENTATION;
+        }
+        if self.munition_descriptor.is_some() {
+            flag |= LE_DETONATION_FLAG_1_MUNITION_DESCRIPTOR;
+        }
+        if self.entity_location.is_some() {
+            flag |= LE_DETONATION_FLAG_1_ENTITY_LOCATION;
+        }
+        flag
+    }
+}
+
+impl BodyRaw for LeDetonation {
+    type Builder = LeDetonationBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::LEDetonation(self)
+    }
+}
+
+impl BodyInfo for LeDetonation {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        let optional_ids = [
+            self.target_live_entity_id.is_some(),
+            self.munition_live_entity_id.is_some(),
+            self.event_id.is_some(),
+        ]
+        .iter()
+        .filter(|&&present| present)
+        .count();
+
+        BASE_LE_DETONATION_BODY_LENGTH
+            + (optional_ids * FOUR_OCTETS) as u16
+            + self
+                .munition_orientation
+                .map_or(0, |orientation| orientation.record_length())
+            + self
+                .munition_descriptor
+                .as_ref()
+                .map_or(0, |_| MUNITION_DESCRIPTOR_LENGTH)
+            + self
+                .entity_location
+                .map_or(0, |location| location.record_length())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEDetonation
+    }
+}
+
+/// Live Entity PDUs identify entities by a `LiveEntityId`, which cannot be expressed as an `EntityId`.
+impl Interaction for LeDetonation {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
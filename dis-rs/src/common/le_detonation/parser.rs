@@ -0,0 +1,76 @@
+use crate::BodyRaw;
+use crate::common::model::PduBody;
+use crate::common::parser::{
+    IResult, context, live_entity_id, live_event_id, live_orientation, munition_descriptor,
+    relative_world_coordinates, vec3_i16,
+};
+use crate::enumerations::DetonationResult;
+use crate::le_detonation::model::{
+    LE_DETONATION_FLAG_1_ENTITY_LOCATION, LE_DETONATION_FLAG_1_EVENT_ID,
+    LE_DETONATION_FLAG_1_FLAG_2_INCLUDED, LE_DETONATION_FLAG_1_MUNITION_DESCRIPTOR,
+    LE_DETONATION_FLAG_1_MUNITION_ID, LE_DETONATION_FLAG_1_MUNITION_ORIENTATION,
+    LE_DETONATION_FLAG_1_TARGET_ID, LeDetonation,
+};
+use nom::Parser;
+use nom::combinator::cond;
+use nom::number::complete::be_u8;
+
+pub(crate) fn le_detonation_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_live_entity_id) =
+        context("Firing Live Entity ID", live_entity_id).parse(input)?;
+    let (input, flag_1) = context("Flag 1", be_u8).parse(input)?;
+    let (input, _flag_2) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_FLAG_2_INCLUDED != 0,
+        context("Flag 2", be_u8),
+    )
+    .parse(input)?;
+    let (input, target_live_entity_id) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_TARGET_ID != 0,
+        context("Target Live Entity ID", live_entity_id),
+    )
+    .parse(input)?;
+    let (input, munition_live_entity_id) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_MUNITION_ID != 0,
+        context("Munition Live Entity ID", live_entity_id),
+    )
+    .parse(input)?;
+    let (input, event_id) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_EVENT_ID != 0,
+        context("Event ID", live_event_id),
+    )
+    .parse(input)?;
+    let (input, world_location) =
+        context("World Location", relative_world_coordinates).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_i16).parse(input)?;
+    let (input, munition_orientation) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_MUNITION_ORIENTATION != 0,
+        context("Munition Orientation", live_orientation),
+    )
+    .parse(input)?;
+    let (input, munition_descriptor) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_MUNITION_DESCRIPTOR != 0,
+        context("Munition Descriptor", munition_descriptor),
+    )
+    .parse(input)?;
+    let (input, entity_location) = cond(
+        flag_1 & LE_DETONATION_FLAG_1_ENTITY_LOCATION != 0,
+        context("Entity Location", vec3_i16),
+    )
+    .parse(input)?;
+    let (input, detonation_result) = context("Detonation Result", be_u8).parse(input)?;
+
+    let body = LeDetonation {
+        firing_live_entity_id,
+        target_live_entity_id,
+        munition_live_entity_id,
+        event_id,
+        world_location,
+        velocity,
+        munition_orientation,
+        munition_descriptor,
+        entity_location,
+        detonation_result: DetonationResult::from(detonation_result),
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,33 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::le_detonation::model::LeDetonation;
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for LeDetonation {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.detonation_flag_1());
+        if let Some(target) = &self.target_live_entity_id {
+            target.serialize(buf);
+        }
+        if let Some(munition) = &self.munition_live_entity_id {
+            munition.serialize(buf);
+        }
+        if let Some(event_id) = &self.event_id {
+            event_id.serialize(buf);
+        }
+        self.world_location.serialize(buf);
+        self.velocity.serialize(buf);
+        if let Some(orientation) = &self.munition_orientation {
+            orientation.serialize(buf);
+        }
+        if let Some(descriptor) = &self.munition_descriptor {
+            descriptor.serialize(buf);
+        }
+        if let Some(location) = &self.entity_location {
+            location.serialize(buf);
+        }
+        buf.put_u8(self.detonation_result.into());
+
+        self.body_length()
+    }
+}
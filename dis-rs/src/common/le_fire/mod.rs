@@ -0,0 +1,65 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{
+        EntityType, LiveEntityId, LiveEventId, MunitionDescriptor, Pdu, PduHeader,
+        RelativeWorldCoordinates, TimeUnits, Timestamp, VectorI16,
+    };
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, EntityKind, LvcIndicator, PduType};
+    use crate::le_fire::model::LeFire;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn le_fire_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LEFire).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = LeFire::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 1, 10))
+            .with_target_live_entity_id(LiveEntityId::new(1, 1, 20))
+            .with_event_id(LiveEventId::new(1, 1, 300))
+            .with_location(RelativeWorldCoordinates::new(2, 10, 20, 1))
+            .with_munition_descriptor(
+                MunitionDescriptor::default()
+                    .with_entity_type(EntityType::default().with_kind(EntityKind::Munition))
+                    .with_quantity(1)
+                    .with_rate(0),
+            )
+            .with_velocity(VectorI16::new(8000, 0, 100))
+            .with_range(2500)
+            .build();
+        assert_eq!(body.fire_flags(), 0b0000_0101);
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        assert_eq!(pdu_length, 12 + 38 + 8);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+        assert_eq!(serialized_length, pdu_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
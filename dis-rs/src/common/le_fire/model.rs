@@ -0,0 +1,100 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, LiveEntityId, LiveEventId, MunitionDescriptor, PduBody, RelativeWorldCoordinates,
+    VectorI16,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::PduType;
+use crate::le_fire::builder::LeFireBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LE_FIRE_BODY_LENGTH: u16 = 38;
+
+pub(crate) const LE_FIRE_FLAG_TARGET_ID: u8 = 0x01;
+pub(crate) const LE_FIRE_FLAG_MUNITION_ID: u8 = 0x02;
+pub(crate) const LE_FIRE_FLAG_EVENT_ID: u8 = 0x04;
+
+/// 9.4.5 LE Fire PDU
+///
+/// The optional identifiers are present on the wire when they are `Some`; the Fire Flags field is derived from them.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LeFire {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: Option<LiveEntityId>,
+    pub event_id: Option<LiveEventId>,
+    pub location: RelativeWorldCoordinates,
+    pub munition_descriptor: MunitionDescriptor,
+    /// Velocity in decimeters per second
+    pub velocity: VectorI16,
+    /// Range in meters
+    pub range: u16,
+}
+
+impl LeFire {
+    /// Computes the Fire Flags field from the optional fields that are present.
+    #[must_use]
+    pub fn fire_flags(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.target_live_entity_id.is_some() {
+            flags |= LE_FIRE_FLAG_TARGET_ID;
+        }
+        if self.munition_live_entity_id.is_some() {
+            flags |= LE_FIRE_FLAG_MUNITION_ID;
+        }
+        if self.event_id.is_some() {
+            flags |= LE_FIRE_FLAG_EVENT_ID;
+        }
+        flags
+    }
+}
+
+impl BodyRaw for LeFire {
+    type Builder = LeFireBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::LEFire(self)
+    }
+}
+
+impl BodyInfo for LeFire {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        let optional_ids = [
+            self.target_live_entity_id.is_some(),
+            self.munition_live_entity_id.is_some(),
+            self.event_id.is_some(),
+        ]
+        .iter()
+        .filter(|&&present| present)
+        .count();
+
+        BASE_LE_FIRE_BODY_LENGTH + (optional_ids * FOUR_OCTETS) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEFire
+    }
+}
+
+/// Live Entity PDUs identify entities by a `LiveEntityId`, which cannot be expressed as an `EntityId`.
+impl Interaction for LeFire {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
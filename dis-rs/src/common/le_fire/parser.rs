@@ -0,0 +1,52 @@
+use crate::BodyRaw;
+use crate::common::model::PduBody;
+use crate::common::parser::{
+    IResult, context, live_entity_id, live_event_id, munition_descriptor,
+    relative_world_coordinates, vec3_i16,
+};
+use crate::le_fire::model::{
+    LE_FIRE_FLAG_EVENT_ID, LE_FIRE_FLAG_MUNITION_ID, LE_FIRE_FLAG_TARGET_ID, LeFire,
+};
+use nom::Parser;
+use nom::combinator::cond;
+use nom::number::complete::{be_u8, be_u16};
+
+pub(crate) fn le_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_live_entity_id) =
+        context("Firing Live Entity ID", live_entity_id).parse(input)?;
+    let (input, flags) = context("Flags", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, target_live_entity_id) = cond(
+        flags & LE_FIRE_FLAG_TARGET_ID != 0,
+        context("Target Live Entity ID", live_entity_id),
+    )
+    .parse(input)?;
+    let (input, munition_live_entity_id) = cond(
+        flags & LE_FIRE_FLAG_MUNITION_ID != 0,
+        context("Munition Live Entity ID", live_entity_id),
+    )
+    .parse(input)?;
+    let (input, event_id) = cond(
+        flags & LE_FIRE_FLAG_EVENT_ID != 0,
+        context("Event ID", live_event_id),
+    )
+    .parse(input)?;
+    let (input, location) = context("Location", relative_world_coordinates).parse(input)?;
+    let (input, munition_descriptor) =
+        context("Munition Descriptor", munition_descriptor).parse(input)?;
+    let (input, velocity) = context("Velocity", vec3_i16).parse(input)?;
+    let (input, range) = context("Range", be_u16).parse(input)?;
+
+    let body = LeFire {
+        firing_live_entity_id,
+        target_live_entity_id,
+        munition_live_entity_id,
+        event_id,
+        location,
+        munition_descriptor,
+        velocity,
+        range,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
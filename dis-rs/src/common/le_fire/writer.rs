@@ -0,0 +1,26 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::le_fire::model::LeFire;
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for LeFire {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.fire_flags());
+        buf.put_u8(0u8);
+        if let Some(target) = &self.target_live_entity_id {
+            target.serialize(buf);
+        }
+        if let Some(munition) = &self.munition_live_entity_id {
+            munition.serialize(buf);
+        }
+        if let Some(event_id) = &self.event_id {
+            event_id.serialize(buf);
+        }
+        self.location.serialize(buf);
+        self.munition_descriptor.serialize(buf);
+        self.velocity.serialize(buf);
+        buf.put_u16(self.range);
+
+        self.body_length()
+    }
+}
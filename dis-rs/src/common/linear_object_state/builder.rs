@@ -0,0 +1,90 @@
+use crate::common::model::{EntityId, ObjectType, SimulationAddress};
+use crate::enumerations::ForceId;
+use crate::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+
+pub struct LinearObjectStateBuilder(LinearObjectState);
+
+impl Default for LinearObjectStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinearObjectStateBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        LinearObjectStateBuilder(LinearObjectState::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: LinearObjectState) -> Self {
+        LinearObjectStateBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> LinearObjectState {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_object_id(mut self, object_id: EntityId) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_referenced_object_id(mut self, referenced_object_id: EntityId) -> Self {
+        self.0.referenced_object_id = referenced_object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_update_number(mut self, update_number: u16) -> Self {
+        self.0.update_number = update_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requester_id(mut self, requester_id: SimulationAddress) -> Self {
+        self.0.requester_id = requester_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_receiving_id(mut self, receiving_id: SimulationAddress) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_type(mut self, object_type: ObjectType) -> Self {
+        self.0.object_type = object_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_linear_segment_parameter(
+        mut self,
+        linear_segment_parameter: LinearSegmentParameter,
+    ) -> Self {
+        self.0
+            .linear_segment_parameters
+            .push(linear_segment_parameter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_linear_segment_parameters(
+        mut self,
+        linear_segment_parameters: Vec<LinearSegmentParameter>,
+    ) -> Self {
+        self.0.linear_segment_parameters = linear_segment_parameters;
+        self
+    }
+}
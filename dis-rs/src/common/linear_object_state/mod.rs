@@ -0,0 +1,110 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        AppearanceLinearObjectTankDitchBreach, AppearanceObjectSpecificChemicalType,
+        CoupledExtensionIndicator, ForceId, LvcIndicator, PduType, PlatformDomain,
+    };
+    use crate::linear_object_state::model::{
+        ExhaustSmokeAppearance, LinearObjectState, LinearSegmentParameter, TankDitchAppearance,
+    };
+    use crate::model::{
+        EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification,
+        ObjectType, Orientation, SimulationAddress,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn linear_object_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LinearObjectState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let tank_ditch = TankDitchAppearance {
+            breach: AppearanceLinearObjectTankDitchBreach::SlightBreaching,
+        };
+        let body = LinearObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 200))
+            .with_update_number(1)
+            .with_force_id(ForceId::Friendly)
+            .with_requester_id(SimulationAddress::new(2, 2))
+            .with_receiving_id(SimulationAddress::new(3, 3))
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::Obstacle,
+                3,
+                0,
+            ))
+            .with_linear_segment_parameter(
+                LinearSegmentParameter::default()
+                    .with_segment_number(1)
+                    .with_segment_modification(ObjectStateModification::new(true, true))
+                    .with_general_segment_appearance(
+                        GeneralObjectAppearance::default().with_percent_complete(75),
+                    )
+                    .with_specific_segment_appearance((&tank_ditch).into())
+                    .with_segment_location(Location::new(10.0, 20.0, 30.0))
+                    .with_segment_orientation(Orientation::new(1.0, 0.0, 0.0))
+                    .with_segment_length(100.0)
+                    .with_segment_width(4.0)
+                    .with_segment_height(0.0)
+                    .with_segment_depth(3.0),
+            )
+            .with_linear_segment_parameter(
+                LinearSegmentParameter::default()
+                    .with_segment_number(2)
+                    .with_segment_location(Location::new(110.0, 20.0, 30.0))
+                    .with_segment_length(50.0),
+            )
+            .build()
+            .into_pdu_body();
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+        assert_eq!(pdu_length, 40 + 2 * 64);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn exhaust_smoke_appearance_bits() {
+        let smoke = ExhaustSmokeAppearance {
+            opacity: 60,
+            is_attached: true,
+            chemical_type: AppearanceObjectSpecificChemicalType::RedPhosphorous,
+        };
+        let value = u32::from(&smoke);
+
+        assert_eq!(value, 0b0000_0111_0011_1100);
+        assert_eq!(ExhaustSmokeAppearance::from(value), smoke);
+    }
+}
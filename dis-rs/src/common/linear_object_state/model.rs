@@ -0,0 +1,237 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation,
+    PduBody, SimulationAddress,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::SIXTY_FOUR_OCTETS;
+use crate::enumerations::{
+    AppearanceLinearObjectLaneMarkerVisible, AppearanceLinearObjectTankDitchBreach,
+    AppearanceObjectSpecificChemicalType, ForceId, PduType,
+};
+use crate::linear_object_state::builder::LinearObjectStateBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LINEAR_OBJECT_STATE_BODY_LENGTH: u16 = 28;
+
+/// 5.11.3.3 Linear Object State PDU
+///
+/// 7.10.5 Linear Object State PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_type: ObjectType,
+    pub linear_segment_parameters: Vec<LinearSegmentParameter>,
+}
+
+impl BodyRaw for LinearObjectState {
+    type Builder = LinearObjectStateBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::LinearObjectState(self)
+    }
+}
+
+impl BodyInfo for LinearObjectState {
+    fn body_length(&self) -> u16 {
+        BASE_LINEAR_OBJECT_STATE_BODY_LENGTH
+            + self
+                .linear_segment_parameters
+                .iter()
+                .map(LinearSegmentParameter::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LinearObjectState
+    }
+}
+
+impl Interaction for LinearObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.52 Linear Segment Parameter record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearSegmentParameter {
+    pub segment_number: u8,
+    pub segment_modification: ObjectStateModification,
+    pub general_segment_appearance: GeneralObjectAppearance,
+    /// The object specific part of the segment appearance, which is interpreted according to the `object_type` of the PDU.
+    /// See `TankDitchAppearance`, `LaneMarkerAppearance` and `ExhaustSmokeAppearance`.
+    pub specific_segment_appearance: u32,
+    pub segment_location: Location,
+    pub segment_orientation: Orientation,
+    pub segment_length: f32,
+    pub segment_width: f32,
+    pub segment_height: f32,
+    pub segment_depth: f32,
+}
+
+impl LinearSegmentParameter {
+    #[must_use]
+    pub fn with_segment_number(mut self, segment_number: u8) -> Self {
+        self.segment_number = segment_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_modification(
+        mut self,
+        segment_modification: ObjectStateModification,
+    ) -> Self {
+        self.segment_modification = segment_modification;
+        self
+    }
+
+    #[must_use]
+    pub fn with_general_segment_appearance(
+        mut self,
+        general_segment_appearance: GeneralObjectAppearance,
+    ) -> Self {
+        self.general_segment_appearance = general_segment_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_specific_segment_appearance(mut self, specific_segment_appearance: u32) -> Self {
+        self.specific_segment_appearance = specific_segment_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_location(mut self, segment_location: Location) -> Self {
+        self.segment_location = segment_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_orientation(mut self, segment_orientation: Orientation) -> Self {
+        self.segment_orientation = segment_orientation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_length(mut self, segment_length: f32) -> Self {
+        self.segment_length = segment_length;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_width(mut self, segment_width: f32) -> Self {
+        self.segment_width = segment_width;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_height(mut self, segment_height: f32) -> Self {
+        self.segment_height = segment_height;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_depth(mut self, segment_depth: f32) -> Self {
+        self.segment_depth = segment_depth;
+        self
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        SIXTY_FOUR_OCTETS as u16
+    }
+}
+
+/// Specific Object Appearance of a Tank Ditch linear object
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TankDitchAppearance {
+    pub breach: AppearanceLinearObjectTankDitchBreach,
+}
+
+impl From<u32> for TankDitchAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            breach: AppearanceLinearObjectTankDitchBreach::from((value & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&TankDitchAppearance> for u32 {
+    fn from(value: &TankDitchAppearance) -> Self {
+        u32::from(u8::from(value.breach))
+    }
+}
+
+/// Specific Object Appearance of a Minefield Lane Marker linear object
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LaneMarkerAppearance {
+    pub visible_side: AppearanceLinearObjectLaneMarkerVisible,
+}
+
+impl From<u32> for LaneMarkerAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            visible_side: AppearanceLinearObjectLaneMarkerVisible::from((value & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&LaneMarkerAppearance> for u32 {
+    fn from(value: &LaneMarkerAppearance) -> Self {
+        u32::from(u8::from(value.visible_side))
+    }
+}
+
+/// Specific Object Appearance of an Exhaust Smoke linear object
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExhaustSmokeAppearance {
+    /// Opacity in percent
+    pub opacity: u8,
+    pub is_attached: bool,
+    pub chemical_type: AppearanceObjectSpecificChemicalType,
+}
+
+impl From<u32> for ExhaustSmokeAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            opacity: (value & 0xFF) as u8,
+            is_attached: ((value >> 8) & 0x01) != 0,
+            chemical_type: AppearanceObjectSpecificChemicalType::from(((value >> 9) & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&ExhaustSmokeAppearance> for u32 {
+    fn from(value: &ExhaustSmokeAppearance) -> Self {
+        let opacity = u32::from(value.opacity);
+        let attached = u32::from(value.is_attached) << 8;
+        let chemical_type = u32::from(u8::from(value.chemical_type)) << 9;
+
+        opacity | attached | chemical_type
+    }
+}
@@ -0,0 +1,75 @@
+use crate::BodyRaw;
+use crate::common::model::{GeneralObjectAppearance, ObjectStateModification, PduBody};
+use crate::common::parser::{
+    IResult, context, entity_id, location, object_type, orientation, simulation_address,
+};
+use crate::enumerations::ForceId;
+use crate::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
+
+pub(crate) fn linear_object_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, object_id) = context("Object ID", entity_id).parse(input)?;
+    let (input, referenced_object_id) = context("Referenced Object ID", entity_id).parse(input)?;
+    let (input, update_number) = context("Update Number", be_u16).parse(input)?;
+    let (input, force_id) = context("Force ID", be_u8).parse(input)?;
+    let force_id = ForceId::from(force_id);
+    let (input, number_of_segments) = context("Number of Segments", be_u8).parse(input)?;
+    let (input, requester_id) = context("Requester ID", simulation_address).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", simulation_address).parse(input)?;
+    let (input, object_type) = context("Object Type", object_type).parse(input)?;
+    let (input, linear_segment_parameters) = count(
+        context("Linear Segment Parameter", linear_segment_parameter),
+        number_of_segments.into(),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        LinearObjectState::builder()
+            .with_object_id(object_id)
+            .with_referenced_object_id(referenced_object_id)
+            .with_update_number(update_number)
+            .with_force_id(force_id)
+            .with_requester_id(requester_id)
+            .with_receiving_id(receiving_id)
+            .with_object_type(object_type)
+            .with_linear_segment_parameters(linear_segment_parameters)
+            .build()
+            .into_pdu_body(),
+    ))
+}
+
+pub(crate) fn linear_segment_parameter(input: &[u8]) -> IResult<&[u8], LinearSegmentParameter> {
+    let (input, segment_number) = context("Segment Number", be_u8).parse(input)?;
+    let (input, segment_modification) = context("Segment Modification", be_u8).parse(input)?;
+    let (input, general_segment_appearance) =
+        context("General Segment Appearance", be_u16).parse(input)?;
+    let (input, specific_segment_appearance) =
+        context("Specific Segment Appearance", be_u32).parse(input)?;
+    let (input, segment_location) = context("Segment Location", location).parse(input)?;
+    let (input, segment_orientation) = context("Segment Orientation", orientation).parse(input)?;
+    let (input, segment_length) = context("Segment Length", be_f32).parse(input)?;
+    let (input, segment_width) = context("Segment Width", be_f32).parse(input)?;
+    let (input, segment_height) = context("Segment Height", be_f32).parse(input)?;
+    let (input, segment_depth) = context("Segment Depth", be_f32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+
+    Ok((
+        input,
+        LinearSegmentParameter::default()
+            .with_segment_number(segment_number)
+            .with_segment_modification(ObjectStateModification::from(segment_modification))
+            .with_general_segment_appearance(GeneralObjectAppearance::from(
+                general_segment_appearance,
+            ))
+            .with_specific_segment_appearance(specific_segment_appearance)
+            .with_segment_location(segment_location)
+            .with_segment_orientation(segment_orientation)
+            .with_segment_length(segment_length)
+            .with_segment_width(segment_width)
+            .with_segment_height(segment_height)
+            .with_segment_depth(segment_depth),
+    ))
+}
@@ -0,0 +1,41 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for LinearObjectState {
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8(self.linear_segment_parameters.len() as u8);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        self.object_type.serialize(buf);
+        self.linear_segment_parameters
+            .iter()
+            .map(|segment| segment.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
+
+impl Serialize for LinearSegmentParameter {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.segment_number);
+        buf.put_u8((&self.segment_modification).into());
+        buf.put_u16((&self.general_segment_appearance).into());
+        buf.put_u32(self.specific_segment_appearance);
+        self.segment_location.serialize(buf);
+        self.segment_orientation.serialize(buf);
+        buf.put_f32(self.segment_length);
+        buf.put_f32(self.segment_width);
+        buf.put_f32(self.segment_height);
+        buf.put_f32(self.segment_depth);
+        buf.put_u32(0u32);
+
+        self.record_length()
+    }
+}
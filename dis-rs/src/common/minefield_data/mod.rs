@@ -0,0 +1,215 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, EntityKind, LvcIndicator, PduType};
+    use crate::minefield_data::model::{
+        DataFilter, Mine, MineAlgae, MineFusing, MinePaintScheme, MinefieldData, TripWire,
+    };
+    use crate::model::{
+        ClockTime, EntityId, EntityType, MinefieldId, Orientation, PerimeterPointCoordinate,
+        VectorF32,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn data_filter_bits() {
+        let filter = DataFilter {
+            ground_burial_depth_offset: true,
+            fusing: true,
+            number_of_vertices: true,
+            ..Default::default()
+        };
+        let bits = u32::from(&filter);
+
+        assert_eq!(bits, 0x0000_1101);
+        assert_eq!(DataFilter::from(bits), filter);
+        assert_eq!(u32::from(&DataFilter::all()), 0x0000_1FFF);
+    }
+
+    #[test]
+    fn mine_fusing_and_paint_scheme_bits() {
+        let fusing = MineFusing::new(3, 5, true);
+        assert_eq!(MineFusing::from(u16::from(&fusing)), fusing);
+
+        let paint_scheme = MinePaintScheme::new(MineAlgae::Moderate, 7);
+        assert_eq!(u8::from(&paint_scheme), 0b0001_1110);
+        assert_eq!(MinePaintScheme::from(u8::from(&paint_scheme)), paint_scheme);
+    }
+
+    #[test]
+    fn minefield_data_internal_consistency_location_only() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldData).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldData::builder()
+            .with_minefield_id(MinefieldId::new(1, 1, 10))
+            .with_requesting_entity_id(EntityId::new(2, 2, 20))
+            .with_mine_type(EntityType::default().with_kind(EntityKind::Munition))
+            .with_mine(Mine::new(VectorF32::new(1.0, 2.0, 0.0)))
+            .with_mine(Mine::new(VectorF32::new(3.0, 4.0, 0.0)))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn minefield_data_internal_consistency_all_fields() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldData).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let mine = |x: f32, wires: Vec<TripWire>| {
+            Mine::new(VectorF32::new(x, 2.0, 0.0))
+                .with_ground_burial_depth_offset(0.1)
+                .with_water_burial_depth_offset(0.2)
+                .with_snow_burial_depth_offset(0.3)
+                .with_orientation(Orientation::new(0.5, 0.0, 0.0))
+                .with_thermal_contrast(1.5)
+                .with_reflectance(0.7)
+                .with_emplacement_time(ClockTime::new(1, 100))
+                .with_entity_number(12)
+                .with_fusing(MineFusing::new(1, 2, true))
+                .with_scalar_detection_coefficient(9)
+                .with_paint_scheme(MinePaintScheme::new(MineAlgae::Light, 4))
+                .with_trip_wires(wires)
+        };
+        let body = MinefieldData::builder()
+            .with_minefield_id(MinefieldId::new(1, 1, 10))
+            .with_requesting_entity_id(EntityId::new(2, 2, 20))
+            .with_minefield_sequence_number(8)
+            .with_request_id(4)
+            .with_pdu_sequence_number(1)
+            .with_number_of_pdus(1)
+            .with_data_filter(DataFilter::all())
+            .with_mine_type(EntityType::default().with_kind(EntityKind::Munition))
+            .with_sensor_types(vec![1, 2, 3])
+            .with_mine(mine(
+                1.0,
+                vec![
+                    TripWire::new(vec![
+                        PerimeterPointCoordinate::new(1.0, 1.0),
+                        PerimeterPointCoordinate::new(2.0, 2.0),
+                    ]),
+                    TripWire::new(vec![PerimeterPointCoordinate::new(3.0, 3.0)]),
+                ],
+            ))
+            .with_mine(mine(2.0, vec![]))
+            .with_mine(mine(
+                3.0,
+                vec![TripWire::new(vec![PerimeterPointCoordinate::new(4.0, 4.0)])],
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn minefield_data_vertices_without_trip_wires() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldData).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let mine = |x: f32, wires: Vec<TripWire>| {
+            Mine::new(VectorF32::new(x, 2.0, 0.0)).with_trip_wires(wires)
+        };
+        let body = |mines: Vec<Mine>| {
+            MinefieldData::builder()
+                .with_minefield_id(MinefieldId::new(1, 1, 10))
+                .with_requesting_entity_id(EntityId::new(2, 2, 20))
+                .with_data_filter(DataFilter {
+                    number_of_vertices: true,
+                    ..Default::default()
+                })
+                .with_mine_type(EntityType::default().with_kind(EntityKind::Munition))
+                .with_mines(mines)
+                .build()
+                .into_pdu_body()
+        };
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body(vec![
+                mine(
+                    1.0,
+                    vec![TripWire::new(vec![
+                        PerimeterPointCoordinate::new(1.0, 1.0),
+                        PerimeterPointCoordinate::new(2.0, 2.0),
+                    ])],
+                ),
+                mine(2.0, vec![]),
+            ]),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(serialized_length, pdu_length);
+        assert_eq!(buf.len(), usize::from(pdu_length));
+
+        // The trip wires cannot be sent without their counts
+        let expected_body = body(vec![mine(1.0, vec![]), mine(2.0, vec![])]);
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(parsed.body, expected_body);
+    }
+}
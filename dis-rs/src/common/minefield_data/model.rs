@@ -0,0 +1,435 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    ClockTime, EntityId, EntityType, MinefieldId, Orientation, PduBody, PerimeterPointCoordinate,
+    VectorF32, length_padded_to_num,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, TWELVE_OCTETS, TWO_OCTETS};
+use crate::enumerations::PduType;
+use crate::minefield_data::builder::MinefieldDataBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_MINEFIELD_DATA_BODY_LENGTH: u16 = 32;
+
+/// 5.10.4 Minefield Data PDU
+///
+/// 7.9.4 Minefield Data PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldData {
+    pub minefield_id: MinefieldId,
+    pub requesting_entity_id: EntityId,
+    pub minefield_sequence_number: u16,
+    pub request_id: u8,
+    pub pdu_sequence_number: u8,
+    pub number_of_pdus: u8,
+    pub data_filter: DataFilter,
+    pub mine_type: EntityType,
+    pub sensor_types: Vec<u16>,
+    pub mines: Vec<Mine>,
+}
+
+impl BodyRaw for MinefieldData {
+    type Builder = MinefieldDataBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldData(self)
+    }
+}
+
+impl BodyInfo for MinefieldData {
+    fn body_length(&self) -> u16 {
+        let number_of_mines = self.mines.len();
+        let number_of_wires: usize = self.mines.iter().map(|mine| mine.trip_wires.len()).sum();
+        let number_of_vertices: usize = self
+            .mines
+            .iter()
+            .flat_map(|mine| mine.trip_wires.iter())
+            .map(|wire| wire.vertices.len())
+            .sum();
+        let filter = &self.data_filter;
+        let per_mine_f32_fields = [
+            filter.ground_burial_depth_offset,
+            filter.water_burial_depth_offset,
+            filter.snow_burial_depth_offset,
+            filter.thermal_contrast,
+            filter.reflectance,
+        ]
+        .iter()
+        .filter(|&&present| present)
+        .count();
+
+        let sensor_types_length =
+            length_padded_to_num(self.sensor_types.len() * TWO_OCTETS, FOUR_OCTETS).record_length;
+        let aligned_mine_fields_length = number_of_mines
+            * (TWELVE_OCTETS
+                + per_mine_f32_fields * FOUR_OCTETS
+                + usize::from(filter.mine_orientation) * TWELVE_OCTETS
+                + usize::from(filter.mine_emplacement_time) * EIGHT_OCTETS);
+        let unaligned_mine_fields_length = length_padded_to_num(
+            number_of_mines
+                * (usize::from(filter.mine_entity_number) * TWO_OCTETS
+                    + usize::from(filter.fusing) * TWO_OCTETS
+                    + usize::from(filter.scalar_detection_coefficient)
+                    + usize::from(filter.paint_scheme)),
+            FOUR_OCTETS,
+        )
+        .record_length;
+        let wires_length = if filter.number_of_trip_wires {
+            length_padded_to_num(number_of_mines, FOUR_OCTETS).record_length
+        } else {
+            0
+        };
+        let vertices_length = if filter.number_of_trip_wires && filter.number_of_vertices {
+            length_padded_to_num(number_of_wires, FOUR_OCTETS).record_length
+                + number_of_vertices * EIGHT_OCTETS
+        } else {
+            0
+        };
+
+        BASE_MINEFIELD_DATA_BODY_LENGTH
+            + (sensor_types_length
+                + aligned_mine_fields_length
+                + unaligned_mine_fields_length
+                + wires_length
+                + vertices_length) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldData
+    }
+}
+
+impl Interaction for MinefieldData {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+}
+
+/// The data of a single mine in a Minefield Data PDU.
+///
+/// The optional fields are present on the wire when the corresponding bit of the
+/// `DataFilter` of the PDU is set. Fields set to `None` while selected by the filter
+/// are serialized as zero.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mine {
+    pub location: VectorF32,
+    pub ground_burial_depth_offset: Option<f32>,
+    pub water_burial_depth_offset: Option<f32>,
+    pub snow_burial_depth_offset: Option<f32>,
+    pub orientation: Option<Orientation>,
+    pub thermal_contrast: Option<f32>,
+    pub reflectance: Option<f32>,
+    pub emplacement_time: Option<ClockTime>,
+    pub entity_number: Option<u16>,
+    pub fusing: Option<MineFusing>,
+    pub scalar_detection_coefficient: Option<u8>,
+    pub paint_scheme: Option<MinePaintScheme>,
+    pub trip_wires: Vec<TripWire>,
+}
+
+impl Mine {
+    #[must_use]
+    pub fn new(location: VectorF32) -> Self {
+        Self {
+            location,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_location(mut self, location: VectorF32) -> Self {
+        self.location = location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_ground_burial_depth_offset(mut self, offset: f32) -> Self {
+        self.ground_burial_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_water_burial_depth_offset(mut self, offset: f32) -> Self {
+        self.water_burial_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_snow_burial_depth_offset(mut self, offset: f32) -> Self {
+        self.snow_burial_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    #[must_use]
+    pub fn with_thermal_contrast(mut self, thermal_contrast: f32) -> Self {
+        self.thermal_contrast = Some(thermal_contrast);
+        self
+    }
+
+    #[must_use]
+    pub fn with_reflectance(mut self, reflectance: f32) -> Self {
+        self.reflectance = Some(reflectance);
+        self
+    }
+
+    #[must_use]
+    pub fn with_emplacement_time(mut self, emplacement_time: ClockTime) -> Self {
+        self.emplacement_time = Some(emplacement_time);
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_number(mut self, entity_number: u16) -> Self {
+        self.entity_number = Some(entity_number);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fusing(mut self, fusing: MineFusing) -> Self {
+        self.fusing = Some(fusing);
+        self
+    }
+
+    #[must_use]
+    pub fn with_scalar_detection_coefficient(mut self, coefficient: u8) -> Self {
+        self.scalar_detection_coefficient = Some(coefficient);
+        self
+    }
+
+    #[must_use]
+    pub fn with_paint_scheme(mut self, paint_scheme: MinePaintScheme) -> Self {
+        self.paint_scheme = Some(paint_scheme);
+        self
+    }
+
+    #[must_use]
+    pub fn with_trip_wire(mut self, trip_wire: TripWire) -> Self {
+        self.trip_wires.push(trip_wire);
+        self
+    }
+
+    #[must_use]
+    pub fn with_trip_wires(mut self, trip_wires: Vec<TripWire>) -> Self {
+        self.trip_wires = trip_wires;
+        self
+    }
+}
+
+/// A trip, pull or tension wire attached to a mine, described by its vertices
+/// relative to the location of the mine.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TripWire {
+    pub vertices: Vec<PerimeterPointCoordinate>,
+}
+
+impl TripWire {
+    #[must_use]
+    pub fn new(vertices: Vec<PerimeterPointCoordinate>) -> Self {
+        Self { vertices }
+    }
+}
+
+/// 6.2.22 Data Filter record (SISO-REF-010 UID 203)
+///
+/// Selects which optional mine fields are present in a Minefield Data PDU,
+/// or are requested in a Minefield Query PDU.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DataFilter {
+    pub ground_burial_depth_offset: bool,
+    pub water_burial_depth_offset: bool,
+    pub snow_burial_depth_offset: bool,
+    pub mine_orientation: bool,
+    pub thermal_contrast: bool,
+    pub reflectance: bool,
+    pub mine_emplacement_time: bool,
+    pub mine_entity_number: bool,
+    pub fusing: bool,
+    pub scalar_detection_coefficient: bool,
+    pub paint_scheme: bool,
+    pub number_of_trip_wires: bool,
+    pub number_of_vertices: bool,
+}
+
+impl DataFilter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a `DataFilter` with all fields selected.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::from(0x0000_1FFF)
+    }
+}
+
+impl From<u32> for DataFilter {
+    fn from(value: u32) -> Self {
+        let bit = |position: u32| ((value >> position) & 0x01) != 0;
+        Self {
+            ground_burial_depth_offset: bit(0),
+            water_burial_depth_offset: bit(1),
+            snow_burial_depth_offset: bit(2),
+            mine_orientation: bit(3),
+            thermal_contrast: bit(4),
+            reflectance: bit(5),
+            mine_emplacement_time: bit(6),
+            mine_entity_number: bit(7),
+            fusing: bit(8),
+            scalar_detection_coefficient: bit(9),
+            paint_scheme: bit(10),
+            number_of_trip_wires: bit(11),
+            number_of_vertices: bit(12),
+        }
+    }
+}
+
+impl From<&DataFilter> for u32 {
+    fn from(value: &DataFilter) -> Self {
+        u32::from(value.ground_burial_depth_offset)
+            | u32::from(value.water_burial_depth_offset) << 1
+            | u32::from(value.snow_burial_depth_offset) << 2
+            | u32::from(value.mine_orientation) << 3
+            | u32::from(value.thermal_contrast) << 4
+            | u32::from(value.reflectance) << 5
+            | u32::from(value.mine_emplacement_time) << 6
+            | u32::from(value.mine_entity_number) << 7
+            | u32::from(value.fusing) << 8
+            | u32::from(value.scalar_detection_coefficient) << 9
+            | u32::from(value.paint_scheme) << 10
+            | u32::from(value.number_of_trip_wires) << 11
+            | u32::from(value.number_of_vertices) << 12
+    }
+}
+
+/// 6.2.59 Minefield Fusing record (SISO-REF-010 UID 192)
+///
+/// The primary and secondary fuse types are the raw values of SISO-REF-010 UID 193.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MineFusing {
+    pub primary_fuse: u8,
+    pub secondary_fuse: u8,
+    pub has_anti_handling_device: bool,
+}
+
+impl MineFusing {
+    #[must_use]
+    pub fn new(primary_fuse: u8, secondary_fuse: u8, has_anti_handling_device: bool) -> Self {
+        Self {
+            primary_fuse,
+            secondary_fuse,
+            has_anti_handling_device,
+        }
+    }
+}
+
+impl From<u16> for MineFusing {
+    fn from(value: u16) -> Self {
+        Self {
+            primary_fuse: (value & 0x007F) as u8,
+            secondary_fuse: ((value >> 7) & 0x007F) as u8,
+            has_anti_handling_device: ((value >> 14) & 0x0001) != 0,
+        }
+    }
+}
+
+impl From<&MineFusing> for u16 {
+    fn from(value: &MineFusing) -> Self {
+        (u16::from(value.primary_fuse) & 0x007F)
+            | (u16::from(value.secondary_fuse) & 0x007F) << 7
+            | u16::from(value.has_anti_handling_device) << 14
+    }
+}
+
+/// 6.2.60 Minefield Paint Scheme record (SISO-REF-010 UID 202)
+///
+/// The paint scheme is the raw value of SISO-REF-010 UID 201.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinePaintScheme {
+    pub algae: MineAlgae,
+    pub paint_scheme: u8,
+}
+
+impl MinePaintScheme {
+    #[must_use]
+    pub fn new(algae: MineAlgae, paint_scheme: u8) -> Self {
+        Self {
+            algae,
+            paint_scheme,
+        }
+    }
+}
+
+impl From<u8> for MinePaintScheme {
+    fn from(value: u8) -> Self {
+        Self {
+            algae: MineAlgae::from(value & 0x03),
+            paint_scheme: value >> 2,
+        }
+    }
+}
+
+impl From<&MinePaintScheme> for u8 {
+    fn from(value: &MinePaintScheme) -> Self {
+        u8::from(value.algae) | (value.paint_scheme << 2)
+    }
+}
+
+/// Custom defined enum to model the Algae field of the Minefield Paint Scheme record (SISO-REF-010 UID 200)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MineAlgae {
+    #[default]
+    None, // 0
+    Light,    // 1
+    Moderate, // 2
+    Heavy,    // 3
+}
+
+impl From<u8> for MineAlgae {
+    fn from(value: u8) -> Self {
+        match value & 0x03 {
+            1 => Self::Light,
+            2 => Self::Moderate,
+            3 => Self::Heavy,
+            _ => Self::None,
+        }
+    }
+}
+
+impl From<MineAlgae> for u8 {
+    fn from(value: MineAlgae) -> Self {
+        match value {
+            MineAlgae::None => 0,
+            MineAlgae::Light => 1,
+            MineAlgae::Moderate => 2,
+            MineAlgae::Heavy => 3,
+        }
+    }
+}
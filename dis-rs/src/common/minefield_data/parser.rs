@@ -0,0 +1,207 @@
+use crate::BodyRaw;
+use crate::common::model::{PduBody, length_padded_to_num};
+use crate::common::parser::{
+    IResult, clock_time, context, entity_id, entity_type, minefield_id, orientation,
+    perimeter_point_coordinate, vec3_f32,
+};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::minefield_data::model::{
+    DataFilter, Mine, MineFusing, MinePaintScheme, MinefieldData, TripWire,
+};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::combinator::cond;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u8, be_u16, be_u32};
+
+#[allow(clippy::too_many_lines)]
+pub(crate) fn minefield_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", minefield_id).parse(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id).parse(input)?;
+    let (input, minefield_sequence_number) =
+        context("Minefield Sequence Number", be_u16).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u8).parse(input)?;
+    let (input, pdu_sequence_number) = context("PDU Sequence Number", be_u8).parse(input)?;
+    let (input, number_of_pdus) = context("Number of PDUs", be_u8).parse(input)?;
+    let (input, number_of_mines) = context("Number of Mines", be_u8).parse(input)?;
+    let (input, number_of_sensor_types) = context("Number of Sensor Types", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, data_filter) = context("Data Filter", be_u32).parse(input)?;
+    let filter = DataFilter::from(data_filter);
+    let (input, mine_type) = context("Mine Type", entity_type).parse(input)?;
+    let (input, sensor_types) = count(
+        context("Sensor Type", be_u16),
+        number_of_sensor_types.into(),
+    )
+    .parse(input)?;
+    let (input, _padding) = context(
+        "Padding",
+        take(
+            length_padded_to_num(
+                usize::from(number_of_sensor_types) * TWO_OCTETS,
+                FOUR_OCTETS,
+            )
+            .padding_length,
+        ),
+    )
+    .parse(input)?;
+
+    let number_of_mines = usize::from(number_of_mines);
+    let (input, locations) = count(context("Location", vec3_f32), number_of_mines).parse(input)?;
+    let (input, ground_burial_depth_offsets) = cond(
+        filter.ground_burial_depth_offset,
+        context(
+            "Ground Burial Depth Offsets",
+            count(be_f32, number_of_mines),
+        ),
+    )
+    .parse(input)?;
+    let (input, water_burial_depth_offsets) = cond(
+        filter.water_burial_depth_offset,
+        context("Water Burial Depth Offsets", count(be_f32, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, snow_burial_depth_offsets) = cond(
+        filter.snow_burial_depth_offset,
+        context("Snow Burial Depth Offsets", count(be_f32, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, orientations) = cond(
+        filter.mine_orientation,
+        context("Orientations", count(orientation, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, thermal_contrasts) = cond(
+        filter.thermal_contrast,
+        context("Thermal Contrasts", count(be_f32, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, reflectances) = cond(
+        filter.reflectance,
+        context("Reflectances", count(be_f32, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, emplacement_times) = cond(
+        filter.mine_emplacement_time,
+        context("Emplacement Times", count(clock_time, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, entity_numbers) = cond(
+        filter.mine_entity_number,
+        context("Entity Numbers", count(be_u16, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, fusings) = cond(
+        filter.fusing,
+        context("Fusings", count(be_u16, number_of_mines)),
+    )
+    .parse(input)?;
+    let (input, scalar_detection_coefficients) = cond(
+        filter.scalar_detection_coefficient,
+        context(
+            "Scalar Detection Coefficients",
+            count(be_u8, number_of_mines),
+        ),
+    )
+    .parse(input)?;
+    let (input, paint_schemes) = cond(
+        filter.paint_scheme,
+        context("Paint Schemes", count(be_u8, number_of_mines)),
+    )
+    .parse(input)?;
+    let unaligned_length = number_of_mines
+        * (usize::from(filter.mine_entity_number) * TWO_OCTETS
+            + usize::from(filter.fusing) * TWO_OCTETS
+            + usize::from(filter.scalar_detection_coefficient)
+            + usize::from(filter.paint_scheme));
+    let (input, _padding) = context(
+        "Padding",
+        take(length_padded_to_num(unaligned_length, FOUR_OCTETS).padding_length),
+    )
+    .parse(input)?;
+
+    let (input, number_of_trip_wires) = cond(
+        filter.number_of_trip_wires,
+        context("Number of Trip Wires", count(be_u8, number_of_mines)),
+    )
+    .parse(input)?;
+    let number_of_trip_wires = number_of_trip_wires.unwrap_or_else(|| vec![0; number_of_mines]);
+    let total_trip_wires = number_of_trip_wires.iter().map(|&n| usize::from(n)).sum();
+    let (input, _padding) = if filter.number_of_trip_wires {
+        context(
+            "Padding",
+            take(length_padded_to_num(number_of_mines, FOUR_OCTETS).padding_length),
+        )
+        .parse(input)?
+    } else {
+        (input, &[][..])
+    };
+    let (input, number_of_vertices) = cond(
+        filter.number_of_vertices,
+        context("Number of Vertices", count(be_u8, total_trip_wires)),
+    )
+    .parse(input)?;
+    let number_of_vertices = number_of_vertices.unwrap_or_else(|| vec![0; total_trip_wires]);
+    let (input, _padding) = if filter.number_of_vertices {
+        context(
+            "Padding",
+            take(length_padded_to_num(total_trip_wires, FOUR_OCTETS).padding_length),
+        )
+        .parse(input)?
+    } else {
+        (input, &[][..])
+    };
+    let total_vertices = number_of_vertices.iter().map(|&n| usize::from(n)).sum();
+    let (input, vertices) = count(
+        context("Vertex", perimeter_point_coordinate),
+        total_vertices,
+    )
+    .parse(input)?;
+
+    let mut vertices = vertices.into_iter();
+    let mut number_of_vertices = number_of_vertices.into_iter();
+    let mines = locations
+        .into_iter()
+        .enumerate()
+        .map(|(i, location)| {
+            let trip_wires = (0..number_of_trip_wires[i])
+                .map(|_| {
+                    let wire_vertices = number_of_vertices.next().unwrap_or_default();
+                    TripWire::new(vertices.by_ref().take(wire_vertices.into()).collect())
+                })
+                .collect();
+            Mine {
+                location,
+                ground_burial_depth_offset: ground_burial_depth_offsets.as_ref().map(|v| v[i]),
+                water_burial_depth_offset: water_burial_depth_offsets.as_ref().map(|v| v[i]),
+                snow_burial_depth_offset: snow_burial_depth_offsets.as_ref().map(|v| v[i]),
+                orientation: orientations.as_ref().map(|v| v[i]),
+                thermal_contrast: thermal_contrasts.as_ref().map(|v| v[i]),
+                reflectance: reflectances.as_ref().map(|v| v[i]),
+                emplacement_time: emplacement_times.as_ref().map(|v| v[i]),
+                entity_number: entity_numbers.as_ref().map(|v| v[i]),
+                fusing: fusings.as_ref().map(|v| MineFusing::from(v[i])),
+                scalar_detection_coefficient: scalar_detection_coefficients.as_ref().map(|v| v[i]),
+                paint_scheme: paint_schemes.as_ref().map(|v| MinePaintScheme::from(v[i])),
+                trip_wires,
+            }
+        })
+        .collect();
+
+    Ok((
+        input,
+        MinefieldData::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_minefield_sequence_number(minefield_sequence_number)
+            .with_request_id(request_id)
+            .with_pdu_sequence_number(pdu_sequence_number)
+            .with_number_of_pdus(number_of_pdus)
+            .with_data_filter(filter)
+            .with_mine_type(mine_type)
+            .with_sensor_types(sensor_types)
+            .with_mines(mines)
+            .build()
+            .into_pdu_body(),
+    ))
+}
@@ -0,0 +1,137 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::minefield_data::model::{DataFilter, Mine, MinefieldData};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for MinefieldData {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        let filter = &self.data_filter;
+
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.pdu_sequence_number);
+        buf.put_u8(self.number_of_pdus);
+        buf.put_u8(self.mines.len() as u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u32(filter.into());
+        self.mine_type.serialize(buf);
+        self.sensor_types
+            .iter()
+            .for_each(|&sensor_type| buf.put_u16(sensor_type));
+        buf.put_bytes(
+            0u8,
+            length_padded_to_num(self.sensor_types.len() * TWO_OCTETS, FOUR_OCTETS).padding_length,
+        );
+
+        serialize_mine_fields(&self.mines, filter, buf);
+        serialize_trip_wires(&self.mines, filter, buf);
+
+        self.body_length()
+    }
+}
+
+/// Serializes the per-mine fields selected by the `DataFilter`, up to and including the paint scheme,
+/// followed by the padding to a 32-bit boundary.
+fn serialize_mine_fields(mines: &[Mine], filter: &DataFilter, buf: &mut BytesMut) {
+    for mine in mines {
+        mine.location.serialize(buf);
+    }
+    if filter.ground_burial_depth_offset {
+        for mine in mines {
+            buf.put_f32(mine.ground_burial_depth_offset.unwrap_or_default());
+        }
+    }
+    if filter.water_burial_depth_offset {
+        for mine in mines {
+            buf.put_f32(mine.water_burial_depth_offset.unwrap_or_default());
+        }
+    }
+    if filter.snow_burial_depth_offset {
+        for mine in mines {
+            buf.put_f32(mine.snow_burial_depth_offset.unwrap_or_default());
+        }
+    }
+    if filter.mine_orientation {
+        for mine in mines {
+            mine.orientation.unwrap_or_default().serialize(buf);
+        }
+    }
+    if filter.thermal_contrast {
+        for mine in mines {
+            buf.put_f32(mine.thermal_contrast.unwrap_or_default());
+        }
+    }
+    if filter.reflectance {
+        for mine in mines {
+            buf.put_f32(mine.reflectance.unwrap_or_default());
+        }
+    }
+    if filter.mine_emplacement_time {
+        for mine in mines {
+            mine.emplacement_time.unwrap_or_default().serialize(buf);
+        }
+    }
+    if filter.mine_entity_number {
+        for mine in mines {
+            buf.put_u16(mine.entity_number.unwrap_or_default());
+        }
+    }
+    if filter.fusing {
+        for mine in mines {
+            buf.put_u16((&mine.fusing.unwrap_or_default()).into());
+        }
+    }
+    if filter.scalar_detection_coefficient {
+        for mine in mines {
+            buf.put_u8(mine.scalar_detection_coefficient.unwrap_or_default());
+        }
+    }
+    if filter.paint_scheme {
+        for mine in mines {
+            buf.put_u8((&mine.paint_scheme.unwrap_or_default()).into());
+        }
+    }
+    let unaligned_length = mines.len()
+        * (usize::from(filter.mine_entity_number) * TWO_OCTETS
+            + usize::from(filter.fusing) * TWO_OCTETS
+            + usize::from(filter.scalar_detection_coefficient)
+            + usize::from(filter.paint_scheme));
+    buf.put_bytes(
+        0u8,
+        length_padded_to_num(unaligned_length, FOUR_OCTETS).padding_length,
+    );
+}
+
+/// Serializes the trip wire counts, vertex counts and vertices selected by the `DataFilter`.
+fn serialize_trip_wires(mines: &[Mine], filter: &DataFilter, buf: &mut BytesMut) {
+    if filter.number_of_trip_wires {
+        for mine in mines {
+            buf.put_u8(mine.trip_wires.len() as u8);
+        }
+        buf.put_bytes(
+            0u8,
+            length_padded_to_num(mines.len(), FOUR_OCTETS).padding_length,
+        );
+    }
+    // Without the trip wire counts there are no trip wires to give vertices
+    if filter.number_of_trip_wires && filter.number_of_vertices {
+        let wires = mines.iter().flat_map(|mine| mine.trip_wires.iter());
+        let number_of_wires = wires.clone().count();
+        wires.clone().for_each(|wire| {
+            buf.put_u8(wire.vertices.len() as u8);
+        });
+        buf.put_bytes(
+            0u8,
+            length_padded_to_num(number_of_wires, FOUR_OCTETS).padding_length,
+        );
+        wires
+            .flat_map(|wire| wire.vertices.iter())
+            .for_each(|vertex| {
+                vertex.serialize(buf);
+            });
+    }
+}
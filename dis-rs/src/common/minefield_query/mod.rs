@@ -0,0 +1,61 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, EntityKind, LvcIndicator, PduType};
+    use crate::minefield_data::model::DataFilter;
+    use crate::minefield_query::model::MinefieldQuery;
+    use crate::model::{EntityId, EntityType, MinefieldId, PerimeterPointCoordinate};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn minefield_query_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldQuery).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldQuery::builder()
+            .with_minefield_id(MinefieldId::new(1, 1, 10))
+            .with_requesting_entity_id(EntityId::new(2, 2, 20))
+            .with_request_id(5)
+            .with_data_filter(DataFilter::all())
+            .with_requested_mine_type(EntityType::default().with_kind(EntityKind::Munition))
+            .with_requested_perimeter_point(PerimeterPointCoordinate::new(10.0, 20.0))
+            .with_requested_perimeter_point(PerimeterPointCoordinate::new(30.0, 40.0))
+            .with_sensor_type(1)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
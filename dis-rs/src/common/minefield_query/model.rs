@@ -0,0 +1,71 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, EntityType, MinefieldId, PduBody, PerimeterPointCoordinate, length_padded_to_num,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::enumerations::PduType;
+use crate::minefield_data::model::DataFilter;
+use crate::minefield_query::builder::MinefieldQueryBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_MINEFIELD_QUERY_BODY_LENGTH: u16 = 28;
+
+/// 5.10.3 Minefield Query PDU
+///
+/// 7.9.3 Minefield Query PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldQuery {
+    pub minefield_id: MinefieldId,
+    pub requesting_entity_id: EntityId,
+    pub request_id: u8,
+    pub data_filter: DataFilter,
+    pub requested_mine_type: EntityType,
+    pub requested_perimeter_points: Vec<PerimeterPointCoordinate>,
+    pub sensor_types: Vec<u16>,
+}
+
+impl BodyRaw for MinefieldQuery {
+    type Builder = MinefieldQueryBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldQuery(self)
+    }
+}
+
+impl BodyInfo for MinefieldQuery {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_QUERY_BODY_LENGTH
+            + self
+                .requested_perimeter_points
+                .iter()
+                .map(PerimeterPointCoordinate::record_length)
+                .sum::<u16>()
+            + length_padded_to_num(self.sensor_types.len() * TWO_OCTETS, FOUR_OCTETS).record_length
+                as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldQuery
+    }
+}
+
+impl Interaction for MinefieldQuery {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
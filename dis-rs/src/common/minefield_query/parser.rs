@@ -0,0 +1,60 @@
+use crate::BodyRaw;
+use crate::common::model::{PduBody, length_padded_to_num};
+use crate::common::parser::{
+    IResult, context, entity_id, entity_type, minefield_id, perimeter_point_coordinate,
+};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::minefield_data::model::DataFilter;
+use crate::minefield_query::model::MinefieldQuery;
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+pub(crate) fn minefield_query_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", minefield_id).parse(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u8).parse(input)?;
+    let (input, number_of_perimeter_points) =
+        context("Number of Perimeter Points", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, number_of_sensor_types) = context("Number of Sensor Types", be_u8).parse(input)?;
+    let (input, data_filter) = context("Data Filter", be_u32).parse(input)?;
+    let data_filter = DataFilter::from(data_filter);
+    let (input, requested_mine_type) = context("Requested Mine Type", entity_type).parse(input)?;
+    let (input, requested_perimeter_points) = count(
+        context("Requested Perimeter Point", perimeter_point_coordinate),
+        number_of_perimeter_points.into(),
+    )
+    .parse(input)?;
+    let (input, sensor_types) = count(
+        context("Sensor Type", be_u16),
+        number_of_sensor_types.into(),
+    )
+    .parse(input)?;
+    let (input, _padding) = context(
+        "Padding",
+        take(
+            length_padded_to_num(
+                usize::from(number_of_sensor_types) * TWO_OCTETS,
+                FOUR_OCTETS,
+            )
+            .padding_length,
+        ),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        MinefieldQuery::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_request_id(request_id)
+            .with_data_filter(data_filter)
+            .with_requested_mine_type(requested_mine_type)
+            .with_requested_perimeter_points(requested_perimeter_points)
+            .with_sensor_types(sensor_types)
+            .build()
+            .into_pdu_body(),
+    ))
+}
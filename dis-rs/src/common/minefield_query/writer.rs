@@ -0,0 +1,31 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::minefield_query::model::MinefieldQuery;
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for MinefieldQuery {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.requested_perimeter_points.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        buf.put_u32((&self.data_filter).into());
+        self.requested_mine_type.serialize(buf);
+        self.requested_perimeter_points
+            .iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        self.sensor_types
+            .iter()
+            .for_each(|&sensor_type| buf.put_u16(sensor_type));
+        buf.put_bytes(
+            0u8,
+            length_padded_to_num(self.sensor_types.len() * TWO_OCTETS, FOUR_OCTETS).padding_length,
+        );
+
+        self.body_length()
+    }
+}
@@ -0,0 +1,57 @@
+use crate::common::model::{EntityId, MinefieldId};
+use crate::minefield_response_nack::model::MinefieldResponseNack;
+
+pub struct MinefieldResponseNackBuilder(MinefieldResponseNack);
+
+impl Default for MinefieldResponseNackBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinefieldResponseNackBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        MinefieldResponseNackBuilder(MinefieldResponseNack::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: MinefieldResponseNack) -> Self {
+        MinefieldResponseNackBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> MinefieldResponseNack {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_minefield_id(mut self, minefield_id: MinefieldId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_missing_pdu_sequence_number(mut self, sequence_number: u8) -> Self {
+        self.0.missing_pdu_sequence_numbers.push(sequence_number);
+        self
+    }
+
+    #[must_use]
+    pub fn with_missing_pdu_sequence_numbers(mut self, sequence_numbers: Vec<u8>) -> Self {
+        self.0.missing_pdu_sequence_numbers = sequence_numbers;
+        self
+    }
+}
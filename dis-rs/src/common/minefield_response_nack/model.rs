@@ -0,0 +1,62 @@
+use crate::BodyRaw;
+use crate::common::model::{EntityId, MinefieldId, PduBody, length_padded_to_num};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::PduType;
+use crate::minefield_response_nack::builder::MinefieldResponseNackBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH: usize = 14;
+
+/// 5.10.5 Minefield Response NACK PDU
+///
+/// 7.9.5 Minefield Response Negative Acknowledgment (NACK) PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldResponseNack {
+    pub minefield_id: MinefieldId,
+    pub requesting_entity_id: EntityId,
+    pub request_id: u8,
+    pub missing_pdu_sequence_numbers: Vec<u8>,
+}
+
+impl BodyRaw for MinefieldResponseNack {
+    type Builder = MinefieldResponseNackBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldResponseNACK(self)
+    }
+}
+
+impl BodyInfo for MinefieldResponseNack {
+    fn body_length(&self) -> u16 {
+        length_padded_to_num(
+            BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH + self.missing_pdu_sequence_numbers.len(),
+            FOUR_OCTETS,
+        )
+        .record_length as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldResponseNACK
+    }
+}
+
+impl Interaction for MinefieldResponseNack {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,45 @@
+use crate::BodyRaw;
+use crate::common::model::{PduBody, length_padded_to_num};
+use crate::common::parser::{IResult, context, entity_id, minefield_id};
+use crate::constants::FOUR_OCTETS;
+use crate::minefield_response_nack::model::{
+    BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH, MinefieldResponseNack,
+};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+
+pub(crate) fn minefield_response_nack_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", minefield_id).parse(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u8).parse(input)?;
+    let (input, number_of_missing_pdus) = context("Number of Missing PDUs", be_u8).parse(input)?;
+    let (input, missing_pdu_sequence_numbers) = count(
+        context("Missing PDU Sequence Number", be_u8),
+        number_of_missing_pdus.into(),
+    )
+    .parse(input)?;
+    let (input, _padding) = context(
+        "Padding",
+        take(
+            length_padded_to_num(
+                BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH + usize::from(number_of_missing_pdus),
+                FOUR_OCTETS,
+            )
+            .padding_length,
+        ),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        MinefieldResponseNack::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_request_id(request_id)
+            .with_missing_pdu_sequence_numbers(missing_pdu_sequence_numbers)
+            .build()
+            .into_pdu_body(),
+    ))
+}
@@ -0,0 +1,27 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::FOUR_OCTETS;
+use crate::minefield_response_nack::model::{
+    BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH, MinefieldResponseNack,
+};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for MinefieldResponseNack {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.missing_pdu_sequence_numbers.len() as u8);
+        buf.put_slice(&self.missing_pdu_sequence_numbers);
+        buf.put_bytes(
+            0u8,
+            length_padded_to_num(
+                BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH + self.missing_pdu_sequence_numbers.len(),
+                FOUR_OCTETS,
+            )
+            .padding_length,
+        );
+
+        self.body_length()
+    }
+}
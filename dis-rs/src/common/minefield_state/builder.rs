@@ -0,0 +1,105 @@
+use crate::common::model::{
+    EntityType, Location, MinefieldId, Orientation, PerimeterPointCoordinate,
+};
+use crate::enumerations::ForceId;
+use crate::minefield_state::model::{MinefieldAppearance, MinefieldProtocolMode, MinefieldState};
+
+pub struct MinefieldStateBuilder(MinefieldState);
+
+impl Default for MinefieldStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinefieldStateBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        MinefieldStateBuilder(MinefieldState::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: MinefieldState) -> Self {
+        MinefieldStateBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> MinefieldState {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_minefield_id(mut self, minefield_id: MinefieldId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_minefield_sequence_number(mut self, minefield_sequence_number: u16) -> Self {
+        self.0.minefield_sequence_number = minefield_sequence_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_minefield_type(mut self, minefield_type: EntityType) -> Self {
+        self.0.minefield_type = minefield_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_minefield_location(mut self, minefield_location: Location) -> Self {
+        self.0.minefield_location = minefield_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_minefield_orientation(mut self, minefield_orientation: Orientation) -> Self {
+        self.0.minefield_orientation = minefield_orientation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_appearance(mut self, appearance: MinefieldAppearance) -> Self {
+        self.0.appearance = appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_protocol_mode(mut self, protocol_mode: MinefieldProtocolMode) -> Self {
+        self.0.protocol_mode = protocol_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_perimeter_point(mut self, perimeter_point: PerimeterPointCoordinate) -> Self {
+        self.0.perimeter_points.push(perimeter_point);
+        self
+    }
+
+    #[must_use]
+    pub fn with_perimeter_points(
+        mut self,
+        perimeter_points: Vec<PerimeterPointCoordinate>,
+    ) -> Self {
+        self.0.perimeter_points = perimeter_points;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mine_type(mut self, mine_type: EntityType) -> Self {
+        self.0.mine_types.push(mine_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_mine_types(mut self, mine_types: Vec<EntityType>) -> Self {
+        self.0.mine_types = mine_types;
+        self
+    }
+}
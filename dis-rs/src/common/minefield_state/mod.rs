@@ -0,0 +1,121 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        AppearanceEntityOrObjectState, CoupledExtensionIndicator, EntityKind, ForceId,
+        LvcIndicator, PduType,
+    };
+    use crate::minefield_state::model::{
+        MinefieldActiveStatus, MinefieldAppearance, MinefieldAppearanceType, MinefieldProtocolMode,
+        MinefieldState,
+    };
+    use crate::model::{EntityType, Location, MinefieldId, Orientation, PerimeterPointCoordinate};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn minefield_appearance_bits() {
+        let appearance = MinefieldAppearance::new()
+            .with_minefield_type(MinefieldAppearanceType::PureAntiTank)
+            .with_active_status(MinefieldActiveStatus::Inactive)
+            .with_is_lane(true)
+            .with_state(AppearanceEntityOrObjectState::Deactivated);
+        let bits = u16::from(&appearance);
+
+        assert_eq!(bits, 0b0010_0000_0001_0110);
+        assert_eq!(MinefieldAppearance::from(bits), appearance);
+    }
+
+    #[test]
+    fn minefield_state_internal_consistency_v7() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldState::builder()
+            .with_minefield_id(MinefieldId::new(1, 1, 10))
+            .with_minefield_sequence_number(3)
+            .with_force_id(ForceId::Opposing)
+            .with_minefield_type(EntityType::default().with_kind(EntityKind::Munition))
+            .with_minefield_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_minefield_orientation(Orientation::new(1.0, 0.0, 0.0))
+            .with_appearance(
+                MinefieldAppearance::new()
+                    .with_minefield_type(MinefieldAppearanceType::PureAntiPersonnel)
+                    .with_is_lane(true),
+            )
+            .with_protocol_mode(MinefieldProtocolMode::QRPMode)
+            .with_perimeter_point(PerimeterPointCoordinate::new(0.0, 0.0))
+            .with_perimeter_point(PerimeterPointCoordinate::new(100.0, 0.0))
+            .with_perimeter_point(PerimeterPointCoordinate::new(100.0, 50.0))
+            .with_mine_type(EntityType::default().with_kind(EntityKind::Munition))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn minefield_state_internal_consistency_v6() {
+        let header = PduHeader::new_v6(1, PduType::MinefieldState);
+
+        let body = MinefieldState::builder()
+            .with_minefield_id(MinefieldId::new(1, 1, 10))
+            .with_force_id(ForceId::Friendly)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, EntityType, Location, MinefieldId, Orientation, PduBody, PerimeterPointCoordinate,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::EIGHT_OCTETS;
+use crate::enumerations::{AppearanceEntityOrObjectState, ForceId, PduType};
+use crate::minefield_state::builder::MinefieldStateBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BASE_MINEFIELD_STATE_BODY_LENGTH: u16 = 60;
+
+/// 5.10.2 Minefield State PDU
+///
+/// 7.9.2 Minefield State PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldState {
+    pub minefield_id: MinefieldId,
+    pub minefield_sequence_number: u16,
+    pub force_id: ForceId,
+    pub minefield_type: EntityType,
+    pub minefield_location: Location,
+    pub minefield_orientation: Orientation,
+    pub appearance: MinefieldAppearance,
+    pub protocol_mode: MinefieldProtocolMode,
+    pub perimeter_points: Vec<PerimeterPointCoordinate>,
+    pub mine_types: Vec<EntityType>,
+}
+
+impl BodyRaw for MinefieldState {
+    type Builder = MinefieldStateBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldState(self)
+    }
+}
+
+impl BodyInfo for MinefieldState {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_STATE_BODY_LENGTH
+            + self
+                .perimeter_points
+                .iter()
+                .map(PerimeterPointCoordinate::record_length)
+                .sum::<u16>()
+            + (self.mine_types.len() * EIGHT_OCTETS) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldState
+    }
+}
+
+impl Interaction for MinefieldState {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.55 Minefield Appearance record (SISO-REF-010 UID 190)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldAppearance {
+    pub minefield_type: MinefieldAppearanceType,
+    pub active_status: MinefieldActiveStatus,
+    pub is_lane: bool,
+    pub state: AppearanceEntityOrObjectState,
+}
+
+impl MinefieldAppearance {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_minefield_type(mut self, minefield_type: MinefieldAppearanceType) -> Self {
+        self.minefield_type = minefield_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_active_status(mut self, active_status: MinefieldActiveStatus) -> Self {
+        self.active_status = active_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_is_lane(mut self, is_lane: bool) -> Self {
+        self.is_lane = is_lane;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state(mut self, state: AppearanceEntityOrObjectState) -> Self {
+        self.state = state;
+        self
+    }
+}
+
+impl From<u16> for MinefieldAppearance {
+    fn from(value: u16) -> Self {
+        let minefield_type = MinefieldAppearanceType::from((value & 0x0003) as u8);
+        let active_status = MinefieldActiveStatus::from(((value >> 2) & 0x0003) as u8);
+        let is_lane = ((value >> 4) & 0x0001) != 0;
+        let state = AppearanceEntityOrObjectState::from(((value >> 13) & 0x0001) as u8);
+
+        Self {
+            minefield_type,
+            active_status,
+            is_lane,
+            state,
+        }
+    }
+}
+
+impl From<&MinefieldAppearance> for u16 {
+    fn from(value: &MinefieldAppearance) -> Self {
+        let minefield_type = u16::from(u8::from(value.minefield_type)) & 0x0003;
+        let active_status = (u16::from(u8::from(value.active_status)) & 0x0003) << 2;
+        let is_lane = u16::from(value.is_lane) << 4;
+        let state = (u16::from(u8::from(value.state)) & 0x0001) << 13;
+
+        minefield_type | active_status | is_lane | state
+    }
+}
+
+/// Custom defined enum to model the Minefield Type field of the Minefield Appearance record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinefieldAppearanceType {
+    #[default]
+    MixedAntiPersonnelAndAntiTank, // 0
+    PureAntiPersonnel, // 1
+    PureAntiTank,      // 2
+    Unspecified(u8),
+}
+
+impl From<u8> for MinefieldAppearanceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::MixedAntiPersonnelAndAntiTank,
+            1 => Self::PureAntiPersonnel,
+            2 => Self::PureAntiTank,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<MinefieldAppearanceType> for u8 {
+    fn from(value: MinefieldAppearanceType) -> Self {
+        match value {
+            MinefieldAppearanceType::MixedAntiPersonnelAndAntiTank => 0,
+            MinefieldAppearanceType::PureAntiPersonnel => 1,
+            MinefieldAppearanceType::PureAntiTank => 2,
+            MinefieldAppearanceType::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Custom defined enum to model the Active Status field of the Minefield Appearance record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinefieldActiveStatus {
+    #[default]
+    Active, // 0
+    Inactive, // 1
+    Unspecified(u8),
+}
+
+impl From<u8> for MinefieldActiveStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Active,
+            1 => Self::Inactive,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<MinefieldActiveStatus> for u8 {
+    fn from(value: MinefieldActiveStatus) -> Self {
+        match value {
+            MinefieldActiveStatus::Active => 0,
+            MinefieldActiveStatus::Inactive => 1,
+            MinefieldActiveStatus::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// 6.2.69 Protocol Mode record (SISO-REF-010 UID 336)
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinefieldProtocolMode {
+    #[default]
+    HeartbeatMode, // 0
+    QRPMode, // 1
+    Unspecified(u8),
+}
+
+impl From<u16> for MinefieldProtocolMode {
+    fn from(value: u16) -> Self {
+        match value & 0x0003 {
+            0 => Self::HeartbeatMode,
+            1 => Self::QRPMode,
+            unspecified => Self::Unspecified(unspecified as u8),
+        }
+    }
+}
+
+impl From<MinefieldProtocolMode> for u16 {
+    fn from(value: MinefieldProtocolMode) -> Self {
+        match value {
+            MinefieldProtocolMode::HeartbeatMode => 0,
+            MinefieldProtocolMode::QRPMode => 1,
+            MinefieldProtocolMode::Unspecified(unspecified) => u16::from(unspecified) & 0x0003,
+        }
+    }
+}
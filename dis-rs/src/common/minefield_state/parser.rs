@@ -0,0 +1,55 @@
+use crate::BodyRaw;
+use crate::common::model::PduBody;
+use crate::common::parser::{
+    IResult, context, entity_type, location, minefield_id, orientation, perimeter_point_coordinate,
+};
+use crate::entity_state::parser::force_id;
+use crate::minefield_state::model::{MinefieldAppearance, MinefieldProtocolMode, MinefieldState};
+use nom::Parser;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16};
+
+pub(crate) fn minefield_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", minefield_id).parse(input)?;
+    let (input, minefield_sequence_number) =
+        context("Minefield Sequence Number", be_u16).parse(input)?;
+    let (input, force_id) = context("Force ID", force_id).parse(input)?;
+    let (input, number_of_perimeter_points) =
+        context("Number of Perimeter Points", be_u8).parse(input)?;
+    let (input, minefield_type) = context("Minefield Type", entity_type).parse(input)?;
+    let (input, number_of_mine_types) = context("Number of Mine Types", be_u16).parse(input)?;
+    let (input, minefield_location) = context("Minefield Location", location).parse(input)?;
+    let (input, minefield_orientation) =
+        context("Minefield Orientation", orientation).parse(input)?;
+    let (input, appearance) = context("Appearance", be_u16).parse(input)?;
+    let appearance = MinefieldAppearance::from(appearance);
+    let (input, protocol_mode) = context("Protocol Mode", be_u16).parse(input)?;
+    let protocol_mode = MinefieldProtocolMode::from(protocol_mode);
+    let (input, perimeter_points) = count(
+        context("Perimeter Point", perimeter_point_coordinate),
+        number_of_perimeter_points.into(),
+    )
+    .parse(input)?;
+    let (input, mine_types) = count(
+        context("Entity Type", entity_type),
+        number_of_mine_types.into(),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        MinefieldState::builder()
+            .with_minefield_id(minefield_id)
+            .with_minefield_sequence_number(minefield_sequence_number)
+            .with_force_id(force_id)
+            .with_minefield_type(minefield_type)
+            .with_minefield_location(minefield_location)
+            .with_minefield_orientation(minefield_orientation)
+            .with_appearance(appearance)
+            .with_protocol_mode(protocol_mode)
+            .with_perimeter_points(perimeter_points)
+            .with_mine_types(mine_types)
+            .build()
+            .into_pdu_body(),
+    ))
+}
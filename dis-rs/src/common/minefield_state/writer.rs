@@ -0,0 +1,28 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::minefield_state::model::MinefieldState;
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for MinefieldState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        self.force_id.serialize(buf);
+        buf.put_u8(self.perimeter_points.len() as u8);
+        self.minefield_type.serialize(buf);
+        buf.put_u16(self.mine_types.len() as u16);
+        self.minefield_location.serialize(buf);
+        self.minefield_orientation.serialize(buf);
+        buf.put_u16((&self.appearance).into());
+        buf.put_u16(self.protocol_mode.into());
+        self.perimeter_points
+            .iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        self.mine_types
+            .iter()
+            .map(|mine_type| mine_type.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
@@ -1,6 +1,9 @@
 pub mod model;
 pub(crate) mod parser;
+pub mod pdu_view;
+pub mod stream;
 pub mod timestamp;
+pub mod validation;
 
 pub mod acknowledge;
 pub mod acknowledge_r;
@@ -9,6 +12,9 @@
 pub mod action_response;
 pub mod action_response_r;
 pub mod aggregate_state;
+pub mod appearance;
+pub mod areal_object_state;
+pub mod articulated_parts;
 pub mod attribute;
 pub mod collision;
 pub mod collision_elastic;
@@ -22,16 +28,32 @@
 pub mod data_r;
 pub mod designator;
 pub mod detonation;
+pub mod directed_energy_fire;
 pub mod electromagnetic_emission;
+pub mod entity_damage_status;
 pub mod entity_state;
 pub mod entity_state_update;
+pub mod environmental_process;
 pub mod event_report;
 pub mod event_report_r;
 pub mod fire;
+pub mod gridded_data;
 pub mod iff;
+pub mod information_operations_action;
+pub mod information_operations_report;
+pub mod intercom_control;
+pub mod intercom_signal;
 pub mod is_group_of;
 pub mod is_part_of;
+pub mod le_detonation;
+pub mod le_fire;
+pub mod linear_object_state;
+pub mod minefield_data;
+pub mod minefield_query;
+pub mod minefield_response_nack;
+pub mod minefield_state;
 pub mod other;
+pub mod point_object_state;
 pub mod receiver;
 pub mod record_query_r;
 pub mod record_r;
@@ -54,14 +76,16 @@
 pub mod stop_freeze_r;
 pub mod transfer_ownership;
 pub mod transmitter;
+pub mod tspi;
 pub mod underwater_acoustic;
 
+mod compatibility;
 pub mod errors;
 mod writer;
 
 use crate::common::errors::DisError;
 use crate::common::model::Pdu;
-use crate::common::parser::parse_multiple_pdu;
+use crate::common::parser::{parse_multiple_pdu, parse_multiple_pdu_results};
 use crate::enumerations::{PduType, ProtocolVersion};
 use crate::model::PduBody;
 use bytes::BytesMut;
@@ -153,6 +177,26 @@ pub fn parse(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
     parse_multiple_pdu(input)
 }
 
+/// The result of parsing a single PDU from a buffer containing multiple PDUs.
+#[derive(Debug, PartialEq)]
+pub struct ParsedPdu {
+    /// Byte offset of the start of the PDU in the input buffer
+    pub offset: usize,
+    pub result: Result<Pdu, DisError>,
+}
+
+/// Parses the contents of the input, determining the DIS version by itself,
+/// and returns a separate result for each PDU in the buffer.
+///
+/// Unlike [`parse`], a PDU that fails to parse does not cause the valid PDUs in the same buffer to be lost.
+/// The PDUs are framed using the PDU length in the header. When a PDU cannot be framed, because
+/// the header is invalid or the buffer is shorter than indicated, an error is returned for that offset
+/// and parsing stops.
+#[must_use]
+pub fn parse_each(input: &[u8]) -> Vec<ParsedPdu> {
+    parse_multiple_pdu_results(input)
+}
+
 /// Parses the contents of the input as DIS version 6.
 /// This function tries to parse as many PDUs as there are in the buffer,
 /// assuming there are only complete PDUs present in the input.
@@ -7,6 +7,9 @@
 use crate::common::acknowledge::model::Acknowledge;
 use crate::common::action_request::model::ActionRequest;
 use crate::common::action_response::model::ActionResponse;
+use crate::common::appearance::model::Appearance;
+use crate::common::areal_object_state::model::ArealObjectState;
+use crate::common::articulated_parts::model::ArticulatedParts;
 use crate::common::attribute::model::Attribute;
 use crate::common::collision::model::Collision;
 use crate::common::collision_elastic::model::CollisionElastic;
@@ -16,13 +19,29 @@
 use crate::common::data_query::model::DataQuery;
 use crate::common::designator::model::Designator;
 use crate::common::detonation::model::Detonation;
+use crate::common::directed_energy_fire::model::DirectedEnergyFire;
 use crate::common::electromagnetic_emission::model::ElectromagneticEmission;
+use crate::common::entity_damage_status::model::EntityDamageStatus;
 use crate::common::entity_state::model::EntityState;
 use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::environmental_process::model::EnvironmentalProcess;
 use crate::common::event_report::model::EventReport;
 use crate::common::fire::model::Fire;
+use crate::common::gridded_data::model::GriddedData;
 use crate::common::iff::model::Iff;
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::intercom_control::model::IntercomControl;
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::le_detonation::model::LeDetonation;
+use crate::common::le_fire::model::LeFire;
+use crate::common::linear_object_state::model::LinearObjectState;
+use crate::common::minefield_data::model::MinefieldData;
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::minefield_state::model::MinefieldState;
 use crate::common::other::model::Other;
+use crate::common::point_object_state::model::PointObjectState;
 use crate::common::receiver::model::Receiver;
 use crate::common::remove_entity::model::RemoveEntity;
 use crate::common::set_data::model::SetData;
@@ -31,13 +50,22 @@
 use crate::common::stop_freeze::model::StopFreeze;
 pub use crate::common::timestamp::{TimeUnits, Timestamp};
 use crate::common::transmitter::model::Transmitter;
+use crate::common::tspi::model::Tspi;
 use crate::common::{BodyInfo, Interaction};
 use crate::constants::{
-    EIGHT_OCTETS, FIFTEEN_OCTETS, NO_REMAINDER, PDU_HEADER_LEN_BYTES, SIX_OCTETS,
+    EIGHT_OCTETS, FIFTEEN_OCTETS, FOUR_OCTETS, NO_REMAINDER, PDU_HEADER_LEN_BYTES, SIX_OCTETS,
+    THREE_OCTETS,
 };
 use crate::create_entity_r::model::CreateEntityR;
 use crate::data_query_r::model::DataQueryR;
 use crate::data_r::model::DataR;
+use crate::enumerations::{
+    AppearanceEntityOrObjectState, AppearanceObjectGeneralDamage,
+    AppearanceObjectGeneralIEDPresent, AppearanceObjectGeneralPredistributed,
+    AppearanceObjectSpecificBreachState, Country, EntityKind, ExplosiveMaterialCategories,
+    MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily,
+    ProtocolVersion, VariableRecordType,
+};
 use crate::enumerations::{
     ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator,
     AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus,
@@ -45,11 +73,6 @@
     EntityAssociationPhysicalConnectionType, SeparationPreEntityIndicator,
     SeparationReasonForSeparation, StationName,
 };
-use crate::enumerations::{
-    Country, EntityKind, ExplosiveMaterialCategories, MunitionDescriptorFuse,
-    MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion,
-    VariableRecordType,
-};
 use crate::event_report_r::model::EventReportR;
 use crate::fixed_parameters::{NO_APPLIC, NO_ENTITY, NO_SITE};
 use crate::is_group_of::model::IsGroupOf;
@@ -214,26 +237,26 @@ pub enum PduBody {
     IFF(Iff),
     UnderwaterAcoustic(UnderwaterAcoustic),
     SupplementalEmissionEntityState(SEES),
-    IntercomSignal,
-    IntercomControl,
+    IntercomSignal(IntercomSignal),
+    IntercomControl(IntercomControl),
     AggregateState(AggregateState),
     IsGroupOf(IsGroupOf),
     TransferOwnership(TransferOwnership),
     IsPartOf(IsPartOf),
-    MinefieldState,
-    MinefieldQuery,
-    MinefieldData,
-    MinefieldResponseNACK,
-    EnvironmentalProcess,
-    GriddedData,
-    PointObjectState,
-    LinearObjectState,
-    ArealObjectState,
-    TSPI,
-    Appearance,
-    ArticulatedParts,
-    LEFire,
-    LEDetonation,
+    MinefieldState(MinefieldState),
+    MinefieldQuery(MinefieldQuery),
+    MinefieldData(MinefieldData),
+    MinefieldResponseNACK(MinefieldResponseNack),
+    EnvironmentalProcess(EnvironmentalProcess),
+    GriddedData(GriddedData),
+    PointObjectState(PointObjectState),
+    LinearObjectState(LinearObjectState),
+    ArealObjectState(ArealObjectState),
+    TSPI(Tspi),
+    Appearance(Appearance),
+    ArticulatedParts(ArticulatedParts),
+    LEFire(LeFire),
+    LEDetonation(LeDetonation),
     CreateEntityR(CreateEntityR),
     RemoveEntityR(RemoveEntityR),
     StartResumeR(StartResumeR),
@@ -251,10 +274,10 @@ pub enum PduBody {
     RecordQueryR(RecordQueryR),
     CollisionElastic(CollisionElastic),
     EntityStateUpdate(EntityStateUpdate),
-    DirectedEnergyFire,
-    EntityDamageStatus,
-    InformationOperationsAction,
-    InformationOperationsReport,
+    DirectedEnergyFire(DirectedEnergyFire),
+    EntityDamageStatus(EntityDamageStatus),
+    InformationOperationsAction(InformationOperationsAction),
+    InformationOperationsReport(InformationOperationsReport),
     Attribute(Attribute),
 }
 
@@ -293,26 +316,26 @@ fn body_length(&self) -> u16 {
             PduBody::IFF(body) => body.body_length(),
             PduBody::UnderwaterAcoustic(body) => body.body_length(),
             PduBody::SupplementalEmissionEntityState(body) => body.body_length(),
-            PduBody::IntercomSignal => 0,
-            PduBody::IntercomControl => 0,
+            PduBody::IntercomSignal(body) => body.body_length(),
+            PduBody::IntercomControl(body) => body.body_length(),
             PduBody::AggregateState(body) => body.body_length(),
             PduBody::IsGroupOf(body) => body.body_length(),
             PduBody::TransferOwnership(body) => body.body_length(),
             PduBody::IsPartOf(body) => body.body_length(),
-            PduBody::MinefieldState => 0,
-            PduBody::MinefieldQuery => 0,
-            PduBody::MinefieldData => 0,
-            PduBody::MinefieldResponseNACK => 0,
-            PduBody::EnvironmentalProcess => 0,
-            PduBody::GriddedData => 0,
-            PduBody::PointObjectState => 0,
-            PduBody::LinearObjectState => 0,
-            PduBody::ArealObjectState => 0,
-            PduBody::TSPI => 0,
-            PduBody::Appearance => 0,
-            PduBody::ArticulatedParts => 0,
-            PduBody::LEFire => 0,
-            PduBody::LEDetonation => 0,
+            PduBody::MinefieldState(body) => body.body_length(),
+            PduBody::MinefieldQuery(body) => body.body_length(),
+            PduBody::MinefieldData(body) => body.body_length(),
+            PduBody::MinefieldResponseNACK(body) => body.body_length(),
+            PduBody::EnvironmentalProcess(body) => body.body_length(),
+            PduBody::GriddedData(body) => body.body_length(),
+            PduBody::PointObjectState(body) => body.body_length(),
+            PduBody::LinearObjectState(body) => body.body_length(),
+            PduBody::ArealObjectState(body) => body.body_length(),
+            PduBody::TSPI(body) => body.body_length(),
+            PduBody::Appearance(body) => body.body_length(),
+            PduBody::ArticulatedParts(body) => body.body_length(),
+            PduBody::LEFire(body) => body.body_length(),
+            PduBody::LEDetonation(body) => body.body_length(),
             PduBody::CreateEntityR(body) => body.body_length(),
             PduBody::RemoveEntityR(body) => body.body_length(),
             PduBody::StartResumeR(body) => body.body_length(),
@@ -330,10 +353,10 @@ fn body_length(&self) -> u16 {
             PduBody::RecordQueryR(body) => body.body_length(),
             PduBody::CollisionElastic(body) => body.body_length(),
             PduBody::EntityStateUpdate(body) => body.body_length(),
-            PduBody::DirectedEnergyFire => 0,
-            PduBody::EntityDamageStatus => 0,
-            PduBody::InformationOperationsAction => 0,
-            PduBody::InformationOperationsReport => 0,
+            PduBody::DirectedEnergyFire(body) => body.body_length(),
+            PduBody::EntityDamageStatus(body) => body.body_length(),
+            PduBody::InformationOperationsAction(body) => body.body_length(),
+            PduBody::InformationOperationsReport(body) => body.body_length(),
             PduBody::Attribute(body) => body.body_length(),
         }
     }
@@ -371,26 +394,26 @@ fn body_type(&self) -> PduType {
             PduBody::IFF(body) => body.body_type(),
             PduBody::UnderwaterAcoustic(body) => body.body_type(),
             PduBody::SupplementalEmissionEntityState(body) => body.body_type(),
-            PduBody::IntercomSignal => PduType::IntercomSignal,
-            PduBody::IntercomControl => PduType::IntercomControl,
+            PduBody::IntercomSignal(body) => body.body_type(),
+            PduBody::IntercomControl(body) => body.body_type(),
             PduBody::AggregateState(body) => body.body_type(),
             PduBody::IsGroupOf(body) => body.body_type(),
             PduBody::TransferOwnership(body) => body.body_type(),
             PduBody::IsPartOf(body) => body.body_type(),
-            PduBody::MinefieldState => PduType::MinefieldState,
-            PduBody::MinefieldQuery => PduType::MinefieldQuery,
-            PduBody::MinefieldData => PduType::MinefieldData,
-            PduBody::MinefieldResponseNACK => PduType::MinefieldResponseNACK,
-            PduBody::EnvironmentalProcess => PduType::EnvironmentalProcess,
-            PduBody::GriddedData => PduType::GriddedData,
-            PduBody::PointObjectState => PduType::PointObjectState,
-            PduBody::LinearObjectState => PduType::LinearObjectState,
-            PduBody::ArealObjectState => PduType::ArealObjectState,
-            PduBody::TSPI => PduType::TSPI,
-            PduBody::Appearance => PduType::Appearance,
-            PduBody::ArticulatedParts => PduType::ArticulatedParts,
-            PduBody::LEFire => PduType::LEFire,
-            PduBody::LEDetonation => PduType::LEDetonation,
+            PduBody::MinefieldState(body) => body.body_type(),
+            PduBody::MinefieldQuery(body) => body.body_type(),
+            PduBody::MinefieldData(body) => body.body_type(),
+            PduBody::MinefieldResponseNACK(body) => body.body_type(),
+            PduBody::EnvironmentalProcess(body) => body.body_type(),
+            PduBody::GriddedData(body) => body.body_type(),
+            PduBody::PointObjectState(body) => body.body_type(),
+            PduBody::LinearObjectState(body) => body.body_type(),
+            PduBody::ArealObjectState(body) => body.body_type(),
+            PduBody::TSPI(body) => body.body_type(),
+            PduBody::Appearance(body) => body.body_type(),
+            PduBody::ArticulatedParts(body) => body.body_type(),
+            PduBody::LEFire(body) => body.body_type(),
+            PduBody::LEDetonation(body) => body.body_type(),
             PduBody::CreateEntityR(body) => body.body_type(),
             PduBody::RemoveEntityR(body) => body.body_type(),
             PduBody::StartResumeR(body) => body.body_type(),
@@ -408,10 +431,10 @@ fn body_type(&self) -> PduType {
             PduBody::RecordQueryR(body) => body.body_type(),
             PduBody::CollisionElastic(body) => body.body_type(),
             PduBody::EntityStateUpdate(body) => body.body_type(),
-            PduBody::DirectedEnergyFire => PduType::DirectedEnergyFire,
-            PduBody::EntityDamageStatus => PduType::EntityDamageStatus,
-            PduBody::InformationOperationsAction => PduType::InformationOperationsAction,
-            PduBody::InformationOperationsReport => PduType::InformationOperationsReport,
+            PduBody::DirectedEnergyFire(body) => body.body_type(),
+            PduBody::EntityDamageStatus(body) => body.body_type(),
+            PduBody::InformationOperationsAction(body) => body.body_type(),
+            PduBody::InformationOperationsReport(body) => body.body_type(),
             PduBody::Attribute(body) => body.body_type(),
         }
     }
@@ -452,26 +475,26 @@ fn originator(&self) -> Option<&EntityId> {
             PduBody::IFF(body) => body.originator(),
             PduBody::UnderwaterAcoustic(body) => body.originator(),
             PduBody::SupplementalEmissionEntityState(body) => body.originator(),
-            PduBody::IntercomSignal => None,
-            PduBody::IntercomControl => None,
+            PduBody::IntercomSignal(body) => body.originator(),
+            PduBody::IntercomControl(body) => body.originator(),
             PduBody::AggregateState(body) => body.originator(),
             PduBody::IsGroupOf(body) => body.originator(),
             PduBody::TransferOwnership(body) => body.originator(),
             PduBody::IsPartOf(body) => body.originator(),
-            PduBody::MinefieldState => None,
-            PduBody::MinefieldQuery => None,
-            PduBody::MinefieldData => None,
-            PduBody::MinefieldResponseNACK => None,
-            PduBody::EnvironmentalProcess => None,
-            PduBody::GriddedData => None,
-            PduBody::PointObjectState => None,
-            PduBody::LinearObjectState => None,
-            PduBody::ArealObjectState => None,
-            PduBody::TSPI => None,
-            PduBody::Appearance => None,
-            PduBody::ArticulatedParts => None,
-            PduBody::LEFire => None,
-            PduBody::LEDetonation => None,
+            PduBody::MinefieldState(body) => body.originator(),
+            PduBody::MinefieldQuery(body) => body.originator(),
+            PduBody::MinefieldData(body) => body.originator(),
+            PduBody::MinefieldResponseNACK(body) => body.originator(),
+            PduBody::EnvironmentalProcess(body) => body.originator(),
+            PduBody::GriddedData(body) => body.originator(),
+            PduBody::PointObjectState(body) => body.originator(),
+            PduBody::LinearObjectState(body) => body.originator(),
+            PduBody::ArealObjectState(body) => body.originator(),
+            PduBody::TSPI(body) => body.originator(),
+            PduBody::Appearance(body) => body.originator(),
+            PduBody::ArticulatedParts(body) => body.originator(),
+            PduBody::LEFire(body) => body.originator(),
+            PduBody::LEDetonation(body) => body.originator(),
             PduBody::CreateEntityR(body) => body.originator(),
             PduBody::RemoveEntityR(body) => body.originator(),
             PduBody::StartResumeR(body) => body.originator(),
@@ -489,10 +512,10 @@ fn originator(&self) -> Option<&EntityId> {
             PduBody::RecordQueryR(body) => body.originator(),
             PduBody::CollisionElastic(body) => body.originator(),
             PduBody::EntityStateUpdate(body) => body.originator(),
-            PduBody::DirectedEnergyFire => None,
-            PduBody::EntityDamageStatus => None,
-            PduBody::InformationOperationsAction => None,
-            PduBody::InformationOperationsReport => None,
+            PduBody::DirectedEnergyFire(body) => body.originator(),
+            PduBody::EntityDamageStatus(body) => body.originator(),
+            PduBody::InformationOperationsAction(body) => body.originator(),
+            PduBody::InformationOperationsReport(body) => body.originator(),
             PduBody::Attribute(body) => body.originator(),
         }
     }
@@ -531,26 +554,26 @@ fn receiver(&self) -> Option<&EntityId> {
             PduBody::IFF(body) => body.receiver(),
             PduBody::UnderwaterAcoustic(body) => body.receiver(),
             PduBody::SupplementalEmissionEntityState(body) => body.receiver(),
-            PduBody::IntercomSignal => None,
-            PduBody::IntercomControl => None,
+            PduBody::IntercomSignal(body) => body.receiver(),
+            PduBody::IntercomControl(body) => body.receiver(),
             PduBody::AggregateState(body) => body.receiver(),
             PduBody::IsGroupOf(body) => body.receiver(),
             PduBody::TransferOwnership(body) => body.receiver(),
             PduBody::IsPartOf(body) => body.receiver(),
-            PduBody::MinefieldState => None,
-            PduBody::MinefieldQuery => None,
-            PduBody::MinefieldData => None,
-            PduBody::MinefieldResponseNACK => None,
-            PduBody::EnvironmentalProcess => None,
-            PduBody::GriddedData => None,
-            PduBody::PointObjectState => None,
-            PduBody::LinearObjectState => None,
-            PduBody::ArealObjectState => None,
-            PduBody::TSPI => None,
-            PduBody::Appearance => None,
-            PduBody::ArticulatedParts => None,
-            PduBody::LEFire => None,
-            PduBody::LEDetonation => None,
+            PduBody::MinefieldState(body) => body.receiver(),
+            PduBody::MinefieldQuery(body) => body.receiver(),
+            PduBody::MinefieldData(body) => body.receiver(),
+            PduBody::MinefieldResponseNACK(body) => body.receiver(),
+            PduBody::EnvironmentalProcess(body) => body.receiver(),
+            PduBody::GriddedData(body) => body.receiver(),
+            PduBody::PointObjectState(body) => body.receiver(),
+            PduBody::LinearObjectState(body) => body.receiver(),
+            PduBody::ArealObjectState(body) => body.receiver(),
+            PduBody::TSPI(body) => body.receiver(),
+            PduBody::Appearance(body) => body.receiver(),
+            PduBody::ArticulatedParts(body) => body.receiver(),
+            PduBody::LEFire(body) => body.receiver(),
+            PduBody::LEDetonation(body) => body.receiver(),
             PduBody::CreateEntityR(body) => body.receiver(),
             PduBody::RemoveEntityR(body) => body.receiver(),
             PduBody::StartResumeR(body) => body.receiver(),
@@ -568,10 +591,10 @@ fn receiver(&self) -> Option<&EntityId> {
             PduBody::RecordQueryR(body) => body.receiver(),
             PduBody::CollisionElastic(body) => body.receiver(),
             PduBody::EntityStateUpdate(body) => body.receiver(),
-            PduBody::DirectedEnergyFire => None,
-            PduBody::EntityDamageStatus => None,
-            PduBody::InformationOperationsAction => None,
-            PduBody::InformationOperationsReport => None,
+            PduBody::DirectedEnergyFire(body) => body.receiver(),
+            PduBody::EntityDamageStatus(body) => body.receiver(),
+            PduBody::InformationOperationsAction(body) => body.receiver(),
+            PduBody::InformationOperationsReport(body) => body.receiver(),
             PduBody::Attribute(body) => body.receiver(),
         }
     }
@@ -703,7 +726,7 @@ impl TryFrom<&[&str]> for SimulationAddress {
     fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
         const NUM_DIGITS: usize = 2;
         if value.len() != NUM_DIGITS {
-            return Err(DisError::ParseError(format!(
+            return Err(DisError::StringFormatError(format!(
                 "SimulationAddress string pattern does not contain precisely {NUM_DIGITS} digits"
             )));
         }
@@ -712,12 +735,14 @@ fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
                 .get(0)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u16>()
-                .map_err(|_| DisError::ParseError("Invalid site id digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid site id digit".to_string()))?,
             application_id: value
                 .get(1)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u16>()
-                .map_err(|_| DisError::ParseError("Invalid application id digit".to_string()))?,
+                .map_err(|_| {
+                    DisError::StringFormatError("Invalid application id digit".to_string())
+                })?,
         })
     }
 }
@@ -749,7 +774,7 @@ fn try_from(value: String) -> Result<Self, Self::Error> {
 
 /// 6.2.28 Entity Identifier record
 /// 6.2.81 Simulation Identifier record
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EntityId {
     pub simulation_address: SimulationAddress,
@@ -814,7 +839,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         const NUM_DIGITS: usize = 3;
         let mut ss = s.split(':').collect::<Vec<&str>>();
         if ss.len() != NUM_DIGITS {
-            return Err(DisError::ParseError(format!(
+            return Err(DisError::StringFormatError(format!(
                 "EntityId string pattern does not contain precisely {NUM_DIGITS} digits"
             )));
         }
@@ -822,7 +847,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             .pop()
             .expect("Impossible - checked for correct number of digits")
             .parse::<u16>()
-            .map_err(|_| DisError::ParseError("Invalid entity id digit".to_string()))?;
+            .map_err(|_| DisError::StringFormatError("Invalid entity id digit".to_string()))?;
         Ok(Self {
             simulation_address: ss.as_slice().try_into()?,
             entity_id,
@@ -846,7 +871,7 @@ fn try_from(value: String) -> Result<Self, Self::Error> {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventId {
     pub simulation_address: SimulationAddress,
@@ -897,7 +922,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         const NUM_DIGITS: usize = 3;
         let mut ss = s.split(':').collect::<Vec<&str>>();
         if ss.len() != NUM_DIGITS {
-            return Err(DisError::ParseError(format!(
+            return Err(DisError::StringFormatError(format!(
                 "EventId string pattern does not contain precisely {NUM_DIGITS} digits"
             )));
         }
@@ -905,7 +930,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             .pop()
             .expect("Impossible - checked for correct number of digits")
             .parse::<u16>()
-            .map_err(|_| DisError::ParseError("Invalid event id digit".to_string()))?;
+            .map_err(|_| DisError::StringFormatError("Invalid event id digit".to_string()))?;
         Ok(Self {
             simulation_address: ss.as_slice().try_into()?,
             event_id,
@@ -929,6 +954,530 @@ fn try_from(value: String) -> Result<Self, Self::Error> {
     }
 }
 
+/// 6.2.56 Minefield Identifier record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldId {
+    pub simulation_address: SimulationAddress,
+    pub minefield_number: u16,
+}
+
+impl MinefieldId {
+    #[must_use]
+    pub fn new(site_id: u16, application_id: u16, minefield_number: u16) -> Self {
+        Self {
+            simulation_address: SimulationAddress {
+                site_id,
+                application_id,
+            },
+            minefield_number,
+        }
+    }
+
+    #[must_use]
+    pub fn new_sim_address(simulation_address: SimulationAddress, minefield_number: u16) -> Self {
+        Self {
+            simulation_address,
+            minefield_number,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        SIX_OCTETS as u16
+    }
+}
+
+impl Display for MinefieldId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.simulation_address, self.minefield_number)
+    }
+}
+
+/// 6.2.66 Perimeter Point Coordinate record
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerimeterPointCoordinate {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl PerimeterPointCoordinate {
+    #[must_use]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        EIGHT_OCTETS as u16
+    }
+}
+
+/// 6.2.63 Object Type record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ObjectType {
+    pub domain: PlatformDomain,
+    pub kind: ObjectKind,
+    pub category: u8,
+    pub subcategory: u8,
+}
+
+impl ObjectType {
+    #[must_use]
+    pub fn new(domain: PlatformDomain, kind: ObjectKind, category: u8, subcategory: u8) -> Self {
+        Self {
+            domain,
+            kind,
+            category,
+            subcategory,
+        }
+    }
+
+    #[must_use]
+    pub fn with_domain(mut self, domain: PlatformDomain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: ObjectKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_category(mut self, category: u8) -> Self {
+        self.category = category;
+        self
+    }
+
+    #[must_use]
+    pub fn with_subcategory(mut self, subcategory: u8) -> Self {
+        self.subcategory = subcategory;
+        self
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl Display for ObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}",
+            u8::from(self.domain),
+            u8::from(self.kind),
+            self.category,
+            self.subcategory,
+        )
+    }
+}
+
+/// Object Kind field of the Object Type record (SISO-REF-010 UID 225)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ObjectKind {
+    #[default]
+    Other, // 0
+    Obstacle,            // 1
+    PreparedPosition,    // 2
+    CulturalFeature,     // 3
+    Passageway,          // 4
+    TacticalSmoke,       // 5
+    ObstacleMarker,      // 6
+    ObstacleBreach,      // 7
+    EnvironmentalObject, // 8
+    Unspecified(u8),
+}
+
+impl From<u8> for ObjectKind {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::Obstacle,
+            2 => Self::PreparedPosition,
+            3 => Self::CulturalFeature,
+            4 => Self::Passageway,
+            5 => Self::TacticalSmoke,
+            6 => Self::ObstacleMarker,
+            7 => Self::ObstacleBreach,
+            8 => Self::EnvironmentalObject,
+            unspecified => Self::Unspecified(unspecified),
+        }
+    }
+}
+
+impl From<ObjectKind> for u8 {
+    fn from(value: ObjectKind) -> Self {
+        match value {
+            ObjectKind::Other => 0,
+            ObjectKind::Obstacle => 1,
+            ObjectKind::PreparedPosition => 2,
+            ObjectKind::CulturalFeature => 3,
+            ObjectKind::Passageway => 4,
+            ObjectKind::TacticalSmoke => 5,
+            ObjectKind::ObstacleMarker => 6,
+            ObjectKind::ObstacleBreach => 7,
+            ObjectKind::EnvironmentalObject => 8,
+            ObjectKind::Unspecified(unspecified) => unspecified,
+        }
+    }
+}
+
+/// Modifications field of the Point Object State PDU and the Areal Object State PDU,
+/// and Segment Modification field of the Linear Segment Parameter record (SISO-REF-010 UIDs 240-242)
+///
+/// The Areal Object State PDU only defines the location bit.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ObjectStateModification {
+    pub is_location_modified: bool,
+    pub is_orientation_modified: bool,
+}
+
+impl ObjectStateModification {
+    #[must_use]
+    pub fn new(is_location_modified: bool, is_orientation_modified: bool) -> Self {
+        Self {
+            is_location_modified,
+            is_orientation_modified,
+        }
+    }
+}
+
+impl From<u8> for ObjectStateModification {
+    fn from(value: u8) -> Self {
+        Self {
+            is_location_modified: (value & 0x01) != 0,
+            is_orientation_modified: ((value >> 1) & 0x01) != 0,
+        }
+    }
+}
+
+impl From<&ObjectStateModification> for u8 {
+    fn from(value: &ObjectStateModification) -> Self {
+        u8::from(value.is_location_modified) | (u8::from(value.is_orientation_modified) << 1)
+    }
+}
+
+/// General Object Appearance, the 16-bit general part of the Object Appearance of
+/// synthetic environment objects (SISO-REF-010 UID 229)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeneralObjectAppearance {
+    pub percent_complete: u8,
+    pub damage: AppearanceObjectGeneralDamage,
+    pub predistributed: AppearanceObjectGeneralPredistributed,
+    pub state: AppearanceEntityOrObjectState,
+    pub is_smoking: bool,
+    pub is_flaming: bool,
+    pub ied_present: AppearanceObjectGeneralIEDPresent,
+}
+
+impl GeneralObjectAppearance {
+    #[must_use]
+    pub fn with_percent_complete(mut self, percent_complete: u8) -> Self {
+        self.percent_complete = percent_complete;
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage(mut self, damage: AppearanceObjectGeneralDamage) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    #[must_use]
+    pub fn with_predistributed(
+        mut self,
+        predistributed: AppearanceObjectGeneralPredistributed,
+    ) -> Self {
+        self.predistributed = predistributed;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state(mut self, state: AppearanceEntityOrObjectState) -> Self {
+        self.state = state;
+        self
+    }
+
+    #[must_use]
+    pub fn with_smoking(mut self, is_smoking: bool) -> Self {
+        self.is_smoking = is_smoking;
+        self
+    }
+
+    #[must_use]
+    pub fn with_flaming(mut self, is_flaming: bool) -> Self {
+        self.is_flaming = is_flaming;
+        self
+    }
+
+    #[must_use]
+    pub fn with_ied_present(mut self, ied_present: AppearanceObjectGeneralIEDPresent) -> Self {
+        self.ied_present = ied_present;
+        self
+    }
+}
+
+impl From<u16> for GeneralObjectAppearance {
+    fn from(value: u16) -> Self {
+        Self {
+            percent_complete: (value & 0x00FF) as u8,
+            damage: AppearanceObjectGeneralDamage::from(((value >> 8) & 0x03) as u8),
+            predistributed: AppearanceObjectGeneralPredistributed::from(
+                ((value >> 10) & 0x01) as u8,
+            ),
+            state: AppearanceEntityOrObjectState::from(((value >> 11) & 0x01) as u8),
+            is_smoking: ((value >> 12) & 0x01) != 0,
+            is_flaming: ((value >> 13) & 0x01) != 0,
+            ied_present: AppearanceObjectGeneralIEDPresent::from(((value >> 14) & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&GeneralObjectAppearance> for u16 {
+    fn from(value: &GeneralObjectAppearance) -> Self {
+        let percent_complete = u16::from(value.percent_complete);
+        let damage = u16::from(u8::from(value.damage)) << 8;
+        let predistributed = u16::from(u8::from(value.predistributed)) << 10;
+        let state = u16::from(u8::from(value.state)) << 11;
+        let smoking = u16::from(value.is_smoking) << 12;
+        let flaming = u16::from(value.is_flaming) << 13;
+        let ied_present = u16::from(u8::from(value.ied_present)) << 14;
+
+        percent_complete | damage | predistributed | state | smoking | flaming | ied_present
+    }
+}
+
+/// Specific Object Appearance of objects that can be breached, such as log cribs, abatis,
+/// vehicle defilades and minefields (SISO-REF-010 UID 407 for bits 0-1)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BreachableObjectAppearance {
+    pub breach_state: AppearanceObjectSpecificBreachState,
+}
+
+impl BreachableObjectAppearance {
+    #[must_use]
+    pub fn new(breach_state: AppearanceObjectSpecificBreachState) -> Self {
+        Self { breach_state }
+    }
+}
+
+impl From<u32> for BreachableObjectAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            breach_state: AppearanceObjectSpecificBreachState::from((value & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&BreachableObjectAppearance> for u32 {
+    fn from(value: &BreachableObjectAppearance) -> Self {
+        u32::from(u8::from(value.breach_state))
+    }
+}
+
+/// Live Simulation Address record, the simulation address of a Live Entity (LE)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveSimulationAddress {
+    pub site_number: u8,
+    pub application_number: u8,
+}
+
+impl LiveSimulationAddress {
+    #[must_use]
+    pub fn new(site_number: u8, application_number: u8) -> Self {
+        Self {
+            site_number,
+            application_number,
+        }
+    }
+}
+
+impl Display for LiveSimulationAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.site_number, self.application_number)
+    }
+}
+
+/// Live Entity Identifier record, the compact entity identifier used in the Live Entity (LE) PDUs
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityId {
+    pub simulation_address: LiveSimulationAddress,
+    pub entity_number: u16,
+}
+
+impl LiveEntityId {
+    #[must_use]
+    pub fn new(site_number: u8, application_number: u8, entity_number: u16) -> Self {
+        Self {
+            simulation_address: LiveSimulationAddress::new(site_number, application_number),
+            entity_number,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl Display for LiveEntityId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.simulation_address, self.entity_number)
+    }
+}
+
+/// Live Event Identifier record, the compact event identifier used in the Live Entity (LE) PDUs
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEventId {
+    pub simulation_address: LiveSimulationAddress,
+    pub event_number: u16,
+}
+
+impl LiveEventId {
+    #[must_use]
+    pub fn new(site_number: u8, application_number: u8, event_number: u16) -> Self {
+        Self {
+            simulation_address: LiveSimulationAddress::new(site_number, application_number),
+            event_number,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl Display for LiveEventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.simulation_address, self.event_number)
+    }
+}
+
+/// Relative World Coordinates record
+///
+/// A location expressed as an offset in meters from a reference point that is agreed upon for the exercise.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeWorldCoordinates {
+    pub reference_point: u16,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+}
+
+impl RelativeWorldCoordinates {
+    #[must_use]
+    pub fn new(reference_point: u16, delta_x: i16, delta_y: i16, delta_z: i16) -> Self {
+        Self {
+            reference_point,
+            delta_x,
+            delta_y,
+            delta_z,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        EIGHT_OCTETS as u16
+    }
+}
+
+/// Live Entity Orientation record
+///
+/// Euler angles expressed as 8-bit binary angles, where the full range of the field maps to one full circle.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveOrientation {
+    pub psi: u8,
+    pub theta: u8,
+    pub phi: u8,
+}
+
+impl LiveOrientation {
+    #[must_use]
+    pub fn new(psi: u8, theta: u8, phi: u8) -> Self {
+        Self { psi, theta, phi }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        THREE_OCTETS as u16
+    }
+}
+
+/// Vector record with 16-bit signed integer components, as used by the Live Entity (LE) PDUs
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VectorI16 {
+    pub first_vector_component: i16,
+    pub second_vector_component: i16,
+    pub third_vector_component: i16,
+}
+
+impl VectorI16 {
+    #[must_use]
+    pub fn new(first: i16, second: i16, third: i16) -> Self {
+        Self {
+            first_vector_component: first,
+            second_vector_component: second,
+            third_vector_component: third,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        SIX_OCTETS as u16
+    }
+}
+
+/// Vector record with 8-bit signed integer components, as used by the Live Entity (LE) PDUs
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VectorI8 {
+    pub first_vector_component: i8,
+    pub second_vector_component: i8,
+    pub third_vector_component: i8,
+}
+
+impl VectorI8 {
+    #[must_use]
+    pub fn new(first: i8, second: i8, third: i8) -> Self {
+        Self {
+            first_vector_component: first,
+            second_vector_component: second,
+            third_vector_component: third,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn record_length(&self) -> u16 {
+        THREE_OCTETS as u16
+    }
+}
+
 /// 6.2.96 Vector record
 /// 6.2.7 Angular Velocity Vector record
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
@@ -1130,7 +1679,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         const NUM_DIGITS: usize = 7;
         let ss = s.split(':').collect::<Vec<&str>>();
         if ss.len() != NUM_DIGITS {
-            return Err(DisError::ParseError(format!(
+            return Err(DisError::StringFormatError(format!(
                 "EntityType string pattern does not contain precisely {NUM_DIGITS} digits"
             )));
         }
@@ -1139,40 +1688,42 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
                 .get(0)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid kind digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid kind digit".to_string()))?
                 .into(),
             domain: ss
                 .get(1)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid domain digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid domain digit".to_string()))?
                 .into(),
             country: ss
                 .get(2)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u16>()
-                .map_err(|_| DisError::ParseError("Invalid country digit".to_string()))?
+                .map_err(|_| DisError::StringFormatError("Invalid country digit".to_string()))?
                 .into(),
             category: ss
                 .get(3)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid category digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid category digit".to_string()))?,
             subcategory: ss
                 .get(4)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid subcategory digit".to_string()))?,
+                .map_err(|_| {
+                    DisError::StringFormatError("Invalid subcategory digit".to_string())
+                })?,
             specific: ss
                 .get(5)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid specific digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid specific digit".to_string()))?,
             extra: ss
                 .get(6)
                 .expect("Impossible - checked for correct number of digits")
                 .parse::<u8>()
-                .map_err(|_| DisError::ParseError("Invalid extra digit".to_string()))?,
+                .map_err(|_| DisError::StringFormatError("Invalid extra digit".to_string()))?,
         })
     }
 }
@@ -1814,6 +2365,86 @@ pub fn with_records(mut self, records: Vec<Vec<u8>>) -> Self {
     }
 }
 
+/// Default instances of every `PduBody` variant, for tests that cover all PDU types
+#[cfg(test)]
+pub(crate) fn default_bodies() -> Vec<PduBody> {
+    vec![
+        PduBody::Other(Other::default()),
+        PduBody::EntityState(EntityState::default()),
+        PduBody::Fire(Fire::default()),
+        PduBody::Detonation(Detonation::default()),
+        PduBody::Collision(Collision::default()),
+        PduBody::ServiceRequest(ServiceRequest::default()),
+        PduBody::ResupplyOffer(ResupplyOffer::default()),
+        PduBody::ResupplyReceived(ResupplyReceived::default()),
+        PduBody::ResupplyCancel(ResupplyCancel::default()),
+        PduBody::RepairComplete(RepairComplete::default()),
+        PduBody::RepairResponse(RepairResponse::default()),
+        PduBody::CreateEntity(CreateEntity::default()),
+        PduBody::RemoveEntity(RemoveEntity::default()),
+        PduBody::StartResume(StartResume::default()),
+        PduBody::StopFreeze(StopFreeze::default()),
+        PduBody::Acknowledge(Acknowledge::default()),
+        PduBody::ActionRequest(ActionRequest::default()),
+        PduBody::ActionResponse(ActionResponse::default()),
+        PduBody::DataQuery(DataQuery::default()),
+        PduBody::SetData(SetData::default()),
+        PduBody::Data(Data::default()),
+        PduBody::EventReport(EventReport::default()),
+        PduBody::Comment(Comment::default()),
+        PduBody::ElectromagneticEmission(ElectromagneticEmission::default()),
+        PduBody::Designator(Designator::default()),
+        PduBody::Transmitter(Transmitter::default()),
+        PduBody::Signal(Signal::default()),
+        PduBody::Receiver(Receiver::default()),
+        PduBody::IFF(Iff::default()),
+        PduBody::UnderwaterAcoustic(UnderwaterAcoustic::default()),
+        PduBody::SupplementalEmissionEntityState(SEES::default()),
+        PduBody::IntercomSignal(IntercomSignal::default()),
+        PduBody::IntercomControl(IntercomControl::default()),
+        PduBody::AggregateState(AggregateState::default()),
+        PduBody::IsGroupOf(IsGroupOf::default()),
+        PduBody::TransferOwnership(TransferOwnership::default()),
+        PduBody::IsPartOf(IsPartOf::default()),
+        PduBody::MinefieldState(MinefieldState::default()),
+        PduBody::MinefieldQuery(MinefieldQuery::default()),
+        PduBody::MinefieldData(MinefieldData::default()),
+        PduBody::MinefieldResponseNACK(MinefieldResponseNack::default()),
+        PduBody::EnvironmentalProcess(EnvironmentalProcess::default()),
+        PduBody::GriddedData(GriddedData::default()),
+        PduBody::PointObjectState(PointObjectState::default()),
+        PduBody::LinearObjectState(LinearObjectState::default()),
+        PduBody::ArealObjectState(ArealObjectState::default()),
+        PduBody::TSPI(Tspi::default()),
+        PduBody::Appearance(Appearance::default()),
+        PduBody::ArticulatedParts(ArticulatedParts::default()),
+        PduBody::LEFire(LeFire::default()),
+        PduBody::LEDetonation(LeDetonation::default()),
+        PduBody::CreateEntityR(CreateEntityR::default()),
+        PduBody::RemoveEntityR(RemoveEntityR::default()),
+        PduBody::StartResumeR(StartResumeR::default()),
+        PduBody::StopFreezeR(StopFreezeR::default()),
+        PduBody::AcknowledgeR(AcknowledgeR::default()),
+        PduBody::ActionRequestR(ActionRequestR::default()),
+        PduBody::ActionResponseR(ActionResponseR::default()),
+        PduBody::DataQueryR(DataQueryR::default()),
+        PduBody::SetDataR(SetDataR::default()),
+        PduBody::DataR(DataR::default()),
+        PduBody::EventReportR(EventReportR::default()),
+        PduBody::CommentR(CommentR::default()),
+        PduBody::RecordR(RecordR::default()),
+        PduBody::SetRecordR(SetRecordR::default()),
+        PduBody::RecordQueryR(RecordQueryR::default()),
+        PduBody::CollisionElastic(CollisionElastic::default()),
+        PduBody::EntityStateUpdate(EntityStateUpdate::default()),
+        PduBody::DirectedEnergyFire(DirectedEnergyFire::default()),
+        PduBody::EntityDamageStatus(EntityDamageStatus::default()),
+        PduBody::InformationOperationsAction(InformationOperationsAction::default()),
+        PduBody::InformationOperationsReport(InformationOperationsReport::default()),
+        PduBody::Attribute(Attribute::default()),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1866,14 +2497,14 @@ fn entity_type_from_str() {
         assert_eq!(EntityType::from_str(ENTITY_TYPE_STR).unwrap(), ENTITY_TYPE);
         let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityType string pattern does not contain precisely 7 digits"
         );
         let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID_EXTRA);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
     }
 
@@ -1881,7 +2512,7 @@ fn entity_type_from_str() {
     fn entity_type_from_str_not_seven_digits() {
         let err = EntityType::from_str(ENTITY_TYPE_STR_NOT_SEVEN_DIGITS);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityType string pattern does not contain precisely 7 digits"
@@ -1896,14 +2527,14 @@ fn entity_type_try_from_str() {
         );
         let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityType string pattern does not contain precisely 7 digits"
         );
         let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
     }
 
@@ -1915,14 +2546,14 @@ fn entity_type_try_from_string() {
         );
         let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityType string pattern does not contain precisely 7 digits"
         );
         let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
     }
 
@@ -1939,14 +2570,14 @@ fn simulation_address_from_str() {
         );
         let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "SimulationAddress string pattern does not contain precisely 2 digits"
         );
         let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
     }
 
@@ -1954,7 +2585,7 @@ fn simulation_address_from_str() {
     fn simulation_address_from_str_not_two_digits() {
         let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_NOT_TWO_DIGITS);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "SimulationAddress string pattern does not contain precisely 2 digits"
@@ -1969,7 +2600,7 @@ fn simulation_address_try_from_str() {
         );
         let err = TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "SimulationAddress string pattern does not contain precisely 2 digits"
@@ -1977,7 +2608,7 @@ fn simulation_address_try_from_str() {
         let err =
             TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
     }
 
@@ -1990,7 +2621,7 @@ fn simulation_address_try_from_string() {
         let err =
             TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "SimulationAddress string pattern does not contain precisely 2 digits"
@@ -1999,7 +2630,7 @@ fn simulation_address_try_from_string() {
             SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID.to_string(),
         );
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
     }
 
@@ -2013,14 +2644,14 @@ fn entity_id_from_str() {
         assert_eq!(EntityId::from_str(ENTITY_ID_STR).unwrap(), ENTITY_ID);
         let err = EntityId::from_str(ENTITY_ID_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityId string pattern does not contain precisely 3 digits"
         );
         let err = EntityId::from_str(ENTITY_ID_STR_INVALID_ENTITY_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid entity id digit");
     }
 
@@ -2028,7 +2659,7 @@ fn entity_id_from_str() {
     fn entity_id_from_str_not_three_digits() {
         let err = EntityId::from_str(ENTITY_ID_STR_NOT_THREE_DIGITS);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityId string pattern does not contain precisely 3 digits"
@@ -2043,14 +2674,14 @@ fn entity_id_try_from_str() {
         );
         let err = TryInto::<EntityId>::try_into(ENTITY_ID_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityId string pattern does not contain precisely 3 digits"
         );
         let err = TryInto::<EntityId>::try_into(ENTITY_ID_STR_INVALID_ENTITY_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid entity id digit");
     }
 
@@ -2062,14 +2693,14 @@ fn entity_id_try_from_string() {
         );
         let err = TryInto::<EntityId>::try_into(ENTITY_ID_STR_INVALID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EntityId string pattern does not contain precisely 3 digits"
         );
         let err = TryInto::<EntityId>::try_into(ENTITY_ID_STR_INVALID_ENTITY_ID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid entity id digit");
     }
 
@@ -2083,14 +2714,14 @@ fn event_id_from_str() {
         assert_eq!(EventId::from_str(EVENT_ID_STR).unwrap(), EVENT_ID);
         let err = EventId::from_str(EVENT_ID_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EventId string pattern does not contain precisely 3 digits"
         );
         let err = EventId::from_str(EVENT_ID_STR_INVALID_EVENT_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid event id digit");
     }
 
@@ -2098,7 +2729,7 @@ fn event_id_from_str() {
     fn event_id_from_str_not_three_digits() {
         let err = EventId::from_str(EVENT_ID_STR_NOT_THREE_DIGITS);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EventId string pattern does not contain precisely 3 digits"
@@ -2113,14 +2744,14 @@ fn event_id_try_from_str() {
         );
         let err = TryInto::<EventId>::try_into(EVENT_ID_STR_INVALID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EventId string pattern does not contain precisely 3 digits"
         );
         let err = TryInto::<EventId>::try_into(EVENT_ID_STR_INVALID_EVENT_ID);
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid event id digit");
     }
 
@@ -2132,14 +2763,14 @@ fn event_id_try_from_string() {
         );
         let err = TryInto::<EventId>::try_into(EVENT_ID_STR_INVALID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(
             err.unwrap_err().to_string(),
             "EventId string pattern does not contain precisely 3 digits"
         );
         let err = TryInto::<EventId>::try_into(EVENT_ID_STR_INVALID_EVENT_ID.to_string());
         assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert!(matches!(err, Err(DisError::StringFormatError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid event id digit");
     }
 }
@@ -1,12 +1,12 @@
 use crate::BodyRaw;
 use crate::common::model::{EntityId, PduBody, PduHeader};
 use crate::common::other::model::Other;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::constants::PDU_HEADER_LEN_BYTES;
 use crate::enumerations::PduType;
+use nom::Parser;
 use nom::bytes::complete::take;
 use nom::combinator::peek;
-use nom::{IResult, Parser};
 
 pub(crate) fn other_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
@@ -27,7 +27,8 @@ pub(crate) fn other_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8],
             | PduType::RemoveEntityR
             | PduType::AggregateState
             | PduType::IFF => {
-                let (input, originating) = peek_originating_field(input)?;
+                let (input, originating) =
+                    context("Originating", peek_originating_field).parse(input)?;
                 (input, Some(originating), None)
             }
             // PDUs with both an origin and a receiver
@@ -98,7 +99,7 @@ pub(crate) fn other_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8],
         };
 
         let body_length_bytes = header.pdu_length.saturating_sub(PDU_HEADER_LEN_BYTES);
-        let (input, body) = take(body_length_bytes)(input)?;
+        let (input, body) = context("Body", take(body_length_bytes)).parse(input)?;
         let inner_body = body.to_vec();
         let body = Other::builder()
             .with_body(inner_body)
@@ -111,12 +112,12 @@ pub(crate) fn other_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8],
 }
 
 fn peek_originating_field(input: &[u8]) -> IResult<&[u8], EntityId> {
-    let (input, originating_id) = peek(entity_id).parse(input)?;
+    let (input, originating_id) = context("Originating ID", peek(entity_id)).parse(input)?;
     Ok((input, originating_id))
 }
 
 fn peek_originating_receiving_fields(input: &[u8]) -> IResult<&[u8], (EntityId, EntityId)> {
-    let (input, fields) = peek((entity_id, entity_id)).parse(input)?;
+    let (input, fields) = context("Fields", peek((entity_id, entity_id))).parse(input)?;
     Ok((input, fields))
 }
 
@@ -3,9 +3,13 @@
 use crate::action_response_r::parser::action_response_r_body;
 use crate::aggregate_state::parser::aggregate_state_body;
 use crate::comment_r::parser::comment_r_body;
+use crate::common::ParsedPdu;
 use crate::common::acknowledge::parser::acknowledge_body;
 use crate::common::action_request::parser::action_request_body;
 use crate::common::action_response::parser::action_response_body;
+use crate::common::appearance::parser::appearance_body;
+use crate::common::areal_object_state::parser::areal_object_state_body;
+use crate::common::articulated_parts::parser::articulated_parts_body;
 use crate::common::attribute::parser::attribute_body;
 use crate::common::collision::parser::collision_body;
 use crate::common::collision_elastic::parser::collision_elastic_body;
@@ -15,21 +19,39 @@
 use crate::common::data_query::parser::data_query_body;
 use crate::common::designator::parser::designator_body;
 use crate::common::detonation::parser::detonation_body;
+use crate::common::directed_energy_fire::parser::directed_energy_fire_body;
 use crate::common::electromagnetic_emission::parser::emission_body;
+use crate::common::entity_damage_status::parser::entity_damage_status_body;
 use crate::common::entity_state::parser::entity_state_body;
 use crate::common::entity_state_update::parser::entity_state_update_body;
-use crate::common::errors::DisError;
+use crate::common::environmental_process::parser::environmental_process_body;
+use crate::common::errors::{DisError, RecordError};
 use crate::common::event_report::parser::event_report_body;
 use crate::common::fire::parser::fire_body;
+use crate::common::gridded_data::parser::gridded_data_body;
 use crate::common::iff::parser::iff_body;
+use crate::common::information_operations_action::parser::information_operations_action_body;
+use crate::common::information_operations_report::parser::information_operations_report_body;
+use crate::common::intercom_control::parser::intercom_control_body;
+use crate::common::intercom_signal::parser::intercom_signal_body;
+use crate::common::le_detonation::parser::le_detonation_body;
+use crate::common::le_fire::parser::le_fire_body;
+use crate::common::linear_object_state::parser::linear_object_state_body;
+use crate::common::minefield_data::parser::minefield_data_body;
+use crate::common::minefield_query::parser::minefield_query_body;
+use crate::common::minefield_response_nack::parser::minefield_response_nack_body;
+use crate::common::minefield_state::parser::minefield_state_body;
 use crate::common::model::{
     ArticulatedPart, AttachedPart, BeamData, ClockTime, DatumSpecification,
     EntityAssociationParameter, EntityId, EntityType, EntityTypeParameter, EventId,
-    ExpendableDescriptor, ExplosionDescriptor, FixedDatum, Location, MunitionDescriptor,
-    Orientation, Pdu, PduBody, PduHeader, SeparationParameter, SimulationAddress, Timestamp,
-    VariableDatum, VariableParameter, VectorF32, length_padded_to_num,
+    ExpendableDescriptor, ExplosionDescriptor, FixedDatum, LiveEntityId, LiveEventId,
+    LiveOrientation, LiveSimulationAddress, Location, MinefieldId, MunitionDescriptor, ObjectKind,
+    ObjectType, Orientation, Pdu, PduBody, PduHeader, PerimeterPointCoordinate,
+    RelativeWorldCoordinates, SeparationParameter, SimulationAddress, Timestamp, VariableDatum,
+    VariableParameter, VectorF32, VectorI8, VectorI16, length_padded_to_num,
 };
 use crate::common::other::parser::other_body;
+use crate::common::point_object_state::parser::point_object_state_body;
 use crate::common::receiver::parser::receiver_body;
 use crate::common::remove_entity::parser::remove_entity_body;
 use crate::common::set_data::parser::set_data_body;
@@ -37,12 +59,15 @@
 use crate::common::start_resume::parser::start_resume_body;
 use crate::common::stop_freeze::parser::stop_freeze_body;
 use crate::common::transmitter::parser::transmitter_body;
+use crate::common::tspi::parser::tspi_body;
 use crate::constants::{
     EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES,
 };
 use crate::create_entity_r::parser::create_entity_r_body;
 use crate::data_query_r::parser::data_query_r_body;
 use crate::data_r::parser::data_r_body;
+#[cfg(feature = "dissector")]
+use crate::dissector;
 use crate::enumerations::{
     ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator,
     AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus,
@@ -76,102 +101,212 @@
 use crate::transfer_ownership::parser::transfer_ownership_body;
 use crate::underwater_acoustic::parser::underwater_acoustic_body;
 use crate::v7::parser::parse_pdu_status;
-use nom::IResult;
 use nom::bytes::complete::take;
 use nom::combinator::peek;
 use nom::error::ErrorKind::Eof;
 use nom::multi::{count, many1};
-use nom::number::complete::{be_f32, be_f64, be_i32, be_u8, be_u16, be_u32, be_u64};
+use nom::number::complete::{be_f32, be_f64, be_i8, be_i16, be_i32, be_u8, be_u16, be_u32, be_u64};
 use nom::{Err, Parser};
+#[cfg(feature = "dissector")]
+use std::fmt::Debug;
 
+/// Result type of the nom parsers in the crate, using [`RecordError`] to locate parse failures.
+pub(crate) type IResult<I, O> = nom::IResult<I, O, RecordError<I>>;
+
+#[cfg(not(feature = "dissector"))]
+pub(crate) use nom::error::context;
+
+/// Names the record parsed by `parser` in parse errors, like `nom::error::context`.
+///
+/// The span and decoded value of the record are also reported to the [`dissector`] while it is
+/// dissecting a PDU.
+#[cfg(feature = "dissector")]
+pub(crate) fn context<'a, O, F>(
+    record: &'static str,
+    parser: F,
+) -> impl Parser<&'a [u8], Output = O, Error = RecordError<&'a [u8]>>
+where
+    O: Debug,
+    F: Parser<&'a [u8], Output = O, Error = RecordError<&'a [u8]>>,
+{
+    field(record, nom::error::context(record, parser))
+}
+
+/// Without the `dissector` feature, naming a field for the dissector has no effect.
+#[cfg(not(feature = "dissector"))]
+pub(crate) fn field<F>(_name: &'static str, parser: F) -> F {
+    parser
+}
+
+/// Names a field of a shared record (such as the Site ID of an Entity ID) for the dissector only.
+/// Parse errors keep naming the record, which carries the role of the record in the PDU.
+#[cfg(feature = "dissector")]
+pub(crate) fn field<'a, O, F>(
+    name: &'static str,
+    mut parser: F,
+) -> impl Parser<&'a [u8], Output = O, Error = RecordError<&'a [u8]>>
+where
+    O: Debug,
+    F: Parser<&'a [u8], Output = O, Error = RecordError<&'a [u8]>>,
+{
+    move |input: &'a [u8]| {
+        if !dissector::is_recording() {
+            return parser.parse(input);
+        }
+        dissector::enter_record();
+        let result = parser.parse(input);
+        dissector::exit_record(name, input, &result);
+        result
+    }
+}
+
+/// Parses as many PDUs from the input as possible, stopping at the first PDU that fails to parse.
+/// Returns an error only when not a single PDU could be parsed.
 pub(crate) fn parse_multiple_pdu(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    match many1(pdu).parse(input) {
-        Ok((_, pdus)) => Ok(pdus),
-        Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
+    let mut pdus = Vec::new();
+    let mut input = input;
+    loop {
+        match pdu(input) {
+            Ok((remainder, pdu)) => {
+                pdus.push(pdu);
+                input = remainder;
+            }
+            Err(err) if pdus.is_empty() => return Err(err),
+            Err(_) => return Ok(pdus),
+        }
     }
 }
 
-#[allow(dead_code)]
-pub(crate) fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
-    match pdu(input) {
-        Ok((_, pdu)) => Ok(pdu),
-        Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
+/// Parses each PDU in the input separately, returning a result and the byte offset for every PDU.
+/// A PDU of which the body fails to parse does not affect the other PDUs in the input.
+/// Parsing stops when a PDU cannot be framed, i.e. the header is invalid or the input is too short.
+pub(crate) fn parse_multiple_pdu_results(input: &[u8]) -> Vec<ParsedPdu> {
+    let mut results = Vec::new();
+    let mut offset = 0;
+    while offset < input.len() {
+        match split_pdu(&input[offset..]) {
+            Ok((_, bytes, _)) => {
+                results.push(ParsedPdu {
+                    offset,
+                    result: parse_pdu(bytes),
+                });
+                offset += bytes.len();
+            }
+            Err(err) => {
+                results.push(ParsedPdu {
+                    offset,
+                    result: Err(err),
+                });
+                break;
+            }
+        }
     }
+    results
+}
+
+/// Parses the header of the first PDU in the input, and splits the input into the bytes of that PDU and the remainder.
+pub(crate) fn split_pdu(input: &[u8]) -> Result<(PduHeader, &[u8], &[u8]), DisError> {
+    let (_, header) = pdu_header(input).map_err(|err| header_error(input, &err))?;
+
+    let pdu_length = usize::from(header.pdu_length);
+    if pdu_length < PDU_HEADER_LEN_BYTES as usize {
+        return Err(DisError::InvalidPduLength(header.pdu_length));
+    }
+    if input.len() < pdu_length {
+        return Err(DisError::InsufficientPduLength(
+            header.pdu_length - PDU_HEADER_LEN_BYTES,
+            (input.len() - PDU_HEADER_LEN_BYTES as usize) as u16,
+        ));
+    }
+
+    let (bytes, remainder) = input.split_at(pdu_length);
+    Ok((header, bytes, remainder))
+}
+
+pub(crate) fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
+    pdu(input).map(|(_, pdu)| pdu)
 }
 
 #[allow(dead_code)]
 pub(crate) fn parse_multiple_header(input: &[u8]) -> Result<Vec<PduHeader>, DisError> {
     match many1(pdu_header_skip_body).parse(input) {
         Ok((_, headers)) => Ok(headers),
-        Err(parse_error) => {
-            if let Err::Error(ref error) = parse_error
-                && error.code == Eof
-            {
-                return Err(DisError::InsufficientHeaderLength(input.len() as u16));
-            }
-            Err(DisError::ParseError(parse_error.to_string()))
-        }
+        Err(parse_error) => Err(header_error(input, &parse_error)),
     }
 }
 
 /// Parse the input for a PDU header, and skip the rest of the pdu body in the input
 #[allow(dead_code)]
 pub(crate) fn parse_header(input: &[u8]) -> Result<PduHeader, DisError> {
-    match pdu_header(input) {
-        Ok((input, header)) => {
-            let skipped = skip_body(header.pdu_length)(input); // Discard the body
-            if let Err(Err::Error(error)) = skipped {
-                return if error.code == Eof {
-                    Err(DisError::InsufficientPduLength(
-                        header.pdu_length - PDU_HEADER_LEN_BYTES,
-                        input.len() as u16,
-                    ))
-                } else {
-                    Err(DisError::ParseError(
-                        "ParseError while parsing a pdu header and skipping body.".to_string(),
-                    ))
-                };
-            }
-            Ok(header)
-        }
-        Err(parse_error) => {
-            if let Err::Error(ref error) = parse_error
-                && error.code == Eof
-            {
-                return Err(DisError::InsufficientHeaderLength(input.len() as u16));
-            }
-            Err(DisError::ParseError(parse_error.to_string()))
-        }
+    let (body, header) = pdu_header(input).map_err(|err| header_error(input, &err))?;
+    if skip_body(header.pdu_length)(body).is_err() {
+        return Err(DisError::InsufficientPduLength(
+            header.pdu_length - PDU_HEADER_LEN_BYTES,
+            body.len() as u16,
+        ));
+    }
+    Ok(header)
+}
+
+/// Maps a parser error for the PDU header at the start of `input` to a `DisError`.
+fn header_error(input: &[u8], err: &Err<RecordError<&[u8]>>) -> DisError {
+    match err {
+        Err::Error(error) | Err::Failure(error) if error.kind != Eof => DisError::MalformedHeader {
+            offset: input.len() - error.input.len(),
+            record: error.record.unwrap_or("PDU Header"),
+            kind: error.kind,
+        },
+        _ => DisError::InsufficientHeaderLength(input.len() as u16),
+    }
+}
+
+/// Maps a parser error for the body of the PDU at the start of `input` to a `DisError`.
+/// The offset of the error is relative to the start of the PDU.
+fn body_error(input: &[u8], header: &PduHeader, err: &Err<RecordError<&[u8]>>) -> DisError {
+    let (offset, record, kind) = match err {
+        Err::Error(error) | Err::Failure(error) => (
+            input.len() - error.input.len(),
+            error.record.unwrap_or("PDU body"),
+            error.kind,
+        ),
+        Err::Incomplete(_) => (input.len(), "PDU body", Eof),
+    };
+    let found = input.len().saturating_sub(PDU_HEADER_LEN_BYTES as usize);
+    DisError::MalformedPdu {
+        pdu_type: header.pdu_type,
+        protocol_version: header.protocol_version,
+        offset,
+        record,
+        kind,
+        expected: header.pdu_length.saturating_sub(PDU_HEADER_LEN_BYTES),
+        found: u16::try_from(found).unwrap_or(u16::MAX),
     }
 }
 
+/// Parses the PDU at the start of the input, returning the remaining input.
 #[cfg_attr(
     all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
     hotpath::measure
 )]
-fn pdu(input: &[u8]) -> IResult<&[u8], Pdu> {
-    // parse the header
-    let (input, header) = pdu_header(input)?;
-
-    // if (header.pdu_length - PDU_HEADER_LEN_BYTES) as usize > input.len() {
-    //     // FIXME signal correct sort of error when the input is too small for the indicated PDU length
-    //     return nom::error::make_error(input, nom::error::ErrorKind::Eof);
-    // }
+fn pdu(input: &[u8]) -> Result<(&[u8], Pdu), DisError> {
+    let (body, header) = pdu_header(input).map_err(|err| header_error(input, &err))?;
+    let (remainder, body) =
+        pdu_body(&header)(body).map_err(|err| body_error(input, &header, &err))?;
 
-    // parse the body based on the type
-    // and produce the final pdu combined with the header
-    let (input, body) = pdu_body(&header)(input)?;
+    Ok((remainder, Pdu { header, body }))
+}
 
-    Ok((input, Pdu { header, body }))
+pub(crate) fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
+    context("PDU Header", pdu_header_fields).parse(input)
 }
 
-fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
-    let protocol_version = protocol_version;
-    let exercise_id = be_u8;
-    let pdu_type = pdu_type;
-    let protocol_family = protocol_family;
-    let timestamp = timestamp;
-    let pdu_length = be_u16;
+fn pdu_header_fields(input: &[u8]) -> IResult<&[u8], PduHeader> {
+    let protocol_version = context("Protocol Version", protocol_version);
+    let exercise_id = context("Exercise ID", be_u8);
+    let pdu_type = context("PDU Type", pdu_type);
+    let protocol_family = context("Protocol Family", protocol_family);
+    let timestamp = context("Timestamp", timestamp);
+    let pdu_length = context("Length", be_u16);
 
     let (input, (protocol_version, exercise_id, pdu_type, protocol_family, timestamp, pdu_length)) =
         (
@@ -185,11 +320,11 @@ fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
             .parse(input)?;
     let (input, pdu_status, padding) = match u8::from(protocol_version) {
         legacy_version if (1..=5).contains(&legacy_version) => {
-            let (input, padding) = be_u16(input)?;
+            let (input, padding) = context("Padding", be_u16).parse(input)?;
             (input, None, padding)
         }
         6 => {
-            let (input, padding) = be_u16(input)?;
+            let (input, padding) = context("Padding", be_u16).parse(input)?;
             (input, None, padding)
         }
         7 => {
@@ -224,7 +359,7 @@ fn pdu_header_skip_body(input: &[u8]) -> IResult<&[u8], PduHeader> {
     Ok((input, header))
 }
 
-fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
+pub(crate) fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
         // parse the body of the PDU based on the type
         // NOTE only processes supported PduTypes; process others as 'Other'
@@ -261,26 +396,26 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::IFF => iff_body(input)?,
             PduType::UnderwaterAcoustic => underwater_acoustic_body(input)?,
             PduType::SupplementalEmissionEntityState => sees_body(input)?,
-            // PduType::IntercomSignal => {}
-            // PduType::IntercomControl => {}
+            PduType::IntercomSignal => intercom_signal_body(input)?,
+            PduType::IntercomControl => intercom_control_body(input)?,
             PduType::AggregateState => aggregate_state_body(input)?,
             PduType::IsGroupOf => is_group_of_body(input)?,
             PduType::TransferOwnership => transfer_ownership_body(input)?,
             PduType::IsPartOf => is_part_of_body(input)?,
-            // PduType::MinefieldState => {}
-            // PduType::MinefieldQuery => {}
-            // PduType::MinefieldData => {}
-            // PduType::MinefieldResponseNACK => {}
-            // PduType::EnvironmentalProcess => {}
-            // PduType::GriddedData => {}
-            // PduType::PointObjectState => {}
-            // PduType::LinearObjectState => {}
-            // PduType::ArealObjectState => {}
-            // PduType::TSPI => {}
-            // PduType::Appearance => {}
-            // PduType::ArticulatedParts => {}
-            // PduType::LEFire => {}
-            // PduType::LEDetonation => {}
+            PduType::MinefieldState => minefield_state_body(input)?,
+            PduType::MinefieldQuery => minefield_query_body(input)?,
+            PduType::MinefieldData => minefield_data_body(input)?,
+            PduType::MinefieldResponseNACK => minefield_response_nack_body(input)?,
+            PduType::EnvironmentalProcess => environmental_process_body(input)?,
+            PduType::GriddedData => gridded_data_body(input)?,
+            PduType::PointObjectState => point_object_state_body(input)?,
+            PduType::LinearObjectState => linear_object_state_body(input)?,
+            PduType::ArealObjectState => areal_object_state_body(input)?,
+            PduType::TSPI => tspi_body(input)?,
+            PduType::Appearance => appearance_body(input)?,
+            PduType::ArticulatedParts => articulated_parts_body(input)?,
+            PduType::LEFire => le_fire_body(input)?,
+            PduType::LEDetonation => le_detonation_body(input)?,
             PduType::CreateEntityR => create_entity_r_body(input)?,
             PduType::RemoveEntityR => remove_entity_r_body(input)?,
             PduType::StartResumeR => start_resume_r_body(input)?,
@@ -298,13 +433,12 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::RecordQueryR => record_query_r_body(input)?,
             PduType::CollisionElastic => collision_elastic_body(input)?,
             PduType::EntityStateUpdate => entity_state_update_body(input)?,
-            // PduType::DirectedEnergyFire => {}
-            // PduType::EntityDamageStatus => {}
-            // PduType::InformationOperationsAction => {}
-            // PduType::InformationOperationsReport => {}
+            PduType::DirectedEnergyFire => directed_energy_fire_body(input)?,
+            PduType::EntityDamageStatus => entity_damage_status_body(input)?,
+            PduType::InformationOperationsAction => information_operations_action_body(input)?,
+            PduType::InformationOperationsReport => information_operations_report_body(input)?,
             PduType::Attribute => attribute_body(input)?,
             PduType::Unspecified(_type_number) => other_body(header)(input)?, // TODO Log unsupported type number?
-            _ => other_body(header)(input)?,
         };
         Ok((input, body))
     }
@@ -315,7 +449,7 @@ pub(crate) fn parse_peek_protocol_version(input: &[u8]) -> Result<ProtocolVersio
     let parse_result = peek_protocol_version(input);
     match parse_result {
         Ok((_, protocol_version)) => Ok(ProtocolVersion::from(protocol_version)),
-        Err(err) => Err(DisError::ParseError(err.to_string())),
+        Err(err) => Err(header_error(input, &err)),
     }
 }
 
@@ -366,14 +500,15 @@ pub(crate) fn skip_body(total_bytes: u16) -> impl Fn(&[u8]) -> IResult<&[u8], &[
 }
 
 pub(crate) fn simulation_address(input: &[u8]) -> IResult<&[u8], SimulationAddress> {
-    let (input, site_id) = be_u16(input)?;
-    let (input, application_id) = be_u16(input)?;
+    let (input, site_id) = field("Site ID", be_u16).parse(input)?;
+    let (input, application_id) = field("Application ID", be_u16).parse(input)?;
     Ok((input, SimulationAddress::new(site_id, application_id)))
 }
 
 pub(crate) fn entity_id(input: &[u8]) -> IResult<&[u8], EntityId> {
-    let (input, simulation_address) = simulation_address(input)?;
-    let (input, entity_id) = be_u16(input)?;
+    let (input, simulation_address) =
+        field("Simulation Address", simulation_address).parse(input)?;
+    let (input, entity_id) = field("Entity Number", be_u16).parse(input)?;
     Ok((
         input,
         EntityId {
@@ -383,14 +518,97 @@ pub(crate) fn entity_id(input: &[u8]) -> IResult<&[u8], EntityId> {
     ))
 }
 
+pub(crate) fn live_simulation_address(input: &[u8]) -> IResult<&[u8], LiveSimulationAddress> {
+    let (input, site_number) = field("Site Number", be_u8).parse(input)?;
+    let (input, application_number) = field("Application Number", be_u8).parse(input)?;
+    Ok((
+        input,
+        LiveSimulationAddress::new(site_number, application_number),
+    ))
+}
+
+pub(crate) fn live_entity_id(input: &[u8]) -> IResult<&[u8], LiveEntityId> {
+    let (input, simulation_address) =
+        field("Simulation Address", live_simulation_address).parse(input)?;
+    let (input, entity_number) = field("Entity Number", be_u16).parse(input)?;
+    Ok((
+        input,
+        LiveEntityId {
+            simulation_address,
+            entity_number,
+        },
+    ))
+}
+
+pub(crate) fn live_event_id(input: &[u8]) -> IResult<&[u8], LiveEventId> {
+    let (input, simulation_address) =
+        field("Simulation Address", live_simulation_address).parse(input)?;
+    let (input, event_number) = field("Event Number", be_u16).parse(input)?;
+    Ok((
+        input,
+        LiveEventId {
+            simulation_address,
+            event_number,
+        },
+    ))
+}
+
+pub(crate) fn relative_world_coordinates(input: &[u8]) -> IResult<&[u8], RelativeWorldCoordinates> {
+    let (input, reference_point) = field("Reference Point", be_u16).parse(input)?;
+    let (input, delta_x) = field("Delta X", be_i16).parse(input)?;
+    let (input, delta_y) = field("Delta Y", be_i16).parse(input)?;
+    let (input, delta_z) = field("Delta Z", be_i16).parse(input)?;
+    Ok((
+        input,
+        RelativeWorldCoordinates::new(reference_point, delta_x, delta_y, delta_z),
+    ))
+}
+
+pub(crate) fn live_orientation(input: &[u8]) -> IResult<&[u8], LiveOrientation> {
+    let (input, psi) = field("Psi", be_u8).parse(input)?;
+    let (input, theta) = field("Theta", be_u8).parse(input)?;
+    let (input, phi) = field("Phi", be_u8).parse(input)?;
+    Ok((input, LiveOrientation::new(psi, theta, phi)))
+}
+
+pub(crate) fn vec3_i16(input: &[u8]) -> IResult<&[u8], VectorI16> {
+    let (input, first) = field("First Component", be_i16).parse(input)?;
+    let (input, second) = field("Second Component", be_i16).parse(input)?;
+    let (input, third) = field("Third Component", be_i16).parse(input)?;
+    Ok((input, VectorI16::new(first, second, third)))
+}
+
+pub(crate) fn vec3_i8(input: &[u8]) -> IResult<&[u8], VectorI8> {
+    let (input, first) = field("First Component", be_i8).parse(input)?;
+    let (input, second) = field("Second Component", be_i8).parse(input)?;
+    let (input, third) = field("Third Component", be_i8).parse(input)?;
+    Ok((input, VectorI8::new(first, second, third)))
+}
+
+pub(crate) fn minefield_id(input: &[u8]) -> IResult<&[u8], MinefieldId> {
+    let (input, simulation_address) =
+        field("Simulation Address", simulation_address).parse(input)?;
+    let (input, minefield_number) = field("Minefield Number", be_u16).parse(input)?;
+    Ok((
+        input,
+        MinefieldId::new_sim_address(simulation_address, minefield_number),
+    ))
+}
+
+pub(crate) fn perimeter_point_coordinate(input: &[u8]) -> IResult<&[u8], PerimeterPointCoordinate> {
+    let (input, x) = field("X", be_f32).parse(input)?;
+    let (input, y) = field("Y", be_f32).parse(input)?;
+    Ok((input, PerimeterPointCoordinate::new(x, y)))
+}
+
 pub(crate) fn entity_type(input: &[u8]) -> IResult<&[u8], EntityType> {
-    let (input, kind) = kind(input)?;
-    let (input, domain) = domain(input)?;
-    let (input, country) = country(input)?;
-    let (input, category) = be_u8(input)?;
-    let (input, subcategory) = be_u8(input)?;
-    let (input, specific) = be_u8(input)?;
-    let (input, extra) = be_u8(input)?;
+    let (input, kind) = field("Kind", kind).parse(input)?;
+    let (input, domain) = field("Domain", domain).parse(input)?;
+    let (input, country) = field("Country", country).parse(input)?;
+    let (input, category) = field("Category", be_u8).parse(input)?;
+    let (input, subcategory) = field("Subcategory", be_u8).parse(input)?;
+    let (input, specific) = field("Specific", be_u8).parse(input)?;
+    let (input, extra) = field("Extra", be_u8).parse(input)?;
     Ok((
         input,
         EntityType {
@@ -405,6 +623,17 @@ pub(crate) fn entity_type(input: &[u8]) -> IResult<&[u8], EntityType> {
     ))
 }
 
+pub(crate) fn object_type(input: &[u8]) -> IResult<&[u8], ObjectType> {
+    let (input, domain) = field("Domain", domain).parse(input)?;
+    let (input, kind) = field("Kind", be_u8).parse(input)?;
+    let (input, category) = field("Category", be_u8).parse(input)?;
+    let (input, subcategory) = field("Subcategory", be_u8).parse(input)?;
+    Ok((
+        input,
+        ObjectType::new(domain, ObjectKind::from(kind), category, subcategory),
+    ))
+}
+
 fn kind(input: &[u8]) -> IResult<&[u8], EntityKind> {
     let (input, kind) = be_u8(input)?;
     let kind = EntityKind::from(kind);
@@ -424,66 +653,44 @@ fn country(input: &[u8]) -> IResult<&[u8], Country> {
 }
 
 pub(crate) fn vec3_f32(input: &[u8]) -> IResult<&[u8], VectorF32> {
-    let (input, elements) = count(be_f32, 3).parse(input)?;
-    #[allow(clippy::get_first)]
+    let (input, first_vector_component) = field("First Component", be_f32).parse(input)?;
+    let (input, second_vector_component) = field("Second Component", be_f32).parse(input)?;
+    let (input, third_vector_component) = field("Third Component", be_f32).parse(input)?;
     Ok((
         input,
         VectorF32 {
-            first_vector_component: *elements
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            second_vector_component: *elements
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            third_vector_component: *elements
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
+            first_vector_component,
+            second_vector_component,
+            third_vector_component,
         },
     ))
 }
 
 pub(crate) fn location(input: &[u8]) -> IResult<&[u8], Location> {
-    let (input, locations) = count(be_f64, 3).parse(input)?;
-    #[allow(clippy::get_first)]
+    let (input, x_coordinate) = field("X", be_f64).parse(input)?;
+    let (input, y_coordinate) = field("Y", be_f64).parse(input)?;
+    let (input, z_coordinate) = field("Z", be_f64).parse(input)?;
     Ok((
         input,
         Location {
-            x_coordinate: *locations
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            y_coordinate: *locations
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            z_coordinate: *locations
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
+            x_coordinate,
+            y_coordinate,
+            z_coordinate,
         },
     ))
 }
 
 pub(crate) fn orientation(input: &[u8]) -> IResult<&[u8], Orientation> {
-    let (input, orientations) = count(be_f32, 3).parse(input)?;
-    #[allow(clippy::get_first)]
-    Ok((
-        input,
-        Orientation {
-            psi: *orientations
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            theta: *orientations
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            phi: *orientations
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
-        },
-    ))
+    let (input, psi) = field("Psi", be_f32).parse(input)?;
+    let (input, theta) = field("Theta", be_f32).parse(input)?;
+    let (input, phi) = field("Phi", be_f32).parse(input)?;
+    Ok((input, Orientation { psi, theta, phi }))
 }
 
 pub(crate) fn event_id(input: &[u8]) -> IResult<&[u8], EventId> {
-    let (input, site_id) = be_u16(input)?;
-    let (input, application_id) = be_u16(input)?;
-    let (input, event_id) = be_u16(input)?;
+    let (input, site_id) = field("Site ID", be_u16).parse(input)?;
+    let (input, application_id) = field("Application ID", be_u16).parse(input)?;
+    let (input, event_id) = field("Event Number", be_u16).parse(input)?;
     Ok((
         input,
         EventId {
@@ -497,11 +704,11 @@ pub(crate) fn event_id(input: &[u8]) -> IResult<&[u8], EventId> {
 }
 
 pub(crate) fn munition_descriptor(input: &[u8]) -> IResult<&[u8], MunitionDescriptor> {
-    let (input, entity_type) = entity_type(input)?;
-    let (input, warhead) = warhead(input)?;
-    let (input, fuse) = fuse(input)?;
-    let (input, quantity) = be_u16(input)?;
-    let (input, rate) = be_u16(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
+    let (input, warhead) = context("Warhead", warhead).parse(input)?;
+    let (input, fuse) = context("Fuse", fuse).parse(input)?;
+    let (input, quantity) = context("Quantity", be_u16).parse(input)?;
+    let (input, rate) = context("Rate", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -528,10 +735,11 @@ fn fuse(input: &[u8]) -> IResult<&[u8], MunitionDescriptorFuse> {
 }
 
 pub(crate) fn explosion_descriptor(input: &[u8]) -> IResult<&[u8], ExplosionDescriptor> {
-    let (input, entity_type) = entity_type(input)?;
-    let (input, explosive_material) = explosive_material(input)?;
-    let (input, _pad_out) = be_u16(input)?;
-    let (input, explosive_force) = be_f32(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
+    let (input, explosive_material) =
+        context("Explosive Material", explosive_material).parse(input)?;
+    let (input, _pad_out) = context("Padding", be_u16).parse(input)?;
+    let (input, explosive_force) = context("Explosive Force", be_f32).parse(input)?;
 
     Ok((
         input,
@@ -550,26 +758,33 @@ fn explosive_material(input: &[u8]) -> IResult<&[u8], ExplosiveMaterialCategorie
 }
 
 pub(crate) fn expendable_descriptor(input: &[u8]) -> IResult<&[u8], ExpendableDescriptor> {
-    let (input, entity_type) = entity_type(input)?;
-    let (input, _pad_out) = be_u64(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
+    let (input, _pad_out) = context("Padding", be_u64).parse(input)?;
 
     Ok((input, ExpendableDescriptor { entity_type }))
 }
 
 pub(crate) fn clock_time(input: &[u8]) -> IResult<&[u8], ClockTime> {
-    let (input, hour) = be_i32(input)?;
-    let (input, time_past_hour) = be_u32(input)?;
+    let (input, hour) = field("Hour", be_i32).parse(input)?;
+    let (input, time_past_hour) = field("Time Past Hour", be_u32).parse(input)?;
     let time = ClockTime::new(hour, time_past_hour);
     Ok((input, time))
 }
 
 pub(crate) fn datum_specification(input: &[u8]) -> IResult<&[u8], DatumSpecification> {
-    let (input, num_fixed_datums) = be_u32(input)?;
-    let (input, num_variable_datums) = be_u32(input)?;
-
-    let (input, fixed_datums) = count(fixed_datum, num_fixed_datums as usize).parse(input)?;
-    let (input, variable_datums) =
-        count(variable_datum, num_variable_datums as usize).parse(input)?;
+    let (input, num_fixed_datums) = context("Number of Fixed Datums", be_u32).parse(input)?;
+    let (input, num_variable_datums) = context("Number of Variable Datums", be_u32).parse(input)?;
+
+    let (input, fixed_datums) = count(
+        context("Fixed Datum", fixed_datum),
+        num_fixed_datums as usize,
+    )
+    .parse(input)?;
+    let (input, variable_datums) = count(
+        context("Variable Datum", variable_datum),
+        num_variable_datums as usize,
+    )
+    .parse(input)?;
 
     let datums = DatumSpecification::new(fixed_datums, variable_datums);
 
@@ -577,8 +792,8 @@ pub(crate) fn datum_specification(input: &[u8]) -> IResult<&[u8], DatumSpecifica
 }
 
 pub(crate) fn fixed_datum(input: &[u8]) -> IResult<&[u8], FixedDatum> {
-    let (input, datum_id) = be_u32(input)?;
-    let (input, datum_value) = be_u32(input)?;
+    let (input, datum_id) = context("Datum ID", be_u32).parse(input)?;
+    let (input, datum_value) = context("Datum Value", be_u32).parse(input)?;
 
     let datum_id = VariableRecordType::from(datum_id);
     let datum = FixedDatum::new(datum_id, datum_value);
@@ -587,9 +802,9 @@ pub(crate) fn fixed_datum(input: &[u8]) -> IResult<&[u8], FixedDatum> {
 }
 
 pub(crate) fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
-    let (input, datum_id) = be_u32(input)?;
+    let (input, datum_id) = context("Datum ID", be_u32).parse(input)?;
     let datum_id = VariableRecordType::from(datum_id);
-    let (input, datum_length_bits) = be_u32(input)?;
+    let (input, datum_length_bits) = context("Datum Length", be_u32).parse(input)?;
 
     // NOTE: The standard defines the data length and padding in bits.
     // However, we assume that one only puts in values that consists of whole bytes.
@@ -597,8 +812,10 @@ pub(crate) fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
     let datum_length_bytes = datum_length_bits as usize / ONE_BYTE_IN_BITS;
     let padded_record = length_padded_to_num(datum_length_bytes, EIGHT_OCTETS);
 
-    let (input, datum_value) = take(padded_record.data_length)(input)?;
-    let (input, _datum_padding) = take(padded_record.padding_length)(input)?;
+    let (input, datum_value): (&[u8], &[u8]) =
+        context("Datum Value", take(padded_record.data_length)).parse(input)?;
+    let (input, _datum_padding) =
+        context("Padding", take(padded_record.padding_length)).parse(input)?;
 
     let variable_datum = VariableDatum::new(datum_id, datum_value.to_vec());
 
@@ -606,16 +823,27 @@ pub(crate) fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
 }
 
 pub(crate) fn variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, parameter_type_designator) = be_u8(input)?;
+    let (input, parameter_type_designator) =
+        context("Parameter Type Designator", be_u8).parse(input)?;
     let parameter_type = VariableParameterRecordType::from(parameter_type_designator);
     let (input, variable_parameter) = match parameter_type {
-        VariableParameterRecordType::ArticulatedPart => articulated_part(input)?,
-        VariableParameterRecordType::AttachedPart => attached_part(input)?,
-        VariableParameterRecordType::Separation => separation(input)?,
-        VariableParameterRecordType::EntityType => entity_type_variable_parameter(input)?,
-        VariableParameterRecordType::EntityAssociation => entity_association(input)?,
+        VariableParameterRecordType::ArticulatedPart => {
+            context("Articulated Part", articulated_part).parse(input)?
+        }
+        VariableParameterRecordType::AttachedPart => {
+            context("Attached Part", attached_part).parse(input)?
+        }
+        VariableParameterRecordType::Separation => {
+            context("Separation", separation).parse(input)?
+        }
+        VariableParameterRecordType::EntityType => {
+            context("Entity Type", entity_type_variable_parameter).parse(input)?
+        }
+        VariableParameterRecordType::EntityAssociation => {
+            context("Entity Association", entity_association).parse(input)?
+        }
         VariableParameterRecordType::Unspecified(_) => {
-            let (input, bytes) = take(15usize)(input)?;
+            let (input, bytes) = context("Parameter Fields", take(15usize)).parse(input)?;
             (
                 input,
                 VariableParameter::Unspecified(
@@ -631,13 +859,13 @@ pub(crate) fn variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParamet
 
 /// I.2.2 Articulated parts
 fn articulated_part(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, change_indicator) = be_u8(input)?;
-    let (input, attachment_id) = be_u16(input)?;
-    let (input, parameter_type) = be_u32(input)?; // Parameter Type = Type Class + Type Metric
+    let (input, change_indicator) = context("Change Indicator", be_u8).parse(input)?;
+    let (input, attachment_id) = context("Attachment ID", be_u16).parse(input)?;
+    let (input, parameter_type) = context("Parameter Type", be_u32).parse(input)?; // Parameter Type = Type Class + Type Metric
     let type_metric: u32 = parameter_type & FIVE_LEAST_SIGNIFICANT_BITS; // 5 least significant bits are the Type Metric
     let type_class: u32 = parameter_type - type_metric; // Rest of the bits (Param Type minus Type Metric) are the Type Class
     let (input, value) = be_f32(input)?;
-    let (input, _pad_out) = be_u32(input)?;
+    let (input, _pad_out) = context("Padding", be_u32).parse(input)?;
 
     Ok((
         input,
@@ -652,11 +880,11 @@ fn articulated_part(input: &[u8]) -> IResult<&[u8], VariableParameter> {
 }
 
 fn attached_part(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, detached_indicator) = be_u8(input)?;
+    let (input, detached_indicator) = context("Detached Indicator", be_u8).parse(input)?;
     let detached_indicator = AttachedPartDetachedIndicator::from(detached_indicator);
-    let (input, attachment_id) = be_u16(input)?;
-    let (input, attached_part) = be_u32(input)?;
-    let (input, entity_type) = entity_type(input)?;
+    let (input, attachment_id) = context("Attachment ID", be_u16).parse(input)?;
+    let (input, attached_part) = context("Attached Part", be_u32).parse(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
 
     Ok((
         input,
@@ -670,14 +898,15 @@ fn attached_part(input: &[u8]) -> IResult<&[u8], VariableParameter> {
 }
 
 fn entity_association(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, change_indicator) = be_u8(input)?;
-    let (input, association_status) = be_u8(input)?;
-    let (input, association_type) = be_u8(input)?;
-    let (input, entity_id) = entity_id(input)?;
-    let (input, own_station_location) = be_u16(input)?;
-    let (input, physical_connection_type) = be_u8(input)?;
-    let (input, group_member_type) = be_u8(input)?;
-    let (input, group_number) = be_u16(input)?;
+    let (input, change_indicator) = context("Change Indicator", be_u8).parse(input)?;
+    let (input, association_status) = context("Association Status", be_u8).parse(input)?;
+    let (input, association_type) = context("Association Type", be_u8).parse(input)?;
+    let (input, entity_id) = context("Entity ID", entity_id).parse(input)?;
+    let (input, own_station_location) = context("Own Station Location", be_u16).parse(input)?;
+    let (input, physical_connection_type) =
+        context("Physical Connection Type", be_u8).parse(input)?;
+    let (input, group_member_type) = context("Group Member Type", be_u8).parse(input)?;
+    let (input, group_number) = context("Group Number", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -697,10 +926,10 @@ fn entity_association(input: &[u8]) -> IResult<&[u8], VariableParameter> {
 }
 
 fn entity_type_variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, change_indicator) = be_u8(input)?;
-    let (input, entity_type) = entity_type(input)?;
-    let (input, _pad_out_16) = be_u16(input)?;
-    let (input, _pad_out_32) = be_u32(input)?;
+    let (input, change_indicator) = context("Change Indicator", be_u8).parse(input)?;
+    let (input, entity_type) = context("Entity Type", entity_type).parse(input)?;
+    let (input, _pad_out_16) = context("Padding", be_u16).parse(input)?;
+    let (input, _pad_out_32) = context("Padding", be_u32).parse(input)?;
 
     Ok((
         input,
@@ -712,12 +941,12 @@ fn entity_type_variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParame
 }
 
 fn separation(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, reason) = be_u8(input)?;
-    let (input, pre_entity_indicator) = be_u8(input)?;
-    let (input, parent_entity_id) = entity_id(input)?;
-    let (input, _pad_16) = be_u16(input)?;
-    let (input, station_name) = be_u16(input)?;
-    let (input, station_number) = be_u16(input)?;
+    let (input, reason) = context("Reason", be_u8).parse(input)?;
+    let (input, pre_entity_indicator) = context("Pre Entity Indicator", be_u8).parse(input)?;
+    let (input, parent_entity_id) = context("Parent Entity ID", entity_id).parse(input)?;
+    let (input, _pad_16) = context("Padding", be_u16).parse(input)?;
+    let (input, station_name) = context("Station Name", be_u16).parse(input)?;
+    let (input, station_number) = context("Station Number", be_u16).parse(input)?;
 
     Ok((
         input,
@@ -732,11 +961,11 @@ fn separation(input: &[u8]) -> IResult<&[u8], VariableParameter> {
 }
 
 pub(crate) fn beam_data(input: &[u8]) -> IResult<&[u8], BeamData> {
-    let (input, azimuth_center) = be_f32(input)?;
-    let (input, azimuth_sweep) = be_f32(input)?;
-    let (input, elevation_center) = be_f32(input)?;
-    let (input, elevation_sweep) = be_f32(input)?;
-    let (input, sweep_sync) = be_f32(input)?;
+    let (input, azimuth_center) = context("Azimuth Center", be_f32).parse(input)?;
+    let (input, azimuth_sweep) = context("Azimuth Sweep", be_f32).parse(input)?;
+    let (input, elevation_center) = context("Elevation Center", be_f32).parse(input)?;
+    let (input, elevation_sweep) = context("Elevation Sweep", be_f32).parse(input)?;
+    let (input, sweep_sync) = context("Sweep Sync", be_f32).parse(input)?;
 
     let data = BeamData::new()
         .with_azimuth_center(azimuth_center)
@@ -749,8 +978,8 @@ pub(crate) fn beam_data(input: &[u8]) -> IResult<&[u8], BeamData> {
 }
 
 pub(crate) fn supply_quantity(input: &[u8]) -> IResult<&[u8], SupplyQuantity> {
-    let (input, supply_type) = entity_type(input)?;
-    let (input, quantity) = be_f32(input)?;
+    let (input, supply_type) = context("Supply Type", entity_type).parse(input)?;
+    let (input, quantity) = context("Quantity", be_f32).parse(input)?;
 
     Ok((
         input,
@@ -762,8 +991,12 @@ pub(crate) fn supply_quantity(input: &[u8]) -> IResult<&[u8], SupplyQuantity> {
 
 /// Parses the `RecordSpecification` record (6.2.73)
 pub(crate) fn record_specification(input: &[u8]) -> IResult<&[u8], RecordSpecification> {
-    let (input, number_of_records) = be_u32(input)?;
-    let (input, record_sets) = count(record_set, number_of_records as usize).parse(input)?;
+    let (input, number_of_records) = context("Number of Records", be_u32).parse(input)?;
+    let (input, record_sets) = count(
+        context("Record Set", record_set),
+        number_of_records as usize,
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -777,19 +1010,23 @@ pub(crate) fn record_specification(input: &[u8]) -> IResult<&[u8], RecordSpecifi
 /// Record length is defined in bits, but this function always rounds up to the next full byte.
 /// This is compensated for in the padding.
 pub(crate) fn record_set(input: &[u8]) -> IResult<&[u8], RecordSet> {
-    let (input, record_id) = be_u32(input)?;
+    let (input, record_id) = context("Record ID", be_u32).parse(input)?;
     let record_id = VariableRecordType::from(record_id);
-    let (input, serial_number) = be_u32(input)?;
-    let (input, _padding) = be_u32(input)?;
-    let (input, record_length_bits) = be_u16(input)?;
+    let (input, serial_number) = context("Serial Number", be_u32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
+    let (input, record_length_bits) = context("Record Length", be_u16).parse(input)?;
     let record_length_bytes = ceil_bits_to_bytes(record_length_bits);
-    let (input, record_count) = be_u16(input)?;
-    let (input, record_values): (&[u8], Vec<&[u8]>) =
-        count(take(record_length_bytes), record_count as usize).parse(input)?;
+    let (input, record_count) = context("Record Count", be_u16).parse(input)?;
+    let (input, record_values): (&[u8], Vec<&[u8]>) = count(
+        context("Record Value", take(record_length_bytes)),
+        record_count as usize,
+    )
+    .parse(input)?;
     let record_values = record_values.iter().map(|values| values.to_vec()).collect();
     let padded_record_length =
         length_padded_to_num((record_length_bytes * record_count) as usize, EIGHT_OCTETS);
-    let (input, _padding) = take(padded_record_length.padding_length)(input)?;
+    let (input, _padding) =
+        context("Padding", take(padded_record_length.padding_length)).parse(input)?;
 
     Ok((
         input,
@@ -946,4 +1183,212 @@ fn skip_body_total_length_cannot_contain_a_header() {
         assert_eq!(input, [0x00, 0x00]);
         assert_eq!(skipped, empty_array);
     }
+
+    #[test]
+    fn parse_each_pdu_separately() {
+        use crate::BodyRaw;
+        use crate::common::model::{EntityId, Pdu, PduHeader, TimeUnits, Timestamp};
+        use crate::common::parse_each;
+        use crate::entity_state::model::EntityState;
+        use crate::remove_entity::model::RemoveEntity;
+        use bytes::BytesMut;
+
+        let timestamp = Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap());
+        let mut buf = BytesMut::with_capacity(1024);
+        let first = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+            timestamp,
+        );
+        first.serialize(&mut buf).unwrap();
+        let first_length = buf.len();
+        // Entity State PDU header indicating a 2-byte body, which cannot be parsed
+        buf.extend_from_slice(&[
+            0x07, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0xf4,
+        ]);
+        let third = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::RemoveEntity),
+            RemoveEntity::builder().build().into_pdu_body(),
+            timestamp,
+        );
+        third.serialize(&mut buf).unwrap();
+
+        let results = parse_each(&buf);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].offset, 0);
+        assert_eq!(
+            results[0].result.as_ref().unwrap().header.pdu_type,
+            PduType::EntityState
+        );
+        assert_eq!(results[1].offset, first_length);
+        assert!(results[1].result.is_err());
+        assert_eq!(results[2].offset, first_length + 14);
+        assert_eq!(results[2].result.as_ref().unwrap().body, third.body);
+    }
+
+    #[test]
+    fn parse_each_stops_at_truncated_pdu() {
+        use crate::common::parse_each;
+
+        let bytes: [u8; 26] = [
+            0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0c, 0x00, 0x00, 0x06, 0x01,
+            0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0xd0, 0x00, 0x00, 0x01, 0xf4,
+        ];
+
+        let results = parse_each(&bytes);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].offset, 12);
+        assert_eq!(
+            results[1].result,
+            Err(DisError::InsufficientPduLength(
+                208 - PDU_HEADER_LEN_BYTES,
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_pdu_malformed_body() {
+        use crate::common::parser::parse_pdu;
+        use nom::error::ErrorKind;
+
+        // Entity State PDU header indicating a 2-byte body, which cannot be parsed
+        let bytes: [u8; 14] = [
+            0x07, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0xf4,
+        ];
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(
+            error,
+            DisError::MalformedPdu {
+                pdu_type: PduType::EntityState,
+                protocol_version: ProtocolVersion::IEEE1278_12012,
+                offset: 14,
+                record: "Entity ID",
+                kind: ErrorKind::Eof,
+                expected: 2,
+                found: 2,
+            }
+        );
+    }
+
+    /// Serializes `body` into a PDU and cuts it off at `length` bytes, adjusting the PDU length
+    /// field so the truncation is only noticed while parsing the body.
+    fn truncated_pdu_bytes(body: crate::common::model::PduBody, length: u16) -> Vec<u8> {
+        use crate::BodyInfo;
+        use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+        use bytes::BytesMut;
+
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, body.body_type()),
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let mut buf = BytesMut::with_capacity(1024);
+        pdu.serialize(&mut buf).unwrap();
+        buf.truncate(length.into());
+        buf[8..10].copy_from_slice(&length.to_be_bytes());
+        buf.to_vec()
+    }
+
+    #[test]
+    fn parse_pdu_malformed_body_field() {
+        use crate::BodyRaw;
+        use crate::common::parser::parse_pdu;
+        use crate::intercom_control::model::IntercomControl;
+        use nom::error::ErrorKind;
+
+        // Cut off in the Master Channel ID field
+        let bytes = truncated_pdu_bytes(IntercomControl::builder().build().into_pdu_body(), 35);
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(
+            error,
+            DisError::MalformedPdu {
+                pdu_type: PduType::IntercomControl,
+                protocol_version: ProtocolVersion::IEEE1278_12012,
+                offset: 34,
+                record: "Master Channel ID",
+                kind: ErrorKind::Eof,
+                expected: 23,
+                found: 23,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pdu_malformed_intercom_parameters() {
+        use crate::BodyRaw;
+        use crate::common::parser::parse_pdu;
+        use crate::intercom_control::model::{IntercomCommunicationsParameters, IntercomControl};
+        use nom::error::ErrorKind;
+
+        let body = IntercomControl::builder()
+            .with_intercom_parameter(IntercomCommunicationsParameters::default())
+            .build()
+            .into_pdu_body();
+        // Cut off in the Record Specific Field of the Intercom Communications Parameters record
+        let bytes = truncated_pdu_bytes(body, 50);
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(
+            error,
+            DisError::MalformedPdu {
+                pdu_type: PduType::IntercomControl,
+                protocol_version: ProtocolVersion::IEEE1278_12012,
+                offset: 44,
+                record: "Record Specific Field",
+                kind: ErrorKind::Eof,
+                expected: 38,
+                found: 38,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pdu_malformed_track_jam() {
+        use crate::BodyRaw;
+        use crate::common::parser::parse_pdu;
+        use crate::electromagnetic_emission::model::{
+            Beam, ElectromagneticEmission, EmitterSystem, TrackJam,
+        };
+        use nom::error::ErrorKind;
+
+        let body = ElectromagneticEmission::builder()
+            .with_emitter_system(
+                EmitterSystem::default()
+                    .with_beam(Beam::default().with_track_jam(TrackJam::default())),
+            )
+            .build()
+            .into_pdu_body();
+        // Cut off in the Beam Number field of the Track/Jam Data record
+        let bytes = truncated_pdu_bytes(body, 107);
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(
+            error,
+            DisError::MalformedPdu {
+                pdu_type: PduType::ElectromagneticEmission,
+                protocol_version: ProtocolVersion::IEEE1278_12012,
+                offset: 107,
+                record: "Beam Number",
+                kind: ErrorKind::Eof,
+                expected: 95,
+                found: 95,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pdu_header_too_short() {
+        use crate::common::parser::parse_pdu;
+
+        let bytes: [u8; 10] = [0x07, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x60];
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(error, DisError::InsufficientHeaderLength(10));
+    }
 }
@@ -0,0 +1,564 @@
+//! Borrowing, lazily parsed view of a PDU in a byte buffer.
+//!
+//! A [`PduView`] parses only the PDU header, and reads the originating and receiving `EntityId`s
+//! directly from their position in the body, without allocating. This allows routers and gateways to
+//! filter traffic cheaply, and upgrade only the PDUs of interest to a full `Pdu` using [`PduView::to_pdu`].
+use crate::common::Interaction;
+use crate::common::directed_energy_fire::model::{
+    DE_AREA_AIMPOINT_RECORD_TYPE, DE_PRECISION_AIMPOINT_RECORD_TYPE, DE_RECORD_HEADER_LENGTH,
+};
+use crate::common::errors::DisError;
+use crate::common::model::{EntityId, Pdu, PduHeader};
+use crate::common::parser::{entity_id, parse_pdu, split_pdu};
+use crate::common::transmitter::model::BEAM_ANTENNA_PATTERN_OCTETS;
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{PduType, ProtocolVersion};
+use crate::v7::model::PduStatus;
+
+/// Location of the originating and receiving `EntityId`s in the body of a PDU type
+enum IdOffsets {
+    /// Fixed byte offsets in the body, if the PDU type has the id
+    Fixed(Option<usize>, Option<usize>),
+    /// The receiving id is located in variable records, of which the offset is found by walking the record headers
+    Variable(Option<usize>, fn(&[u8]) -> Option<usize>),
+}
+
+// Electromagnetic Emission PDU: offsets in the body, emitter system and beam records
+const EE_NUMBER_OF_SYSTEMS_OFFSET: usize = 13;
+const EE_SYSTEMS_OFFSET: usize = 16;
+const EE_SYSTEM_NUMBER_OF_BEAMS_OFFSET: usize = 1;
+const EE_SYSTEM_HEADER_LENGTH: usize = 20;
+const EE_BEAM_NUMBER_OF_TARGETS_OFFSET: usize = 45;
+const EE_BEAM_HEADER_LENGTH: usize = 52;
+
+// Directed Energy Fire PDU: offsets in the body and DE records
+const DE_NUMBER_OF_RECORDS_OFFSET: usize = 74;
+const DE_RECORDS_OFFSET: usize = 76;
+const DE_PRECISION_AIMPOINT_TARGET_OFFSET: usize = 68;
+const DE_AREA_AIMPOINT_PATTERN_COUNT_OFFSET: usize = 8;
+const DE_AREA_AIMPOINT_DEPOSITION_COUNT_OFFSET: usize = 10;
+const DE_AREA_AIMPOINT_PATTERNS_OFFSET: usize = 12;
+const ENTITY_ID_LENGTH: usize = 6;
+
+/// A borrowed PDU in a byte buffer, of which only the header is parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PduView<'a> {
+    header: PduHeader,
+    bytes: &'a [u8],
+    originator: Option<EntityId>,
+    receiver: Option<EntityId>,
+}
+
+impl<'a> PduView<'a> {
+    /// Creates a view of the first PDU in `input`, returning the view and the remaining input.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the input does not contain a complete PDU header,
+    /// or when the input is shorter than the length indicated in the header.
+    #[cfg_attr(
+        all(feature = "hotpath", not(feature = "_test_no_instrumentation")),
+        hotpath::measure
+    )]
+    pub fn parse(input: &'a [u8]) -> Result<(Self, &'a [u8]), DisError> {
+        let (header, bytes, remainder) = split_pdu(input)?;
+        let body = &bytes[PDU_HEADER_LEN_BYTES as usize..];
+        let (originator, receiver) = match id_offsets(header.pdu_type) {
+            IdOffsets::Fixed(originator, receiver) => (
+                originator.and_then(|offset| id_at(body, offset)),
+                receiver.and_then(|offset| id_at(body, offset)),
+            ),
+            IdOffsets::Variable(originator, receiver) => (
+                originator.and_then(|offset| id_at(body, offset)),
+                receiver(body).and_then(|offset| id_at(body, offset)),
+            ),
+        };
+
+        Ok((
+            Self {
+                header,
+                bytes,
+                originator,
+                receiver,
+            },
+            remainder,
+        ))
+    }
+
+    #[must_use]
+    pub fn header(&self) -> &PduHeader {
+        &self.header
+    }
+
+    #[must_use]
+    pub fn pdu_type(&self) -> PduType {
+        self.header.pdu_type
+    }
+
+    #[must_use]
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.header.protocol_version
+    }
+
+    #[must_use]
+    pub fn exercise_id(&self) -> u8 {
+        self.header.exercise_id
+    }
+
+    /// The PDU Status record, only present for DIS v7 PDUs
+    #[must_use]
+    pub fn pdu_status(&self) -> Option<&PduStatus> {
+        self.header.pdu_status.as_ref()
+    }
+
+    /// The length of the PDU body in bytes, as indicated by the header
+    #[must_use]
+    pub fn body_length(&self) -> u16 {
+        self.header.pdu_length - PDU_HEADER_LEN_BYTES
+    }
+
+    /// The bytes of the complete PDU, including the header
+    #[must_use]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The bytes of the PDU body
+    #[must_use]
+    pub fn body_bytes(&self) -> &'a [u8] {
+        &self.bytes[PDU_HEADER_LEN_BYTES as usize..]
+    }
+
+    /// Parses the complete PDU into an owned `Pdu`
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the PDU body cannot be parsed.
+    pub fn to_pdu(&self) -> Result<Pdu, DisError> {
+        parse_pdu(self.bytes)
+    }
+}
+
+impl Interaction for PduView<'_> {
+    fn originator(&self) -> Option<&EntityId> {
+        self.originator.as_ref()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.receiver.as_ref()
+    }
+}
+
+/// Iterator over the PDUs in a byte buffer, yielding a [`PduView`] for each PDU.
+///
+/// Iteration stops after the first error.
+pub struct PduViews<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for PduViews<'a> {
+    type Item = Result<PduView<'a>, DisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        match PduView::parse(self.input) {
+            Ok((view, remainder)) => {
+                self.input = remainder;
+                Some(Ok(view))
+            }
+            Err(err) => {
+                self.input = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Returns an iterator of borrowed [`PduView`]s over the PDUs in `input`, without parsing the PDU bodies.
+#[must_use]
+pub fn parse_views(input: &[u8]) -> PduViews<'_> {
+    PduViews { input }
+}
+
+fn id_at(body: &[u8], offset: usize) -> Option<EntityId> {
+    body.get(offset..)
+        .and_then(|input| entity_id(input).ok())
+        .map(|(_, id)| id)
+}
+
+fn u16_at(body: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        body.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn u32_at(body: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        body.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// Offset of the first track/jam target of the first beam of the first emitter system,
+/// as returned by the `Interaction` implementation of `ElectromagneticEmission`.
+fn emission_receiver_offset(body: &[u8]) -> Option<usize> {
+    if *body.get(EE_NUMBER_OF_SYSTEMS_OFFSET)? == 0 {
+        return None;
+    }
+    let system = EE_SYSTEMS_OFFSET;
+    if *body.get(system + EE_SYSTEM_NUMBER_OF_BEAMS_OFFSET)? == 0 {
+        return None;
+    }
+    let beam = system + EE_SYSTEM_HEADER_LENGTH;
+    if *body.get(beam + EE_BEAM_NUMBER_OF_TARGETS_OFFSET)? == 0 {
+        return None;
+    }
+    Some(beam + EE_BEAM_HEADER_LENGTH)
+}
+
+/// Offset of the target of the first Precision Aimpoint record, or the first target energy deposition
+/// of an Area Aimpoint record, as returned by the `Interaction` implementation of `DirectedEnergyFire`.
+fn de_fire_receiver_offset(body: &[u8]) -> Option<usize> {
+    let number_of_records = u16_at(body, DE_NUMBER_OF_RECORDS_OFFSET)?;
+    let mut record = DE_RECORDS_OFFSET;
+
+    for _ in 0..number_of_records {
+        let record_type = u32_at(body, record)?;
+        let record_length = usize::from(u16_at(body, record + 4)?.max(DE_RECORD_HEADER_LENGTH));
+        let record_end = record + record_length;
+
+        let target = match record_type {
+            DE_PRECISION_AIMPOINT_RECORD_TYPE => Some(record + DE_PRECISION_AIMPOINT_TARGET_OFFSET),
+            DE_AREA_AIMPOINT_RECORD_TYPE
+                if u16_at(body, record + DE_AREA_AIMPOINT_DEPOSITION_COUNT_OFFSET)? > 0 =>
+            {
+                let patterns = usize::from(u16_at(
+                    body,
+                    record + DE_AREA_AIMPOINT_PATTERN_COUNT_OFFSET,
+                )?);
+                Some(
+                    record
+                        + DE_AREA_AIMPOINT_PATTERNS_OFFSET
+                        + patterns * usize::from(BEAM_ANTENNA_PATTERN_OCTETS),
+                )
+            }
+            _ => None,
+        };
+        if let Some(target) = target {
+            // The target must lie within the record, otherwise the record cannot be parsed
+            return (target + ENTITY_ID_LENGTH <= record_end).then_some(target);
+        }
+        record = record_end;
+    }
+    None
+}
+
+/// Offsets of the ids returned by the `Interaction` implementation of each PDU body
+#[allow(clippy::match_same_arms)]
+fn id_offsets(pdu_type: PduType) -> IdOffsets {
+    match pdu_type {
+        PduType::Fire
+        | PduType::Detonation
+        | PduType::Collision
+        | PduType::CollisionElastic
+        | PduType::ServiceRequest
+        | PduType::ResupplyOffer
+        | PduType::ResupplyReceived
+        | PduType::ResupplyCancel
+        | PduType::RepairComplete
+        | PduType::RepairResponse
+        | PduType::CreateEntity
+        | PduType::RemoveEntity
+        | PduType::StartResume
+        | PduType::StopFreeze
+        | PduType::Acknowledge
+        | PduType::ActionRequest
+        | PduType::ActionResponse
+        | PduType::DataQuery
+        | PduType::SetData
+        | PduType::Data
+        | PduType::EventReport
+        | PduType::Comment
+        | PduType::TransferOwnership
+        | PduType::IsPartOf
+        | PduType::CreateEntityR
+        | PduType::RemoveEntityR
+        | PduType::StartResumeR
+        | PduType::StopFreezeR
+        | PduType::AcknowledgeR
+        | PduType::ActionRequestR
+        | PduType::ActionResponseR
+        | PduType::DataQueryR
+        | PduType::SetDataR
+        | PduType::DataR
+        | PduType::EventReportR
+        | PduType::CommentR
+        | PduType::RecordR
+        | PduType::SetRecordR
+        | PduType::RecordQueryR
+        | PduType::InformationOperationsAction
+        | PduType::InformationOperationsReport => IdOffsets::Fixed(Some(0), Some(6)),
+        PduType::EntityState
+        | PduType::EntityStateUpdate
+        | PduType::EntityDamageStatus
+        | PduType::Transmitter
+        | PduType::Signal
+        | PduType::IntercomSignal
+        | PduType::IFF
+        | PduType::UnderwaterAcoustic
+        | PduType::SupplementalEmissionEntityState
+        | PduType::IsGroupOf
+        | PduType::EnvironmentalProcess
+        | PduType::GriddedData
+        | PduType::PointObjectState
+        | PduType::LinearObjectState
+        | PduType::ArealObjectState => IdOffsets::Fixed(Some(0), None),
+        PduType::Designator => IdOffsets::Fixed(Some(0), Some(8)),
+        PduType::Receiver => IdOffsets::Fixed(Some(16), Some(0)),
+        PduType::IntercomControl => IdOffsets::Fixed(Some(2), Some(14)),
+        PduType::MinefieldQuery | PduType::MinefieldResponseNACK => IdOffsets::Fixed(Some(6), None),
+        PduType::MinefieldData => IdOffsets::Fixed(None, Some(6)),
+        PduType::ElectromagneticEmission => IdOffsets::Variable(Some(0), emission_receiver_offset),
+        PduType::DirectedEnergyFire => IdOffsets::Variable(Some(0), de_fire_receiver_offset),
+        PduType::Other
+        | PduType::AggregateState
+        | PduType::MinefieldState
+        | PduType::TSPI
+        | PduType::Appearance
+        | PduType::ArticulatedParts
+        | PduType::LEFire
+        | PduType::LEDetonation
+        | PduType::Attribute
+        | PduType::Unspecified(_) => IdOffsets::Fixed(None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::BodyInfo;
+    use crate::common::model::{PduBody, TimeUnits, Timestamp, default_bodies};
+    use crate::designator::model::Designator;
+    use crate::directed_energy_fire::model::{
+        DEAreaAimpoint, DEDamageDescription, DEPrecisionAimpoint, DERecord,
+        DETargetEnergyDeposition, DirectedEnergyFire,
+    };
+    use crate::electromagnetic_emission::model::{
+        Beam, ElectromagneticEmission, EmitterSystem, TrackJam,
+    };
+    use crate::entity_state::model::EntityState;
+    use crate::fire::model::Fire;
+    use crate::intercom_control::model::IntercomControl;
+    use crate::receiver::model::Receiver;
+    use crate::transmitter::model::BeamAntennaPattern;
+    use bytes::BytesMut;
+
+    fn serialize(bodies: Vec<PduBody>) -> BytesMut {
+        let mut buf = BytesMut::with_capacity(4096);
+        for body in bodies {
+            let pdu = Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, body.body_type()),
+                body,
+                Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+            );
+            pdu.serialize(&mut buf).unwrap();
+        }
+        buf
+    }
+
+    fn assert_ids_match(body: &PduBody) {
+        let buf = serialize(vec![body.clone()]);
+        let (view, remainder) = PduView::parse(&buf).unwrap();
+
+        assert!(remainder.is_empty());
+        assert_eq!(view.originator(), body.originator());
+        assert_eq!(view.receiver(), body.receiver());
+        assert_eq!(view.body_length(), body.body_length());
+
+        let parsed = view.to_pdu().unwrap().body;
+        assert_eq!(view.originator(), parsed.originator());
+        assert_eq!(view.receiver(), parsed.receiver());
+    }
+
+    #[test]
+    fn view_header_and_ids() {
+        let body = Fire::builder()
+            .with_firing_entity_id(EntityId::new(1, 2, 3))
+            .with_target_entity_id(EntityId::new(4, 5, 6))
+            .build()
+            .into_pdu_body();
+        let buf = serialize(vec![body]);
+        let (view, _) = PduView::parse(&buf).unwrap();
+
+        assert_eq!(view.pdu_type(), PduType::Fire);
+        assert_eq!(view.protocol_version(), ProtocolVersion::IEEE1278_12012);
+        assert_eq!(view.exercise_id(), 1);
+        assert!(view.pdu_status().is_some());
+        assert_eq!(view.as_bytes().len(), buf.len());
+        assert_eq!(view.originator(), Some(&EntityId::new(1, 2, 3)));
+        assert_eq!(view.receiver(), Some(&EntityId::new(4, 5, 6)));
+    }
+
+    #[test]
+    fn ids_match_interaction_of_parsed_body() {
+        assert_ids_match(
+            &EntityState::builder()
+                .with_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &Designator::builder()
+                .with_designating_entity_id(EntityId::new(1, 1, 1))
+                .with_designated_entity_id(EntityId::new(2, 2, 2))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &Receiver::builder()
+                .with_radio_reference_id(EntityId::new(1, 1, 1))
+                .with_transmitter_radio_reference_id(EntityId::new(2, 2, 2))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &IntercomControl::builder()
+                .with_source_entity_id(EntityId::new(1, 1, 1))
+                .with_master_intercom_reference_id(EntityId::new(2, 2, 2))
+                .build()
+                .into_pdu_body(),
+        );
+    }
+
+    #[test]
+    fn emission_ids_match_interaction_of_parsed_body() {
+        let target = TrackJam::new().with_entity_id(EntityId::new(2, 2, 2));
+        let with_target =
+            |beam: Beam| EmitterSystem::new().with_beam(beam.with_track_jam(target.clone()));
+
+        assert_ids_match(
+            &ElectromagneticEmission::builder()
+                .with_emitting_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &ElectromagneticEmission::builder()
+                .with_emitting_entity_id(EntityId::new(1, 1, 1))
+                .with_emitter_system(EmitterSystem::new().with_beam(Beam::new()))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &ElectromagneticEmission::builder()
+                .with_emitting_entity_id(EntityId::new(1, 1, 1))
+                .with_emitter_system(with_target(Beam::new()))
+                .with_emitter_system(with_target(Beam::new().with_number(2)))
+                .build()
+                .into_pdu_body(),
+        );
+    }
+
+    #[test]
+    fn de_fire_ids_match_interaction_of_parsed_body() {
+        let area_aimpoint = DEAreaAimpoint::new()
+            .with_beam_antenna_pattern(BeamAntennaPattern::new())
+            .with_beam_antenna_pattern(BeamAntennaPattern::new())
+            .with_target_energy_deposition(DETargetEnergyDeposition::new(
+                EntityId::new(3, 3, 3),
+                1.0,
+            ));
+
+        assert_ids_match(
+            &DirectedEnergyFire::builder()
+                .with_firing_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &DirectedEnergyFire::builder()
+                .with_firing_entity_id(EntityId::new(1, 1, 1))
+                .with_de_record(DERecord::DamageDescription(DEDamageDescription::new()))
+                .with_de_record(DERecord::PrecisionAimpoint(
+                    DEPrecisionAimpoint::new().with_target_entity_id(EntityId::new(2, 2, 2)),
+                ))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_ids_match(
+            &DirectedEnergyFire::builder()
+                .with_firing_entity_id(EntityId::new(1, 1, 1))
+                .with_de_record(DERecord::AreaAimpoint(DEAreaAimpoint::new()))
+                .with_de_record(DERecord::AreaAimpoint(area_aimpoint))
+                .build()
+                .into_pdu_body(),
+        );
+    }
+
+    #[test]
+    fn view_matches_parsed_pdu_for_all_body_types() {
+        for body in default_bodies() {
+            let buf = serialize(vec![body.clone()]);
+            let (view, remainder) = PduView::parse(&buf).unwrap();
+            let pdu = view.to_pdu().unwrap();
+
+            assert!(remainder.is_empty());
+            assert_eq!(view.header(), &pdu.header, "{:?}", body.body_type());
+            assert_eq!(view.pdu_type(), pdu.header.pdu_type);
+            assert_eq!(view.protocol_version(), pdu.header.protocol_version);
+            assert_eq!(view.exercise_id(), pdu.header.exercise_id);
+            assert_eq!(view.pdu_status(), pdu.header.pdu_status.as_ref());
+            assert_eq!(view.body_length(), pdu.body.body_length());
+            assert_eq!(view.as_bytes(), &buf[..]);
+            assert_eq!(
+                view.body_bytes(),
+                &buf[PDU_HEADER_LEN_BYTES as usize..],
+                "{:?}",
+                pdu.header.pdu_type
+            );
+            assert_eq!(
+                view.originator(),
+                pdu.body.originator(),
+                "{:?}",
+                pdu.header.pdu_type
+            );
+            assert_eq!(
+                view.receiver(),
+                pdu.body.receiver(),
+                "{:?}",
+                pdu.header.pdu_type
+            );
+        }
+    }
+
+    #[test]
+    fn iterate_views() {
+        let buf = serialize(vec![
+            EntityState::builder().build().into_pdu_body(),
+            Fire::builder().build().into_pdu_body(),
+        ]);
+        let types: Vec<PduType> = parse_views(&buf)
+            .map(|view| view.unwrap().pdu_type())
+            .collect();
+
+        assert_eq!(types, vec![PduType::EntityState, PduType::Fire]);
+    }
+
+    #[test]
+    fn insufficient_length() {
+        let buf = serialize(vec![Fire::builder().build().into_pdu_body()]);
+
+        assert!(matches!(
+            PduView::parse(&buf[..8]),
+            Err(DisError::InsufficientHeaderLength(8))
+        ));
+        assert!(matches!(
+            PduView::parse(&buf[..20]),
+            Err(DisError::InsufficientPduLength(_, 8))
+        ));
+
+        let mut views = parse_views(&buf[..20]);
+        assert!(views.next().unwrap().is_err());
+        assert!(views.next().is_none());
+    }
+}
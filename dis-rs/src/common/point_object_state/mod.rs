@@ -0,0 +1,115 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{Pdu, PduHeader, TimeUnits, Timestamp};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        AppearanceEntityOrObjectState, AppearanceObjectGeneralDamage,
+        AppearanceObjectGeneralIEDPresent, AppearanceObjectSpecificChemicalType,
+        CoupledExtensionIndicator, ForceId, LvcIndicator, PduType, PlatformDomain,
+    };
+    use crate::model::{
+        EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification,
+        ObjectType, Orientation, SimulationAddress,
+    };
+    use crate::point_object_state::model::{PointObjectState, SmokeBurstAppearance};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn point_object_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::PointObjectState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let smoke = SmokeBurstAppearance {
+            opacity: 80,
+            size: 20,
+            height: 10,
+            number_of_bursts: 3,
+            chemical_type: AppearanceObjectSpecificChemicalType::WhitePhosphorous,
+        };
+        let body = PointObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 100))
+            .with_referenced_object_id(EntityId::new(1, 1, 99))
+            .with_update_number(3)
+            .with_force_id(ForceId::Opposing)
+            .with_modifications(ObjectStateModification::new(true, false))
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::TacticalSmoke,
+                1,
+                2,
+            ))
+            .with_object_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_object_orientation(Orientation::new(0.5, 0.0, 0.0))
+            .with_specific_object_appearance((&smoke).into())
+            .with_general_object_appearance(
+                GeneralObjectAppearance::default()
+                    .with_percent_complete(100)
+                    .with_smoking(true),
+            )
+            .with_requester_id(SimulationAddress::new(2, 2))
+            .with_receiving_id(SimulationAddress::new(3, 3))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+        assert_eq!(pdu_length, 88);
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn general_object_appearance_bits() {
+        let appearance = GeneralObjectAppearance::default()
+            .with_percent_complete(50)
+            .with_damage(AppearanceObjectGeneralDamage::Destroyed)
+            .with_state(AppearanceEntityOrObjectState::Deactivated)
+            .with_flaming(true)
+            .with_ied_present(AppearanceObjectGeneralIEDPresent::CompletelyHidden);
+        let value = u16::from(&appearance);
+
+        assert_eq!(value, 0b1110_1010_0011_0010);
+        assert_eq!(GeneralObjectAppearance::from(value), appearance);
+    }
+
+    #[test]
+    fn smoke_burst_appearance_bits() {
+        let value: u32 = 0b1100_0011_0000_1010_0001_0100_0101_0000;
+        let smoke = SmokeBurstAppearance::from(value);
+
+        assert_eq!(smoke.opacity, 80);
+        assert_eq!(smoke.size, 20);
+        assert_eq!(smoke.height, 10);
+        assert_eq!(smoke.number_of_bursts, 3);
+        assert_eq!(u32::from(&smoke), value);
+    }
+}
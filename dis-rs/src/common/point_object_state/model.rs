@@ -0,0 +1,152 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation,
+    PduBody, SimulationAddress,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{AppearanceObjectSpecificChemicalType, ForceId, PduType};
+use crate::point_object_state::builder::PointObjectStateBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const POINT_OBJECT_STATE_BODY_LENGTH: u16 = 76;
+
+/// 5.11.3.2 Point Object State PDU
+///
+/// 7.10.4 Point Object State PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    pub object_location: Location,
+    pub object_orientation: Orientation,
+    /// The object specific part of the Object Appearance, which is interpreted according to the `object_type`.
+    /// See `CraterAppearance`, `RibbonBridgeAppearance`, `SmokeBurstAppearance` and `BreachableObjectAppearance`.
+    pub specific_object_appearance: u32,
+    pub general_object_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+}
+
+impl BodyRaw for PointObjectState {
+    type Builder = PointObjectStateBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::PointObjectState(self)
+    }
+}
+
+impl BodyInfo for PointObjectState {
+    fn body_length(&self) -> u16 {
+        POINT_OBJECT_STATE_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::PointObjectState
+    }
+}
+
+impl Interaction for PointObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Specific Object Appearance of a Crater point object
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CraterAppearance {
+    /// Size of the crater in meters
+    pub size: u8,
+}
+
+impl From<u32> for CraterAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            size: (value & 0xFF) as u8,
+        }
+    }
+}
+
+impl From<&CraterAppearance> for u32 {
+    fn from(value: &CraterAppearance) -> Self {
+        u32::from(value.size)
+    }
+}
+
+/// Specific Object Appearance of a Ribbon Bridge point object
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RibbonBridgeAppearance {
+    pub number_of_segments: u8,
+}
+
+impl From<u32> for RibbonBridgeAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            number_of_segments: (value & 0xFF) as u8,
+        }
+    }
+}
+
+impl From<&RibbonBridgeAppearance> for u32 {
+    fn from(value: &RibbonBridgeAppearance) -> Self {
+        u32::from(value.number_of_segments)
+    }
+}
+
+/// Specific Object Appearance of Air Burst and Ground Burst (smoke) point objects
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmokeBurstAppearance {
+    /// Opacity in percent
+    pub opacity: u8,
+    /// Size in meters
+    pub size: u8,
+    /// Height in meters
+    pub height: u8,
+    /// Number of bursts, 6 bits
+    pub number_of_bursts: u8,
+    pub chemical_type: AppearanceObjectSpecificChemicalType,
+}
+
+impl From<u32> for SmokeBurstAppearance {
+    fn from(value: u32) -> Self {
+        Self {
+            opacity: (value & 0xFF) as u8,
+            size: ((value >> 8) & 0xFF) as u8,
+            height: ((value >> 16) & 0xFF) as u8,
+            number_of_bursts: ((value >> 24) & 0x3F) as u8,
+            chemical_type: AppearanceObjectSpecificChemicalType::from(((value >> 30) & 0x03) as u8),
+        }
+    }
+}
+
+impl From<&SmokeBurstAppearance> for u32 {
+    fn from(value: &SmokeBurstAppearance) -> Self {
+        let opacity = u32::from(value.opacity);
+        let size = u32::from(value.size) << 8;
+        let height = u32::from(value.height) << 16;
+        let number_of_bursts = (u32::from(value.number_of_bursts) & 0x3F) << 24;
+        let chemical_type = u32::from(u8::from(value.chemical_type)) << 30;
+
+        opacity | size | height | number_of_bursts | chemical_type
+    }
+}
@@ -0,0 +1,24 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::point_object_state::model::PointObjectState;
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for PointObjectState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8((&self.modifications).into());
+        self.object_type.serialize(buf);
+        self.object_location.serialize(buf);
+        self.object_orientation.serialize(buf);
+        buf.put_u32(self.specific_object_appearance);
+        buf.put_u16((&self.general_object_appearance).into());
+        buf.put_u16(0u16);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        buf.put_u32(0u32);
+
+        self.body_length()
+    }
+}
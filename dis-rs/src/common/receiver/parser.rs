@@ -1,20 +1,22 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::common::receiver::model::Receiver;
 use crate::enumerations::ReceiverState;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_f32, be_u16};
 
 pub(crate) fn receiver_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, radio_reference_id) = entity_id(input)?;
-    let (input, radio_number) = be_u16(input)?;
-    let (input, receiver_state) = be_u16(input)?;
+    let (input, radio_reference_id) = context("Radio Reference ID", entity_id).parse(input)?;
+    let (input, radio_number) = context("Radio Number", be_u16).parse(input)?;
+    let (input, receiver_state) = context("Receiver State", be_u16).parse(input)?;
     let receiver_state = ReceiverState::from(receiver_state);
-    let (input, _padding) = be_u16(input)?;
-    let (input, received_power) = be_f32(input)?;
-    let (input, transmitter_radio_reference_id) = entity_id(input)?;
-    let (input, transmitter_radio_number) = be_u16(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, received_power) = context("Received Power", be_f32).parse(input)?;
+    let (input, transmitter_radio_reference_id) =
+        context("Transmitter Radio Reference ID", entity_id).parse(input)?;
+    let (input, transmitter_radio_number) =
+        context("Transmitter Radio Number", be_u16).parse(input)?;
 
     let body = Receiver::builder()
         .with_radio_reference_id(radio_reference_id)
@@ -1,25 +1,27 @@
 use crate::BodyRaw;
 use crate::common::model::{PduBody, Timestamp};
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::enumerations::{RecordQueryREventType, RequiredReliabilityService, VariableRecordType};
 use crate::record_query_r::model::{RecordQueryR, RecordQuerySpecification};
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16, be_u32};
-use nom::{IResult, Parser};
 
 pub(crate) fn record_query_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, required_reliability_service) = be_u8(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, required_reliability_service) =
+        context("Required Reliability Service", be_u8).parse(input)?;
     let required_reliability_service =
         RequiredReliabilityService::from(required_reliability_service);
-    let (input, _padding) = be_u8(input)?;
-    let (input, event_type) = be_u16(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, event_type) = context("Event Type", be_u16).parse(input)?;
     let event_type = RecordQueryREventType::from(event_type);
-    let (input, time) = be_u32(input)?;
+    let (input, time) = context("Time", be_u32).parse(input)?;
     let time = Timestamp::new(time);
-    let (input, record_query_specification) = record_query_specification(input)?;
+    let (input, record_query_specification) =
+        context("Record Query Specification", record_query_specification).parse(input)?;
 
     let body = RecordQueryR::builder()
         .with_origination_id(originating_id)
@@ -35,8 +37,8 @@ pub(crate) fn record_query_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 pub(crate) fn record_query_specification(input: &[u8]) -> IResult<&[u8], RecordQuerySpecification> {
-    let (input, record_count) = be_u32(input)?;
-    let (input, records) = count(be_u32, record_count as usize).parse(input)?;
+    let (input, record_count) = context("Record Count", be_u32).parse(input)?;
+    let (input, records) = count(context("Record", be_u32), record_count as usize).parse(input)?;
     let records = records
         .iter()
         .map(|record| VariableRecordType::from(*record))
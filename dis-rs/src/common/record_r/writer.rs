@@ -9,7 +9,8 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
         buf.put_u32(self.request_id);
         buf.put_u8(self.required_reliability_service.into());
         buf.put_u8(0u8);
-        buf.put_u32(self.event_type.into());
+        // The Event Type of the Record-R PDU is a 16-bit field
+        buf.put_u16(u32::from(self.event_type) as u16);
         buf.put_u32(self.response_serial_number);
         let record_specification_bytes = self.record_specification.serialize(buf);
 
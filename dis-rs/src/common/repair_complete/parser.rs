@@ -1,17 +1,17 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::enumerations::RepairCompleteRepair;
 use crate::repair_complete::model::RepairComplete;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u16;
 
 pub(crate) fn repair_complete_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, repairing_id) = entity_id(input)?;
-    let (input, repair) = be_u16(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, repairing_id) = context("Repairing ID", entity_id).parse(input)?;
+    let (input, repair) = context("Repair", be_u16).parse(input)?;
     let repair = RepairCompleteRepair::from(repair);
-    let (input, _padding) = be_u16(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
 
     let body = RepairComplete::builder()
         .with_receiving_id(receiving_id)
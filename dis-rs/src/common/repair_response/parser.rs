@@ -1,18 +1,18 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::enumerations::RepairResponseRepairResult;
 use crate::repair_response::model::RepairResponse;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u8, be_u16};
 
 pub(crate) fn repair_response_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, repairing_id) = entity_id(input)?;
-    let (input, repair_result) = be_u8(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, repairing_id) = context("Repairing ID", entity_id).parse(input)?;
+    let (input, repair_result) = context("Repair Result", be_u8).parse(input)?;
     let repair_result = RepairResponseRepairResult::from(repair_result);
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
 
     let body = RepairResponse::builder()
         .with_receiving_id(receiving_id)
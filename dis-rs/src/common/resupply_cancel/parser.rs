@@ -1,12 +1,12 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::resupply_cancel::model::ResupplyCancel;
-use nom::IResult;
+use nom::Parser;
 
 pub(crate) fn resupply_cancel_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, requesting_id) = entity_id(input)?;
-    let (input, servicing_id) = entity_id(input)?;
+    let (input, requesting_id) = context("Requesting ID", entity_id).parse(input)?;
+    let (input, servicing_id) = context("Servicing ID", entity_id).parse(input)?;
 
     let body = ResupplyCancel::builder()
         .with_requesting_id(requesting_id)
@@ -1,18 +1,22 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, supply_quantity};
+use crate::common::parser::{IResult, context, entity_id, supply_quantity};
 use crate::resupply_received::model::ResupplyReceived;
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16};
-use nom::{IResult, Parser};
 
 pub(crate) fn resupply_received_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, requesting_id) = entity_id(input)?;
-    let (input, servicing_id) = entity_id(input)?;
-    let (input, nr_of_supplies) = be_u8(input)?;
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, supplies) = count(supply_quantity, nr_of_supplies.into()).parse(input)?;
+    let (input, requesting_id) = context("Requesting ID", entity_id).parse(input)?;
+    let (input, servicing_id) = context("Servicing ID", entity_id).parse(input)?;
+    let (input, nr_of_supplies) = context("Number of Supplies", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, supplies) = count(
+        context("Supply Quantity", supply_quantity),
+        nr_of_supplies.into(),
+    )
+    .parse(input)?;
 
     let body = ResupplyReceived::builder()
         .with_requesting_id(requesting_id)
@@ -1,23 +1,29 @@
 use crate::BodyRaw;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::model::PduBody;
 use crate::sees::model::{PropulsionSystemData, SEES, VectoringNozzleSystemData};
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u16};
-use nom::{IResult, Parser};
 
 pub(crate) fn sees_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_entity_id) = entity_id(input)?;
-    let (input, ir_signature) = be_u16(input)?;
-    let (input, acoustic_signature) = be_u16(input)?;
-    let (input, rcs_signature) = be_u16(input)?;
-    let (input, nr_of_propulsion_systems) = be_u16(input)?;
-    let (input, nr_of_vectoring_nozzle_systems) = be_u16(input)?;
-
-    let (input, propulsion_systems) =
-        count(propulsion_system_data, nr_of_propulsion_systems.into()).parse(input)?;
+    let (input, originating_entity_id) =
+        context("Originating Entity ID", entity_id).parse(input)?;
+    let (input, ir_signature) = context("IR Signature", be_u16).parse(input)?;
+    let (input, acoustic_signature) = context("Acoustic Signature", be_u16).parse(input)?;
+    let (input, rcs_signature) = context("RCS Signature", be_u16).parse(input)?;
+    let (input, nr_of_propulsion_systems) =
+        context("Number of Propulsion Systems", be_u16).parse(input)?;
+    let (input, nr_of_vectoring_nozzle_systems) =
+        context("Number of Vectoring Nozzle Systems", be_u16).parse(input)?;
+
+    let (input, propulsion_systems) = count(
+        context("Propulsion System Data", propulsion_system_data),
+        nr_of_propulsion_systems.into(),
+    )
+    .parse(input)?;
     let (input, vectoring_nozzle_systems) = count(
-        vectoring_nozzle_system_data,
+        context("Vectoring Nozzle System Data", vectoring_nozzle_system_data),
         nr_of_vectoring_nozzle_systems.into(),
     )
     .parse(input)?;
@@ -35,8 +41,8 @@ pub(crate) fn sees_body(input: &[u8]) -> IResult<&[u8], PduBody> {
 }
 
 fn propulsion_system_data(input: &[u8]) -> IResult<&[u8], PropulsionSystemData> {
-    let (input, power_setting) = be_f32(input)?;
-    let (input, engine_rpm) = be_f32(input)?;
+    let (input, power_setting) = context("Power Setting", be_f32).parse(input)?;
+    let (input, engine_rpm) = context("Engine RPM", be_f32).parse(input)?;
 
     Ok((
         input,
@@ -47,8 +53,8 @@ fn propulsion_system_data(input: &[u8]) -> IResult<&[u8], PropulsionSystemData>
 }
 
 fn vectoring_nozzle_system_data(input: &[u8]) -> IResult<&[u8], VectoringNozzleSystemData> {
-    let (input, horizontal) = be_f32(input)?;
-    let (input, vertical) = be_f32(input)?;
+    let (input, horizontal) = context("Horizontal", be_f32).parse(input)?;
+    let (input, vertical) = context("Vertical", be_f32).parse(input)?;
 
     Ok((
         input,
@@ -1,20 +1,24 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::{entity_id, supply_quantity};
+use crate::common::parser::{IResult, context, entity_id, supply_quantity};
 use crate::common::service_request::model::ServiceRequest;
 use crate::enumerations::ServiceRequestServiceTypeRequested;
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_u16};
-use nom::{IResult, Parser};
 
 pub(crate) fn service_request_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, requesting_id) = entity_id(input)?;
-    let (input, servicing_id) = entity_id(input)?;
-    let (input, service_type_requested) = be_u8(input)?;
+    let (input, requesting_id) = context("Requesting ID", entity_id).parse(input)?;
+    let (input, servicing_id) = context("Servicing ID", entity_id).parse(input)?;
+    let (input, service_type_requested) = context("Service Type Requested", be_u8).parse(input)?;
     let service_type_requested = ServiceRequestServiceTypeRequested::from(service_type_requested);
-    let (input, nr_of_supplies) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, supplies) = count(supply_quantity, nr_of_supplies.into()).parse(input)?;
+    let (input, nr_of_supplies) = context("Number of Supplies", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, supplies) = count(
+        context("Supply Quantity", supply_quantity),
+        nr_of_supplies.into(),
+    )
+    .parse(input)?;
 
     let body = ServiceRequest::builder()
         .with_requesting_id(requesting_id)
@@ -1,13 +1,13 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::entity_id;
+use crate::common::parser::{IResult, context, entity_id};
 use crate::common::signal::model::{EncodingScheme, Signal};
 use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
 use crate::enumerations::{
     SignalEncodingClass, SignalEncodingType, SignalTdlType, SignalUserProtocolIdentificationNumber,
 };
 use crate::model::length_padded_to_num;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u16, be_u32};
 
 #[cfg_attr(
@@ -15,21 +15,28 @@
     hotpath::measure
 )]
 pub(crate) fn signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, radio_reference_id) = entity_id(input)?;
-    let (input, radio_number) = be_u16(input)?;
-    let (input, encoding_scheme) = be_u16(input)?;
-    let (input, tdl_type) = be_u16(input)?;
+    let (input, radio_reference_id) = context("Radio Reference ID", entity_id).parse(input)?;
+    let (input, radio_number) = context("Radio Number", be_u16).parse(input)?;
+    let (input, encoding_scheme) = context("Encoding Scheme", be_u16).parse(input)?;
+    let (input, tdl_type) = context("TDL Type", be_u16).parse(input)?;
     let tdl_type = SignalTdlType::from(tdl_type);
-    let (input, sample_rate) = be_u32(input)?;
+    let (input, sample_rate) = context("Sample Rate", be_u32).parse(input)?;
 
-    let (input, data_length_in_bits) = be_u16(input)?;
-    let (input, samples) = be_u16(input)?;
-    let (input, data) =
-        nom::bytes::complete::take(data_length_in_bits / ONE_BYTE_IN_BITS as u16)(input)?;
+    let (input, data_length_in_bits) = context("Data Length in Bits", be_u16).parse(input)?;
+    let (input, samples) = context("Samples", be_u16).parse(input)?;
+    let (input, data) = context(
+        "Data",
+        nom::bytes::complete::take(data_length_in_bits / ONE_BYTE_IN_BITS as u16),
+    )
+    .parse(input)?;
 
     let data_length_in_bytes = data_length_in_bits / ONE_BYTE_IN_BITS as u16;
     let padded_record = length_padded_to_num(data_length_in_bytes as usize, FOUR_OCTETS);
-    let (input, _padding) = nom::bytes::complete::take(padded_record.padding_length)(input)?;
+    let (input, _padding) = context(
+        "Padding",
+        nom::bytes::complete::take(padded_record.padding_length),
+    )
+    .parse(input)?;
 
     let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
 
@@ -46,7 +53,7 @@ pub(crate) fn signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     Ok((input, body.into_pdu_body()))
 }
 
-fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
+pub(crate) fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
     let encoding_class = encoding_scheme_bytes >> 14;
     let low_bits = encoding_scheme_bytes & 0x3FFF;
     let encoding_class = SignalEncodingClass::from(encoding_class);
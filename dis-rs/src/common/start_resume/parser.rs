@@ -1,16 +1,16 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::{clock_time, entity_id};
+use crate::common::parser::{IResult, clock_time, context, entity_id};
 use crate::common::start_resume::model::StartResume;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u32;
 
 pub(crate) fn start_resume_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, real_world_time) = clock_time(input)?;
-    let (input, simulation_time) = clock_time(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, real_world_time) = context("Real World Time", clock_time).parse(input)?;
+    let (input, simulation_time) = context("Simulation Time", clock_time).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
 
     let body = StartResume::builder()
         .with_origination_id(originating_id)
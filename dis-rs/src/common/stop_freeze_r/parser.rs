@@ -1,20 +1,21 @@
 use crate::BodyRaw;
 use crate::common::model::PduBody;
-use crate::common::parser::{clock_time, entity_id};
+use crate::common::parser::{IResult, clock_time, context, entity_id};
 use crate::enumerations::{RequiredReliabilityService, StopFreezeFrozenBehavior, StopFreezeReason};
 use crate::stop_freeze_r::model::StopFreezeR;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u8, be_u32};
 
 pub(crate) fn stop_freeze_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, real_world_time) = clock_time(input)?;
-    let (input, reason) = be_u8(input)?;
-    let (input, behavior) = be_u8(input)?;
-    let (input, required_reliability_service) = be_u8(input)?;
-    let (input, _padding) = be_u8(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, real_world_time) = context("Real World Time", clock_time).parse(input)?;
+    let (input, reason) = context("Reason", be_u8).parse(input)?;
+    let (input, behavior) = context("Behavior", be_u8).parse(input)?;
+    let (input, required_reliability_service) =
+        context("Required Reliability Service", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
 
     let reason = StopFreezeReason::from(reason);
     let behavior = StopFreezeFrozenBehavior::from(behavior);
@@ -0,0 +1,304 @@
+//! Incremental decoding of PDUs from a byte stream.
+//!
+//! Transports such as TCP do not preserve message boundaries: a single read can contain part of a PDU,
+//! or several PDUs at once. The [`DisStreamDecoder`] buffers incoming chunks and uses the `pdu_length`
+//! field of the PDU header to frame complete PDUs.
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::parser::parse_pdu;
+use crate::constants::{MAXIMUM_PDU_SIZE_OCTETS, PDU_HEADER_LEN_BYTES};
+use crate::enumerations::{PduType, ProtocolFamily};
+use bytes::{Buf, BytesMut};
+
+const PROTOCOL_VERSION_OFFSET: usize = 0;
+const PDU_TYPE_OFFSET: usize = 2;
+const PROTOCOL_FAMILY_OFFSET: usize = 3;
+const PDU_LENGTH_OFFSET: usize = 8;
+const MAX_PROTOCOL_VERSION: u8 = 7;
+
+/// Stateful decoder that frames PDUs from arbitrary chunks of a byte stream.
+///
+/// Chunks are added using [`DisStreamDecoder::push`], after which complete PDUs can be taken
+/// using [`DisStreamDecoder::next_pdu`]. Incomplete data is kept until the next chunk arrives.
+///
+/// When the buffered data does not start with a plausible PDU header, the decoder skips bytes
+/// until it finds one, and reports the number of discarded bytes as an error.
+/// A header is plausible when the protocol version, PDU type and protocol family are consistent,
+/// and the PDU length lies between the header length and the maximum PDU length.
+#[derive(Debug)]
+pub struct DisStreamDecoder {
+    buffer: BytesMut,
+    skipped: usize,
+    max_pdu_length: u16,
+}
+
+impl Default for DisStreamDecoder {
+    fn default() -> Self {
+        Self {
+            buffer: BytesMut::default(),
+            skipped: 0,
+            max_pdu_length: MAXIMUM_PDU_SIZE_OCTETS as u16,
+        }
+    }
+}
+
+impl DisStreamDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum `pdu_length` of a plausible PDU header, which defaults to
+    /// the maximum PDU size of 8192 octets defined by the standard.
+    #[must_use]
+    pub fn with_max_pdu_length(mut self, max_pdu_length: u16) -> Self {
+        self.max_pdu_length = max_pdu_length;
+        self
+    }
+
+    /// Appends a chunk of received bytes to the internal buffer.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Takes the next complete PDU from the buffered data.
+    ///
+    /// Returns `None` when more data is needed to complete the next PDU.
+    /// Returns `Some(Err(_))` when bytes had to be discarded to resynchronise on a PDU header,
+    /// or when a correctly framed PDU could not be parsed. Decoding can continue after an error.
+    pub fn next_pdu(&mut self) -> Option<Result<Pdu, DisError>> {
+        while self.buffer.len() >= PDU_HEADER_LEN_BYTES as usize {
+            if is_plausible_header(&self.buffer, self.max_pdu_length) {
+                break;
+            }
+            self.buffer.advance(1);
+            self.skipped += 1;
+        }
+
+        if self.buffer.len() < PDU_HEADER_LEN_BYTES as usize {
+            return None;
+        }
+
+        if self.skipped > 0 {
+            let skipped = std::mem::take(&mut self.skipped);
+            return Some(Err(DisError::DiscardedBytes(skipped)));
+        }
+
+        let pdu_length = usize::from(pdu_length(&self.buffer));
+        if self.buffer.len() < pdu_length {
+            return None;
+        }
+
+        let frame = self.buffer.split_to(pdu_length);
+        Some(parse_pdu(&frame))
+    }
+
+    /// Appends a chunk of received bytes and returns all PDUs that can be completed.
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<Result<Pdu, DisError>> {
+        self.push(chunk);
+        std::iter::from_fn(|| self.next_pdu()).collect()
+    }
+
+    /// Returns the number of bytes buffered but not yet decoded.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Discards all buffered data.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.skipped = 0;
+    }
+}
+
+fn pdu_length(header: &[u8]) -> u16 {
+    u16::from_be_bytes([header[PDU_LENGTH_OFFSET], header[PDU_LENGTH_OFFSET + 1]])
+}
+
+/// Checks whether `header` starts with values that can form a valid PDU header.
+fn is_plausible_header(header: &[u8], max_pdu_length: u16) -> bool {
+    let protocol_version = header[PROTOCOL_VERSION_OFFSET];
+    let pdu_type = PduType::from(header[PDU_TYPE_OFFSET]);
+    let protocol_family = ProtocolFamily::from(header[PROTOCOL_FAMILY_OFFSET]);
+
+    (1..=MAX_PROTOCOL_VERSION).contains(&protocol_version)
+        && !matches!(pdu_type, PduType::Unspecified(_))
+        && protocol_family == ProtocolFamily::from(pdu_type)
+        && (PDU_HEADER_LEN_BYTES..=max_pdu_length).contains(&pdu_length(header))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::BodyInfo;
+    use crate::common::model::{EntityId, PduBody, PduHeader, TimeUnits, Timestamp};
+    use crate::entity_state::model::EntityState;
+    use crate::remove_entity::model::RemoveEntity;
+
+    fn serialize(bodies: Vec<PduBody>) -> BytesMut {
+        let mut buf = BytesMut::with_capacity(4096);
+        for body in bodies {
+            let pdu = Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, body.body_type()),
+                body,
+                Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+            );
+            pdu.serialize(&mut buf).unwrap();
+        }
+        buf
+    }
+
+    fn bodies() -> Vec<PduBody> {
+        vec![
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+            RemoveEntity::builder()
+                .with_origination_id(EntityId::new(1, 1, 2))
+                .with_receiving_id(EntityId::new(1, 1, 3))
+                .build()
+                .into_pdu_body(),
+        ]
+    }
+
+    #[test]
+    fn decode_multiple_pdus_in_one_chunk() {
+        let buf = serialize(bodies());
+        let mut decoder = DisStreamDecoder::new();
+
+        let pdus = decoder.decode(&buf);
+
+        assert_eq!(pdus.len(), 2);
+        assert_eq!(
+            pdus[0].as_ref().unwrap().header.pdu_type,
+            PduType::EntityState
+        );
+        assert_eq!(
+            pdus[1].as_ref().unwrap().header.pdu_type,
+            PduType::RemoveEntity
+        );
+        assert_eq!(decoder.buffered_len(), 0);
+    }
+
+    #[test]
+    fn decode_pdu_split_over_chunks() {
+        let buf = serialize(bodies());
+        let mut decoder = DisStreamDecoder::new();
+
+        let mut pdus = Vec::new();
+        for chunk in buf.chunks(7) {
+            pdus.extend(decoder.decode(chunk));
+        }
+
+        assert_eq!(pdus.len(), 2);
+        assert!(pdus.iter().all(Result::is_ok));
+        assert_eq!(decoder.buffered_len(), 0);
+    }
+
+    #[test]
+    fn retain_partial_pdu() {
+        let buf = serialize(bodies());
+        let first_length = usize::from(pdu_length(&buf));
+        let mut decoder = DisStreamDecoder::new();
+
+        let pdus = decoder.decode(&buf[..first_length + 5]);
+        assert_eq!(pdus.len(), 1);
+        assert_eq!(decoder.buffered_len(), 5);
+
+        let pdus = decoder.decode(&buf[first_length + 5..]);
+        assert_eq!(pdus.len(), 1);
+        assert_eq!(
+            pdus[0].as_ref().unwrap().header.pdu_type,
+            PduType::RemoveEntity
+        );
+    }
+
+    #[test]
+    fn resynchronise_after_garbage() {
+        let buf = serialize(bodies());
+        let mut input = vec![0xFF, 0x00, 0xFF];
+        input.extend_from_slice(&buf);
+        let mut decoder = DisStreamDecoder::new();
+
+        let pdus = decoder.decode(&input);
+
+        assert_eq!(pdus.len(), 3);
+        assert_eq!(pdus[0], Err(DisError::DiscardedBytes(3)));
+        assert_eq!(
+            pdus[1].as_ref().unwrap().header.pdu_type,
+            PduType::EntityState
+        );
+        assert_eq!(
+            pdus[2].as_ref().unwrap().header.pdu_type,
+            PduType::RemoveEntity
+        );
+    }
+
+    #[test]
+    fn resynchronise_after_corrupt_header() {
+        let buf = serialize(bodies());
+        let mut input = buf[..PDU_HEADER_LEN_BYTES as usize].to_vec();
+        input[PDU_LENGTH_OFFSET..PDU_LENGTH_OFFSET + 2].copy_from_slice(&u16::MAX.to_be_bytes());
+        input.extend_from_slice(&buf);
+        let mut decoder = DisStreamDecoder::new();
+
+        let pdus = decoder.decode(&input);
+
+        assert_eq!(pdus.len(), 3);
+        assert_eq!(
+            pdus[0],
+            Err(DisError::DiscardedBytes(PDU_HEADER_LEN_BYTES as usize))
+        );
+        assert_eq!(
+            pdus[1].as_ref().unwrap().header.pdu_type,
+            PduType::EntityState
+        );
+        assert_eq!(
+            pdus[2].as_ref().unwrap().header.pdu_type,
+            PduType::RemoveEntity
+        );
+    }
+
+    #[test]
+    fn reject_header_with_mismatching_protocol_family() {
+        let buf = serialize(bodies());
+        let mut input = buf.to_vec();
+        input[PROTOCOL_FAMILY_OFFSET] = u8::from(ProtocolFamily::SimulationManagement);
+
+        assert!(!is_plausible_header(&input, MAXIMUM_PDU_SIZE_OCTETS as u16));
+        assert!(is_plausible_header(&buf, MAXIMUM_PDU_SIZE_OCTETS as u16));
+    }
+
+    #[test]
+    fn configure_max_pdu_length() {
+        let buf = serialize(bodies());
+        let first_length = pdu_length(&buf);
+        let mut decoder = DisStreamDecoder::new().with_max_pdu_length(first_length - 1);
+
+        let pdus = decoder.decode(&buf);
+
+        assert_eq!(pdus.len(), 2);
+        assert_eq!(
+            pdus[0],
+            Err(DisError::DiscardedBytes(usize::from(first_length)))
+        );
+        assert_eq!(
+            pdus[1].as_ref().unwrap().header.pdu_type,
+            PduType::RemoveEntity
+        );
+    }
+
+    #[test]
+    fn clear_buffer() {
+        let buf = serialize(bodies());
+        let mut decoder = DisStreamDecoder::new();
+
+        assert!(decoder.decode(&buf[..20]).is_empty());
+        decoder.clear();
+        assert_eq!(decoder.buffered_len(), 0);
+        assert!(decoder.next_pdu().is_none());
+    }
+}
@@ -1,21 +1,22 @@
 use crate::BodyRaw;
-use crate::common::parser::{entity_id, record_specification};
+use crate::common::parser::{IResult, context, entity_id, record_specification};
 use crate::enumerations::{RequiredReliabilityService, TransferControlTransferType};
 use crate::model::PduBody;
 use crate::transfer_ownership::model::TransferOwnership;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::{be_u8, be_u32};
 
 pub(crate) fn transfer_ownership_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, required_reliability) = be_u8(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id).parse(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id).parse(input)?;
+    let (input, request_id) = context("Request ID", be_u32).parse(input)?;
+    let (input, required_reliability) = context("Required Reliability", be_u8).parse(input)?;
     let required_reliability = RequiredReliabilityService::from(required_reliability);
-    let (input, transfer_type) = be_u8(input)?;
+    let (input, transfer_type) = context("Transfer Type", be_u8).parse(input)?;
     let transfer_type = TransferControlTransferType::from(transfer_type);
-    let (input, transfer_entity_id) = entity_id(input)?;
-    let (input, record_specification) = record_specification(input)?;
+    let (input, transfer_entity_id) = context("Transfer Entity ID", entity_id).parse(input)?;
+    let (input, record_specification) =
+        context("Record Specification", record_specification).parse(input)?;
 
     Ok((
         input,
@@ -1,6 +1,8 @@
 use crate::BodyRaw;
 use crate::common::model::{PduBody, PduHeader};
-use crate::common::parser::{entity_id, entity_type, location, orientation, vec3_f32};
+use crate::common::parser::{
+    IResult, context, entity_id, entity_type, location, orientation, vec3_f32,
+};
 use crate::common::transmitter::model::{
     BASE_VTP_RECORD_LENGTH, BeamAntennaPattern, CryptoKeyId, ModulationType, SpreadSpectrum,
     Transmitter, VariableTransmitterParameter,
@@ -10,7 +12,6 @@
     TransmitterCryptoSystem, TransmitterInputSource, TransmitterMajorModulation,
     TransmitterModulationTypeSystem, TransmitterTransmitState, VariableRecordType,
 };
-use nom::IResult;
 use nom::Parser;
 use nom::bytes::complete::take;
 use nom::multi::count;
@@ -20,16 +21,17 @@ pub(crate) fn transmitter_body(
     header: &PduHeader,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
-        let (input, radio_reference_id) = entity_id(input)?;
-        let (input, radio_number) = be_u16(input)?;
-        let (input, radio_type) = entity_type(input)?;
-        let (input, transmit_state) = be_u8(input)?;
+        let (input, radio_reference_id) = context("Radio Reference ID", entity_id).parse(input)?;
+        let (input, radio_number) = context("Radio Number", be_u16).parse(input)?;
+        let (input, radio_type) = context("Radio Type", entity_type).parse(input)?;
+        let (input, transmit_state) = context("Transmit State", be_u8).parse(input)?;
         let transmit_state = TransmitterTransmitState::from(transmit_state);
-        let (input, input_source) = be_u8(input)?;
+        let (input, input_source) = context("Input Source", be_u8).parse(input)?;
         let input_source = TransmitterInputSource::from(input_source);
         #[allow(clippy::wildcard_in_or_patterns)]
         let (input, number_of_vtp) = {
-            let (input, number_of_vtp) = be_u16(input)?;
+            let (input, number_of_vtp) =
+                context("Number of Variable Transmitter Parameters", be_u16).parse(input)?;
             match header.protocol_version {
                 ProtocolVersion::IEEE1278_12012 => (input, number_of_vtp),
                 ProtocolVersion::IEEE1278_1A1998 | _ => {
@@ -37,37 +39,51 @@ pub(crate) fn transmitter_body(
                 }
             }
         };
-        let (input, antenna_location) = location(input)?;
-        let (input, relative_antenna_location) = vec3_f32(input)?;
-        let (input, antenna_pattern_type) = be_u16(input)?;
+        let (input, antenna_location) = context("Antenna Location", location).parse(input)?;
+        let (input, relative_antenna_location) =
+            context("Relative Antenna Location", vec3_f32).parse(input)?;
+        let (input, antenna_pattern_type) = context("Antenna Pattern Type", be_u16).parse(input)?;
         let antenna_pattern_type = TransmitterAntennaPatternType::from(antenna_pattern_type);
-        let (input, antenna_pattern_length) = be_u16(input)?;
-        let (input, frequency) = be_u64(input)?;
-        let (input, transmit_frequency_bandwidth) = be_f32(input)?;
-        let (input, power) = be_f32(input)?;
-        let (input, modulation_type) = modulation_type(input)?;
-        let (input, crypto_system) = be_u16(input)?;
+        let (input, antenna_pattern_length) =
+            context("Antenna Pattern Length", be_u16).parse(input)?;
+        let (input, frequency) = context("Frequency", be_u64).parse(input)?;
+        let (input, transmit_frequency_bandwidth) =
+            context("Transmit Frequency Bandwidth", be_f32).parse(input)?;
+        let (input, power) = context("Power", be_f32).parse(input)?;
+        let (input, modulation_type) = context("Modulation Type", modulation_type).parse(input)?;
+        let (input, crypto_system) = context("Crypto System", be_u16).parse(input)?;
         let crypto_system = TransmitterCryptoSystem::from(crypto_system);
-        let (input, crypto_key_id) = crypto_key_id(input)?;
-        let (input, length_of_modulation_parameters) = be_u8(input)?;
-        let (input, _padding) = be_u8(input)?;
-        let (input, _padding) = be_u16(input)?;
+        let (input, crypto_key_id) = context("Crypto Key ID", crypto_key_id).parse(input)?;
+        let (input, length_of_modulation_parameters) =
+            context("Length of Modulation Parameters", be_u8).parse(input)?;
+        let (input, _padding) = context("Padding", be_u8).parse(input)?;
+        let (input, _padding) = context("Padding", be_u16).parse(input)?;
 
         let (input, modulation_parameters) = if length_of_modulation_parameters > 0 {
-            let (input, params) = take(length_of_modulation_parameters)(input)?;
+            let (input, params) = context(
+                "Modulation Parameters",
+                take(length_of_modulation_parameters),
+            )
+            .parse(input)?;
             (input, Some(params))
         } else {
             (input, None)
         };
         let (input, antenna_pattern) = if antenna_pattern_length > 0 {
-            let (input, pattern) = beam_antenna_pattern(input)?;
+            let (input, pattern) = context("Antenna Pattern", beam_antenna_pattern).parse(input)?;
             (input, Some(pattern))
         } else {
             (input, None)
         };
 
-        let (input, vt_params) =
-            count(variable_transmitter_parameter, number_of_vtp.into()).parse(input)?;
+        let (input, vt_params) = count(
+            context(
+                "Variable Transmitter Parameter",
+                variable_transmitter_parameter,
+            ),
+            number_of_vtp.into(),
+        )
+        .parse(input)?;
 
         let body = Transmitter::builder()
             .with_radio_reference_id(radio_reference_id)
@@ -102,12 +118,12 @@ pub(crate) fn transmitter_body(
 }
 
 fn modulation_type(input: &[u8]) -> IResult<&[u8], ModulationType> {
-    let (input, spread_spectrum) = spread_spectrum(input)?;
-    let (input, major_modulation) = be_u16(input)?;
-    let (input, detail) = be_u16(input)?;
+    let (input, spread_spectrum) = context("Spread Spectrum", spread_spectrum).parse(input)?;
+    let (input, major_modulation) = context("Major Modulation", be_u16).parse(input)?;
+    let (input, detail) = context("Detail", be_u16).parse(input)?;
     let major_modulation =
         TransmitterMajorModulation::new_from_bytes_with_detail(major_modulation, detail);
-    let (input, radio_system) = be_u16(input)?;
+    let (input, radio_system) = context("Radio System", be_u16).parse(input)?;
     let radio_system = TransmitterModulationTypeSystem::from(radio_system);
 
     Ok((
@@ -131,18 +147,18 @@ fn crypto_key_id(input: &[u8]) -> IResult<&[u8], CryptoKeyId> {
     Ok((input, CryptoKeyId::from(value)))
 }
 
-fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
-    let (input, beam_direction) = orientation(input)?;
-    let (input, azimuth_beamwidth) = be_f32(input)?;
-    let (input, elevation_beamwidth) = be_f32(input)?;
-    let (input, reference_system) = be_u8(input)?;
+pub(crate) fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
+    let (input, beam_direction) = context("Beam Direction", orientation).parse(input)?;
+    let (input, azimuth_beamwidth) = context("Azimuth Beamwidth", be_f32).parse(input)?;
+    let (input, elevation_beamwidth) = context("Elevation Beamwidth", be_f32).parse(input)?;
+    let (input, reference_system) = context("Reference System", be_u8).parse(input)?;
     let reference_system = TransmitterAntennaPatternReferenceSystem::from(reference_system);
-    let (input, _padding) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, e_z) = be_f32(input)?;
-    let (input, e_x) = be_f32(input)?;
-    let (input, phase) = be_f32(input)?;
-    let (input, _padding) = be_u32(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, e_z) = context("E Z", be_f32).parse(input)?;
+    let (input, e_x) = context("E X", be_f32).parse(input)?;
+    let (input, phase) = context("Phase", be_f32).parse(input)?;
+    let (input, _padding) = context("Padding", be_u32).parse(input)?;
 
     Ok((
         input,
@@ -158,11 +174,12 @@ fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
 }
 
 fn variable_transmitter_parameter(input: &[u8]) -> IResult<&[u8], VariableTransmitterParameter> {
-    let (input, record_type) = be_u32(input)?;
+    let (input, record_type) = context("Record Type", be_u32).parse(input)?;
     let record_type = VariableRecordType::from(record_type);
-    let (input, record_length) = be_u16(input)?;
+    let (input, record_length) = context("Record Length", be_u16).parse(input)?;
     let fields_length_bytes = record_length - BASE_VTP_RECORD_LENGTH;
-    let (input, specific_fields) = take(fields_length_bytes)(input)?;
+    let (input, specific_fields) =
+        context("Specific Fields", take::<_, &[u8], _>(fields_length_bytes)).parse(input)?;
 
     Ok((
         input,
@@ -0,0 +1,86 @@
+use crate::common::model::{LiveEntityId, LiveOrientation, RelativeWorldCoordinates, VectorI16};
+use crate::tspi::model::{
+    LiveDeadReckoningParameters, LiveOrientationError, LivePositionError, Tspi,
+};
+
+pub struct TspiBuilder(Tspi);
+
+impl Default for TspiBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TspiBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        TspiBuilder(Tspi::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: Tspi) -> Self {
+        TspiBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> Tspi {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_location(mut self, entity_location: RelativeWorldCoordinates) -> Self {
+        self.0.entity_location = entity_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_linear_velocity(mut self, entity_linear_velocity: VectorI16) -> Self {
+        self.0.entity_linear_velocity = Some(entity_linear_velocity);
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_orientation(mut self, entity_orientation: LiveOrientation) -> Self {
+        self.0.entity_orientation = Some(entity_orientation);
+        self
+    }
+
+    #[must_use]
+    pub fn with_position_error(mut self, position_error: LivePositionError) -> Self {
+        self.0.position_error = Some(position_error);
+        self
+    }
+
+    #[must_use]
+    pub fn with_orientation_error(mut self, orientation_error: LiveOrientationError) -> Self {
+        self.0.orientation_error = Some(orientation_error);
+        self
+    }
+
+    #[must_use]
+    pub fn with_dead_reckoning_parameters(
+        mut self,
+        dead_reckoning_parameters: LiveDeadReckoningParameters,
+    ) -> Self {
+        self.0.dead_reckoning_parameters = Some(dead_reckoning_parameters);
+        self
+    }
+
+    #[must_use]
+    pub fn with_measured_speed(mut self, measured_speed: u16) -> Self {
+        self.0.measured_speed = Some(measured_speed);
+        self
+    }
+
+    #[must_use]
+    pub fn with_system_specific_data(mut self, system_specific_data: Vec<u8>) -> Self {
+        self.0.system_specific_data = Some(system_specific_data);
+        self
+    }
+}
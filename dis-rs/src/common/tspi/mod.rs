@@ -0,0 +1,89 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::BodyRaw;
+    use crate::common::model::{
+        LiveEntityId, LiveOrientation, Pdu, PduHeader, RelativeWorldCoordinates, TimeUnits,
+        Timestamp, VectorI8, VectorI16,
+    };
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, DeadReckoningAlgorithm, LvcIndicator, PduType,
+    };
+    use crate::tspi::model::{
+        LiveDeadReckoningParameters, LiveOrientationError, LivePositionError, Tspi,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    fn round_trip(body: Tspi) {
+        let header = PduHeader::new_v7(1, PduType::TSPI).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::Live)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+        let original_pdu = Pdu::finalize_from_parts(
+            header,
+            body.into_pdu_body(),
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+        assert_eq!(buf.len(), pdu_length as usize);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn tspi_minimal_internal_consistency() {
+        let body = Tspi::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_entity_location(RelativeWorldCoordinates::new(1, 100, -200, 5))
+            .build();
+        assert_eq!(body.tspi_flag(), 0);
+
+        round_trip(body);
+    }
+
+    #[test]
+    fn tspi_all_fields_internal_consistency() {
+        let body = Tspi::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_entity_location(RelativeWorldCoordinates::new(1, 100, -200, 5))
+            .with_entity_linear_velocity(VectorI16::new(150, -20, 0))
+            .with_entity_orientation(LiveOrientation::new(64, 0, 255))
+            .with_position_error(LivePositionError::new(20, 40))
+            .with_orientation_error(LiveOrientationError::new(1, 2, 3))
+            .with_dead_reckoning_parameters(
+                LiveDeadReckoningParameters::default()
+                    .with_algorithm(DeadReckoningAlgorithm::StaticNonmovingEntity)
+                    .with_other_parameters([1, 2, 3, 4, 5, 6, 7, 8, 9])
+                    .with_linear_acceleration(VectorI8::new(-1, 2, -3))
+                    .with_angular_velocity(VectorI8::new(4, -5, 6)),
+            )
+            .with_measured_speed(151)
+            .with_system_specific_data(vec![0xAA, 0xBB, 0xCC])
+            .build();
+        assert_eq!(body.tspi_flag(), 0b0111_1111);
+
+        round_trip(body);
+    }
+}
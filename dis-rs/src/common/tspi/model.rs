@@ -0,0 +1,205 @@
+use crate::BodyRaw;
+use crate::common::model::{
+    EntityId, LiveEntityId, LiveOrientation, PduBody, RelativeWorldCoordinates, VectorI8, VectorI16,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{FOUR_OCTETS, SIX_OCTETS, TWO_OCTETS};
+use crate::enumerations::{DeadReckoningAlgorithm, PduType};
+use crate::tspi::builder::TspiBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_TSPI_BODY_LENGTH: u16 = 13;
+pub(crate) const LIVE_DR_PARAMETERS_LENGTH: u16 = 16;
+pub(crate) const LIVE_DR_OTHER_PARAMETERS_LENGTH: usize = 9;
+
+pub(crate) const TSPI_FLAG_LINEAR_VELOCITY: u8 = 0x01;
+pub(crate) const TSPI_FLAG_ORIENTATION: u8 = 0x02;
+pub(crate) const TSPI_FLAG_POSITION_ERROR: u8 = 0x04;
+pub(crate) const TSPI_FLAG_ORIENTATION_ERROR: u8 = 0x08;
+pub(crate) const TSPI_FLAG_DEAD_RECKONING: u8 = 0x10;
+pub(crate) const TSPI_FLAG_MEASURED_SPEED: u8 = 0x20;
+pub(crate) const TSPI_FLAG_SYSTEM_SPECIFIC_DATA: u8 = 0x40;
+
+/// 9.4.2 Time Space Position Information (TSPI) PDU
+///
+/// The optional fields are present on the wire when they are `Some`; the TSPI Flag field is derived from them.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tspi {
+    pub live_entity_id: LiveEntityId,
+    pub entity_location: RelativeWorldCoordinates,
+    /// Linear velocity in decimeters per second
+    pub entity_linear_velocity: Option<VectorI16>,
+    pub entity_orientation: Option<LiveOrientation>,
+    pub position_error: Option<LivePositionError>,
+    pub orientation_error: Option<LiveOrientationError>,
+    pub dead_reckoning_parameters: Option<LiveDeadReckoningParameters>,
+    /// Measured speed in decimeters per second
+    pub measured_speed: Option<u16>,
+    pub system_specific_data: Option<Vec<u8>>,
+}
+
+impl Tspi {
+    /// Computes the TSPI Flag field from the optional fields that are present.
+    #[must_use]
+    pub fn tspi_flag(&self) -> u8 {
+        let mut flag = 0u8;
+        if self.entity_linear_velocity.is_some() {
+            flag |= TSPI_FLAG_LINEAR_VELOCITY;
+        }
+        if self.entity_orientation.is_some() {
+            flag |= TSPI_FLAG_ORIENTATION;
+        }
+        if self.position_error.is_some() {
+            flag |= TSPI_FLAG_POSITION_ERROR;
+        }
+        if self.orientation_error.is_some() {
+            flag |= TSPI_FLAG_ORIENTATION_ERROR;
+        }
+        if self.dead_reckoning_parameters.is_some() {
+            flag |= TSPI_FLAG_DEAD_RECKONING;
+        }
+        if self.measured_speed.is_some() {
+            flag |= TSPI_FLAG_MEASURED_SPEED;
+        }
+        if self.system_specific_data.is_some() {
+            flag |= TSPI_FLAG_SYSTEM_SPECIFIC_DATA;
+        }
+        flag
+    }
+}
+
+impl BodyRaw for Tspi {
+    type Builder = TspiBuilder;
+
+    fn builder() -> Self::Builder {
+        Self::Builder::new()
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        Self::Builder::new_from_body(self)
+    }
+
+    fn into_pdu_body(self) -> PduBody {
+        PduBody::TSPI(self)
+    }
+}
+
+impl BodyInfo for Tspi {
+    #[allow(clippy::cast_possible_truncation)]
+    fn body_length(&self) -> u16 {
+        BASE_TSPI_BODY_LENGTH
+            + self
+                .entity_linear_velocity
+                .map_or(0, |velocity| velocity.record_length())
+            + self
+                .entity_orientation
+                .map_or(0, |orientation| orientation.record_length())
+            + self.position_error.map_or(0, |_| FOUR_OCTETS as u16)
+            + self.orientation_error.map_or(0, |_| SIX_OCTETS as u16)
+            + self
+                .dead_reckoning_parameters
+                .map_or(0, |_| LIVE_DR_PARAMETERS_LENGTH)
+            + self.measured_speed.map_or(0, |_| TWO_OCTETS as u16)
+            + self
+                .system_specific_data
+                .as_ref()
+                .map_or(0, |data| 1 + data.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::TSPI
+    }
+}
+
+/// Live Entity PDUs identify entities by a `LiveEntityId`, which cannot be expressed as an `EntityId`.
+impl Interaction for Tspi {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Position Error record of the TSPI PDU, in decimeters
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LivePositionError {
+    pub horizontal_error: u16,
+    pub vertical_error: u16,
+}
+
+impl LivePositionError {
+    #[must_use]
+    pub fn new(horizontal_error: u16, vertical_error: u16) -> Self {
+        Self {
+            horizontal_error,
+            vertical_error,
+        }
+    }
+}
+
+/// Orientation Error record of the TSPI PDU, as binary angles
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveOrientationError {
+    pub azimuth_error: u16,
+    pub elevation_error: u16,
+    pub rotation_error: u16,
+}
+
+impl LiveOrientationError {
+    #[must_use]
+    pub fn new(azimuth_error: u16, elevation_error: u16, rotation_error: u16) -> Self {
+        Self {
+            azimuth_error,
+            elevation_error,
+            rotation_error,
+        }
+    }
+}
+
+/// Live Entity Dead Reckoning Parameters record
+///
+/// The compact counterpart of the Dead Reckoning Parameters record of the Entity State PDU.
+/// Linear acceleration is in decimeters per second squared, angular velocity in binary angles per second.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveDeadReckoningParameters {
+    pub algorithm: DeadReckoningAlgorithm,
+    pub other_parameters: [u8; LIVE_DR_OTHER_PARAMETERS_LENGTH],
+    pub linear_acceleration: VectorI8,
+    pub angular_velocity: VectorI8,
+}
+
+impl LiveDeadReckoningParameters {
+    #[must_use]
+    pub fn with_algorithm(mut self, algorithm: DeadReckoningAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    #[must_use]
+    pub fn with_other_parameters(
+        mut self,
+        other_parameters: [u8; LIVE_DR_OTHER_PARAMETERS_LENGTH],
+    ) -> Self {
+        self.other_parameters = other_parameters;
+        self
+    }
+
+    #[must_use]
+    pub fn with_linear_acceleration(mut self, linear_acceleration: VectorI8) -> Self {
+        self.linear_acceleration = linear_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_angular_velocity(mut self, angular_velocity: VectorI8) -> Self {
+        self.angular_velocity = angular_velocity;
+        self
+    }
+}
@@ -0,0 +1,125 @@
+use crate::BodyRaw;
+use crate::common::model::PduBody;
+use crate::common::parser::{
+    IResult, context, live_entity_id, live_orientation, relative_world_coordinates, vec3_i8,
+    vec3_i16,
+};
+use crate::enumerations::DeadReckoningAlgorithm;
+use crate::tspi::model::{
+    LIVE_DR_OTHER_PARAMETERS_LENGTH, LiveDeadReckoningParameters, LiveOrientationError,
+    LivePositionError, TSPI_FLAG_DEAD_RECKONING, TSPI_FLAG_LINEAR_VELOCITY,
+    TSPI_FLAG_MEASURED_SPEED, TSPI_FLAG_ORIENTATION, TSPI_FLAG_ORIENTATION_ERROR,
+    TSPI_FLAG_POSITION_ERROR, TSPI_FLAG_SYSTEM_SPECIFIC_DATA, Tspi,
+};
+use nom::Parser;
+use nom::bytes::complete::take;
+use nom::combinator::cond;
+use nom::number::complete::{be_u8, be_u16};
+
+pub(crate) fn tspi_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id).parse(input)?;
+    let (input, flag) = context("Flag", be_u8).parse(input)?;
+    let (input, entity_location) =
+        context("Entity Location", relative_world_coordinates).parse(input)?;
+    let (input, entity_linear_velocity) = cond(
+        flag & TSPI_FLAG_LINEAR_VELOCITY != 0,
+        context("Entity Linear Velocity", vec3_i16),
+    )
+    .parse(input)?;
+    let (input, entity_orientation) = cond(
+        flag & TSPI_FLAG_ORIENTATION != 0,
+        context("Entity Orientation", live_orientation),
+    )
+    .parse(input)?;
+    let (input, position_error) = cond(
+        flag & TSPI_FLAG_POSITION_ERROR != 0,
+        context("Live Position Error", live_position_error),
+    )
+    .parse(input)?;
+    let (input, orientation_error) = cond(
+        flag & TSPI_FLAG_ORIENTATION_ERROR != 0,
+        context("Live Orientation Error", live_orientation_error),
+    )
+    .parse(input)?;
+    let (input, dead_reckoning_parameters) = cond(
+        flag & TSPI_FLAG_DEAD_RECKONING != 0,
+        context(
+            "Live Dead Reckoning Parameters",
+            live_dead_reckoning_parameters,
+        ),
+    )
+    .parse(input)?;
+    let (input, measured_speed) = cond(
+        flag & TSPI_FLAG_MEASURED_SPEED != 0,
+        context("Measured Speed", be_u16),
+    )
+    .parse(input)?;
+    let (input, system_specific_data) = cond(
+        flag & TSPI_FLAG_SYSTEM_SPECIFIC_DATA != 0,
+        context("System Specific Data", system_specific_data),
+    )
+    .parse(input)?;
+
+    let body = Tspi {
+        live_entity_id,
+        entity_location,
+        entity_linear_velocity,
+        entity_orientation,
+        position_error,
+        orientation_error,
+        dead_reckoning_parameters,
+        measured_speed,
+        system_specific_data,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn live_position_error(input: &[u8]) -> IResult<&[u8], LivePositionError> {
+    let (input, horizontal_error) = context("Horizontal Error", be_u16).parse(input)?;
+    let (input, vertical_error) = context("Vertical Error", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        LivePositionError::new(horizontal_error, vertical_error),
+    ))
+}
+
+fn live_orientation_error(input: &[u8]) -> IResult<&[u8], LiveOrientationError> {
+    let (input, azimuth_error) = context("Azimuth Error", be_u16).parse(input)?;
+    let (input, elevation_error) = context("Elevation Error", be_u16).parse(input)?;
+    let (input, rotation_error) = context("Rotation Error", be_u16).parse(input)?;
+
+    Ok((
+        input,
+        LiveOrientationError::new(azimuth_error, elevation_error, rotation_error),
+    ))
+}
+
+fn live_dead_reckoning_parameters(input: &[u8]) -> IResult<&[u8], LiveDeadReckoningParameters> {
+    let (input, algorithm) = context("Algorithm", be_u8).parse(input)?;
+    let (input, other_parameters) =
+        context("Other Parameters", take(LIVE_DR_OTHER_PARAMETERS_LENGTH)).parse(input)?;
+    let (input, linear_acceleration) = context("Linear Acceleration", vec3_i8).parse(input)?;
+    let (input, angular_velocity) = context("Angular Velocity", vec3_i8).parse(input)?;
+
+    Ok((
+        input,
+        LiveDeadReckoningParameters::default()
+            .with_algorithm(DeadReckoningAlgorithm::from(algorithm))
+            .with_other_parameters(
+                other_parameters
+                    .try_into()
+                    .expect("Slice has the length of the other parameters field"),
+            )
+            .with_linear_acceleration(linear_acceleration)
+            .with_angular_velocity(angular_velocity),
+    ))
+}
+
+fn system_specific_data(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+    let (input, length) = context("Length", be_u8).parse(input)?;
+    let (input, data) = context("Data", take::<_, &[u8], _>(length)).parse(input)?;
+
+    Ok((input, data.to_vec()))
+}
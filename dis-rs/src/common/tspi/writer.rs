@@ -0,0 +1,50 @@
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::tspi::model::{LiveDeadReckoningParameters, Tspi};
+use bytes::{BufMut, BytesMut};
+
+impl SerializePdu for Tspi {
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u8(self.tspi_flag());
+        self.entity_location.serialize(buf);
+        if let Some(velocity) = &self.entity_linear_velocity {
+            velocity.serialize(buf);
+        }
+        if let Some(orientation) = &self.entity_orientation {
+            orientation.serialize(buf);
+        }
+        if let Some(position_error) = &self.position_error {
+            buf.put_u16(position_error.horizontal_error);
+            buf.put_u16(position_error.vertical_error);
+        }
+        if let Some(orientation_error) = &self.orientation_error {
+            buf.put_u16(orientation_error.azimuth_error);
+            buf.put_u16(orientation_error.elevation_error);
+            buf.put_u16(orientation_error.rotation_error);
+        }
+        if let Some(dr_parameters) = &self.dead_reckoning_parameters {
+            dr_parameters.serialize(buf);
+        }
+        if let Some(measured_speed) = self.measured_speed {
+            buf.put_u16(measured_speed);
+        }
+        if let Some(data) = &self.system_specific_data {
+            buf.put_u8(data.len() as u8);
+            buf.put_slice(data);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for LiveDeadReckoningParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.algorithm.into());
+        buf.put_slice(&self.other_parameters);
+        let acceleration_bytes = self.linear_acceleration.serialize(buf);
+        let velocity_bytes = self.angular_velocity.serialize(buf);
+
+        1 + self.other_parameters.len() as u16 + acceleration_bytes + velocity_bytes
+    }
+}
@@ -1,5 +1,5 @@
 use crate::BodyRaw;
-use crate::common::parser::{entity_id, event_id, vec3_f32};
+use crate::common::parser::{IResult, context, entity_id, event_id, vec3_f32};
 use crate::constants::LEAST_SIGNIFICANT_BIT;
 use crate::enumerations::{
     APAStatus, UAAcousticEmitterSystemFunction, UAAcousticSystemName,
@@ -12,29 +12,43 @@
     APA, AcousticEmitterSystem, PropulsionPlantConfiguration, Shaft, UABeam, UAEmitterSystem,
     UAFundamentalParameterData, UnderwaterAcoustic,
 };
+use nom::Parser;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_i16, be_i32, be_u8, be_u16};
-use nom::{IResult, Parser};
 
 pub(crate) fn underwater_acoustic_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, emitting_entity_id) = entity_id(input)?;
-    let (input, event_id) = event_id(input)?;
-    let (input, state_change_update_indicator) = be_u8(input)?;
+    let (input, emitting_entity_id) = context("Emitting Entity ID", entity_id).parse(input)?;
+    let (input, event_id) = context("Event ID", event_id).parse(input)?;
+    let (input, state_change_update_indicator) =
+        context("State Change Update Indicator", be_u8).parse(input)?;
     let state_change_update_indicator =
         UAStateChangeUpdateIndicator::from(state_change_update_indicator);
-    let (input, _padding) = be_u8(input)?;
-    let (input, passive_parameter_index) = be_u16(input)?;
+    let (input, _padding) = context("Padding", be_u8).parse(input)?;
+    let (input, passive_parameter_index) =
+        context("Passive Parameter Index", be_u16).parse(input)?;
     let passive_parameter_index = UAPassiveParameterIndex::from(passive_parameter_index);
-    let (input, propulsion_plant_configuration) = propulsion_plant_configuration(input)?;
-
-    let (input, number_of_shafts) = be_u8(input)?;
-    let (input, number_of_apas) = be_u8(input)?;
-    let (input, number_of_emitter_systems) = be_u8(input)?;
-
-    let (input, shafts) = count(shaft, number_of_shafts as usize).parse(input)?;
-    let (input, apas) = count(apa, number_of_apas as usize).parse(input)?;
-    let (input, emitter_systems) =
-        count(ua_emitter_system, number_of_emitter_systems as usize).parse(input)?;
+    let (input, propulsion_plant_configuration) = context(
+        "Propulsion Plant Configuration",
+        propulsion_plant_configuration,
+    )
+    .parse(input)?;
+
+    let (input, number_of_shafts) = context("Number of Shafts", be_u8).parse(input)?;
+    let (input, number_of_apas) = context("Number of APAs", be_u8).parse(input)?;
+    let (input, number_of_emitter_systems) =
+        context("Number of Emitter Systems", be_u8).parse(input)?;
+
+    let (input, shafts) = count(context("Shaft", shaft), number_of_shafts as usize).parse(input)?;
+    let (input, apas) = count(
+        context("Additional Passive Activity", apa),
+        number_of_apas as usize,
+    )
+    .parse(input)?;
+    let (input, emitter_systems) = count(
+        context("UA Emitter System", ua_emitter_system),
+        number_of_emitter_systems as usize,
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -67,9 +81,9 @@ fn propulsion_plant_configuration(input: &[u8]) -> IResult<&[u8], PropulsionPlan
 }
 
 fn shaft(input: &[u8]) -> IResult<&[u8], Shaft> {
-    let (input, current_rpm) = be_i16(input)?;
-    let (input, ordered_rpm) = be_i16(input)?;
-    let (input, rpm_rate_of_change) = be_i32(input)?;
+    let (input, current_rpm) = context("Current RPM", be_i16).parse(input)?;
+    let (input, ordered_rpm) = context("Ordered RPM", be_i16).parse(input)?;
+    let (input, rpm_rate_of_change) = context("RPM Rate of Change", be_i32).parse(input)?;
 
     Ok((
         input,
@@ -82,7 +96,7 @@ fn shaft(input: &[u8]) -> IResult<&[u8], Shaft> {
 
 fn apa(input: &[u8]) -> IResult<&[u8], APA> {
     const LAST_TWO_BITS_MASK: u16 = 0x0003;
-    let (input, parameter_index) = be_u16(input)?;
+    let (input, parameter_index) = context("Parameter Index", be_u16).parse(input)?;
     let parameter = UAAdditionalPassiveActivityParameterIndex::from(parameter_index >> 2);
     let parameter_status = APAStatus::from((parameter_index & LAST_TWO_BITS_MASK) as u8);
     let (input, value) = be_i16(input)?;
@@ -97,12 +111,14 @@ fn apa(input: &[u8]) -> IResult<&[u8], APA> {
 }
 
 fn ua_emitter_system(input: &[u8]) -> IResult<&[u8], UAEmitterSystem> {
-    let (input, _data_length) = be_u8(input)?;
-    let (input, number_of_beams) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, acoustic_emitter_system) = acoustic_emitter_system(input)?;
-    let (input, location) = vec3_f32(input)?;
-    let (input, beams) = count(ua_beam, number_of_beams as usize).parse(input)?;
+    let (input, _data_length) = context("Data Length", be_u8).parse(input)?;
+    let (input, number_of_beams) = context("Number of Beams", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, acoustic_emitter_system) =
+        context("Acoustic Emitter System", acoustic_emitter_system).parse(input)?;
+    let (input, location) = context("Location", vec3_f32).parse(input)?;
+    let (input, beams) =
+        count(context("UA Beam", ua_beam), number_of_beams as usize).parse(input)?;
 
     Ok((
         input,
@@ -114,11 +130,11 @@ fn ua_emitter_system(input: &[u8]) -> IResult<&[u8], UAEmitterSystem> {
 }
 
 fn acoustic_emitter_system(input: &[u8]) -> IResult<&[u8], AcousticEmitterSystem> {
-    let (input, acoustic_system_name) = be_u16(input)?;
+    let (input, acoustic_system_name) = context("Acoustic System Name", be_u16).parse(input)?;
     let acoustic_system_name = UAAcousticSystemName::from(acoustic_system_name);
-    let (input, function) = be_u8(input)?;
+    let (input, function) = context("Function", be_u8).parse(input)?;
     let function = UAAcousticEmitterSystemFunction::from(function);
-    let (input, acoustic_id_number) = be_u8(input)?;
+    let (input, acoustic_id_number) = context("Acoustic ID Number", be_u8).parse(input)?;
 
     Ok((
         input,
@@ -130,10 +146,14 @@ fn acoustic_emitter_system(input: &[u8]) -> IResult<&[u8], AcousticEmitterSystem
 }
 
 fn ua_beam(input: &[u8]) -> IResult<&[u8], UABeam> {
-    let (input, beam_data_length) = be_u8(input)?;
-    let (input, beam_id_number) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, fundamental_parameters) = ua_fundamental_parameter_data(input)?;
+    let (input, beam_data_length) = context("Beam Data Length", be_u8).parse(input)?;
+    let (input, beam_id_number) = context("Beam ID Number", be_u8).parse(input)?;
+    let (input, _padding) = context("Padding", be_u16).parse(input)?;
+    let (input, fundamental_parameters) = context(
+        "UA Fundamental Parameter Data",
+        ua_fundamental_parameter_data,
+    )
+    .parse(input)?;
 
     Ok((
         input,
@@ -145,15 +165,18 @@ fn ua_beam(input: &[u8]) -> IResult<&[u8], UABeam> {
 }
 
 fn ua_fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], UAFundamentalParameterData> {
-    let (input, active_emission_parameter_index) = be_u16(input)?;
+    let (input, active_emission_parameter_index) =
+        context("Active Emission Parameter Index", be_u16).parse(input)?;
     let active_emission_parameter_index =
         UAActiveEmissionParameterIndex::from(active_emission_parameter_index);
-    let (input, scan_pattern) = be_u16(input)?;
+    let (input, scan_pattern) = context("Scan Pattern", be_u16).parse(input)?;
     let scan_pattern = UAScanPattern::from(scan_pattern);
-    let (input, beam_center_azimuth) = be_f32(input)?;
-    let (input, azimuthal_beamwidth) = be_f32(input)?;
-    let (input, beam_center_depression_elevation) = be_f32(input)?;
-    let (input, depression_elevation_beamwidth) = be_f32(input)?;
+    let (input, beam_center_azimuth) = context("Beam Center Azimuth", be_f32).parse(input)?;
+    let (input, azimuthal_beamwidth) = context("Azimuthal Beamwidth", be_f32).parse(input)?;
+    let (input, beam_center_depression_elevation) =
+        context("Beam Center Depression Elevation", be_f32).parse(input)?;
+    let (input, depression_elevation_beamwidth) =
+        context("Depression Elevation Beamwidth", be_f32).parse(input)?;
 
     Ok((
         input,
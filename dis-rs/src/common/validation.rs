@@ -0,0 +1,2157 @@
+//! Validation of PDUs against the rules of the DIS standard.
+//!
+//! The [`Validate`] trait checks a PDU body for problems that do not prevent parsing or serialisation,
+//! but make the PDU non-conformant, such as enumeration values that are not defined in SISO-REF-010,
+//! markings that are not ASCII, or records that are only defined in DIS v7 being used in a v6 PDU.
+//! [`Pdu::validate`] additionally checks the consistency of the PDU header with the body.
+//!
+//! Validation is limited to what the parsed model retains:
+//! - Record counts are derived from the collections in the model, so they are checked for fitting their field
+//!   in the wire format, and against other fields that constrain them (e.g. High Density Track/Jam, Data Filter).
+//! - Padding is discarded by the parsers, so only the padding of the PDU header is checked.
+//! - Bodies of PDU types that the crate does not implement (`PduBody::Other`) are reported as `NotValidated`.
+use crate::acknowledge_r::model::AcknowledgeR;
+use crate::action_request_r::model::ActionRequestR;
+use crate::action_response_r::model::ActionResponseR;
+use crate::aggregate_state::model::{AggregateMarking, AggregateState, SilentEntitySystem};
+use crate::comment_r::model::CommentR;
+use crate::common::BodyInfo;
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::appearance::model::Appearance;
+use crate::common::areal_object_state::model::ArealObjectState;
+use crate::common::articulated_parts::model::ArticulatedParts;
+use crate::common::attribute::model::Attribute;
+use crate::common::collision::model::Collision;
+use crate::common::collision_elastic::model::CollisionElastic;
+use crate::common::comment::model::Comment;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::designator::model::Designator;
+use crate::common::detonation::model::{Detonation, DetonationDescriptor};
+use crate::common::directed_energy_fire::model::{
+    DEBeamSpotType, DEComponentDamageStatus, DEComponentIdentification,
+    DEComponentVisualSmokeColor, DEFirePulseShape, DERecord, DirectedEnergyFire,
+};
+use crate::common::electromagnetic_emission::model::{Beam, ElectromagneticEmission};
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::entity_state::model::{EntityMarking, EntityState};
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::environmental_process::model::{EnvironmentRecordData, EnvironmentalProcess};
+use crate::common::event_report::model::EventReport;
+use crate::common::fire::model::{Fire, FireDescriptor};
+use crate::common::gridded_data::model::{
+    GriddedData, GriddedDataConstantGrid, GriddedDataCoordinateSystem,
+};
+use crate::common::iff::model::{Iff, IffDataSpecification, LayerHeader};
+use crate::common::information_operations_action::model::{
+    IOActionPhase, IOActionType, IOCommunicationsNodeType, IOEffectType, IOLinkType, IOProcess,
+    IORecord, IOReportType, IOSimulationSource, IOStatus, IOWarfareType,
+    InformationOperationsAction,
+};
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::intercom_control::model::{
+    IntercomCommand, IntercomCommunicationsParameters, IntercomCommunicationsType, IntercomControl,
+    IntercomControlType, IntercomDestinationLineStateCommand, IntercomTransmitLineState,
+};
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::le_detonation::model::LeDetonation;
+use crate::common::le_fire::model::LeFire;
+use crate::common::linear_object_state::model::LinearObjectState;
+use crate::common::minefield_data::model::{DataFilter, Mine, MinefieldData};
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::minefield_state::model::{
+    MinefieldActiveStatus, MinefieldAppearanceType, MinefieldProtocolMode, MinefieldState,
+};
+use crate::common::model::{
+    EntityType, FixedDatum, MunitionDescriptor, ObjectKind, ObjectType, Pdu, PduBody, PduHeader,
+    RecordSpecification, SupplyQuantity, VariableDatum, VariableParameter,
+};
+use crate::common::other::model::Other;
+use crate::common::point_object_state::model::PointObjectState;
+use crate::common::receiver::model::Receiver;
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::set_data::model::SetData;
+use crate::common::signal::model::{EncodingScheme, Signal};
+use crate::common::start_resume::model::StartResume;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::transmitter::model::{BeamAntennaPattern, Transmitter};
+use crate::common::tspi::model::Tspi;
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::create_entity_r::model::CreateEntityR;
+use crate::data_query_r::model::DataQueryR;
+use crate::data_r::model::DataR;
+use crate::enumerations::{
+    APAStatus, AcknowledgeFlag, ActionId, AggregateStateAggregateState, AggregateStateFormation,
+    AttributeActionCode, BeamStatusBeamState, CollisionType, Country, DeadReckoningAlgorithm,
+    DesignatorCode, DesignatorSystemName, DetonationResult, ElectromagneticEmissionBeamFunction,
+    ElectromagneticEmissionStateUpdateIndicator, EmitterName, EmitterSystemFunction, EntityKind,
+    EntityMarkingCharacterSet, EventType, ForceId, HighDensityTrackJam, IffSystemMode,
+    IffSystemName, IffSystemType, IsGroupOfGroupedEntityCategory, IsPartOfNature, IsPartOfPosition,
+    MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolVersion,
+    ReceiverState, RecordQueryREventType, RepairCompleteRepair, RepairResponseRepairResult,
+    RequestStatus, RequiredReliabilityService, ResponseFlag, ServiceRequestServiceTypeRequested,
+    SignalEncodingClass, SignalEncodingType, SignalTdlType, StationName, StopFreezeReason,
+    TransferControlTransferType, TransmitterAntennaPatternReferenceSystem,
+    TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterInputSource,
+    TransmitterMajorModulation, TransmitterModulationTypeSystem, TransmitterTransmitState,
+    UAAcousticEmitterSystemFunction, UAAcousticSystemName, UAActiveEmissionParameterIndex,
+    UAAdditionalPassiveActivityParameterIndex, UAPassiveParameterIndex,
+    UAPropulsionPlantConfiguration, UAScanPattern, UAStateChangeUpdateIndicator,
+    VariableRecordType,
+};
+use crate::event_report_r::model::EventReportR;
+use crate::is_group_of::model::IsGroupOf;
+use crate::is_part_of::model::IsPartOf;
+use crate::record_query_r::model::RecordQueryR;
+use crate::record_r::model::RecordR;
+use crate::remove_entity_r::model::RemoveEntityR;
+use crate::repair_complete::model::RepairComplete;
+use crate::repair_response::model::RepairResponse;
+use crate::resupply_cancel::model::ResupplyCancel;
+use crate::resupply_offer::model::ResupplyOffer;
+use crate::resupply_received::model::ResupplyReceived;
+use crate::sees::model::SEES;
+use crate::service_request::model::ServiceRequest;
+use crate::set_data_r::model::SetDataR;
+use crate::set_record_r::model::SetRecordR;
+use crate::start_resume_r::model::StartResumeR;
+use crate::stop_freeze_r::model::StopFreezeR;
+use crate::transfer_ownership::model::TransferOwnership;
+use crate::underwater_acoustic::model::UnderwaterAcoustic;
+use thiserror::Error;
+
+const ENTITY_MARKING_MAX_LENGTH: usize = 11;
+const AGGREGATE_MARKING_MAX_LENGTH: usize = 31;
+/// The highest PDU Type number defined in IEEE 1278.1a-1998 (DIS v6), Entity State Update.
+pub(crate) const LAST_V6_PDU_TYPE: u8 = 67;
+
+/// A problem found while validating a PDU.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ValidationFinding {
+    #[error("PDU length in the header is {found}, but the PDU is {expected} bytes long.")]
+    LengthMismatch { expected: u16, found: u16 },
+    #[error("PDU Type in the header is {header:?}, but the body is of type {body:?}.")]
+    PduTypeMismatch { header: PduType, body: PduType },
+    #[error("Padding of {record} is not zero, but {value}.")]
+    NonZeroPadding { record: &'static str, value: u16 },
+    #[error("{record} declares {declared} records, but contains {found}.")]
+    RecordCountMismatch {
+        record: &'static str,
+        declared: usize,
+        found: usize,
+    },
+    #[error("{record} declares a length of {declared} octets, but is {found} octets long.")]
+    RecordLengthMismatch {
+        record: &'static str,
+        declared: usize,
+        found: usize,
+    },
+    #[error("{record} contains {found} records, but at most {max} can be encoded.")]
+    RecordCountOverflow {
+        record: &'static str,
+        found: usize,
+        max: usize,
+    },
+    #[error("Value {value} of {field} is not defined in SISO-REF-010.")]
+    UndefinedEnumValue { field: &'static str, value: u32 },
+    #[error("{field} is not valid ASCII.")]
+    MarkingNotAscii { field: &'static str },
+    #[error("{field} is {length} characters long, but at most {max} are allowed.")]
+    MarkingTooLong {
+        field: &'static str,
+        length: usize,
+        max: usize,
+    },
+    #[error("{field} is only defined in DIS v7, but used in a DIS v6 PDU.")]
+    V7OnlyInV6 { field: &'static str },
+    #[error(
+        "{field} of a mine does not match the Data Filter, which has the field selected: {selected}."
+    )]
+    DataFilterMismatch { field: &'static str, selected: bool },
+    #[error("The contents of {record} are not validated.")]
+    NotValidated { record: &'static str },
+}
+
+/// Trait for PDU bodies and records to validate their contents against the DIS standard.
+pub trait Validate {
+    /// Validates the data structure for the given protocol version.
+    /// Returns all findings, or an empty `Vec` when no problems were found.
+    #[must_use]
+    fn validate(&self, version: ProtocolVersion) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        self.validate_into(version, &mut findings);
+        findings
+    }
+
+    /// Validates the data structure for the given protocol version, appending the findings to `findings`.
+    ///
+    /// The default implementation performs no checks, for data structures that cannot hold invalid values.
+    fn validate_into(&self, _version: ProtocolVersion, _findings: &mut Vec<ValidationFinding>) {}
+}
+
+/// Reports an `UndefinedEnumValue` finding when the enumeration value is `Unspecified`.
+macro_rules! check_defined {
+    ($findings:expr, $field:literal, $enum:ident, $value:expr) => {
+        if let $enum::Unspecified(value) = $value {
+            $findings.push(ValidationFinding::UndefinedEnumValue {
+                field: $field,
+                value: u32::from(value),
+            });
+        }
+    };
+}
+
+impl Pdu {
+    /// Validates the header and body of the PDU, for the protocol version indicated in the header.
+    /// Returns all findings, or an empty `Vec` when no problems were found.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        validate_header(&self.header, &self.body, &mut findings);
+        self.body
+            .validate_into(self.header.protocol_version, &mut findings);
+        findings
+    }
+}
+
+fn validate_header(header: &PduHeader, body: &PduBody, findings: &mut Vec<ValidationFinding>) {
+    check_defined!(
+        findings,
+        "Protocol Version",
+        ProtocolVersion,
+        header.protocol_version
+    );
+    check_defined!(findings, "PDU Type", PduType, header.pdu_type);
+
+    let expected = PDU_HEADER_LEN_BYTES + body.body_length();
+    if header.pdu_length != expected {
+        findings.push(ValidationFinding::LengthMismatch {
+            expected,
+            found: header.pdu_length,
+        });
+    }
+    if !matches!(body, PduBody::Other(_)) && header.pdu_type != body.body_type() {
+        findings.push(ValidationFinding::PduTypeMismatch {
+            header: header.pdu_type,
+            body: body.body_type(),
+        });
+    }
+    if header.padding != 0 {
+        findings.push(ValidationFinding::NonZeroPadding {
+            record: "PDU Header",
+            value: header.padding,
+        });
+    }
+    if header.protocol_version == ProtocolVersion::IEEE1278_1A1998 {
+        if header.pdu_status.is_some() {
+            findings.push(ValidationFinding::V7OnlyInV6 {
+                field: "PDU Status",
+            });
+        }
+        if !matches!(header.pdu_type, PduType::Unspecified(_))
+            && u8::from(header.pdu_type) > LAST_V6_PDU_TYPE
+        {
+            findings.push(ValidationFinding::V7OnlyInV6 { field: "PDU Type" });
+        }
+    }
+}
+
+impl Validate for PduBody {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        match self {
+            PduBody::Other(body) => body.validate_into(version, findings),
+            PduBody::EntityState(body) => body.validate_into(version, findings),
+            PduBody::Fire(body) => body.validate_into(version, findings),
+            PduBody::Detonation(body) => body.validate_into(version, findings),
+            PduBody::Collision(body) => body.validate_into(version, findings),
+            PduBody::ServiceRequest(body) => body.validate_into(version, findings),
+            PduBody::ResupplyOffer(body) => body.validate_into(version, findings),
+            PduBody::ResupplyReceived(body) => body.validate_into(version, findings),
+            PduBody::ResupplyCancel(body) => body.validate_into(version, findings),
+            PduBody::RepairComplete(body) => body.validate_into(version, findings),
+            PduBody::RepairResponse(body) => body.validate_into(version, findings),
+            PduBody::CreateEntity(body) => body.validate_into(version, findings),
+            PduBody::RemoveEntity(body) => body.validate_into(version, findings),
+            PduBody::StartResume(body) => body.validate_into(version, findings),
+            PduBody::StopFreeze(body) => body.validate_into(version, findings),
+            PduBody::Acknowledge(body) => body.validate_into(version, findings),
+            PduBody::ActionRequest(body) => body.validate_into(version, findings),
+            PduBody::ActionResponse(body) => body.validate_into(version, findings),
+            PduBody::DataQuery(body) => body.validate_into(version, findings),
+            PduBody::SetData(body) => body.validate_into(version, findings),
+            PduBody::Data(body) => body.validate_into(version, findings),
+            PduBody::EventReport(body) => body.validate_into(version, findings),
+            PduBody::Comment(body) => body.validate_into(version, findings),
+            PduBody::ElectromagneticEmission(body) => body.validate_into(version, findings),
+            PduBody::Designator(body) => body.validate_into(version, findings),
+            PduBody::Transmitter(body) => body.validate_into(version, findings),
+            PduBody::Signal(body) => body.validate_into(version, findings),
+            PduBody::Receiver(body) => body.validate_into(version, findings),
+            PduBody::IFF(body) => body.validate_into(version, findings),
+            PduBody::UnderwaterAcoustic(body) => body.validate_into(version, findings),
+            PduBody::SupplementalEmissionEntityState(body) => body.validate_into(version, findings),
+            PduBody::IntercomSignal(body) => body.validate_into(version, findings),
+            PduBody::IntercomControl(body) => body.validate_into(version, findings),
+            PduBody::AggregateState(body) => body.validate_into(version, findings),
+            PduBody::IsGroupOf(body) => body.validate_into(version, findings),
+            PduBody::TransferOwnership(body) => body.validate_into(version, findings),
+            PduBody::IsPartOf(body) => body.validate_into(version, findings),
+            PduBody::MinefieldState(body) => body.validate_into(version, findings),
+            PduBody::MinefieldQuery(body) => body.validate_into(version, findings),
+            PduBody::MinefieldData(body) => body.validate_into(version, findings),
+            PduBody::MinefieldResponseNACK(body) => body.validate_into(version, findings),
+            PduBody::EnvironmentalProcess(body) => body.validate_into(version, findings),
+            PduBody::GriddedData(body) => body.validate_into(version, findings),
+            PduBody::PointObjectState(body) => body.validate_into(version, findings),
+            PduBody::LinearObjectState(body) => body.validate_into(version, findings),
+            PduBody::ArealObjectState(body) => body.validate_into(version, findings),
+            PduBody::TSPI(body) => body.validate_into(version, findings),
+            PduBody::Appearance(body) => body.validate_into(version, findings),
+            PduBody::ArticulatedParts(body) => body.validate_into(version, findings),
+            PduBody::LEFire(body) => body.validate_into(version, findings),
+            PduBody::LEDetonation(body) => body.validate_into(version, findings),
+            PduBody::CreateEntityR(body) => body.validate_into(version, findings),
+            PduBody::RemoveEntityR(body) => body.validate_into(version, findings),
+            PduBody::StartResumeR(body) => body.validate_into(version, findings),
+            PduBody::StopFreezeR(body) => body.validate_into(version, findings),
+            PduBody::AcknowledgeR(body) => body.validate_into(version, findings),
+            PduBody::ActionRequestR(body) => body.validate_into(version, findings),
+            PduBody::ActionResponseR(body) => body.validate_into(version, findings),
+            PduBody::DataQueryR(body) => body.validate_into(version, findings),
+            PduBody::SetDataR(body) => body.validate_into(version, findings),
+            PduBody::DataR(body) => body.validate_into(version, findings),
+            PduBody::EventReportR(body) => body.validate_into(version, findings),
+            PduBody::CommentR(body) => body.validate_into(version, findings),
+            PduBody::RecordR(body) => body.validate_into(version, findings),
+            PduBody::SetRecordR(body) => body.validate_into(version, findings),
+            PduBody::RecordQueryR(body) => body.validate_into(version, findings),
+            PduBody::CollisionElastic(body) => body.validate_into(version, findings),
+            PduBody::EntityStateUpdate(body) => body.validate_into(version, findings),
+            PduBody::DirectedEnergyFire(body) => body.validate_into(version, findings),
+            PduBody::EntityDamageStatus(body) => body.validate_into(version, findings),
+            PduBody::InformationOperationsAction(body) => body.validate_into(version, findings),
+            PduBody::InformationOperationsReport(body) => body.validate_into(version, findings),
+            PduBody::Attribute(body) => body.validate_into(version, findings),
+        }
+    }
+}
+
+fn is_v6(version: ProtocolVersion) -> bool {
+    version == ProtocolVersion::IEEE1278_1A1998
+}
+
+fn validate_entity_type(entity_type: &EntityType, findings: &mut Vec<ValidationFinding>) {
+    check_defined!(findings, "Entity Kind", EntityKind, entity_type.kind);
+    check_defined!(findings, "Country", Country, entity_type.country);
+}
+
+/// Returns the name of the Variable Parameter record type when it is only defined in DIS v7.
+pub(crate) fn v7_only_variable_parameter(parameter: &VariableParameter) -> Option<&'static str> {
+    match parameter {
+        VariableParameter::Separation(_) => Some("Separation Variable Parameter"),
+        VariableParameter::EntityType(_) => Some("Entity Type Variable Parameter"),
+        VariableParameter::EntityAssociation(_) => Some("Entity Association Variable Parameter"),
+        VariableParameter::Articulated(_)
+        | VariableParameter::Attached(_)
+        | VariableParameter::Unspecified(_, _) => None,
+    }
+}
+
+fn validate_variable_parameters(
+    parameters: &[VariableParameter],
+    version: ProtocolVersion,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_count("Variable Parameters", parameters.len(), u8::MAX, findings);
+    for parameter in parameters {
+        if let VariableParameter::Unspecified(record_type, _) = parameter {
+            findings.push(ValidationFinding::UndefinedEnumValue {
+                field: "Variable Parameter Record Type",
+                value: u32::from(*record_type),
+            });
+        }
+        if let Some(field) = v7_only_variable_parameter(parameter)
+            && is_v6(version)
+        {
+            findings.push(ValidationFinding::V7OnlyInV6 { field });
+        }
+    }
+}
+
+fn validate_marking(
+    field: &'static str,
+    character_set: EntityMarkingCharacterSet,
+    marking: &str,
+    max_length: usize,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_defined!(
+        findings,
+        "Entity Marking Character Set",
+        EntityMarkingCharacterSet,
+        character_set
+    );
+    if character_set == EntityMarkingCharacterSet::ASCII && !marking.is_ascii() {
+        findings.push(ValidationFinding::MarkingNotAscii { field });
+    }
+    let length = marking.chars().count();
+    if length > max_length {
+        findings.push(ValidationFinding::MarkingTooLong {
+            field,
+            length,
+            max: max_length,
+        });
+    }
+}
+
+impl Validate for EntityMarking {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_marking(
+            "Entity Marking",
+            self.marking_character_set,
+            &self.marking_string,
+            ENTITY_MARKING_MAX_LENGTH,
+            findings,
+        );
+    }
+}
+
+impl Validate for AggregateMarking {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_marking(
+            "Aggregate Marking",
+            self.marking_character_set,
+            &self.marking_string,
+            AGGREGATE_MARKING_MAX_LENGTH,
+            findings,
+        );
+    }
+}
+
+impl Validate for EntityState {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        validate_entity_type(&self.entity_type, findings);
+        validate_entity_type(&self.alternative_entity_type, findings);
+        check_defined!(
+            findings,
+            "Dead Reckoning Algorithm",
+            DeadReckoningAlgorithm,
+            self.dead_reckoning_parameters.algorithm
+        );
+        self.entity_marking.validate_into(version, findings);
+        validate_variable_parameters(&self.variable_parameters, version, findings);
+    }
+}
+
+impl Validate for EntityStateUpdate {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_variable_parameters(&self.variable_parameters, version, findings);
+    }
+}
+
+impl Validate for Fire {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        match &self.descriptor {
+            FireDescriptor::Munition(munition) => {
+                validate_entity_type(&munition.entity_type, findings);
+            }
+            FireDescriptor::Expendable(expendable) => {
+                if is_v6(version) {
+                    findings.push(ValidationFinding::V7OnlyInV6 {
+                        field: "Expendable Descriptor",
+                    });
+                }
+                validate_entity_type(&expendable.entity_type, findings);
+            }
+        }
+    }
+}
+
+impl Validate for Detonation {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        match &self.descriptor {
+            DetonationDescriptor::Munition(munition) => {
+                validate_entity_type(&munition.entity_type, findings);
+            }
+            DetonationDescriptor::Explosion(explosion) => {
+                if is_v6(version) {
+                    findings.push(ValidationFinding::V7OnlyInV6 {
+                        field: "Explosion Descriptor",
+                    });
+                }
+                validate_entity_type(&explosion.entity_type, findings);
+            }
+            DetonationDescriptor::Expendable(expendable) => {
+                if is_v6(version) {
+                    findings.push(ValidationFinding::V7OnlyInV6 {
+                        field: "Expendable Descriptor",
+                    });
+                }
+                validate_entity_type(&expendable.entity_type, findings);
+            }
+        }
+        check_defined!(
+            findings,
+            "Detonation Result",
+            DetonationResult,
+            self.detonation_result
+        );
+        validate_variable_parameters(&self.variable_parameters, version, findings);
+    }
+}
+
+impl Validate for Collision {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Collision Type",
+            CollisionType,
+            self.collision_type
+        );
+    }
+}
+
+impl Validate for AggregateState {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        check_defined!(
+            findings,
+            "Aggregate State",
+            AggregateStateAggregateState,
+            self.aggregate_state
+        );
+        check_defined!(
+            findings,
+            "Formation",
+            AggregateStateFormation,
+            self.formation
+        );
+        self.aggregate_marking.validate_into(version, findings);
+        for (record, count) in [
+            ("Aggregate IDs", self.aggregates.len()),
+            ("Entity IDs", self.entities.len()),
+            (
+                "Silent Aggregate Systems",
+                self.silent_aggregate_systems.len(),
+            ),
+            ("Silent Entity Systems", self.silent_entity_systems.len()),
+        ] {
+            check_count(record, count, u16::MAX, findings);
+        }
+        self.silent_entity_systems
+            .iter()
+            .for_each(|system| system.validate_into(version, findings));
+    }
+}
+
+impl Validate for SilentEntitySystem {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.entity_type, findings);
+        if self.appearances.len() > usize::from(self.number_of_entities) {
+            findings.push(ValidationFinding::RecordCountMismatch {
+                record: "Silent Entity System",
+                declared: usize::from(self.number_of_entities),
+                found: self.appearances.len(),
+            });
+        }
+    }
+}
+
+impl Validate for Appearance {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        if let Some(ForceId::Unspecified(value)) = self.force_id {
+            findings.push(ValidationFinding::UndefinedEnumValue {
+                field: "Force ID",
+                value: u32::from(value),
+            });
+        }
+        if let Some(entity_type) = &self.entity_type {
+            validate_entity_type(entity_type, findings);
+        }
+        if let Some(entity_type) = &self.alternate_entity_type {
+            validate_entity_type(entity_type, findings);
+        }
+        self.entity_marking.validate_into(version, findings);
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        if let Some(value) = self {
+            value.validate_into(version, findings);
+        }
+    }
+}
+
+impl Validate for Other {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        findings.push(ValidationFinding::NotValidated {
+            record: "Other PDU body",
+        });
+    }
+}
+
+impl Validate for ServiceRequest {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Service Type Requested",
+            ServiceRequestServiceTypeRequested,
+            self.service_type_requested
+        );
+        validate_supplies(&self.supplies, findings);
+    }
+}
+
+impl Validate for ResupplyOffer {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_supplies(&self.supplies, findings);
+    }
+}
+
+impl Validate for ResupplyReceived {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_supplies(&self.supplies, findings);
+    }
+}
+
+impl Validate for RepairComplete {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Repair", RepairCompleteRepair, self.repair);
+    }
+}
+
+impl Validate for RepairResponse {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Repair Result",
+            RepairResponseRepairResult,
+            self.repair_result
+        );
+    }
+}
+
+impl Validate for StopFreeze {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Reason", StopFreezeReason, self.reason);
+    }
+}
+
+impl Validate for Acknowledge {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_acknowledge_flags(self.acknowledge_flag, self.response_flag, findings);
+    }
+}
+
+impl Validate for ActionRequest {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Action ID", ActionId, self.action_id);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for ActionResponse {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Request Status",
+            RequestStatus,
+            self.request_status
+        );
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for DataQuery {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_datum_ids(&self.fixed_datum_records, findings);
+        validate_datum_ids(&self.variable_datum_records, findings);
+    }
+}
+
+impl Validate for SetData {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for Data {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for EventReport {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Event Type", EventType, self.event_type);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for Comment {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_datums(&[], &self.variable_datum_records, findings);
+    }
+}
+
+impl Validate for ElectromagneticEmission {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "State Update Indicator",
+            ElectromagneticEmissionStateUpdateIndicator,
+            self.state_update_indicator
+        );
+        check_count(
+            "Emitter Systems",
+            self.emitter_systems.len(),
+            u8::MAX,
+            findings,
+        );
+        for system in &self.emitter_systems {
+            check_defined!(findings, "Emitter Name", EmitterName, system.name);
+            check_defined!(
+                findings,
+                "Emitter System Function",
+                EmitterSystemFunction,
+                system.function
+            );
+            check_count("Beams", system.beams.len(), u8::MAX, findings);
+            for beam in &system.beams {
+                validate_beam(beam, findings);
+            }
+        }
+    }
+}
+
+fn validate_beam(beam: &Beam, findings: &mut Vec<ValidationFinding>) {
+    check_defined!(
+        findings,
+        "Beam Function",
+        ElectromagneticEmissionBeamFunction,
+        beam.beam_function
+    );
+    check_defined!(
+        findings,
+        "High Density Track/Jam",
+        HighDensityTrackJam,
+        beam.high_density_track_jam
+    );
+    check_defined!(
+        findings,
+        "Beam Status",
+        BeamStatusBeamState,
+        beam.beam_status
+    );
+    check_count(
+        "Track/Jam Data",
+        beam.track_jam_data.len(),
+        u8::MAX,
+        findings,
+    );
+    // Targets of a high density track/jam beam are not listed individually
+    if beam.high_density_track_jam == HighDensityTrackJam::Selected
+        && !beam.track_jam_data.is_empty()
+    {
+        findings.push(ValidationFinding::RecordCountMismatch {
+            record: "Track/Jam Data",
+            declared: 0,
+            found: beam.track_jam_data.len(),
+        });
+    }
+}
+
+impl Validate for Designator {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Designator System Name",
+            DesignatorSystemName,
+            self.system_name
+        );
+        check_defined!(findings, "Designator Code", DesignatorCode, self.code);
+        check_defined!(
+            findings,
+            "Dead Reckoning Algorithm",
+            DeadReckoningAlgorithm,
+            self.dead_reckoning_algorithm
+        );
+    }
+}
+
+impl Validate for Transmitter {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.radio_type, findings);
+        check_defined!(
+            findings,
+            "Transmit State",
+            TransmitterTransmitState,
+            self.transmit_state
+        );
+        check_defined!(
+            findings,
+            "Input Source",
+            TransmitterInputSource,
+            self.input_source
+        );
+        check_defined!(
+            findings,
+            "Antenna Pattern Type",
+            TransmitterAntennaPatternType,
+            self.antenna_pattern_type
+        );
+        check_defined!(
+            findings,
+            "Major Modulation",
+            TransmitterMajorModulation,
+            self.modulation_type.major_modulation
+        );
+        check_defined!(
+            findings,
+            "Radio System",
+            TransmitterModulationTypeSystem,
+            self.modulation_type.radio_system
+        );
+        check_defined!(
+            findings,
+            "Crypto System",
+            TransmitterCryptoSystem,
+            self.crypto_system
+        );
+        if let Some(parameters) = &self.modulation_parameters {
+            check_count("Modulation Parameters", parameters.len(), u8::MAX, findings);
+        }
+        if let Some(pattern) = &self.antenna_pattern {
+            validate_beam_antenna_pattern(pattern, findings);
+        }
+        check_count(
+            "Variable Transmitter Parameters",
+            self.variable_transmitter_parameters.len(),
+            u16::MAX,
+            findings,
+        );
+        for parameter in &self.variable_transmitter_parameters {
+            check_defined!(
+                findings,
+                "Variable Transmitter Parameter Record Type",
+                VariableRecordType,
+                parameter.record_type
+            );
+        }
+    }
+}
+
+fn validate_beam_antenna_pattern(
+    pattern: &BeamAntennaPattern,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_defined!(
+        findings,
+        "Antenna Pattern Reference System",
+        TransmitterAntennaPatternReferenceSystem,
+        pattern.reference_system
+    );
+}
+
+impl Validate for Signal {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_encoding_scheme(&self.encoding_scheme, findings);
+        check_defined!(findings, "TDL Type", SignalTdlType, self.tdl_type);
+    }
+}
+
+fn validate_encoding_scheme(scheme: &EncodingScheme, findings: &mut Vec<ValidationFinding>) {
+    let encoding_class = match scheme {
+        EncodingScheme::EncodedAudio {
+            encoding_class,
+            encoding_type,
+        } => {
+            check_defined!(
+                findings,
+                "Encoding Type",
+                SignalEncodingType,
+                *encoding_type
+            );
+            encoding_class
+        }
+        EncodingScheme::RawBinaryData { encoding_class, .. }
+        | EncodingScheme::ApplicationSpecificData { encoding_class, .. }
+        | EncodingScheme::DatabaseIndex { encoding_class, .. }
+        | EncodingScheme::Unspecified { encoding_class, .. } => encoding_class,
+    };
+    check_defined!(
+        findings,
+        "Encoding Class",
+        SignalEncodingClass,
+        *encoding_class
+    );
+}
+
+impl Validate for Receiver {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Receiver State",
+            ReceiverState,
+            self.receiver_state
+        );
+    }
+}
+
+impl Validate for Iff {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "IFF System Type",
+            IffSystemType,
+            self.system_id.system_type
+        );
+        check_defined!(
+            findings,
+            "IFF System Name",
+            IffSystemName,
+            self.system_id.system_name
+        );
+        check_defined!(
+            findings,
+            "IFF System Mode",
+            IffSystemMode,
+            self.system_id.system_mode
+        );
+        if let Some(layer) = &self.layer_2 {
+            validate_layer_header(
+                "IFF Layer 2",
+                2,
+                &layer.layer_header,
+                layer.data_length(),
+                findings,
+            );
+            check_count(
+                "IFF Fundamental Parameter Data",
+                layer.iff_fundamental_parameters.len(),
+                u16::MAX,
+                findings,
+            );
+        }
+        if let Some(layer) = &self.layer_3 {
+            validate_layer_header(
+                "IFF Layer 3",
+                3,
+                &layer.layer_header,
+                layer.data_length(),
+                findings,
+            );
+            validate_iff_data_records(&layer.data_records, findings);
+        }
+        if let Some(layer) = &self.layer_4 {
+            validate_layer_header(
+                "IFF Layer 4",
+                4,
+                &layer.layer_header,
+                layer.data_length(),
+                findings,
+            );
+            validate_iff_data_records(&layer.data_records, findings);
+        }
+        if let Some(layer) = &self.layer_5 {
+            validate_layer_header(
+                "IFF Layer 5",
+                5,
+                &layer.layer_header,
+                layer.data_length(),
+                findings,
+            );
+            validate_iff_data_records(&layer.data_records, findings);
+        }
+    }
+}
+
+fn validate_layer_header(
+    record: &'static str,
+    layer_number: u8,
+    header: &LayerHeader,
+    length: u16,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    if header.layer_number != layer_number {
+        findings.push(ValidationFinding::UndefinedEnumValue {
+            field: "IFF Layer Number",
+            value: u32::from(header.layer_number),
+        });
+    }
+    if header.length != length {
+        findings.push(ValidationFinding::RecordLengthMismatch {
+            record,
+            declared: usize::from(header.length),
+            found: usize::from(length),
+        });
+    }
+}
+
+fn validate_iff_data_records(
+    records: &IffDataSpecification,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_count(
+        "IFF Data Records",
+        records.iff_data_records.len(),
+        u16::MAX,
+        findings,
+    );
+    for record in &records.iff_data_records {
+        check_defined!(
+            findings,
+            "IFF Data Record Type",
+            VariableRecordType,
+            record.record_type
+        );
+    }
+}
+
+impl Validate for UnderwaterAcoustic {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "State/Change Update Indicator",
+            UAStateChangeUpdateIndicator,
+            self.state_change_update_indicator
+        );
+        check_defined!(
+            findings,
+            "Passive Parameter Index",
+            UAPassiveParameterIndex,
+            self.passive_parameter_index
+        );
+        check_defined!(
+            findings,
+            "Propulsion Plant Configuration",
+            UAPropulsionPlantConfiguration,
+            self.propulsion_plant_configuration.configuration
+        );
+        check_count("Shafts", self.shafts.len(), u8::MAX, findings);
+        check_count(
+            "Additional Passive Activities",
+            self.apas.len(),
+            u8::MAX,
+            findings,
+        );
+        for apa in &self.apas {
+            check_defined!(
+                findings,
+                "Additional Passive Activity Parameter Index",
+                UAAdditionalPassiveActivityParameterIndex,
+                apa.parameter
+            );
+            check_defined!(findings, "APA Status", APAStatus, apa.status);
+        }
+        check_count(
+            "Emitter Systems",
+            self.emitter_systems.len(),
+            u8::MAX,
+            findings,
+        );
+        for system in &self.emitter_systems {
+            check_defined!(
+                findings,
+                "Acoustic System Name",
+                UAAcousticSystemName,
+                system.acoustic_emitter_system.acoustic_system_name
+            );
+            check_defined!(
+                findings,
+                "Acoustic Emitter System Function",
+                UAAcousticEmitterSystemFunction,
+                system.acoustic_emitter_system.function
+            );
+            check_count("Beams", system.beams.len(), u8::MAX, findings);
+            for beam in &system.beams {
+                check_defined!(
+                    findings,
+                    "Active Emission Parameter Index",
+                    UAActiveEmissionParameterIndex,
+                    beam.fundamental_parameters.active_emission_parameter_index
+                );
+                check_defined!(
+                    findings,
+                    "Scan Pattern",
+                    UAScanPattern,
+                    beam.fundamental_parameters.scan_pattern
+                );
+            }
+        }
+    }
+}
+
+impl Validate for SEES {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_count(
+            "Propulsion Systems",
+            self.propulsion_systems.len(),
+            u16::MAX,
+            findings,
+        );
+        check_count(
+            "Vectoring Nozzle Systems",
+            self.vectoring_nozzle_systems.len(),
+            u16::MAX,
+            findings,
+        );
+    }
+}
+
+impl Validate for IntercomSignal {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_encoding_scheme(&self.encoding_scheme, findings);
+        check_defined!(findings, "TDL Type", SignalTdlType, self.tdl_type);
+    }
+}
+
+impl Validate for IntercomControl {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Control Type",
+            IntercomControlType,
+            self.control_type
+        );
+        check_defined!(
+            findings,
+            "Communications Type",
+            IntercomCommunicationsType,
+            self.communications_channel_type.communications_type
+        );
+        check_defined!(
+            findings,
+            "Transmit Line State",
+            IntercomTransmitLineState,
+            self.transmit_line_state
+        );
+        check_defined!(findings, "Command", IntercomCommand, self.command);
+        for parameter in &self.intercom_parameters {
+            let line_state_command = match parameter {
+                IntercomCommunicationsParameters::SpecificDestination(destination) => {
+                    destination.destination_line_state_command
+                }
+                IntercomCommunicationsParameters::GroupDestination(destination) => {
+                    destination.destination_line_state_command
+                }
+                IntercomCommunicationsParameters::GroupAssignment(_) => continue,
+                IntercomCommunicationsParameters::Unspecified { record_type, .. } => {
+                    findings.push(ValidationFinding::UndefinedEnumValue {
+                        field: "Intercom Communications Parameters Record Type",
+                        value: u32::from(*record_type),
+                    });
+                    continue;
+                }
+            };
+            check_defined!(
+                findings,
+                "Destination Line State Command",
+                IntercomDestinationLineStateCommand,
+                line_state_command
+            );
+        }
+    }
+}
+
+impl Validate for IsGroupOf {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Grouped Entity Category",
+            IsGroupOfGroupedEntityCategory,
+            self.grouped_entity_category
+        );
+        check_count(
+            "Group Entity Descriptions",
+            self.descriptions.len(),
+            u8::MAX,
+            findings,
+        );
+    }
+}
+
+impl Validate for TransferOwnership {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        check_defined!(
+            findings,
+            "Transfer Type",
+            TransferControlTransferType,
+            self.transfer_type
+        );
+        validate_record_specification(&self.record_specification, findings);
+    }
+}
+
+impl Validate for IsPartOf {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Nature", IsPartOfNature, self.relationship.nature);
+        check_defined!(
+            findings,
+            "Position",
+            IsPartOfPosition,
+            self.relationship.position
+        );
+        check_defined!(
+            findings,
+            "Station Name",
+            StationName,
+            self.named_location_id.station_name
+        );
+        validate_entity_type(&self.part_type, findings);
+    }
+}
+
+impl Validate for MinefieldState {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        validate_entity_type(&self.minefield_type, findings);
+        check_defined!(
+            findings,
+            "Minefield Appearance Type",
+            MinefieldAppearanceType,
+            self.appearance.minefield_type
+        );
+        check_defined!(
+            findings,
+            "Minefield Active Status",
+            MinefieldActiveStatus,
+            self.appearance.active_status
+        );
+        check_defined!(
+            findings,
+            "Protocol Mode",
+            MinefieldProtocolMode,
+            self.protocol_mode
+        );
+        check_count(
+            "Perimeter Points",
+            self.perimeter_points.len(),
+            u8::MAX,
+            findings,
+        );
+        check_count("Mine Types", self.mine_types.len(), u16::MAX, findings);
+        for mine_type in &self.mine_types {
+            validate_entity_type(mine_type, findings);
+        }
+    }
+}
+
+impl Validate for MinefieldQuery {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.requested_mine_type, findings);
+        check_count(
+            "Requested Perimeter Points",
+            self.requested_perimeter_points.len(),
+            u8::MAX,
+            findings,
+        );
+        check_count("Sensor Types", self.sensor_types.len(), u8::MAX, findings);
+    }
+}
+
+impl Validate for MinefieldData {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.mine_type, findings);
+        check_count("Mines", self.mines.len(), u8::MAX, findings);
+        check_count("Sensor Types", self.sensor_types.len(), u8::MAX, findings);
+        for mine in &self.mines {
+            validate_mine(mine, &self.data_filter, findings);
+        }
+    }
+}
+
+/// Checks that the optional fields of a mine are present exactly when selected by the Data Filter,
+/// as fields that are not selected are not transmitted, and selected fields that are absent are sent as zero.
+fn validate_mine(mine: &Mine, filter: &DataFilter, findings: &mut Vec<ValidationFinding>) {
+    for (field, selected, present) in [
+        (
+            "Ground Burial Depth Offset",
+            filter.ground_burial_depth_offset,
+            mine.ground_burial_depth_offset.is_some(),
+        ),
+        (
+            "Water Burial Depth Offset",
+            filter.water_burial_depth_offset,
+            mine.water_burial_depth_offset.is_some(),
+        ),
+        (
+            "Snow Burial Depth Offset",
+            filter.snow_burial_depth_offset,
+            mine.snow_burial_depth_offset.is_some(),
+        ),
+        (
+            "Mine Orientation",
+            filter.mine_orientation,
+            mine.orientation.is_some(),
+        ),
+        (
+            "Thermal Contrast",
+            filter.thermal_contrast,
+            mine.thermal_contrast.is_some(),
+        ),
+        (
+            "Reflectance",
+            filter.reflectance,
+            mine.reflectance.is_some(),
+        ),
+        (
+            "Mine Emplacement Time",
+            filter.mine_emplacement_time,
+            mine.emplacement_time.is_some(),
+        ),
+        (
+            "Mine Entity Number",
+            filter.mine_entity_number,
+            mine.entity_number.is_some(),
+        ),
+        ("Fusing", filter.fusing, mine.fusing.is_some()),
+        (
+            "Scalar Detection Coefficient",
+            filter.scalar_detection_coefficient,
+            mine.scalar_detection_coefficient.is_some(),
+        ),
+        (
+            "Paint Scheme",
+            filter.paint_scheme,
+            mine.paint_scheme.is_some(),
+        ),
+    ] {
+        if selected != present {
+            findings.push(ValidationFinding::DataFilterMismatch { field, selected });
+        }
+    }
+    if !filter.number_of_trip_wires && !mine.trip_wires.is_empty() {
+        findings.push(ValidationFinding::DataFilterMismatch {
+            field: "Trip Wires",
+            selected: false,
+        });
+    }
+    check_count("Trip Wires", mine.trip_wires.len(), u8::MAX, findings);
+    for wire in &mine.trip_wires {
+        check_count("Trip Wire Vertices", wire.vertices.len(), u8::MAX, findings);
+    }
+}
+
+impl Validate for MinefieldResponseNack {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_count(
+            "Missing PDU Sequence Numbers",
+            self.missing_pdu_sequence_numbers.len(),
+            u8::MAX,
+            findings,
+        );
+    }
+}
+
+impl Validate for EnvironmentalProcess {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.environment_type, findings);
+        check_count(
+            "Environment Records",
+            self.environment_records.len(),
+            u16::MAX,
+            findings,
+        );
+        for record in &self.environment_records {
+            match &record.data {
+                EnvironmentRecordData::CombicState(state) => {
+                    validate_entity_type(&state.munition_source, findings);
+                }
+                EnvironmentRecordData::FlareState(state) => {
+                    validate_entity_type(&state.munition_source, findings);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Validate for GriddedData {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Coordinate System",
+            GriddedDataCoordinateSystem,
+            self.coordinate_system
+        );
+        check_defined!(
+            findings,
+            "Constant Grid",
+            GriddedDataConstantGrid,
+            self.constant_grid
+        );
+        validate_entity_type(&self.environment_type, findings);
+        check_count(
+            "Grid Axis Descriptors",
+            self.grid_axis_descriptors.len(),
+            u8::MAX,
+            findings,
+        );
+        check_count("Grid Data", self.grid_data.len(), u8::MAX, findings);
+    }
+}
+
+impl Validate for PointObjectState {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        validate_object_type(self.object_type, findings);
+    }
+}
+
+impl Validate for LinearObjectState {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        validate_object_type(self.object_type, findings);
+        check_count(
+            "Linear Segment Parameters",
+            self.linear_segment_parameters.len(),
+            u8::MAX,
+            findings,
+        );
+    }
+}
+
+impl Validate for ArealObjectState {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Force ID", ForceId, self.force_id);
+        validate_object_type(self.object_type, findings);
+        check_count(
+            "Object Locations",
+            self.object_locations.len(),
+            u16::MAX,
+            findings,
+        );
+    }
+}
+
+fn validate_object_type(object_type: ObjectType, findings: &mut Vec<ValidationFinding>) {
+    check_defined!(findings, "Domain", PlatformDomain, object_type.domain);
+    check_defined!(findings, "Object Kind", ObjectKind, object_type.kind);
+}
+
+impl Validate for Tspi {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        if let Some(parameters) = &self.dead_reckoning_parameters {
+            check_defined!(
+                findings,
+                "Dead Reckoning Algorithm",
+                DeadReckoningAlgorithm,
+                parameters.algorithm
+            );
+        }
+        if let Some(data) = &self.system_specific_data {
+            check_count("System Specific Data", data.len(), u8::MAX, findings);
+        }
+    }
+}
+
+impl Validate for ArticulatedParts {
+    fn validate_into(&self, version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_variable_parameters(&self.variable_parameters, version, findings);
+    }
+}
+
+impl Validate for LeFire {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_munition_descriptor(&self.munition_descriptor, findings);
+    }
+}
+
+impl Validate for LeDetonation {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        if let Some(descriptor) = &self.munition_descriptor {
+            validate_munition_descriptor(descriptor, findings);
+        }
+        check_defined!(
+            findings,
+            "Detonation Result",
+            DetonationResult,
+            self.detonation_result
+        );
+    }
+}
+
+fn validate_munition_descriptor(
+    descriptor: &MunitionDescriptor,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    validate_entity_type(&descriptor.entity_type, findings);
+    check_defined!(
+        findings,
+        "Warhead",
+        MunitionDescriptorWarhead,
+        descriptor.warhead
+    );
+    check_defined!(findings, "Fuse", MunitionDescriptorFuse, descriptor.fuse);
+}
+
+impl Validate for CreateEntityR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+    }
+}
+
+impl Validate for RemoveEntityR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+    }
+}
+
+impl Validate for StartResumeR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+    }
+}
+
+impl Validate for StopFreezeR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Reason", StopFreezeReason, self.reason);
+        validate_reliability(self.required_reliability_service, findings);
+    }
+}
+
+impl Validate for AcknowledgeR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_acknowledge_flags(self.acknowledge_flag, self.response_flag, findings);
+    }
+}
+
+impl Validate for ActionRequestR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        check_defined!(findings, "Action ID", ActionId, self.action_id);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for ActionResponseR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "Request Status",
+            RequestStatus,
+            self.request_status
+        );
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for DataQueryR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        validate_datum_ids(&self.fixed_datum_records, findings);
+        validate_datum_ids(&self.variable_datum_records, findings);
+    }
+}
+
+impl Validate for SetDataR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for DataR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for EventReportR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Event Type", EventType, self.event_type);
+        validate_datums(
+            &self.fixed_datum_records,
+            &self.variable_datum_records,
+            findings,
+        );
+    }
+}
+
+impl Validate for CommentR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_datums(&[], &self.variable_datum_records, findings);
+    }
+}
+
+impl Validate for RecordR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        check_defined!(findings, "Event Type", EventType, self.event_type);
+        validate_record_specification(&self.record_specification, findings);
+    }
+}
+
+impl Validate for SetRecordR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        validate_record_specification(&self.record_specification, findings);
+    }
+}
+
+impl Validate for RecordQueryR {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_reliability(self.required_reliability_service, findings);
+        check_defined!(
+            findings,
+            "Event Type",
+            RecordQueryREventType,
+            self.event_type
+        );
+        validate_datum_ids(&self.record_query_specification.record_ids, findings);
+    }
+}
+
+impl Validate for DirectedEnergyFire {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_entity_type(&self.munition_type, findings);
+        check_defined!(findings, "Pulse Shape", DEFirePulseShape, self.pulse_shape);
+        validate_de_records("DE Records", &self.de_records, findings);
+    }
+}
+
+impl Validate for EntityDamageStatus {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        validate_de_records(
+            "Damage Description Records",
+            &self.damage_description_records,
+            findings,
+        );
+    }
+}
+
+fn validate_de_records(
+    record: &'static str,
+    records: &[DERecord],
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_count(record, records.len(), u16::MAX, findings);
+    for record in records {
+        match record {
+            DERecord::PrecisionAimpoint(aimpoint) => {
+                check_defined!(
+                    findings,
+                    "Beam Spot Type",
+                    DEBeamSpotType,
+                    aimpoint.beam_spot_type
+                );
+            }
+            DERecord::AreaAimpoint(aimpoint) => {
+                check_count(
+                    "Beam Antenna Patterns",
+                    aimpoint.beam_antenna_patterns.len(),
+                    u16::MAX,
+                    findings,
+                );
+                check_count(
+                    "Target Energy Depositions",
+                    aimpoint.target_energy_depositions.len(),
+                    u16::MAX,
+                    findings,
+                );
+                for pattern in &aimpoint.beam_antenna_patterns {
+                    validate_beam_antenna_pattern(pattern, findings);
+                }
+            }
+            DERecord::DamageDescription(description) => {
+                check_defined!(
+                    findings,
+                    "Component Identification",
+                    DEComponentIdentification,
+                    description.component_identification
+                );
+                check_defined!(
+                    findings,
+                    "Component Damage Status",
+                    DEComponentDamageStatus,
+                    description.component_damage_status
+                );
+                check_defined!(
+                    findings,
+                    "Component Visual Smoke Color",
+                    DEComponentVisualSmokeColor,
+                    description.component_visual_smoke_color
+                );
+            }
+            DERecord::Unspecified { .. } => {}
+        }
+    }
+}
+
+impl Validate for InformationOperationsAction {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "IO Warfare Type",
+            IOWarfareType,
+            self.io_warfare_type
+        );
+        check_defined!(
+            findings,
+            "IO Simulation Source",
+            IOSimulationSource,
+            self.io_simulation_source
+        );
+        check_defined!(
+            findings,
+            "IO Action Type",
+            IOActionType,
+            self.io_action_type
+        );
+        check_defined!(
+            findings,
+            "IO Action Phase",
+            IOActionPhase,
+            self.io_action_phase
+        );
+        validate_io_records(&self.io_records, findings);
+    }
+}
+
+impl Validate for InformationOperationsReport {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(
+            findings,
+            "IO Simulation Source",
+            IOSimulationSource,
+            self.io_simulation_source
+        );
+        check_defined!(
+            findings,
+            "IO Report Type",
+            IOReportType,
+            self.io_report_type
+        );
+        validate_io_records(&self.io_records, findings);
+    }
+}
+
+fn validate_io_records(records: &[IORecord], findings: &mut Vec<ValidationFinding>) {
+    check_count("IO Records", records.len(), u16::MAX, findings);
+    for record in records {
+        match record {
+            IORecord::CommunicationsNode(node) => {
+                check_defined!(
+                    findings,
+                    "Communications Node Type",
+                    IOCommunicationsNodeType,
+                    node.communications_node_type
+                );
+            }
+            IORecord::Effect(effect) => {
+                check_defined!(findings, "IO Status", IOStatus, effect.io_status);
+                check_defined!(findings, "IO Link Type", IOLinkType, effect.io_link_type);
+                check_defined!(findings, "IO Effect", IOEffectType, effect.io_effect);
+                check_defined!(findings, "IO Process", IOProcess, effect.io_process);
+            }
+            IORecord::Unspecified { .. } => {}
+        }
+    }
+}
+
+impl Validate for Attribute {
+    fn validate_into(&self, _version: ProtocolVersion, findings: &mut Vec<ValidationFinding>) {
+        check_defined!(findings, "Record PDU Type", PduType, self.record_pdu_type);
+        check_defined!(
+            findings,
+            "Record Protocol Version",
+            ProtocolVersion,
+            self.record_protocol_version
+        );
+        check_defined!(
+            findings,
+            "Master Attribute Record Type",
+            VariableRecordType,
+            self.master_attribute_record_type
+        );
+        check_defined!(
+            findings,
+            "Action Code",
+            AttributeActionCode,
+            self.action_code
+        );
+        check_count(
+            "Attribute Record Sets",
+            self.attribute_record_sets.len(),
+            u16::MAX,
+            findings,
+        );
+        for set in &self.attribute_record_sets {
+            check_count(
+                "Attribute Records",
+                set.attribute_records.len(),
+                u16::MAX,
+                findings,
+            );
+            for record in &set.attribute_records {
+                check_defined!(
+                    findings,
+                    "Attribute Record Type",
+                    VariableRecordType,
+                    record.record_type
+                );
+            }
+        }
+    }
+}
+
+/// PDU bodies that consist solely of identifiers, times and numeric values, which are valid for any value.
+macro_rules! impl_validate_unconstrained {
+    ($($body:ty),* $(,)?) => {
+        $(impl Validate for $body {})*
+    };
+}
+
+impl_validate_unconstrained!(
+    ResupplyCancel,
+    CreateEntity,
+    RemoveEntity,
+    StartResume,
+    CollisionElastic,
+);
+
+fn check_count(
+    record: &'static str,
+    count: usize,
+    max: impl Into<usize>,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    let max = max.into();
+    if count > max {
+        findings.push(ValidationFinding::RecordCountOverflow {
+            record,
+            found: count,
+            max,
+        });
+    }
+}
+
+fn validate_reliability(
+    service: RequiredReliabilityService,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_defined!(
+        findings,
+        "Required Reliability Service",
+        RequiredReliabilityService,
+        service
+    );
+}
+
+fn validate_acknowledge_flags(
+    acknowledge_flag: AcknowledgeFlag,
+    response_flag: ResponseFlag,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    check_defined!(
+        findings,
+        "Acknowledge Flag",
+        AcknowledgeFlag,
+        acknowledge_flag
+    );
+    check_defined!(findings, "Response Flag", ResponseFlag, response_flag);
+}
+
+fn validate_supplies(supplies: &[SupplyQuantity], findings: &mut Vec<ValidationFinding>) {
+    check_count("Supplies", supplies.len(), u8::MAX, findings);
+    for supply in supplies {
+        validate_entity_type(&supply.supply_type, findings);
+    }
+}
+
+fn validate_datum_ids(datum_ids: &[VariableRecordType], findings: &mut Vec<ValidationFinding>) {
+    for datum_id in datum_ids {
+        check_defined!(findings, "Datum ID", VariableRecordType, *datum_id);
+    }
+}
+
+fn validate_datums(
+    fixed_datums: &[FixedDatum],
+    variable_datums: &[VariableDatum],
+    findings: &mut Vec<ValidationFinding>,
+) {
+    for datum in fixed_datums {
+        check_defined!(findings, "Datum ID", VariableRecordType, datum.datum_id);
+    }
+    for datum in variable_datums {
+        check_defined!(findings, "Datum ID", VariableRecordType, datum.datum_id);
+    }
+}
+
+fn validate_record_specification(
+    specification: &RecordSpecification,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    for set in &specification.record_sets {
+        check_defined!(findings, "Record ID", VariableRecordType, set.record_id);
+        check_count("Records", set.records.len(), u16::MAX, findings);
+        for record in &set.records {
+            if record.len() != usize::from(set.record_length_bytes) {
+                findings.push(ValidationFinding::RecordLengthMismatch {
+                    record: "Record Set",
+                    declared: usize::from(set.record_length_bytes),
+                    found: record.len(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::electromagnetic_emission::model::{EmitterSystem, TrackJam};
+    use crate::common::model::{RecordSet, SeparationParameter, TimeUnits, Timestamp};
+    use crate::v7::model::PduStatus;
+
+    fn pdu(header: PduHeader, body: PduBody) -> Pdu {
+        Pdu::finalize_from_parts(
+            header,
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        )
+    }
+
+    #[test]
+    fn valid_entity_state() {
+        let pdu = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder().build().into_pdu_body(),
+        );
+
+        assert!(pdu.validate().is_empty());
+    }
+
+    #[test]
+    fn header_inconsistent_with_body() {
+        let mut pdu = pdu(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder().build().into_pdu_body(),
+        );
+        pdu.header.pdu_length += 4;
+        pdu.header.pdu_type = PduType::Fire;
+        pdu.header.padding = 1;
+
+        let findings = pdu.validate();
+        assert!(findings.contains(&ValidationFinding::LengthMismatch {
+            expected: pdu.pdu_length(),
+            found: pdu.pdu_length() + 4,
+        }));
+        assert!(findings.contains(&ValidationFinding::PduTypeMismatch {
+            header: PduType::Fire,
+            body: PduType::EntityState,
+        }));
+        assert!(findings.contains(&ValidationFinding::NonZeroPadding {
+            record: "PDU Header",
+            value: 1,
+        }));
+    }
+
+    #[test]
+    fn undefined_enum_values() {
+        let body = EntityState::builder()
+            .with_force_id(ForceId::Unspecified(200))
+            .build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![ValidationFinding::UndefinedEnumValue {
+                field: "Force ID",
+                value: 200,
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_marking() {
+        let body = EntityState::builder()
+            .with_marking(EntityMarking::new(
+                "Überlänger Marker",
+                EntityMarkingCharacterSet::ASCII,
+            ))
+            .build();
+
+        let findings = body.validate(ProtocolVersion::IEEE1278_12012);
+        assert!(findings.contains(&ValidationFinding::MarkingNotAscii {
+            field: "Entity Marking",
+        }));
+        assert!(findings.contains(&ValidationFinding::MarkingTooLong {
+            field: "Entity Marking",
+            length: 17,
+            max: ENTITY_MARKING_MAX_LENGTH,
+        }));
+    }
+
+    #[test]
+    fn v7_fields_in_v6_pdu() {
+        let mut pdu = pdu(
+            PduHeader::new_v6(1, PduType::EntityState),
+            EntityState::builder()
+                .with_variable_parameter(VariableParameter::Separation(
+                    SeparationParameter::default(),
+                ))
+                .build()
+                .into_pdu_body(),
+        );
+        pdu.header.pdu_status = Some(PduStatus::default());
+
+        let findings = pdu.validate();
+        assert_eq!(
+            findings,
+            vec![
+                ValidationFinding::V7OnlyInV6 {
+                    field: "PDU Status"
+                },
+                ValidationFinding::V7OnlyInV6 {
+                    field: "Separation Variable Parameter"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn high_density_beam_with_targets() {
+        let body = ElectromagneticEmission::builder()
+            .with_emitter_system(
+                EmitterSystem::new()
+                    .with_name(EmitterName::ANFPS16_5505)
+                    .with_beam(
+                        Beam::new()
+                            .with_high_density_track_jam(HighDensityTrackJam::Selected)
+                            .with_track_jam(TrackJam::new()),
+                    ),
+            )
+            .build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![ValidationFinding::RecordCountMismatch {
+                record: "Track/Jam Data",
+                declared: 0,
+                found: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn record_set_with_records_of_different_length() {
+        let body = RecordR::builder()
+            .with_record_specification(
+                RecordSpecification::default().with_record_set(
+                    RecordSet::default()
+                        .with_record_id(VariableRecordType::Entity_IDList_1)
+                        .with_record(vec![0; 4])
+                        .with_record(vec![0; 8]),
+                ),
+            )
+            .build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![ValidationFinding::RecordLengthMismatch {
+                record: "Record Set",
+                declared: 8,
+                found: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn mine_fields_not_matching_data_filter() {
+        let body = MinefieldData::builder()
+            .with_data_filter(DataFilter {
+                reflectance: true,
+                ..Default::default()
+            })
+            .with_mine(Mine::default().with_thermal_contrast(1.0))
+            .build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![
+                ValidationFinding::DataFilterMismatch {
+                    field: "Thermal Contrast",
+                    selected: false,
+                },
+                ValidationFinding::DataFilterMismatch {
+                    field: "Reflectance",
+                    selected: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unimplemented_pdu_body_is_not_validated() {
+        let body = Other::builder().with_body(vec![0; 8]).build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![ValidationFinding::NotValidated {
+                record: "Other PDU body"
+            }]
+        );
+    }
+
+    #[test]
+    fn undefined_enum_values_in_body() {
+        let body = Acknowledge::builder()
+            .with_acknowledge_flag(AcknowledgeFlag::Unspecified(0))
+            .with_response_flag(ResponseFlag::AbleToComply)
+            .build();
+
+        assert_eq!(
+            body.validate(ProtocolVersion::IEEE1278_12012),
+            vec![ValidationFinding::UndefinedEnumValue {
+                field: "Acknowledge Flag",
+                value: 0,
+            }]
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use crate::DisError;
 use crate::common::model::{
     ArticulatedPart, AttachedPart, BeamData, ClockTime, EntityAssociationParameter, EntityId,
-    EntityTypeParameter, EventId, ExpendableDescriptor, ExplosionDescriptor, FixedDatum, Location,
-    MunitionDescriptor, Orientation, SeparationParameter, SimulationAddress, VariableDatum,
-    VariableParameter, VectorF32, length_padded_to_num,
+    EntityTypeParameter, EventId, ExpendableDescriptor, ExplosionDescriptor, FixedDatum,
+    LiveEntityId, LiveEventId, LiveOrientation, LiveSimulationAddress, Location, MinefieldId,
+    MunitionDescriptor, ObjectType, Orientation, PerimeterPointCoordinate,
+    RelativeWorldCoordinates, SeparationParameter, SimulationAddress, VariableDatum,
+    VariableParameter, VectorF32, VectorI8, VectorI16, length_padded_to_num,
 };
 use crate::common::model::{Pdu, PduBody, PduHeader};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
@@ -87,26 +89,26 @@ pub fn serialize(&self, buf: &mut BytesMut) -> Result<u16, DisError> {
             PduBody::IFF(body) => body.serialize_pdu(version, buf),
             PduBody::UnderwaterAcoustic(body) => body.serialize_pdu(version, buf),
             PduBody::SupplementalEmissionEntityState(body) => body.serialize_pdu(version, buf),
-            // PduBody::IntercomSignal(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IntercomControl(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IntercomSignal(body) => body.serialize_pdu(version, buf),
+            PduBody::IntercomControl(body) => body.serialize_pdu(version, buf),
             PduBody::AggregateState(body) => body.serialize_pdu(version, buf),
             PduBody::IsGroupOf(body) => body.serialize_pdu(version, buf),
             PduBody::TransferOwnership(body) => body.serialize_pdu(version, buf),
             PduBody::IsPartOf(body) => body.serialize_pdu(version, buf),
-            // PduBody::MinefieldState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldQuery(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldResponseNACK(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EnvironmentalProcess(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::GriddedData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::PointObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LinearObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArealObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::TSPI(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::Appearance(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArticulatedParts(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEDetonation(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldState(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldQuery(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldData(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldResponseNACK(body) => body.serialize_pdu(version, buf),
+            PduBody::EnvironmentalProcess(body) => body.serialize_pdu(version, buf),
+            PduBody::GriddedData(body) => body.serialize_pdu(version, buf),
+            PduBody::PointObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::LinearObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::ArealObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::TSPI(body) => body.serialize_pdu(version, buf),
+            PduBody::Appearance(body) => body.serialize_pdu(version, buf),
+            PduBody::ArticulatedParts(body) => body.serialize_pdu(version, buf),
+            PduBody::LEFire(body) => body.serialize_pdu(version, buf),
+            PduBody::LEDetonation(body) => body.serialize_pdu(version, buf),
             PduBody::CreateEntityR(body) => body.serialize_pdu(version, buf),
             PduBody::RemoveEntityR(body) => body.serialize_pdu(version, buf),
             PduBody::StartResumeR(body) => body.serialize_pdu(version, buf),
@@ -124,12 +126,11 @@ pub fn serialize(&self, buf: &mut BytesMut) -> Result<u16, DisError> {
             PduBody::RecordQueryR(body) => body.serialize_pdu(version, buf),
             PduBody::CollisionElastic(body) => body.serialize_pdu(version, buf),
             PduBody::EntityStateUpdate(body) => body.serialize_pdu(version, buf),
-            // PduBody::DirectedEnergyFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EntityDamageStatus(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsAction(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsReport(body) => { body.serialize_pdu(version, buf) }
+            PduBody::DirectedEnergyFire(body) => body.serialize_pdu(version, buf),
+            PduBody::EntityDamageStatus(body) => body.serialize_pdu(version, buf),
+            PduBody::InformationOperationsAction(body) => body.serialize_pdu(version, buf),
+            PduBody::InformationOperationsReport(body) => body.serialize_pdu(version, buf),
             PduBody::Attribute(body) => body.serialize_pdu(version, buf),
-            _ => 0,
         };
 
         Ok(header_size + body_size)
@@ -152,6 +153,67 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
     }
 }
 
+impl Serialize for LiveSimulationAddress {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.site_number);
+        buf.put_u8(self.application_number);
+        2
+    }
+}
+
+impl Serialize for LiveEntityId {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let num_bytes = self.simulation_address.serialize(buf);
+        buf.put_u16(self.entity_number);
+        num_bytes + 2
+    }
+}
+
+impl Serialize for LiveEventId {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let num_bytes = self.simulation_address.serialize(buf);
+        buf.put_u16(self.event_number);
+        num_bytes + 2
+    }
+}
+
+impl Serialize for RelativeWorldCoordinates {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(self.reference_point);
+        buf.put_i16(self.delta_x);
+        buf.put_i16(self.delta_y);
+        buf.put_i16(self.delta_z);
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveOrientation {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.psi);
+        buf.put_u8(self.theta);
+        buf.put_u8(self.phi);
+        self.record_length()
+    }
+}
+
+impl Serialize for VectorI16 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_i16(self.first_vector_component);
+        buf.put_i16(self.second_vector_component);
+        buf.put_i16(self.third_vector_component);
+        self.record_length()
+    }
+}
+
+impl Serialize for VectorI8 {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_i8(self.first_vector_component);
+        buf.put_i8(self.second_vector_component);
+        buf.put_i8(self.third_vector_component);
+        self.record_length()
+    }
+}
+
 impl Serialize for EventId {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let num_bytes = self.simulation_address.serialize(buf);
@@ -160,6 +222,32 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
     }
 }
 
+impl Serialize for MinefieldId {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let num_bytes = self.simulation_address.serialize(buf);
+        buf.put_u16(self.minefield_number);
+        num_bytes + 2
+    }
+}
+
+impl Serialize for PerimeterPointCoordinate {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_f32(self.x);
+        buf.put_f32(self.y);
+        self.record_length()
+    }
+}
+
+impl Serialize for ObjectType {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.domain.into());
+        buf.put_u8(self.kind.into());
+        buf.put_u8(self.category);
+        buf.put_u8(self.subcategory);
+        self.record_length()
+    }
+}
+
 impl Serialize for VectorF32 {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
         buf.put_f32(self.first_vector_component);
@@ -0,0 +1,484 @@
+//! Dead reckoning (DR) extrapolation of entity location, velocity and orientation.
+//!
+//! Implements the dead reckoning algorithms of IEEE 1278.1-2012, Annex E.
+//! World-coordinate algorithms (FPW, RPW, RVW, FVW) extrapolate in the world (ECEF) frame,
+//! body-axis algorithms (FPB, RPB, RVB, FVB) interpret the linear velocity and acceleration in entity (body) coordinates
+//! and integrate them along the rotation given by the angular velocity.
+//! Algorithms with a rotating orientation (R.. variants) extrapolate the orientation using the angular velocity,
+//! starting from the World Orientation Quaternion in the DR Other Parameters when present (E.8.2.3).
+//!
+//! The [`tracker`] module builds on the extrapolation to decide when a sender has to issue a new Entity State PDU.
+pub mod tracker;
+
+pub use tracker::{DeadReckoningTracker, UpdateReason};
+
+use crate::common::entity_state::model::{DrOtherParameters, DrParameters, EntityState};
+use crate::common::model::{Location, Orientation, VectorF32};
+use crate::enumerations::DeadReckoningAlgorithm;
+use crate::utils::rotation::{Quaternion, RotationMatrix};
+use std::time::Duration;
+
+/// Angular rates below this magnitude (rad/s) are treated as no rotation, to avoid dividing by (near) zero.
+const MIN_ANGULAR_RATE: f64 = 1.0e-9;
+
+type Vec3 = [f64; 3];
+type Mat3 = [[f64; 3]; 3];
+
+/// The kinematic state of an entity that is subject to dead reckoning.
+///
+/// `linear_velocity` is expressed in the frame used by the DR algorithm:
+/// world coordinates for the world-coordinate algorithms, entity (body) coordinates for the body-axis algorithms.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct KinematicState {
+    pub location: Location,
+    pub linear_velocity: VectorF32,
+    pub orientation: Orientation,
+}
+
+impl KinematicState {
+    #[must_use]
+    pub fn new(location: Location, linear_velocity: VectorF32, orientation: Orientation) -> Self {
+        Self {
+            location,
+            linear_velocity,
+            orientation,
+        }
+    }
+}
+
+impl From<&EntityState> for KinematicState {
+    fn from(value: &EntityState) -> Self {
+        Self {
+            location: value.entity_location,
+            linear_velocity: value.entity_linear_velocity,
+            orientation: value.entity_orientation,
+        }
+    }
+}
+
+impl EntityState {
+    /// Extrapolates the location, velocity and orientation of this entity over `elapsed` time,
+    /// using its Dead Reckoning Parameters.
+    #[must_use]
+    pub fn dead_reckon(&self, elapsed: Duration) -> KinematicState {
+        extrapolate(
+            &KinematicState::from(self),
+            &self.dead_reckoning_parameters,
+            elapsed,
+        )
+    }
+}
+
+/// Extrapolates `state` over `elapsed` time using the algorithm, linear acceleration, angular velocity
+/// and other parameters in `dr_parameters`.
+///
+/// `DeadReckoningAlgorithm::Other` and unspecified algorithms are not extrapolated, similar to `StaticNonmovingEntity`.
+#[must_use]
+pub fn extrapolate(
+    state: &KinematicState,
+    dr_parameters: &DrParameters,
+    elapsed: Duration,
+) -> KinematicState {
+    let t = elapsed.as_secs_f64();
+    let location = [
+        state.location.x_coordinate,
+        state.location.y_coordinate,
+        state.location.z_coordinate,
+    ];
+    let velocity = vec3_from(&state.linear_velocity);
+    let acceleration = vec3_from(&dr_parameters.linear_acceleration);
+    let angular_velocity = vec3_from(&dr_parameters.angular_velocity);
+
+    let (location, velocity, rotates) = match dr_parameters.algorithm {
+        DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity => {
+            (add(location, scale(velocity, t)), velocity, false)
+        }
+        DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation => {
+            (add(location, scale(velocity, t)), velocity, true)
+        }
+        DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity => (
+            world_position(location, velocity, acceleration, t),
+            add(velocity, scale(acceleration, t)),
+            false,
+        ),
+        DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation => (
+            world_position(location, velocity, acceleration, t),
+            add(velocity, scale(acceleration, t)),
+            true,
+        ),
+        DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates
+        | DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates => {
+            let body_to_world = initial_world_to_body(state, dr_parameters).inverse();
+            let delta = mul_vec(&r1(angular_velocity, t), velocity);
+            (
+                add(location, body_to_world.rotate(delta)),
+                velocity,
+                dr_parameters.algorithm
+                    == DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates,
+            )
+        }
+        DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates
+        | DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates => {
+            let body_to_world = initial_world_to_body(state, dr_parameters).inverse();
+            let delta = add(
+                mul_vec(&r1(angular_velocity, t), velocity),
+                mul_vec(&r2(angular_velocity, t), acceleration),
+            );
+            (
+                add(location, body_to_world.rotate(delta)),
+                add(velocity, scale(acceleration, t)),
+                dr_parameters.algorithm
+                    == DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates,
+            )
+        }
+        DeadReckoningAlgorithm::StaticNonmovingEntity
+        | DeadReckoningAlgorithm::Other
+        | DeadReckoningAlgorithm::Unspecified(_) => (location, velocity, false),
+    };
+
+    let orientation = if rotates {
+        initial_world_to_body(state, dr_parameters)
+            .then(&RotationMatrix(dr_matrix(angular_velocity, t)))
+            .into()
+    } else {
+        state.orientation
+    };
+
+    KinematicState {
+        location: Location::new(location[0], location[1], location[2]),
+        linear_velocity: VectorF32::new(velocity[0] as f32, velocity[1] as f32, velocity[2] as f32),
+        orientation,
+    }
+}
+
+/// P = P0 + V0 * t + 1/2 * A0 * t^2
+fn world_position(location: Vec3, velocity: Vec3, acceleration: Vec3, t: f64) -> Vec3 {
+    add(
+        add(location, scale(velocity, t)),
+        scale(acceleration, 0.5 * t * t),
+    )
+}
+
+/// Initial world-to-body rotation, taken from the World Orientation Quaternion when present (E.8.2.3),
+/// otherwise from the Euler angles of the entity orientation.
+fn initial_world_to_body(state: &KinematicState, dr_parameters: &DrParameters) -> RotationMatrix {
+    match &dr_parameters.other_parameters {
+        DrOtherParameters::WorldOrientationQuaternion(quaternion) => {
+            let quaternion = Quaternion::from(quaternion);
+            if quaternion.norm() < f64::EPSILON {
+                state.orientation.into()
+            } else {
+                quaternion.normalized().into()
+            }
+        }
+        DrOtherParameters::None(_) | DrOtherParameters::LocalEulerAngles(_) => {
+            state.orientation.into()
+        }
+    }
+}
+
+/// DR rotation matrix (E.6), rotating the world-to-body matrix over time `t` with angular velocity `w`:
+/// `[R]w->b(t) = [DR] * [R]w->b(t0)`
+fn dr_matrix(w: Vec3, t: f64) -> Mat3 {
+    let magnitude = norm(w);
+    if magnitude < MIN_ANGULAR_RATE {
+        return IDENTITY;
+    }
+    let wt = magnitude * t;
+    let (sin_wt, cos_wt) = wt.sin_cos();
+
+    combine(
+        (1.0 - cos_wt) / (magnitude * magnitude),
+        &outer(w),
+        cos_wt,
+        -sin_wt / magnitude,
+        &skew(w),
+    )
+}
+
+/// Body-axis linear velocity integration matrix R1 (E.7)
+fn r1(w: Vec3, t: f64) -> Mat3 {
+    let magnitude = norm(w);
+    if magnitude < MIN_ANGULAR_RATE {
+        return combine(0.0, &outer(w), t, 0.0, &skew(w));
+    }
+    let wt = magnitude * t;
+    let (sin_wt, cos_wt) = wt.sin_cos();
+
+    combine(
+        (wt - sin_wt) / magnitude.powi(3),
+        &outer(w),
+        sin_wt / magnitude,
+        (1.0 - cos_wt) / magnitude.powi(2),
+        &skew(w),
+    )
+}
+
+/// Body-axis linear acceleration integration matrix R2 (E.7)
+fn r2(w: Vec3, t: f64) -> Mat3 {
+    let magnitude = norm(w);
+    if magnitude < MIN_ANGULAR_RATE {
+        return combine(0.0, &outer(w), 0.5 * t * t, 0.0, &skew(w));
+    }
+    let wt = magnitude * t;
+    let (sin_wt, cos_wt) = wt.sin_cos();
+
+    combine(
+        (0.5 * wt * wt - cos_wt - wt * sin_wt + 1.0) / magnitude.powi(4),
+        &outer(w),
+        (cos_wt + wt * sin_wt - 1.0) / magnitude.powi(2),
+        (sin_wt - wt * cos_wt) / magnitude.powi(3),
+        &skew(w),
+    )
+}
+
+const IDENTITY: Mat3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+fn vec3_from(v: &VectorF32) -> Vec3 {
+    [
+        f64::from(v.first_vector_component),
+        f64::from(v.second_vector_component),
+        f64::from(v.third_vector_component),
+    ]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(v: Vec3, factor: f64) -> Vec3 {
+    [v[0] * factor, v[1] * factor, v[2] * factor]
+}
+
+fn norm(v: Vec3) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+/// Outer product `w * w^T`
+fn outer(w: Vec3) -> Mat3 {
+    let mut m = [[0.0; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = w[i] * w[j];
+        }
+    }
+    m
+}
+
+/// Skew-symmetric cross product matrix of `w`, such that `skew(w) * v = w x v`
+fn skew(w: Vec3) -> Mat3 {
+    [[0.0, -w[2], w[1]], [w[2], 0.0, -w[0]], [-w[1], w[0], 0.0]]
+}
+
+/// Computes `a * outer + b * I + c * skew`
+fn combine(a: f64, outer: &Mat3, b: f64, c: f64, skew: &Mat3) -> Mat3 {
+    let mut m = [[0.0; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = a * outer[i][j] + b * IDENTITY[i][j] + c * skew[i][j];
+        }
+    }
+    m
+}
+
+fn mul_vec(m: &Mat3, v: Vec3) -> Vec3 {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::entity_state::model::DrWorldOrientationQuaternion;
+    use std::f32::consts::FRAC_PI_2;
+
+    const EPSILON: f64 = 1.0e-6;
+
+    fn assert_location(actual: &Location, expected: (f64, f64, f64)) {
+        assert!(
+            (actual.x_coordinate - expected.0).abs() < EPSILON
+                && (actual.y_coordinate - expected.1).abs() < EPSILON
+                && (actual.z_coordinate - expected.2).abs() < EPSILON,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn assert_orientation(actual: &Orientation, expected: (f32, f32, f32)) {
+        let epsilon = 1.0e-5;
+        assert!(
+            (actual.psi - expected.0).abs() < epsilon
+                && (actual.theta - expected.1).abs() < epsilon
+                && (actual.phi - expected.2).abs() < epsilon,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn state() -> KinematicState {
+        KinematicState::new(
+            Location::new(100.0, 200.0, 300.0),
+            VectorF32::new(10.0, 0.0, 0.0),
+            Orientation::default(),
+        )
+    }
+
+    fn parameters(algorithm: DeadReckoningAlgorithm) -> DrParameters {
+        DrParameters::default()
+            .with_algorithm(algorithm)
+            .with_linear_acceleration(VectorF32::new(0.0, 2.0, 0.0))
+            .with_angular_velocity(VectorF32::new(0.0, 0.0, FRAC_PI_2))
+    }
+
+    #[test]
+    fn static_entity_does_not_move() {
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::StaticNonmovingEntity),
+            Duration::from_secs(10),
+        );
+
+        assert_eq!(result, state());
+    }
+
+    #[test]
+    fn fpw_constant_velocity() {
+        let result = extrapolate(
+            &state(),
+            &parameters(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ),
+            Duration::from_secs(2),
+        );
+
+        assert_location(&result.location, (120.0, 200.0, 300.0));
+        assert_eq!(result.linear_velocity, VectorF32::new(10.0, 0.0, 0.0));
+        assert_eq!(result.orientation, Orientation::default());
+    }
+
+    #[test]
+    fn fvw_constant_acceleration() {
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity),
+            Duration::from_secs(2),
+        );
+
+        assert_location(&result.location, (120.0, 204.0, 300.0));
+        assert_eq!(result.linear_velocity, VectorF32::new(10.0, 4.0, 0.0));
+        assert_eq!(result.orientation, Orientation::default());
+    }
+
+    #[test]
+    fn rpw_rotates_orientation() {
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation),
+            Duration::from_secs(1),
+        );
+
+        assert_location(&result.location, (110.0, 200.0, 300.0));
+        assert_orientation(&result.orientation, (FRAC_PI_2, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rvw_moves_and_rotates() {
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation),
+            Duration::from_millis(500),
+        );
+
+        assert_location(&result.location, (105.0, 200.25, 300.0));
+        assert_orientation(&result.orientation, (FRAC_PI_2 / 2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fpb_follows_quarter_circle() {
+        // moving 10 m/s forward while turning 90 degrees per second traces a quarter circle with radius 20/pi
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates),
+            Duration::from_secs(1),
+        );
+
+        let radius = 10.0 / std::f64::consts::FRAC_PI_2;
+        assert_location(&result.location, (100.0 + radius, 200.0 + radius, 300.0));
+        assert_eq!(result.linear_velocity, VectorF32::new(10.0, 0.0, 0.0));
+        assert_eq!(result.orientation, Orientation::default());
+    }
+
+    #[test]
+    fn rpb_rotates_orientation() {
+        let result = extrapolate(
+            &state(),
+            &parameters(DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates),
+            Duration::from_secs(1),
+        );
+
+        let radius = 10.0 / std::f64::consts::FRAC_PI_2;
+        assert_location(&result.location, (100.0 + radius, 200.0 + radius, 300.0));
+        assert_orientation(&result.orientation, (FRAC_PI_2, 0.0, 0.0));
+    }
+
+    #[test]
+    fn body_algorithms_without_rotation_match_world_algorithms() {
+        let mut dr_parameters =
+            parameters(DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates);
+        dr_parameters.angular_velocity = VectorF32::default();
+        let body = extrapolate(&state(), &dr_parameters, Duration::from_secs(3));
+
+        dr_parameters.algorithm = DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity;
+        let world = extrapolate(&state(), &dr_parameters, Duration::from_secs(3));
+
+        assert_location(
+            &body.location,
+            (
+                world.location.x_coordinate,
+                world.location.y_coordinate,
+                world.location.z_coordinate,
+            ),
+        );
+        assert_eq!(body.linear_velocity, world.linear_velocity);
+
+        dr_parameters.algorithm =
+            DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates;
+        let rotating = extrapolate(&state(), &dr_parameters, Duration::from_secs(3));
+        assert_eq!(rotating.orientation, Orientation::default());
+    }
+
+    #[test]
+    fn rotation_uses_world_orientation_quaternion() {
+        // quaternion for a heading (psi) of 90 degrees, while the Euler angles in the state are zero
+        let half_angle = std::f32::consts::FRAC_PI_4;
+        let dr_parameters = DrParameters::default()
+            .with_algorithm(DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation)
+            .with_parameters(DrOtherParameters::WorldOrientationQuaternion(
+                DrWorldOrientationQuaternion::default()
+                    .with_nil((half_angle.cos() * f32::from(u16::MAX)).round() as u16)
+                    .with_z(half_angle.sin()),
+            ))
+            .with_angular_velocity(VectorF32::new(0.0, 0.0, FRAC_PI_2 / 2.0));
+
+        let result = extrapolate(&state(), &dr_parameters, Duration::from_secs(1));
+
+        assert_orientation(&result.orientation, (3.0 * FRAC_PI_2 / 2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn entity_state_dead_reckon() {
+        let entity_state = EntityState::builder()
+            .with_location(Location::new(0.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(1.0, 2.0, 3.0))
+            .with_dead_reckoning_parameters(DrParameters::default().with_algorithm(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ))
+            .build();
+
+        let result = entity_state.dead_reckon(Duration::from_secs(2));
+
+        assert_location(&result.location, (2.0, 4.0, 6.0));
+    }
+}
@@ -0,0 +1,331 @@
+//! Sender-side dead reckoning threshold tracking (IEEE 1278.1-2012, 5.3.2.3 and Annex E).
+//!
+//! A simulation keeps one [`DeadReckoningTracker`] per owned entity. The tracker remembers the last issued
+//! `EntityState`, extrapolates it the same way remote simulations do, and reports when the true state
+//! has diverged beyond the position or orientation threshold, or when the heartbeat interval has passed.
+use crate::common::entity_state::model::EntityState;
+use crate::common::model::{EntityType, Location, Orientation};
+use crate::enumerations::{DeadReckoningAlgorithm, EntityKind, PlatformDomain};
+use crate::utils::rotation::Quaternion;
+use crate::variable_parameters::VariableParameters;
+use std::time::Duration;
+
+/// The reason why a new Entity State PDU has to be issued for an entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UpdateReason {
+    /// No Entity State PDU has been issued yet for the entity
+    Initial,
+    /// The dead reckoned location differs more than the position threshold from the true location
+    PositionThreshold,
+    /// The dead reckoned orientation differs more than the orientation threshold from the true orientation
+    OrientationThreshold,
+    /// The heartbeat interval has passed since the last issued Entity State PDU
+    Heartbeat,
+}
+
+/// Tracks the last issued Entity State of a single entity and determines when a new one needs to be issued.
+///
+/// Time is passed in explicitly as the simulation time elapsed since an arbitrary (but fixed) epoch,
+/// so the tracker works for both real-time and faster/slower than real-time simulations.
+#[derive(Clone, Debug)]
+pub struct DeadReckoningTracker {
+    last_issued: Option<(EntityState, Duration)>,
+    position_threshold: f64,
+    orientation_threshold: f32,
+    heartbeat: Duration,
+    stationary_heartbeat: Duration,
+}
+
+impl DeadReckoningTracker {
+    /// Creates a tracker with the thresholds and heartbeat interval from `parameters`,
+    /// selecting the heartbeat interval that applies to `entity_type`.
+    #[must_use]
+    pub fn new(entity_type: &EntityType, parameters: &VariableParameters) -> Self {
+        Self {
+            last_issued: None,
+            position_threshold: f64::from(parameters.DRA_POS_THRSH),
+            orientation_threshold: parameters.DRA_ORIENT_THRSH.to_radians(),
+            heartbeat: Duration::from_secs_f32(heartbeat_for_entity_type(entity_type, parameters)),
+            // HBT_STATIONARY is expressed in minutes
+            stationary_heartbeat: Duration::from_secs_f32(parameters.HBT_STATIONARY * 60.0),
+        }
+    }
+
+    /// Sets the position threshold, in meters
+    #[must_use]
+    pub fn with_position_threshold(mut self, meters: f64) -> Self {
+        self.position_threshold = meters;
+        self
+    }
+
+    /// Sets the orientation threshold, in radians
+    #[must_use]
+    pub fn with_orientation_threshold(mut self, radians: f32) -> Self {
+        self.orientation_threshold = radians;
+        self
+    }
+
+    /// Sets the heartbeat interval for moving entities
+    #[must_use]
+    pub fn with_heartbeat(mut self, heartbeat: Duration) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Sets the heartbeat interval for stationary entities (using the `StaticNonmovingEntity` DR algorithm)
+    #[must_use]
+    pub fn with_stationary_heartbeat(mut self, heartbeat: Duration) -> Self {
+        self.stationary_heartbeat = heartbeat;
+        self
+    }
+
+    /// The last issued Entity State, if any
+    #[must_use]
+    pub fn last_issued(&self) -> Option<&EntityState> {
+        self.last_issued.as_ref().map(|(state, _)| state)
+    }
+
+    /// Determines whether a new Entity State PDU needs to be issued, given the true state of the entity at time `now`.
+    ///
+    /// Returns `None` when the dead reckoned version of the last issued state is still within the thresholds.
+    #[must_use]
+    pub fn check(&self, current: &EntityState, now: Duration) -> Option<UpdateReason> {
+        let Some((issued, issued_at)) = &self.last_issued else {
+            return Some(UpdateReason::Initial);
+        };
+        let elapsed = now.saturating_sub(*issued_at);
+
+        let heartbeat = if issued.dead_reckoning_parameters.algorithm
+            == DeadReckoningAlgorithm::StaticNonmovingEntity
+        {
+            self.stationary_heartbeat
+        } else {
+            self.heartbeat
+        };
+
+        let dead_reckoned = issued.dead_reckon(elapsed);
+        if distance(&current.entity_location, &dead_reckoned.location) > self.position_threshold {
+            Some(UpdateReason::PositionThreshold)
+        } else if orientation_difference(&current.entity_orientation, &dead_reckoned.orientation)
+            > self.orientation_threshold
+        {
+            Some(UpdateReason::OrientationThreshold)
+        } else if elapsed >= heartbeat {
+            Some(UpdateReason::Heartbeat)
+        } else {
+            None
+        }
+    }
+
+    /// Records that `issued` has been sent at time `now`, making it the new reference for dead reckoning.
+    pub fn issued(&mut self, issued: EntityState, now: Duration) {
+        self.last_issued = Some((issued, now));
+    }
+
+    /// Checks `current` against the thresholds and, when an update is due, records `current` as issued at `now`.
+    ///
+    /// The caller is expected to send an Entity State PDU for `current` when this returns `Some`.
+    pub fn update(&mut self, current: &EntityState, now: Duration) -> Option<UpdateReason> {
+        let reason = self.check(current, now);
+        if reason.is_some() {
+            self.issued(current.clone(), now);
+        }
+        reason
+    }
+}
+
+/// Selects the Entity State PDU heartbeat interval (in seconds) for an entity type,
+/// by platform domain for platforms and by entity kind otherwise.
+///
+/// Entity types without a dedicated heartbeat parameter use `HBT_ESPDU_PLATFORM_LAND`.
+pub(crate) fn heartbeat_for_entity_type(
+    entity_type: &EntityType,
+    parameters: &VariableParameters,
+) -> f32 {
+    match entity_type.kind {
+        EntityKind::Platform => match entity_type.domain {
+            PlatformDomain::Air => parameters.HBT_ESPDU_PLATFORM_AIR,
+            PlatformDomain::Surface => parameters.HBT_ESPDU_PLATFORM_SURFACE,
+            PlatformDomain::Subsurface => parameters.HBT_ESPDU_PLATFORM_SUBSURFACE,
+            PlatformDomain::Space => parameters.HBT_ESPDU_PLATFORM_SPACE,
+            PlatformDomain::Land | PlatformDomain::Other | PlatformDomain::Unspecified(_) => {
+                parameters.HBT_ESPDU_PLATFORM_LAND
+            }
+        },
+        EntityKind::Munition => parameters.HBT_ESPDU_KIND_MUNITION,
+        EntityKind::LifeForm => parameters.HBT_ESPDU_KIND_LIFE_FORM,
+        EntityKind::Environmental => parameters.HBT_ESPDU_KIND_ENVIRONMENTAL,
+        EntityKind::CulturalFeature => parameters.HBT_ESPDU_KIND_CULTURAL_FEATURE,
+        EntityKind::Supply => parameters.HBT_ESPDU_KIND_SUPPLY,
+        EntityKind::Radio => parameters.HBT_ESPDU_KIND_RADIO,
+        EntityKind::Expendable => parameters.HBT_ESPDU_KIND_EXPENDABLE,
+        EntityKind::SensorEmitter => parameters.HBT_ESPDU_KIND_SENSOR,
+        EntityKind::Other | EntityKind::Unspecified(_) => parameters.HBT_ESPDU_PLATFORM_LAND,
+    }
+}
+
+/// Euclidean distance in meters between two locations
+fn distance(a: &Location, b: &Location) -> f64 {
+    let dx = a.x_coordinate - b.x_coordinate;
+    let dy = a.y_coordinate - b.y_coordinate;
+    let dz = a.z_coordinate - b.z_coordinate;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Angle in radians of the rotation between two orientations
+fn orientation_difference(a: &Orientation, b: &Orientation) -> f32 {
+    Quaternion::from(*a).angle_to(&Quaternion::from(*b)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::entity_state::model::DrParameters;
+    use crate::common::model::VectorF32;
+
+    fn entity_state(location: Location, orientation: Orientation) -> EntityState {
+        EntityState::builder()
+            .with_entity_type(
+                EntityType::default()
+                    .with_kind(EntityKind::Platform)
+                    .with_domain(PlatformDomain::Land),
+            )
+            .with_location(location)
+            .with_velocity(VectorF32::new(10.0, 0.0, 0.0))
+            .with_orientation(orientation)
+            .with_dead_reckoning_parameters(DrParameters::default().with_algorithm(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ))
+            .build()
+    }
+
+    fn tracker() -> DeadReckoningTracker {
+        DeadReckoningTracker::new(
+            &EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Land),
+            &VariableParameters::default(),
+        )
+    }
+
+    #[test]
+    fn first_update_is_initial() {
+        let mut tracker = tracker();
+        let state = entity_state(Location::new(0.0, 0.0, 0.0), Orientation::default());
+
+        assert_eq!(
+            tracker.update(&state, Duration::ZERO),
+            Some(UpdateReason::Initial)
+        );
+        assert_eq!(tracker.last_issued(), Some(&state));
+    }
+
+    #[test]
+    fn within_thresholds_no_update() {
+        let mut tracker = tracker();
+        tracker.issued(
+            entity_state(Location::new(0.0, 0.0, 0.0), Orientation::default()),
+            Duration::ZERO,
+        );
+
+        // moving exactly as dead reckoned, plus a small deviation
+        let current = entity_state(Location::new(20.5, 0.0, 0.0), Orientation::default());
+        assert_eq!(tracker.update(&current, Duration::from_secs(2)), None);
+    }
+
+    #[test]
+    fn position_threshold_exceeded() {
+        let mut tracker = tracker();
+        tracker.issued(
+            entity_state(Location::new(0.0, 0.0, 0.0), Orientation::default()),
+            Duration::ZERO,
+        );
+
+        let current = entity_state(Location::new(20.0, 1.5, 0.0), Orientation::default());
+        assert_eq!(
+            tracker.update(&current, Duration::from_secs(2)),
+            Some(UpdateReason::PositionThreshold)
+        );
+        assert_eq!(tracker.last_issued(), Some(&current));
+    }
+
+    #[test]
+    fn orientation_threshold_exceeded() {
+        let tracker = {
+            let mut tracker = tracker();
+            tracker.issued(
+                entity_state(Location::new(0.0, 0.0, 0.0), Orientation::default()),
+                Duration::ZERO,
+            );
+            tracker
+        };
+
+        let current = entity_state(
+            Location::new(10.0, 0.0, 0.0),
+            Orientation::new(0.0, 0.0, 4.0f32.to_radians()),
+        );
+        assert_eq!(
+            tracker.check(&current, Duration::from_secs(1)),
+            Some(UpdateReason::OrientationThreshold)
+        );
+
+        let current = entity_state(
+            Location::new(10.0, 0.0, 0.0),
+            Orientation::new(2.0f32.to_radians(), 0.0, 0.0),
+        );
+        assert_eq!(tracker.check(&current, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn heartbeat_exceeded() {
+        let mut tracker = tracker();
+        tracker.issued(
+            entity_state(Location::new(0.0, 0.0, 0.0), Orientation::default()),
+            Duration::ZERO,
+        );
+
+        let current = entity_state(Location::new(50.0, 0.0, 0.0), Orientation::default());
+        assert_eq!(
+            tracker.update(&current, Duration::from_secs(5)),
+            Some(UpdateReason::Heartbeat)
+        );
+        let current = entity_state(Location::new(60.0, 0.0, 0.0), Orientation::default());
+        assert_eq!(tracker.update(&current, Duration::from_secs(6)), None);
+    }
+
+    #[test]
+    fn stationary_entities_use_stationary_heartbeat() {
+        let mut tracker = tracker();
+        let stationary = EntityState::builder()
+            .with_dead_reckoning_parameters(
+                DrParameters::default()
+                    .with_algorithm(DeadReckoningAlgorithm::StaticNonmovingEntity),
+            )
+            .build();
+        tracker.issued(stationary.clone(), Duration::ZERO);
+
+        assert_eq!(tracker.check(&stationary, Duration::from_secs(30)), None);
+        assert_eq!(
+            tracker.check(&stationary, Duration::from_mins(1)),
+            Some(UpdateReason::Heartbeat)
+        );
+    }
+
+    #[test]
+    fn heartbeat_from_entity_type() {
+        let parameters = VariableParameters {
+            HBT_ESPDU_PLATFORM_AIR: 2.0,
+            HBT_ESPDU_KIND_MUNITION: 1.0,
+            ..Default::default()
+        };
+
+        let air = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Air);
+        let munition = EntityType::default().with_kind(EntityKind::Munition);
+
+        assert_eq!(heartbeat_for_entity_type(&air, &parameters), 2.0);
+        assert_eq!(heartbeat_for_entity_type(&munition, &parameters), 1.0);
+    }
+}
@@ -0,0 +1,559 @@
+//! Field-level dissection of raw PDUs, for debugging interoperability problems.
+//!
+//! [`dissect`] walks the bytes of a PDU and produces a tree of [`DissectedField`]s, where each field
+//! carries its byte offset, length, raw bytes and decoded value (with enumeration names), similar to
+//! the DIS dissector of Wireshark. The tree renders as indented text through its `Display` implementation.
+//!
+//! The tree is not a separate description of the PDU layouts: the PDU is parsed by the regular parsers
+//! of the crate, which report the span of each named field and record they parse while a PDU is being
+//! dissected. Fields carry their decoded value; records (such as the Firing Entity ID of a Fire PDU)
+//! carry their fields. This covers the header and the body of all implemented PDU types.
+//!
+//! The module requires the `dissector` feature; without it, the parsers do not report any spans.
+use crate::common::errors::RecordError;
+use crate::common::parser::{IResult, parse_pdu, pdu_header};
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use nom::Err;
+use nom::error::ErrorKind;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fmt::{Debug, Display, Formatter};
+
+const MAX_RENDERED_RAW_BYTES: usize = 16;
+
+/// A field or record of a dissected PDU.
+///
+/// Records (such as the PDU Header or an Entity ID) contain their constituent fields in `fields`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DissectedField {
+    pub name: String,
+    /// Offset in bytes from the start of the PDU
+    pub offset: usize,
+    /// Length in bytes
+    pub length: usize,
+    pub raw: Vec<u8>,
+    /// The decoded value, or a summary of a record
+    pub value: String,
+    pub fields: Vec<DissectedField>,
+}
+
+impl DissectedField {
+    /// Returns the (nested) field at the given path of field names, if present.
+    #[must_use]
+    pub fn get(&self, path: &[&str]) -> Option<&DissectedField> {
+        path.iter().try_fold(self, |field, name| {
+            field.fields.iter().find(|child| child.name == *name)
+        })
+    }
+
+    fn render(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        write!(
+            f,
+            "{:indent$}{} [{}..{}]",
+            "",
+            self.name,
+            self.offset,
+            self.offset + self.length,
+            indent = depth * 2
+        )?;
+        if !self.value.is_empty() {
+            write!(f, ": {}", self.value)?;
+        }
+        if self.fields.is_empty() {
+            write!(f, " (")?;
+            for (index, byte) in self.raw.iter().take(MAX_RENDERED_RAW_BYTES).enumerate() {
+                if index > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{byte:02x}")?;
+            }
+            if self.raw.len() > MAX_RENDERED_RAW_BYTES {
+                write!(f, " ...")?;
+            }
+            write!(f, ")")?;
+        }
+        writeln!(f)?;
+        self.fields
+            .iter()
+            .try_for_each(|field| field.render(f, depth + 1))
+    }
+}
+
+impl Display for DissectedField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.render(f, 0)
+    }
+}
+
+/// Dissects the first PDU in `input` into a tree of fields.
+///
+/// Dissection does not fail: when the PDU cannot be parsed, the records up to the point of failure are
+/// reported, with the failing records marked as truncated or invalid.
+/// Bytes within the PDU length that are not covered by the dissected fields are reported as 'Trailing Data'.
+#[must_use]
+pub fn dissect(input: &[u8]) -> DissectedField {
+    // dissect up to the PDU length, or the available input when the PDU is truncated or the length is invalid
+    let pdu_length = input.get(8..10).map_or(input.len(), |length| {
+        usize::from(u16::from_be_bytes([length[0], length[1]]))
+    });
+    let end = if (PDU_HEADER_LEN_BYTES as usize..=input.len()).contains(&pdu_length) {
+        pdu_length
+    } else {
+        input.len()
+    };
+    let input = &input[..end];
+
+    let (pdu, spans) = record_spans(|| parse_pdu(input));
+    let dissection = Dissection {
+        input,
+        base: input.as_ptr() as usize,
+    };
+    let mut spans = spans.into_iter().filter(|span| dissection.contains(span));
+
+    let mut fields = Vec::new();
+    if let Some(header) = spans.next() {
+        fields.push(dissection.field(header));
+    }
+    let body: Vec<Span> = spans.collect();
+    if let Some(first) = body.first() {
+        let (start, _) = dissection.offsets(first);
+        let body_end = body
+            .iter()
+            .map(|span| dissection.offsets(span).1)
+            .max()
+            .unwrap_or(start);
+        fields.push(DissectedField {
+            name: "PDU Body".to_string(),
+            offset: start,
+            length: body_end - start,
+            raw: input[start..body_end].to_vec(),
+            value: match &pdu {
+                Ok(_) => String::new(),
+                Err(err) => format!("<{err}>"),
+            },
+            fields: dissection.fields(body, start, body_end),
+        });
+    }
+    let covered = fields.last().map_or(0, |field| field.offset + field.length);
+    if covered < end {
+        fields.push(dissection.unnamed("Trailing Data", covered, end));
+    }
+
+    DissectedField {
+        name: "PDU".to_string(),
+        offset: 0,
+        length: end,
+        raw: input.to_vec(),
+        value: pdu_header(input)
+            .map(|(_, header)| format!("{} PDU ({})", header.pdu_type, header.protocol_version))
+            .unwrap_or_default(),
+        fields,
+    }
+}
+
+thread_local! {
+    /// Stack of the records being parsed on this thread while a PDU is dissected,
+    /// each holding the spans of the nested records parsed so far.
+    static SPANS: RefCell<Option<Vec<Vec<Span>>>> = const { RefCell::new(None) };
+}
+
+/// A record parsed while dissecting, located by the addresses of its first byte and the byte after it.
+struct Span {
+    name: &'static str,
+    start: usize,
+    end: usize,
+    value: Result<String, ErrorKind>,
+    children: Vec<Span>,
+}
+
+/// Runs `parse` while keeping the spans of the records it parses.
+fn record_spans<T>(parse: impl FnOnce() -> T) -> (T, Vec<Span>) {
+    SPANS.with(|spans| *spans.borrow_mut() = Some(vec![Vec::new()]));
+    let result = parse();
+    let spans = SPANS
+        .with(|spans| spans.borrow_mut().take())
+        .and_then(|mut stack| stack.pop())
+        .unwrap_or_default();
+    (result, spans)
+}
+
+/// Indicates whether the parsers should report the records they parse.
+pub(crate) fn is_recording() -> bool {
+    SPANS.with(|spans| spans.borrow().is_some())
+}
+
+/// Starts a record, which collects the records parsed until the matching call to [`exit_record`].
+pub(crate) fn enter_record() {
+    SPANS.with(|spans| {
+        if let Some(stack) = spans.borrow_mut().as_mut() {
+            stack.push(Vec::new());
+        }
+    });
+}
+
+/// Completes the record started by the last call to [`enter_record`], parsed from `input` with `result`.
+pub(crate) fn exit_record<O: Debug>(name: &'static str, input: &[u8], result: &IResult<&[u8], O>) {
+    let start = input.as_ptr() as usize;
+    SPANS.with(|spans| {
+        let mut spans = spans.borrow_mut();
+        let Some(stack) = spans.as_mut() else {
+            return;
+        };
+        let mut children = stack.pop().unwrap_or_default();
+        let (end, value) = match result {
+            Ok((remainder, output)) => {
+                // failing nested records were alternatives that the parser backtracked from
+                children.retain(|child| child.value.is_ok());
+                // a record is described by its fields, a field by its decoded value
+                let value = if children.is_empty() {
+                    format!("{output:?}")
+                } else {
+                    String::new()
+                };
+                (remainder.as_ptr() as usize, Ok(value))
+            }
+            // a failing record spans the remaining input, as it is not known where it would have ended
+            Err(
+                Err::Error(RecordError { input, kind, .. })
+                | Err::Failure(RecordError { input, kind, .. }),
+            ) => (input.as_ptr() as usize + input.len(), Err(*kind)),
+            Err(Err::Incomplete(_)) => (start + input.len(), Err(ErrorKind::Eof)),
+        };
+        let Some(parent) = stack.last_mut() else {
+            return;
+        };
+        // a record that is parsed again at the same position (e.g., after peeking) replaces the earlier span
+        if parent
+            .last()
+            .is_some_and(|last| last.name == name && last.start == start)
+        {
+            parent.pop();
+        }
+        parent.push(Span {
+            name,
+            start,
+            end,
+            value,
+            children,
+        });
+    });
+}
+
+/// Converts the recorded spans of the PDU in `input` into dissected fields.
+struct Dissection<'a> {
+    input: &'a [u8],
+    base: usize,
+}
+
+impl Dissection<'_> {
+    /// Indicates whether the span lies within the input, as opposed to a copy of (part of) the input.
+    fn contains(&self, span: &Span) -> bool {
+        span.start >= self.base
+            && span.start <= span.end
+            && span.end <= self.base + self.input.len()
+    }
+
+    fn offsets(&self, span: &Span) -> (usize, usize) {
+        (span.start - self.base, span.end - self.base)
+    }
+
+    fn field(&self, span: Span) -> DissectedField {
+        let (start, end) = self.offsets(&span);
+        DissectedField {
+            name: span.name.to_string(),
+            offset: start,
+            length: end - start,
+            raw: self.input[start..end].to_vec(),
+            value: match span.value {
+                Ok(value) => value,
+                Err(ErrorKind::Eof) => "<truncated>".to_string(),
+                Err(kind) => format!("<invalid: {kind:?}>"),
+            },
+            fields: self.fields(span.children, start, end),
+        }
+    }
+
+    /// Dissects the nested records of the record at `start..end`, reporting the bytes in between as 'Fields'.
+    fn fields(&self, spans: Vec<Span>, start: usize, end: usize) -> Vec<DissectedField> {
+        let mut fields = Vec::new();
+        let mut offset = start;
+        for span in spans.into_iter().filter(|span| self.contains(span)) {
+            let field = self.field(span);
+            if field.offset > offset {
+                fields.push(self.unnamed("Fields", offset, field.offset));
+            }
+            offset = offset.max(field.offset + field.length);
+            fields.push(field);
+        }
+        if !fields.is_empty() && offset < end {
+            fields.push(self.unnamed("Fields", offset, end));
+        }
+        fields
+    }
+
+    fn unnamed(&self, name: &str, start: usize, end: usize) -> DissectedField {
+        DissectedField {
+            name: name.to_string(),
+            offset: start,
+            length: end - start,
+            raw: self.input[start..end].to_vec(),
+            value: String::new(),
+            fields: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::BodyInfo;
+    use crate::common::model::{
+        EntityId, EntityType, Pdu, PduBody, PduHeader, TimeUnits, Timestamp, default_bodies,
+    };
+    use crate::electromagnetic_emission::model::{
+        Beam, ElectromagneticEmission, EmitterSystem, TrackJam,
+    };
+    use crate::entity_state::model::{EntityMarking, EntityState};
+    use crate::enumerations::{
+        Country, EntityKind, EntityMarkingCharacterSet, PduType, PlatformDomain,
+    };
+    use crate::fire::model::Fire;
+    use crate::model::{ArticulatedPart, VariableParameter};
+    use bytes::BytesMut;
+
+    fn serialize(body: PduBody) -> Vec<u8> {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(3, body.body_type()),
+            body,
+            Timestamp::Absolute(TimeUnits::new(35_791_394).unwrap()),
+        );
+        let mut buf = BytesMut::with_capacity(usize::from(pdu.pdu_length()));
+        pdu.serialize(&mut buf).unwrap();
+        buf.to_vec()
+    }
+
+    #[test]
+    fn dissect_entity_state() {
+        let bytes = serialize(
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 3))
+                .with_entity_type(
+                    EntityType::default()
+                        .with_kind(EntityKind::Platform)
+                        .with_domain(PlatformDomain::Land)
+                        .with_country(Country::from(225)),
+                )
+                .with_marking(EntityMarking::new(
+                    "TANK1",
+                    EntityMarkingCharacterSet::ASCII,
+                ))
+                .with_variable_parameter(VariableParameter::Articulated(ArticulatedPart::default()))
+                .build()
+                .into_pdu_body(),
+        );
+
+        let pdu = dissect(&bytes);
+
+        assert_eq!(pdu.length, bytes.len());
+        assert_eq!(pdu.value, "EntityState PDU (IEEE1278_12012)");
+        let exercise_id = pdu.get(&["PDU Header", "Exercise ID"]).unwrap();
+        assert_eq!((exercise_id.offset, exercise_id.value.as_str()), (1, "3"));
+
+        let entity_id = pdu.get(&["PDU Body", "Entity ID"]).unwrap();
+        assert_eq!((entity_id.offset, entity_id.length), (12, 6));
+        assert_eq!(
+            pdu.get(&["PDU Body", "Entity ID", "Entity Number"])
+                .unwrap()
+                .value,
+            "3"
+        );
+        let force_id = pdu.get(&["PDU Body", "Force ID"]).unwrap();
+        assert_eq!((force_id.offset, force_id.length), (18, 1));
+        let entity_type = pdu.get(&["PDU Body", "Entity Type"]).unwrap();
+        assert_eq!(entity_type.offset, 20);
+        assert!(entity_type.value.is_empty());
+        let domain = pdu.get(&["PDU Body", "Entity Type", "Domain"]).unwrap();
+        assert_eq!((domain.offset, domain.value.as_str()), (21, "Land"));
+        assert_eq!(
+            pdu.get(&["PDU Body", "Entity Marking", "Marking String"])
+                .unwrap()
+                .value,
+            "\"TANK1\""
+        );
+        assert!(pdu.get(&["PDU Body", "Variable Parameter"]).is_some());
+        assert!(pdu.get(&["Trailing Data"]).is_none());
+
+        let text = pdu.to_string();
+        assert!(text.contains("  PDU Header [0..12]"));
+        assert!(text.contains("    PDU Type [2..3]: EntityState (01)"));
+    }
+
+    #[test]
+    fn dissect_fire_descriptor() {
+        let bytes = serialize(Fire::builder().build().into_pdu_body());
+
+        let pdu = dissect(&bytes);
+
+        let descriptor = pdu.get(&["PDU Body", "Descriptor"]).unwrap();
+        assert_eq!((descriptor.offset, descriptor.length), (64, 16));
+        assert!(pdu.get(&["PDU Body", "Descriptor", "Munition"]).is_some());
+        let range = pdu.get(&["PDU Body"]).unwrap().fields.last().unwrap();
+        assert_eq!(
+            (range.name.as_str(), range.offset, range.length),
+            ("Range", 92, 4)
+        );
+    }
+
+    #[test]
+    fn dissect_role_names() {
+        let bytes = serialize(
+            Fire::builder()
+                .with_firing_entity_id(EntityId::new(1, 1, 1))
+                .with_target_entity_id(EntityId::new(1, 1, 2))
+                .with_entity_id(EntityId::new(1, 1, 3))
+                .build()
+                .into_pdu_body(),
+        );
+
+        let pdu = dissect(&bytes);
+
+        let entity_numbers: Vec<_> = ["Firing Entity ID", "Target Entity ID", "Munition ID"]
+            .iter()
+            .map(|role| {
+                pdu.get(&["PDU Body", role, "Entity Number"])
+                    .unwrap()
+                    .value
+                    .as_str()
+            })
+            .collect();
+        assert_eq!(entity_numbers, ["1", "2", "3"]);
+    }
+
+    /// Checks that each byte of the body is dissected as a named field with a decoded value.
+    fn assert_named_fields(field: &DissectedField, pdu_type: PduType) {
+        assert_ne!(field.name, "Fields", "unnamed bytes in {pdu_type:?}");
+        if field.fields.is_empty() {
+            assert!(
+                !field.value.is_empty(),
+                "no value for {} in {pdu_type:?}",
+                field.name
+            );
+        } else {
+            assert!(field.value.is_empty());
+            field
+                .fields
+                .iter()
+                .for_each(|child| assert_named_fields(child, pdu_type));
+        }
+    }
+
+    #[test]
+    fn dissect_all_pdu_types() {
+        for body in default_bodies() {
+            let pdu_type = body.body_type();
+            let bytes = serialize(body);
+
+            let pdu = dissect(&bytes);
+
+            assert!(pdu.get(&["Trailing Data"]).is_none(), "{pdu_type:?}");
+            let body = pdu.get(&["PDU Body"]).unwrap();
+            assert_eq!(body.offset + body.length, bytes.len(), "{pdu_type:?}");
+            body.fields
+                .iter()
+                .for_each(|field| assert_named_fields(field, pdu_type));
+        }
+    }
+
+    #[test]
+    fn dissect_truncated_pdu() {
+        let bytes = serialize(Fire::builder().build().into_pdu_body());
+
+        let pdu = dissect(&bytes[..23]);
+
+        let body = pdu.get(&["PDU Body"]).unwrap();
+        assert!(body.value.starts_with("<Malformed Fire PDU"));
+        assert_eq!(body.fields.len(), 2);
+        let target = &body.fields[1];
+        assert_eq!((target.offset, target.length), (18, 5));
+        assert_eq!(target.value, "<truncated>");
+    }
+
+    #[test]
+    fn dissect_nested_records() {
+        let bytes = serialize(
+            ElectromagneticEmission::builder()
+                .with_emitter_system(
+                    EmitterSystem::default()
+                        .with_beam(Beam::default().with_track_jam(TrackJam::default())),
+                )
+                .build()
+                .into_pdu_body(),
+        );
+
+        let pdu = dissect(&bytes);
+
+        let system = pdu.get(&["PDU Body", "Emitter System"]).unwrap();
+        assert_eq!(system.offset + system.length, bytes.len());
+        let track_jam = pdu
+            .get(&["PDU Body", "Emitter System", "Beam", "Track/Jam Data"])
+            .unwrap();
+        assert_eq!((track_jam.offset, track_jam.length), (100, 8));
+        assert!(
+            pdu.get(&[
+                "PDU Body",
+                "Emitter System",
+                "Beam",
+                "Track/Jam Data",
+                "Entity ID"
+            ])
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn dissect_simulation_management_body() {
+        let bytes = serialize(
+            crate::acknowledge::model::Acknowledge::builder()
+                .build()
+                .into_pdu_body(),
+        );
+
+        let pdu = dissect(&bytes);
+
+        let body = pdu.get(&["PDU Body"]).unwrap();
+        let fields: Vec<_> = body
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.offset, field.length))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("Originating ID", 12, 6),
+                ("Receiving ID", 18, 6),
+                ("Acknowledge Flag", 24, 2),
+                ("Response Flag", 26, 2),
+                ("Request ID", 28, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn dissect_trailing_data() {
+        let mut bytes = serialize(
+            crate::acknowledge::model::Acknowledge::builder()
+                .build()
+                .into_pdu_body(),
+        );
+        bytes.extend_from_slice(&[0xff; 4]);
+        bytes[8..10].copy_from_slice(&36u16.to_be_bytes());
+
+        let pdu = dissect(&bytes);
+
+        let trailing = pdu.get(&["Trailing Data"]).unwrap();
+        assert_eq!((trailing.offset, trailing.length), (32, 4));
+    }
+}
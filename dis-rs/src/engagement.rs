@@ -0,0 +1,566 @@
+//! Correlation of weapon fire and detonation events into engagements (IEEE 1278.1-2012, 5.4.3).
+//!
+//! Fire and Detonation PDUs describing the same shot share an `EventId`. The [`EngagementTracker`]
+//! is fed with parsed `Pdu`s and links each Fire PDU to its Detonation PDU, and to the Entity State PDUs
+//! of the munition when the munition is simulated as a tracked entity.
+//!
+//! For each [`Engagement`] the time of flight, miss distance and detonation result are available.
+//! Fires that do not detonate within a timeout are reported as not detonated by [`EngagementTracker::expire`],
+//! which also removes finished engagements after a retention period.
+//! As the event number of an `EventId` wraps around, a Fire PDU for a finished engagement starts a new engagement.
+use crate::common::detonation::model::{Detonation, DetonationDescriptor};
+use crate::common::entity_state::model::EntityState;
+use crate::common::fire::model::{Fire, FireDescriptor};
+use crate::common::model::{EntityId, EventId, Location, MunitionDescriptor, Pdu, PduBody};
+use crate::enumerations::DetonationResult;
+use crate::fixed_parameters::NO_ENTITY;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::time::Duration;
+
+/// Default time after the last activity of an engagement before its fire is considered to have not detonated.
+pub const DEFAULT_DETONATION_TIMEOUT: Duration = Duration::from_mins(1);
+/// Default time after the last activity of a finished engagement before it is removed from the tracker.
+pub const DEFAULT_RETENTION: Duration = Duration::from_mins(5);
+
+/// The state of an engagement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EngagementStatus {
+    /// The munition has been fired, and has not detonated yet
+    InFlight,
+    /// A Detonation PDU has been received
+    Detonated,
+    /// No Detonation PDU was received within the timeout after the fire
+    NotDetonated,
+}
+
+/// A single shot, identified by the `EventId` of its Fire and Detonation PDUs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Engagement {
+    pub event_id: EventId,
+    pub status: EngagementStatus,
+    /// The Fire PDU, if received. A Detonation can be received without a preceding Fire PDU.
+    pub fire: Option<Fire>,
+    pub fire_time: Option<Duration>,
+    pub detonation: Option<Detonation>,
+    pub detonation_time: Option<Duration>,
+    /// The last Entity State PDU of the munition, when it is simulated as a tracked entity
+    pub munition_state: Option<EntityState>,
+    /// Time of the last Fire, Detonation or munition Entity State PDU applied to the engagement
+    pub last_activity: Duration,
+}
+
+impl Engagement {
+    fn new(event_id: EventId, now: Duration) -> Self {
+        Self {
+            event_id,
+            status: EngagementStatus::InFlight,
+            fire: None,
+            fire_time: None,
+            detonation: None,
+            detonation_time: None,
+            munition_state: None,
+            last_activity: now,
+        }
+    }
+
+    /// Returns the id of the tracked munition entity, from the Fire or Detonation PDU.
+    #[must_use]
+    pub fn munition_entity_id(&self) -> Option<EntityId> {
+        self.fire
+            .as_ref()
+            .map(|fire| fire.entity_id)
+            .or(self
+                .detonation
+                .as_ref()
+                .map(|detonation| detonation.exploding_entity_id))
+            .filter(|entity_id| is_specified(*entity_id))
+    }
+
+    /// Returns the munition descriptor of the Fire PDU, or of the Detonation PDU when no Fire PDU was received.
+    #[must_use]
+    pub fn munition_descriptor(&self) -> Option<&MunitionDescriptor> {
+        match (&self.fire, &self.detonation) {
+            (
+                Some(Fire {
+                    descriptor: FireDescriptor::Munition(descriptor),
+                    ..
+                }),
+                _,
+            )
+            | (
+                _,
+                Some(Detonation {
+                    descriptor: DetonationDescriptor::Munition(descriptor),
+                    ..
+                }),
+            ) => Some(descriptor),
+            _ => None,
+        }
+    }
+
+    /// Time between receiving the Fire PDU and the Detonation PDU.
+    #[must_use]
+    pub fn time_of_flight(&self) -> Option<Duration> {
+        Some(self.detonation_time?.saturating_sub(self.fire_time?))
+    }
+
+    /// Distance in meters between the detonation and the origin of the target entity.
+    ///
+    /// Derived from the location in entity coordinates of the Detonation PDU, so only available when
+    /// the detonation is associated with a specific target entity.
+    #[must_use]
+    pub fn miss_distance(&self) -> Option<f32> {
+        let detonation = self.detonation.as_ref()?;
+        if !is_specified(detonation.target_entity_id) {
+            return None;
+        }
+        let offset = &detonation.location_in_entity_coordinates;
+        Some(
+            (offset.first_vector_component.powi(2)
+                + offset.second_vector_component.powi(2)
+                + offset.third_vector_component.powi(2))
+            .sqrt(),
+        )
+    }
+
+    #[must_use]
+    pub fn detonation_result(&self) -> Option<DetonationResult> {
+        self.detonation
+            .as_ref()
+            .map(|detonation| detonation.detonation_result)
+    }
+
+    /// Returns the location of the detonation, or the last known location of the munition entity.
+    #[must_use]
+    pub fn munition_location(&self) -> Option<Location> {
+        self.detonation
+            .as_ref()
+            .map(|detonation| detonation.location_in_world_coordinates)
+            .or(self
+                .munition_state
+                .as_ref()
+                .map(|state| state.entity_location))
+    }
+}
+
+/// A change to the tracker resulting from applying a PDU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EngagementChange {
+    /// A Fire PDU started an engagement
+    Fired(EventId),
+    /// An Entity State PDU of the munition of an engagement in flight was received
+    MunitionUpdated(EventId),
+    /// A Detonation PDU completed an engagement
+    Detonated(EventId),
+}
+
+/// Keeps all engagements, keyed by `EventId`.
+///
+/// Time is passed in explicitly as the simulation time elapsed since an arbitrary (but fixed) epoch.
+#[derive(Clone, Debug)]
+pub struct EngagementTracker {
+    engagements: HashMap<EventId, Engagement>,
+    munitions: HashMap<EntityId, EventId>,
+    detonation_timeout: Duration,
+    retention: Duration,
+}
+
+impl Default for EngagementTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngagementTracker {
+    /// Creates an empty tracker that uses the `DEFAULT_DETONATION_TIMEOUT` and `DEFAULT_RETENTION`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            engagements: HashMap::new(),
+            munitions: HashMap::new(),
+            detonation_timeout: DEFAULT_DETONATION_TIMEOUT,
+            retention: DEFAULT_RETENTION,
+        }
+    }
+
+    /// Sets the time after the last activity of an engagement after which it is considered not detonated.
+    #[must_use]
+    pub fn with_detonation_timeout(mut self, detonation_timeout: Duration) -> Self {
+        self.detonation_timeout = detonation_timeout;
+        self
+    }
+
+    /// Sets the time after the last activity of a finished engagement after which it is removed.
+    #[must_use]
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Applies a `Pdu` to the tracker, returning the resulting change if any.
+    pub fn apply(&mut self, pdu: &Pdu, now: Duration) -> Option<EngagementChange> {
+        self.apply_body(&pdu.body, now)
+    }
+
+    /// Applies a `PduBody` to the tracker, returning the resulting change if any.
+    ///
+    /// Entity State PDUs are only applied when the entity is the munition of an engagement in flight.
+    pub fn apply_body(&mut self, body: &PduBody, now: Duration) -> Option<EngagementChange> {
+        match body {
+            PduBody::Fire(body) => self.apply_fire(body, now),
+            PduBody::Detonation(body) => Some(self.apply_detonation(body, now)),
+            PduBody::EntityState(body) => self.apply_munition_state(body, now),
+            _ => None,
+        }
+    }
+
+    fn apply_fire(&mut self, fire: &Fire, now: Duration) -> Option<EngagementChange> {
+        let engagement = match self.engagements.entry(fire.event_id) {
+            // The event number wrapped around, so the Fire PDU starts a new engagement
+            Entry::Occupied(mut entry)
+                if entry.get().fire.is_some()
+                    && entry.get().status != EngagementStatus::InFlight =>
+            {
+                entry.insert(Engagement::new(fire.event_id, now));
+                entry.into_mut()
+            }
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Engagement::new(fire.event_id, now)),
+        };
+        // A Detonation PDU overtook the Fire PDU
+        let detonated = engagement.detonation.is_some();
+
+        engagement.fire = Some(fire.clone());
+        engagement.fire_time = Some(now);
+        engagement.last_activity = now;
+
+        if detonated {
+            return None;
+        }
+        if is_specified(fire.entity_id) {
+            self.munitions.insert(fire.entity_id, fire.event_id);
+        }
+        Some(EngagementChange::Fired(fire.event_id))
+    }
+
+    fn apply_detonation(&mut self, detonation: &Detonation, now: Duration) -> EngagementChange {
+        let engagement = match self.engagements.entry(detonation.event_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Engagement::new(detonation.event_id, now)),
+        };
+
+        engagement.detonation = Some(detonation.clone());
+        engagement.detonation_time = Some(now);
+        engagement.last_activity = now;
+        engagement.status = EngagementStatus::Detonated;
+
+        if let Some(munition) = engagement.munition_entity_id() {
+            self.munitions.remove(&munition);
+        }
+        EngagementChange::Detonated(detonation.event_id)
+    }
+
+    fn apply_munition_state(
+        &mut self,
+        entity_state: &EntityState,
+        now: Duration,
+    ) -> Option<EngagementChange> {
+        let event_id = self.munitions.get(&entity_state.entity_id)?;
+        let engagement = self.engagements.get_mut(event_id)?;
+
+        engagement.munition_state = Some(entity_state.clone());
+        engagement.last_activity = now;
+        Some(EngagementChange::MunitionUpdated(*event_id))
+    }
+
+    /// Marks all engagements in flight without activity within the detonation timeout at time `now` as
+    /// not detonated, returning their event ids.
+    ///
+    /// Finished engagements without activity within the retention period are removed.
+    pub fn expire(&mut self, now: Duration) -> Vec<EventId> {
+        self.engagements.retain(|_, engagement| {
+            engagement.status == EngagementStatus::InFlight
+                || now.saturating_sub(engagement.last_activity) <= self.retention
+        });
+
+        let mut expired = Vec::new();
+        for engagement in self.engagements.values_mut() {
+            if engagement.status == EngagementStatus::InFlight
+                && now.saturating_sub(engagement.last_activity) > self.detonation_timeout
+            {
+                engagement.status = EngagementStatus::NotDetonated;
+                if let Some(munition) = engagement.munition_entity_id() {
+                    self.munitions.remove(&munition);
+                }
+                expired.push(engagement.event_id);
+            }
+        }
+        expired
+    }
+
+    #[must_use]
+    pub fn get(&self, event_id: &EventId) -> Option<&Engagement> {
+        self.engagements.get(event_id)
+    }
+
+    /// Removes an engagement from the tracker, returning it.
+    pub fn remove(&mut self, event_id: &EventId) -> Option<Engagement> {
+        let engagement = self.engagements.remove(event_id)?;
+        if let Some(munition) = engagement.munition_entity_id() {
+            self.munitions.remove(&munition);
+        }
+        Some(engagement)
+    }
+
+    /// Iterates over all engagements, in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = &Engagement> {
+        self.engagements.values()
+    }
+
+    /// Iterates over all engagements in which the given entity fired
+    pub fn fired_by<'a>(&'a self, entity_id: &'a EntityId) -> impl Iterator<Item = &'a Engagement> {
+        self.engagements.values().filter(move |engagement| {
+            engagement
+                .fire
+                .as_ref()
+                .is_some_and(|fire| &fire.firing_entity_id == entity_id)
+        })
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.engagements.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.engagements.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.engagements.clear();
+        self.munitions.clear();
+    }
+}
+
+fn is_specified(entity_id: EntityId) -> bool {
+    entity_id.entity_id != NO_ENTITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::model::VectorF32;
+
+    const SHOOTER: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 1,
+        },
+        entity_id: 1,
+    };
+    const TARGET: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 2,
+        },
+        entity_id: 1,
+    };
+    const MUNITION: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 1,
+        },
+        entity_id: 99,
+    };
+
+    fn fire(event: u16, munition: EntityId) -> PduBody {
+        Fire::builder()
+            .with_firing_entity_id(SHOOTER)
+            .with_target_entity_id(TARGET)
+            .with_entity_id(munition)
+            .with_event_id(EventId::new(1, 1, event))
+            .with_descriptor(FireDescriptor::Munition(MunitionDescriptor::default()))
+            .build()
+            .into_pdu_body()
+    }
+
+    fn detonation(event: u16) -> PduBody {
+        Detonation::builder()
+            .with_source_entity_id(SHOOTER)
+            .with_target_entity_id(TARGET)
+            .with_event_id(EventId::new(1, 1, event))
+            .with_entity_location(VectorF32::new(3.0, 4.0, 0.0))
+            .with_detonation_result(DetonationResult::EntityImpact)
+            .build()
+            .into_pdu_body()
+    }
+
+    #[test]
+    fn fire_linked_to_detonation() {
+        let mut tracker = EngagementTracker::new();
+        let event_id = EventId::new(1, 1, 1);
+
+        assert_eq!(
+            tracker.apply_body(&fire(1, EntityId::default()), Duration::from_secs(10)),
+            Some(EngagementChange::Fired(event_id))
+        );
+        assert_eq!(
+            tracker.apply_body(&detonation(1), Duration::from_millis(12_500)),
+            Some(EngagementChange::Detonated(event_id))
+        );
+
+        let engagement = tracker.get(&event_id).unwrap();
+        assert_eq!(engagement.status, EngagementStatus::Detonated);
+        assert_eq!(
+            engagement.time_of_flight(),
+            Some(Duration::from_millis(2_500))
+        );
+        assert_eq!(engagement.miss_distance(), Some(5.0));
+        assert_eq!(
+            engagement.detonation_result(),
+            Some(DetonationResult::EntityImpact)
+        );
+        assert!(engagement.munition_descriptor().is_some());
+        assert_eq!(tracker.fired_by(&SHOOTER).count(), 1);
+    }
+
+    #[test]
+    fn munition_entity_is_tracked() {
+        let mut tracker = EngagementTracker::new();
+        let event_id = EventId::new(1, 1, 2);
+        tracker.apply_body(&fire(2, MUNITION), Duration::ZERO);
+
+        let munition_state = EntityState::builder()
+            .with_entity_id(MUNITION)
+            .with_location(Location::new(1.0, 2.0, 3.0))
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            tracker.apply_body(&munition_state, Duration::from_secs(1)),
+            Some(EngagementChange::MunitionUpdated(event_id))
+        );
+
+        let engagement = tracker.get(&event_id).unwrap();
+        assert_eq!(engagement.munition_entity_id(), Some(MUNITION));
+        assert_eq!(
+            engagement.munition_location(),
+            Some(Location::new(1.0, 2.0, 3.0))
+        );
+
+        // Entity State PDUs of other entities are ignored
+        let other = EntityState::builder()
+            .with_entity_id(TARGET)
+            .build()
+            .into_pdu_body();
+        assert!(tracker.apply_body(&other, Duration::from_secs(1)).is_none());
+    }
+
+    #[test]
+    fn detonation_without_fire() {
+        let mut tracker = EngagementTracker::new();
+        let event_id = EventId::new(1, 1, 3);
+
+        tracker.apply_body(&detonation(3), Duration::from_secs(5));
+        let engagement = tracker.get(&event_id).unwrap();
+
+        assert_eq!(engagement.status, EngagementStatus::Detonated);
+        assert!(engagement.fire.is_none());
+        assert!(engagement.time_of_flight().is_none());
+
+        // A late Fire PDU completes the engagement without restarting it
+        assert!(
+            tracker
+                .apply_body(&fire(3, EntityId::default()), Duration::from_secs(6))
+                .is_none()
+        );
+        assert_eq!(
+            tracker.get(&event_id).unwrap().status,
+            EngagementStatus::Detonated
+        );
+    }
+
+    #[test]
+    fn fire_without_detonation_expires() {
+        let mut tracker = EngagementTracker::new().with_detonation_timeout(Duration::from_secs(30));
+        tracker.apply_body(&fire(4, MUNITION), Duration::ZERO);
+        tracker.apply_body(&fire(5, EntityId::default()), Duration::ZERO);
+        tracker.apply_body(&detonation(5), Duration::from_secs(2));
+
+        // Munition updates keep the engagement in flight
+        let munition_state = EntityState::builder()
+            .with_entity_id(MUNITION)
+            .build()
+            .into_pdu_body();
+        tracker.apply_body(&munition_state, Duration::from_secs(20));
+        assert!(tracker.expire(Duration::from_secs(40)).is_empty());
+
+        assert_eq!(
+            tracker.expire(Duration::from_secs(51)),
+            vec![EventId::new(1, 1, 4)]
+        );
+        assert_eq!(
+            tracker.get(&EventId::new(1, 1, 4)).unwrap().status,
+            EngagementStatus::NotDetonated
+        );
+        assert!(
+            tracker
+                .apply_body(&munition_state, Duration::from_secs(52))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn fire_after_finished_engagement_starts_new_engagement() {
+        let mut tracker = EngagementTracker::new().with_detonation_timeout(Duration::from_secs(30));
+        let event_id = EventId::new(1, 1, 6);
+        tracker.apply_body(&fire(6, EntityId::default()), Duration::ZERO);
+        tracker.apply_body(&detonation(6), Duration::from_secs(2));
+
+        // The event number wrapped around
+        assert_eq!(
+            tracker.apply_body(&fire(6, MUNITION), Duration::from_secs(100)),
+            Some(EngagementChange::Fired(event_id))
+        );
+        let engagement = tracker.get(&event_id).unwrap();
+        assert_eq!(engagement.status, EngagementStatus::InFlight);
+        assert!(engagement.detonation.is_none());
+        assert_eq!(engagement.fire_time, Some(Duration::from_secs(100)));
+        assert_eq!(engagement.munition_entity_id(), Some(MUNITION));
+
+        assert_eq!(tracker.expire(Duration::from_secs(131)), vec![event_id]);
+        assert_eq!(
+            tracker.apply_body(&fire(6, EntityId::default()), Duration::from_secs(200)),
+            Some(EngagementChange::Fired(event_id))
+        );
+        assert_eq!(
+            tracker.get(&event_id).unwrap().status,
+            EngagementStatus::InFlight
+        );
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn finished_engagements_are_removed_after_retention() {
+        let mut tracker = EngagementTracker::new()
+            .with_detonation_timeout(Duration::from_secs(30))
+            .with_retention(Duration::from_mins(1));
+        tracker.apply_body(&fire(7, EntityId::default()), Duration::ZERO);
+        tracker.apply_body(&detonation(7), Duration::from_secs(2));
+        tracker.apply_body(&fire(8, EntityId::default()), Duration::ZERO);
+        tracker.apply_body(&fire(9, EntityId::default()), Duration::from_secs(50));
+
+        assert_eq!(
+            tracker.expire(Duration::from_secs(40)),
+            vec![EventId::new(1, 1, 8)]
+        );
+        assert_eq!(tracker.len(), 3);
+
+        // Engagement 7 detonated at 2s and 8 had its last activity at 0s
+        assert!(tracker.expire(Duration::from_secs(63)).is_empty());
+        assert_eq!(tracker.len(), 1);
+        assert!(tracker.get(&EventId::new(1, 1, 9)).is_some());
+    }
+}
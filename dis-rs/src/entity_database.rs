@@ -0,0 +1,390 @@
+//! Receiver-side database of the current state of remote entities (IEEE 1278.1-2012, 5.3.2 and 5.3.5).
+//!
+//! The [`EntityDatabase`] is fed with parsed `Pdu`s and keeps the last known `EntityState` of each
+//! entity keyed by `EntityId`. Entity State, Entity State Update and Remove Entity PDUs are applied,
+//! all other PDUs are ignored. Entities that do not receive an update within their heartbeat timeout
+//! are expired with [`EntityDatabase::expire`].
+//!
+//! Each applied change is reported back as an [`EntityChange`], so that applications can react to
+//! entities being added, updated, destroyed or removed.
+use crate::common::entity_state::model::{EntityAppearance, EntityState};
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::model::{EntityId, Pdu, PduBody};
+use crate::dead_reckoning::KinematicState;
+use crate::dead_reckoning::tracker::heartbeat_for_entity_type;
+use crate::enumerations::DeadReckoningAlgorithm;
+use crate::variable_parameters::VariableParameters;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::time::Duration;
+
+/// The last known state of a remote entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityRecord {
+    pub entity_state: EntityState,
+    /// Time of the last applied Entity State (Update) PDU
+    pub last_update: Duration,
+    /// The entity's appearance indicates it is destroyed
+    pub destroyed: bool,
+}
+
+impl EntityRecord {
+    /// Dead reckons the last known state of the entity to time `now`.
+    #[must_use]
+    pub fn dead_reckon(&self, now: Duration) -> KinematicState {
+        self.entity_state
+            .dead_reckon(now.saturating_sub(self.last_update))
+    }
+}
+
+/// A change to the database resulting from applying a PDU or expiring entities.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityChange {
+    /// A previously unknown entity was added
+    Added(EntityId),
+    /// The state of a known entity was updated
+    Updated(EntityId),
+    /// The state of a known entity was updated, and its appearance changed to destroyed
+    Destroyed(EntityId),
+    /// An entity was removed from the database
+    Removed(EntityId, RemovalReason),
+}
+
+/// The reason an entity was removed from the database.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RemovalReason {
+    /// A Remove Entity PDU for the entity was received
+    RemoveEntity,
+    /// No update was received within the heartbeat timeout
+    Timeout,
+}
+
+/// Keeps the current state of all remote entities, keyed by `EntityId`.
+///
+/// Time is passed in explicitly as the simulation time elapsed since an arbitrary (but fixed) epoch.
+#[derive(Clone, Debug, Default)]
+pub struct EntityDatabase {
+    entities: HashMap<EntityId, EntityRecord>,
+    parameters: VariableParameters,
+}
+
+impl EntityDatabase {
+    /// Creates an empty database that uses the heartbeat and timeout multiplier values from `parameters`.
+    #[must_use]
+    pub fn new(parameters: VariableParameters) -> Self {
+        Self {
+            entities: HashMap::new(),
+            parameters,
+        }
+    }
+
+    /// Applies a `Pdu` to the database, returning the resulting change if any.
+    pub fn apply(&mut self, pdu: &Pdu, now: Duration) -> Option<EntityChange> {
+        self.apply_body(&pdu.body, now)
+    }
+
+    /// Applies a `PduBody` to the database, returning the resulting change if any.
+    ///
+    /// Entity State Update PDUs for unknown entities are ignored, as the entity type is not known.
+    pub fn apply_body(&mut self, body: &PduBody, now: Duration) -> Option<EntityChange> {
+        match body {
+            PduBody::EntityState(body) => Some(self.apply_entity_state(body, now)),
+            PduBody::EntityStateUpdate(body) => self.apply_entity_state_update(body, now),
+            PduBody::RemoveEntity(body) => self
+                .remove(&body.receiving_id)
+                .map(|_| EntityChange::Removed(body.receiving_id, RemovalReason::RemoveEntity)),
+            _ => None,
+        }
+    }
+
+    fn apply_entity_state(&mut self, body: &EntityState, now: Duration) -> EntityChange {
+        let destroyed = body.entity_appearance.is_destroyed();
+        let record = EntityRecord {
+            entity_state: body.clone(),
+            last_update: now,
+            destroyed,
+        };
+        match self.entities.entry(body.entity_id) {
+            Entry::Occupied(mut entry) => {
+                let was_destroyed = entry.get().destroyed;
+                entry.insert(record);
+                update_change(body.entity_id, was_destroyed, destroyed)
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(record);
+                EntityChange::Added(body.entity_id)
+            }
+        }
+    }
+
+    fn apply_entity_state_update(
+        &mut self,
+        body: &EntityStateUpdate,
+        now: Duration,
+    ) -> Option<EntityChange> {
+        let record = self.entities.get_mut(&body.entity_id)?;
+        let state = &mut record.entity_state;
+
+        state.entity_linear_velocity = body.entity_linear_velocity;
+        state.entity_location = body.entity_location;
+        state.entity_orientation = body.entity_orientation;
+        // The appearance of an Entity State Update PDU is parsed without knowing the entity type
+        state.entity_appearance =
+            EntityAppearance::from_bytes(u32::from(&body.entity_appearance), &state.entity_type);
+        state
+            .variable_parameters
+            .clone_from(&body.variable_parameters);
+
+        let was_destroyed = record.destroyed;
+        record.destroyed = state.entity_appearance.is_destroyed();
+        record.last_update = now;
+
+        Some(update_change(
+            body.entity_id,
+            was_destroyed,
+            record.destroyed,
+        ))
+    }
+
+    /// Removes all entities that have not been updated within their heartbeat timeout at time `now`.
+    ///
+    /// The timeout is the heartbeat interval for the entity type, or `HBT_STATIONARY` for entities
+    /// using the `StaticNonmovingEntity` DR algorithm, multiplied by `HBT_TIMEOUT_MPLIER`.
+    pub fn expire(&mut self, now: Duration) -> Vec<EntityChange> {
+        let expired: Vec<EntityId> = self
+            .entities
+            .iter()
+            .filter(|(_, record)| now.saturating_sub(record.last_update) > self.timeout(record))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in &expired {
+            self.entities.remove(id);
+        }
+
+        expired
+            .into_iter()
+            .map(|id| EntityChange::Removed(id, RemovalReason::Timeout))
+            .collect()
+    }
+
+    fn timeout(&self, record: &EntityRecord) -> Duration {
+        let heartbeat = if record.entity_state.dead_reckoning_parameters.algorithm
+            == DeadReckoningAlgorithm::StaticNonmovingEntity
+        {
+            // HBT_STATIONARY is expressed in minutes
+            self.parameters.HBT_STATIONARY * 60.0
+        } else {
+            heartbeat_for_entity_type(&record.entity_state.entity_type, &self.parameters)
+        };
+        Duration::from_secs_f32(heartbeat * self.parameters.HBT_TIMEOUT_MPLIER)
+    }
+
+    /// Removes an entity from the database, returning its last known record.
+    pub fn remove(&mut self, entity_id: &EntityId) -> Option<EntityRecord> {
+        self.entities.remove(entity_id)
+    }
+
+    /// Returns the last known record of an entity
+    #[must_use]
+    pub fn get(&self, entity_id: &EntityId) -> Option<&EntityRecord> {
+        self.entities.get(entity_id)
+    }
+
+    #[must_use]
+    pub fn contains(&self, entity_id: &EntityId) -> bool {
+        self.entities.contains_key(entity_id)
+    }
+
+    /// Iterates over all entities in the database, in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&EntityId, &EntityRecord)> {
+        self.entities.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Removes all entities from the database
+    pub fn clear(&mut self) {
+        self.entities.clear();
+    }
+}
+
+fn update_change(entity_id: EntityId, was_destroyed: bool, destroyed: bool) -> EntityChange {
+    if destroyed && !was_destroyed {
+        EntityChange::Destroyed(entity_id)
+    } else {
+        EntityChange::Updated(entity_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::model::{EntityType, Location, PduHeader, TimeUnits, Timestamp};
+    use crate::enumerations::{
+        AppearanceDamage, EntityKind, LandPlatformAppearance, PduType, PlatformDomain,
+    };
+    use crate::remove_entity::model::RemoveEntity;
+
+    fn land_platform() -> EntityType {
+        EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land)
+    }
+
+    fn entity_state(id: u16, damage: AppearanceDamage) -> PduBody {
+        EntityState::builder()
+            .with_entity_id(EntityId::new(1, 1, id))
+            .with_entity_type(land_platform())
+            .with_appearance(EntityAppearance::LandPlatform(LandPlatformAppearance {
+                damage,
+                ..Default::default()
+            }))
+            .with_location(Location::new(1.0, 2.0, 3.0))
+            .build()
+            .into_pdu_body()
+    }
+
+    #[test]
+    fn add_and_update_entity() {
+        let mut database = EntityDatabase::default();
+        let id = EntityId::new(1, 1, 1);
+
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            entity_state(1, AppearanceDamage::NoDamage),
+            Timestamp::Relative(TimeUnits::default()),
+        );
+        assert_eq!(
+            database.apply(&pdu, Duration::ZERO),
+            Some(EntityChange::Added(id))
+        );
+        assert_eq!(
+            database.apply(&pdu, Duration::from_secs(1)),
+            Some(EntityChange::Updated(id))
+        );
+        assert_eq!(database.len(), 1);
+        assert_eq!(
+            database.get(&id).unwrap().last_update,
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn entity_state_update_for_known_entity() {
+        let mut database = EntityDatabase::default();
+        let id = EntityId::new(1, 1, 1);
+        database.apply_body(&entity_state(1, AppearanceDamage::NoDamage), Duration::ZERO);
+
+        let appearance = EntityAppearance::LandPlatform(LandPlatformAppearance {
+            damage: AppearanceDamage::Destroyed,
+            ..Default::default()
+        });
+        let update = EntityStateUpdate::builder()
+            .with_entity_id(id)
+            .with_location(Location::new(10.0, 20.0, 30.0))
+            .with_appearance(EntityAppearance::Unspecified(
+                u32::from(&appearance).to_be_bytes(),
+            ))
+            .build()
+            .into_pdu_body();
+
+        assert_eq!(
+            database.apply_body(&update, Duration::from_secs(2)),
+            Some(EntityChange::Destroyed(id))
+        );
+        let record = database.get(&id).unwrap();
+        assert!(record.destroyed);
+        assert_eq!(record.entity_state.entity_appearance, appearance);
+        assert_eq!(
+            record.entity_state.entity_location,
+            Location::new(10.0, 20.0, 30.0)
+        );
+        assert_eq!(record.entity_state.entity_type, land_platform());
+    }
+
+    #[test]
+    fn entity_state_update_for_unknown_entity_is_ignored() {
+        let mut database = EntityDatabase::default();
+        let update = EntityStateUpdate::builder()
+            .with_entity_id(EntityId::new(1, 1, 1))
+            .build()
+            .into_pdu_body();
+
+        assert_eq!(database.apply_body(&update, Duration::ZERO), None);
+        assert!(database.is_empty());
+    }
+
+    #[test]
+    fn entity_destroyed_from_appearance() {
+        let mut database = EntityDatabase::default();
+        let id = EntityId::new(1, 1, 1);
+        database.apply_body(&entity_state(1, AppearanceDamage::NoDamage), Duration::ZERO);
+
+        assert_eq!(
+            database.apply_body(
+                &entity_state(1, AppearanceDamage::Destroyed),
+                Duration::ZERO
+            ),
+            Some(EntityChange::Destroyed(id))
+        );
+        assert_eq!(
+            database.apply_body(
+                &entity_state(1, AppearanceDamage::Destroyed),
+                Duration::ZERO
+            ),
+            Some(EntityChange::Updated(id))
+        );
+        assert!(database.get(&id).unwrap().destroyed);
+    }
+
+    #[test]
+    fn remove_entity() {
+        let mut database = EntityDatabase::default();
+        let id = EntityId::new(1, 1, 1);
+        database.apply_body(&entity_state(1, AppearanceDamage::NoDamage), Duration::ZERO);
+
+        let remove = RemoveEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(id)
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            database.apply_body(&remove, Duration::ZERO),
+            Some(EntityChange::Removed(id, RemovalReason::RemoveEntity))
+        );
+        assert!(database.is_empty());
+        assert_eq!(database.apply_body(&remove, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn expire_entities_after_heartbeat_timeout() {
+        let mut database = EntityDatabase::default();
+        database.apply_body(&entity_state(1, AppearanceDamage::NoDamage), Duration::ZERO);
+        database.apply_body(
+            &entity_state(2, AppearanceDamage::NoDamage),
+            Duration::from_secs(10),
+        );
+
+        // default heartbeat of 5 s and timeout multiplier of 2.4
+        assert!(database.expire(Duration::from_secs(12)).is_empty());
+        assert_eq!(
+            database.expire(Duration::from_secs(13)),
+            vec![EntityChange::Removed(
+                EntityId::new(1, 1, 1),
+                RemovalReason::Timeout
+            )]
+        );
+        assert_eq!(database.len(), 1);
+        assert!(database.contains(&EntityId::new(1, 1, 2)));
+    }
+}
@@ -0,0 +1,136 @@
+//! Descriptions of the entity types defined in SISO-REF-010 (Entity Types records).
+//!
+//! The lookup table is generated from the SISO-REF-010 XML file when the `entity-types`
+//! feature is enabled. Each category, subcategory, specific and extra row of the
+//! reference is included, with the zero-valued remaining fields of the entity type.
+//! For example, `1:1:225:1:0:0:0` is described as 'Tank' and `1:1:225:1:1:0:0` as 'M1 Abrams'.
+use crate::common::model::EntityType;
+
+/// The (kind, domain, country, category, subcategory, specific, extra) fields of an entity type.
+type EntityTypeFields = (u8, u8, u16, u8, u8, u8, u8);
+
+include!(concat!(env!("OUT_DIR"), "/entity_types.rs"));
+
+impl EntityType {
+    /// Returns the SISO-REF-010 description of this entity type, if it is defined.
+    ///
+    /// Only exact matches are returned; use [`EntityType::closest_description`] to fall back
+    /// to the description of a less specific entity type.
+    #[must_use]
+    pub fn description(&self) -> Option<&'static str> {
+        lookup(fields(self))
+    }
+
+    /// Returns the SISO-REF-010 description of this entity type, or of the most specific
+    /// enclosing entity type (zeroing the extra, specific, subcategory and category fields in turn).
+    #[must_use]
+    pub fn closest_description(&self) -> Option<&'static str> {
+        let (kind, domain, country, category, subcategory, specific, extra) = fields(self);
+        lookup((
+            kind,
+            domain,
+            country,
+            category,
+            subcategory,
+            specific,
+            extra,
+        ))
+        .or_else(|| lookup((kind, domain, country, category, subcategory, specific, 0)))
+        .or_else(|| lookup((kind, domain, country, category, subcategory, 0, 0)))
+        .or_else(|| lookup((kind, domain, country, category, 0, 0, 0)))
+    }
+
+    /// Returns the first entity type with the given SISO-REF-010 description.
+    ///
+    /// Descriptions are not unique across kinds, domains and countries;
+    /// use [`find_by_description`] to obtain all matching entity types.
+    #[must_use]
+    pub fn from_description(description: &str) -> Option<Self> {
+        find_by_description(description).next()
+    }
+}
+
+/// Returns all entity types with the given SISO-REF-010 description, in entity type order.
+pub fn find_by_description(description: &str) -> impl Iterator<Item = EntityType> + '_ {
+    ENTITY_TYPES
+        .iter()
+        .filter(move |(_, desc)| *desc == description)
+        .map(|(fields, _)| entity_type(*fields))
+}
+
+/// Returns all entity types defined in SISO-REF-010 with their descriptions, in entity type order.
+pub fn entity_types() -> impl Iterator<Item = (EntityType, &'static str)> {
+    ENTITY_TYPES
+        .iter()
+        .map(|(fields, desc)| (entity_type(*fields), *desc))
+}
+
+fn lookup(fields: EntityTypeFields) -> Option<&'static str> {
+    ENTITY_TYPES
+        .binary_search_by(|(entry, _)| entry.cmp(&fields))
+        .ok()
+        .map(|index| ENTITY_TYPES[index].1)
+}
+
+fn fields(entity_type: &EntityType) -> EntityTypeFields {
+    (
+        u8::from(entity_type.kind),
+        u8::from(entity_type.domain),
+        u16::from(entity_type.country),
+        entity_type.category,
+        entity_type.subcategory,
+        entity_type.specific,
+        entity_type.extra,
+    )
+}
+
+fn entity_type(
+    (kind, domain, country, category, subcategory, specific, extra): EntityTypeFields,
+) -> EntityType {
+    EntityType {
+        kind: kind.into(),
+        domain: domain.into(),
+        country: country.into(),
+        category,
+        subcategory,
+        specific,
+        extra,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(ENTITY_TYPES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn describe_entity_type() {
+        let tank = EntityType::from_str("1:1:225:1:0:0:0").unwrap();
+        let abrams = EntityType::from_str("1:1:225:1:1:0:0").unwrap();
+
+        assert_eq!(tank.description(), Some("Tank"));
+        assert_eq!(abrams.description(), Some("M1 Abrams"));
+    }
+
+    #[test]
+    fn closest_description() {
+        let unknown_variant = EntityType::from_str("1:1:225:1:1:200:0").unwrap();
+
+        assert_eq!(unknown_variant.description(), None);
+        assert_eq!(unknown_variant.closest_description(), Some("M1 Abrams"));
+    }
+
+    #[test]
+    fn entity_type_from_description() {
+        assert_eq!(
+            EntityType::from_description("M1 Abrams"),
+            Some(EntityType::from_str("1:1:225:1:1:0:0").unwrap())
+        );
+        assert_eq!(EntityType::from_description("Not an entity type"), None);
+    }
+}
@@ -9,9 +9,20 @@
 
 extern crate core;
 
+#[cfg(feature = "pcap-file")]
+pub mod capture;
 mod common;
 mod constants;
+pub mod dead_reckoning;
+#[cfg(feature = "dissector")]
+pub mod dissector;
+pub mod engagement;
+pub mod entity_database;
+#[cfg(feature = "entity-types")]
+pub mod entity_types;
 mod fixed_parameters;
+pub mod recording;
+pub mod simulation_management;
 pub mod utils;
 mod v6;
 mod v7;
@@ -22,9 +33,13 @@
 pub use common::Serialize;
 pub use common::entity_state::parser::dr_other_parameters as parse_dr_other_parameters;
 pub use common::parse;
+pub use common::parse_each;
 pub use common::parse_v6 as parse_v6_pdus;
 pub use common::parse_v7 as parse_v7_pdus;
+pub use common::pdu_view::{PduView, parse_views};
+pub use common::stream::DisStreamDecoder;
 pub use common::supported_protocol_versions;
+pub use common::validation::{Validate, ValidationFinding};
 pub use v7::entity_state::entity_capabilities_from_bytes;
 pub use v7::parser::parse_pdu_status_fields;
 pub use v7::writer::serialize_pdu_status;
@@ -0,0 +1,666 @@
+//! Recording of received DIS datagrams, and timed replay of recordings.
+//!
+//! A [`Recorder`] writes each received datagram, together with its receive time and source address, to a log.
+//! A [`RecordingReader`] reads the log back, and a [`Replayer`] replays it with the original pacing,
+//! optionally at a different speed, from an offset, in a loop, and with the exercise id and timestamps
+//! of the PDUs rewritten.
+//!
+//! The log format is a file header followed by a sequence of records, all in network byte order:
+//! - File header: the magic bytes `DISLOG`, the format version (`u8`) and a reserved byte.
+//! - Record: receive time in nanoseconds since the UNIX epoch (`u64`), address family of the source
+//!   (`u8`, 0 for none, 4 for IPv4 and 6 for IPv6), the source address and port (when present),
+//!   the payload length (`u32`) and the payload.
+//!
+//! Each record header contains the length of the payload, so a reader can seek through
+//! the log without reading the payloads.
+use crate::common::timestamp::{TimeUnits, Timestamp};
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+const MAGIC: [u8; 6] = *b"DISLOG";
+const FORMAT_VERSION: u8 = 1;
+const FILE_HEADER_LENGTH: u64 = 8;
+
+const ADDRESS_FAMILY_NONE: u8 = 0;
+const ADDRESS_FAMILY_IPV4: u8 = 4;
+const ADDRESS_FAMILY_IPV6: u8 = 6;
+
+const EXERCISE_ID_OFFSET: usize = 1;
+const TIMESTAMP_OFFSET: usize = 4;
+const PDU_LENGTH_OFFSET: usize = 8;
+
+const SECONDS_PER_HOUR: u64 = 3600;
+
+#[derive(Debug, Error)]
+pub enum RecordingError {
+    #[error("Error reading or writing the recording: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("The file is not a DIS recording.")]
+    InvalidHeader,
+    #[error("Recording format version {0} is not supported.")]
+    UnsupportedVersion(u8),
+    #[error("Record has an invalid address family {0}.")]
+    InvalidAddressFamily(u8),
+    #[error("The recording ends with an incomplete record.")]
+    TruncatedRecord,
+    #[error("The replay time of a record cannot be represented at replay speed factor {0}.")]
+    ReplayTimeOverflow(f64),
+    #[error("Replay speed factor {0} is not a positive, finite number.")]
+    InvalidReplaySpeed(f64),
+}
+
+/// A received datagram, as stored in a recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedDatagram {
+    /// Receive time, as the time since the UNIX epoch
+    pub time: Duration,
+    pub source: Option<SocketAddr>,
+    pub payload: Vec<u8>,
+}
+
+/// Writes received datagrams to a recording.
+pub struct Recorder<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a recorder, writing the file header to `writer`.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the file header cannot be written.
+    pub fn new(mut writer: W) -> Result<Self, RecordingError> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[FORMAT_VERSION, 0])?;
+        Ok(Self { writer })
+    }
+
+    /// Records a datagram, received at the current system time.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the record cannot be written.
+    pub fn record_now(
+        &mut self,
+        source: Option<SocketAddr>,
+        payload: &[u8],
+    ) -> Result<(), RecordingError> {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        self.write_record(time, source, payload)
+    }
+
+    /// Records a datagram.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the record cannot be written.
+    pub fn record(&mut self, datagram: &RecordedDatagram) -> Result<(), RecordingError> {
+        self.write_record(datagram.time, datagram.source, &datagram.payload)
+    }
+
+    fn write_record(
+        &mut self,
+        time: Duration,
+        source: Option<SocketAddr>,
+        payload: &[u8],
+    ) -> Result<(), RecordingError> {
+        let time = u64::try_from(time.as_nanos()).unwrap_or(u64::MAX);
+        self.writer.write_all(&time.to_be_bytes())?;
+        match source.map(|source| (source.ip(), source.port())) {
+            None => self.writer.write_all(&[ADDRESS_FAMILY_NONE])?,
+            Some((IpAddr::V4(ip), port)) => {
+                self.writer.write_all(&[ADDRESS_FAMILY_IPV4])?;
+                self.writer.write_all(&ip.octets())?;
+                self.writer.write_all(&port.to_be_bytes())?;
+            }
+            Some((IpAddr::V6(ip), port)) => {
+                self.writer.write_all(&[ADDRESS_FAMILY_IPV6])?;
+                self.writer.write_all(&ip.octets())?;
+                self.writer.write_all(&port.to_be_bytes())?;
+            }
+        }
+        self.writer
+            .write_all(&(payload.len() as u32).to_be_bytes())?;
+        self.writer.write_all(payload)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when flushing fails.
+    pub fn flush(&mut self) -> Result<(), RecordingError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Consumes the recorder, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// The header of a record, without the payload.
+struct RecordHeader {
+    time: Duration,
+    source: Option<SocketAddr>,
+    payload_length: u32,
+}
+
+/// Reads the datagrams from a recording, in recorded order.
+pub struct RecordingReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> RecordingReader<R> {
+    /// Creates a reader, validating the file header of the recording.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the file header cannot be read, or is not a supported DIS recording.
+    pub fn new(mut reader: R) -> Result<Self, RecordingError> {
+        let mut header = [0u8; FILE_HEADER_LENGTH as usize];
+        reader.read_exact(&mut header)?;
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(RecordingError::InvalidHeader);
+        }
+        let version = header[MAGIC.len()];
+        if version != FORMAT_VERSION {
+            return Err(RecordingError::UnsupportedVersion(version));
+        }
+        Ok(Self { reader })
+    }
+
+    /// Reads the next datagram, returning `Ok(None)` at the end of the recording.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the record cannot be read, or is incomplete.
+    pub fn read_datagram(&mut self) -> Result<Option<RecordedDatagram>, RecordingError> {
+        let Some(header) = self.read_header()? else {
+            return Ok(None);
+        };
+        let mut payload = vec![0u8; header.payload_length as usize];
+        self.read_exact(&mut payload)?;
+        Ok(Some(RecordedDatagram {
+            time: header.time,
+            source: header.source,
+            payload,
+        }))
+    }
+
+    fn read_header(&mut self) -> Result<Option<RecordHeader>, RecordingError> {
+        let mut time = [0u8; 8];
+        // the end of the recording is only valid at a record boundary
+        let mut read = 0;
+        while read < time.len() {
+            match self.reader.read(&mut time[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(RecordingError::TruncatedRecord),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        let time = Duration::from_nanos(u64::from_be_bytes(time));
+
+        let mut family = [0u8; 1];
+        self.read_exact(&mut family)?;
+        let source = match family[0] {
+            ADDRESS_FAMILY_NONE => None,
+            ADDRESS_FAMILY_IPV4 => {
+                let mut address = [0u8; 6];
+                self.read_exact(&mut address)?;
+                let ip = <[u8; 4]>::try_from(&address[..4]).expect("Slice of 4 bytes");
+                Some(SocketAddr::new(
+                    IpAddr::from(ip),
+                    u16::from_be_bytes([address[4], address[5]]),
+                ))
+            }
+            ADDRESS_FAMILY_IPV6 => {
+                let mut address = [0u8; 18];
+                self.read_exact(&mut address)?;
+                let ip = <[u8; 16]>::try_from(&address[..16]).expect("Slice of 16 bytes");
+                Some(SocketAddr::new(
+                    IpAddr::from(ip),
+                    u16::from_be_bytes([address[16], address[17]]),
+                ))
+            }
+            family => return Err(RecordingError::InvalidAddressFamily(family)),
+        };
+
+        let mut payload_length = [0u8; 4];
+        self.read_exact(&mut payload_length)?;
+        Ok(Some(RecordHeader {
+            time,
+            source,
+            payload_length: u32::from_be_bytes(payload_length),
+        }))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), RecordingError> {
+        self.reader.read_exact(buf).map_err(|err| {
+            if err.kind() == ErrorKind::UnexpectedEof {
+                RecordingError::TruncatedRecord
+            } else {
+                err.into()
+            }
+        })
+    }
+
+    /// Consumes the reader, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read + Seek> RecordingReader<R> {
+    /// Moves the reader back to the first record of the recording.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when seeking fails.
+    pub fn rewind(&mut self) -> Result<(), RecordingError> {
+        self.reader.seek(SeekFrom::Start(FILE_HEADER_LENGTH))?;
+        Ok(())
+    }
+
+    /// Moves the reader to the first record that was received at or after `offset`,
+    /// relative to the receive time of the first record. The payloads of the skipped records are not read.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when seeking fails, or a record cannot be read.
+    pub fn seek(&mut self, offset: Duration) -> Result<(), RecordingError> {
+        self.rewind()?;
+        let mut start = None;
+        loop {
+            let position = self.reader.stream_position()?;
+            let Some(header) = self.read_header()? else {
+                return Ok(());
+            };
+            let start = *start.get_or_insert(header.time);
+            if header.time.saturating_sub(start) >= offset {
+                self.reader.seek(SeekFrom::Start(position))?;
+                return Ok(());
+            }
+            self.reader
+                .seek(SeekFrom::Current(i64::from(header.payload_length)))?;
+        }
+    }
+}
+
+impl<R: Read> Iterator for RecordingReader<R> {
+    type Item = Result<RecordedDatagram, RecordingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_datagram().transpose()
+    }
+}
+
+/// The speed at which a recording is replayed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ReplaySpeed {
+    /// Replay with the original pacing, scaled by a positive, finite factor (e.g. 2.0 replays twice as fast)
+    Factor(f64),
+    /// Replay all records without delays
+    AsFastAsPossible,
+}
+
+impl Default for ReplaySpeed {
+    fn default() -> Self {
+        Self::Factor(1.0)
+    }
+}
+
+/// A datagram scheduled for replay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayedDatagram {
+    /// The moment at which the datagram is due to be sent
+    pub due: Instant,
+    /// The recorded datagram, with the PDUs rewritten according to the replay settings
+    pub datagram: RecordedDatagram,
+}
+
+/// Replays a recording with the original pacing.
+///
+/// [`Replayer::next_scheduled`] returns the next datagram and the moment it is due, without waiting,
+/// which suits async applications. Iterating the `Replayer` blocks the current thread until each datagram is due.
+pub struct Replayer<R: Read + Seek> {
+    reader: RecordingReader<R>,
+    speed: ReplaySpeed,
+    looping: bool,
+    exercise_id: Option<u8>,
+    rewrite_timestamps: bool,
+    /// Pacing reference: the moment and system time at which the reference record was replayed, and its receive time
+    reference: Option<(Instant, SystemTime, Duration)>,
+}
+
+impl<R: Read + Seek> Replayer<R> {
+    /// Creates a replayer for the recording in `reader`.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when the file header cannot be read, or is not a supported DIS recording.
+    pub fn new(reader: R) -> Result<Self, RecordingError> {
+        Ok(Self {
+            reader: RecordingReader::new(reader)?,
+            speed: ReplaySpeed::default(),
+            looping: false,
+            exercise_id: None,
+            rewrite_timestamps: false,
+            reference: None,
+        })
+    }
+
+    /// Sets the replay speed.
+    ///
+    /// # Errors
+    /// Returns `RecordingError::InvalidReplaySpeed` when the factor of `ReplaySpeed::Factor` is not
+    /// a positive, finite number.
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Result<Self, RecordingError> {
+        if let ReplaySpeed::Factor(factor) = speed
+            && !(factor.is_finite() && factor > 0.0)
+        {
+            return Err(RecordingError::InvalidReplaySpeed(factor));
+        }
+        self.speed = speed;
+        Ok(self)
+    }
+
+    /// Restarts the replay from the first record when the end of the recording is reached.
+    #[must_use]
+    pub fn with_looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Rewrites the exercise id of all replayed PDUs to `exercise_id`.
+    #[must_use]
+    pub fn with_exercise_id(mut self, exercise_id: u8) -> Self {
+        self.exercise_id = Some(exercise_id);
+        self
+    }
+
+    /// Rewrites the timestamps of all replayed PDUs to the time past the hour at which they are due,
+    /// keeping the absolute or relative indication.
+    #[must_use]
+    pub fn with_rewritten_timestamps(mut self) -> Self {
+        self.rewrite_timestamps = true;
+        self
+    }
+
+    /// Continues the replay from the first record that was received at or after `offset`,
+    /// relative to the start of the recording. The next record is due immediately.
+    ///
+    /// # Errors
+    /// Returns a `RecordingError` when seeking fails, or a record cannot be read.
+    pub fn seek(&mut self, offset: Duration) -> Result<(), RecordingError> {
+        self.reference = None;
+        self.reader.seek(offset)
+    }
+
+    /// Reads the next datagram and determines when it is due, without waiting.
+    pub fn next_scheduled(&mut self) -> Option<Result<ReplayedDatagram, RecordingError>> {
+        let mut datagram = match self.read_datagram().transpose()? {
+            Ok(datagram) => datagram,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let (instant, system_time, reference_time) = *self
+            .reference
+            .get_or_insert_with(|| (Instant::now(), SystemTime::now(), datagram.time));
+        let (due, elapsed) = match self.speed {
+            ReplaySpeed::Factor(factor) => {
+                // Very small factors stretch the gap beyond what a `Duration` or `Instant` can hold
+                let elapsed = Duration::try_from_secs_f64(
+                    datagram.time.saturating_sub(reference_time).as_secs_f64() / factor,
+                )
+                .unwrap_or(Duration::MAX);
+                match instant.checked_add(elapsed) {
+                    Some(due) => (due, elapsed),
+                    None => return Some(Err(RecordingError::ReplayTimeOverflow(factor))),
+                }
+            }
+            ReplaySpeed::AsFastAsPossible => (instant, Duration::ZERO),
+        };
+
+        let timestamp = self
+            .rewrite_timestamps
+            .then(|| time_past_the_hour(system_time.checked_add(elapsed).unwrap_or(system_time)));
+        rewrite_pdus(&mut datagram.payload, self.exercise_id, timestamp);
+
+        Some(Ok(ReplayedDatagram { due, datagram }))
+    }
+
+    fn read_datagram(&mut self) -> Result<Option<RecordedDatagram>, RecordingError> {
+        match self.reader.read_datagram()? {
+            None if self.looping => {
+                self.reader.rewind()?;
+                self.reference = None;
+                self.reader.read_datagram()
+            }
+            datagram => Ok(datagram),
+        }
+    }
+}
+
+impl<R: Read + Seek> Iterator for Replayer<R> {
+    type Item = Result<RecordedDatagram, RecordingError>;
+
+    /// Returns the next datagram when it is due, blocking the current thread until then.
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_scheduled()?.map(|replayed| {
+            std::thread::sleep(replayed.due.saturating_duration_since(Instant::now()));
+            replayed.datagram
+        }))
+    }
+}
+
+/// Converts a system time to the `TimeUnits` past the hour.
+fn time_past_the_hour(time: SystemTime) -> TimeUnits {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let past_the_hour = Duration::new(
+        since_epoch.as_secs() % SECONDS_PER_HOUR,
+        since_epoch.subsec_nanos(),
+    );
+    TimeUnits::from_duration(past_the_hour).unwrap_or(TimeUnits::MAX)
+}
+
+/// Rewrites the exercise id and timestamp in the headers of the PDUs contained in `payload`.
+///
+/// The headers are patched in place, so PDUs that this crate cannot parse are rewritten as well.
+/// Rewriting stops at the first PDU that does not fit the remaining payload.
+fn rewrite_pdus(payload: &mut [u8], exercise_id: Option<u8>, time_units: Option<TimeUnits>) {
+    if exercise_id.is_none() && time_units.is_none() {
+        return;
+    }
+
+    let mut offset = 0;
+    while let Some(header) = payload.get_mut(offset..offset + PDU_HEADER_LEN_BYTES as usize) {
+        let pdu_length = usize::from(u16::from_be_bytes([
+            header[PDU_LENGTH_OFFSET],
+            header[PDU_LENGTH_OFFSET + 1],
+        ]));
+        if pdu_length < PDU_HEADER_LEN_BYTES as usize {
+            return;
+        }
+
+        if let Some(exercise_id) = exercise_id {
+            header[EXERCISE_ID_OFFSET] = exercise_id;
+        }
+        if let Some(time_units) = time_units {
+            let timestamp_bytes = &mut header[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 4];
+            let timestamp = match Timestamp::new(u32::from_be_bytes(
+                <[u8; 4]>::try_from(&*timestamp_bytes).expect("Slice of 4 bytes"),
+            )) {
+                Timestamp::Relative(_) => Timestamp::Relative(time_units),
+                Timestamp::Absolute(_) => Timestamp::Absolute(time_units),
+            };
+            timestamp_bytes.copy_from_slice(&timestamp.to_u32().to_be_bytes());
+        }
+        offset += pdu_length;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::model::{EntityId, Pdu, PduHeader};
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::PduType;
+    use bytes::BytesMut;
+    use std::io::Cursor;
+
+    fn payload(exercise_id: u8, timestamp: Timestamp) -> Vec<u8> {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(exercise_id, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 1, 1))
+                .build()
+                .into_pdu_body(),
+            timestamp,
+        );
+        let mut buf = BytesMut::with_capacity(usize::from(pdu.pdu_length()));
+        pdu.serialize(&mut buf).unwrap();
+        buf.to_vec()
+    }
+
+    fn datagram(millis: u64) -> RecordedDatagram {
+        RecordedDatagram {
+            time: Duration::from_millis(1_700_000_000_000 + millis),
+            source: Some("10.0.0.1:3000".parse().unwrap()),
+            payload: payload(1, Timestamp::Absolute(TimeUnits::new(1000).unwrap())),
+        }
+    }
+
+    fn recording(datagrams: &[RecordedDatagram]) -> Cursor<Vec<u8>> {
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+        for datagram in datagrams {
+            recorder.record(datagram).unwrap();
+        }
+        Cursor::new(recorder.into_inner())
+    }
+
+    #[test]
+    fn record_and_read() {
+        let datagrams = vec![
+            datagram(0),
+            RecordedDatagram {
+                source: Some("[fe80::1]:3001".parse().unwrap()),
+                ..datagram(10)
+            },
+            RecordedDatagram {
+                source: None,
+                ..datagram(20)
+            },
+        ];
+
+        let read = RecordingReader::new(recording(&datagrams))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(read, datagrams);
+    }
+
+    #[test]
+    fn invalid_recordings() {
+        assert!(matches!(
+            RecordingReader::new(Cursor::new(b"NOTALOG!".to_vec())),
+            Err(RecordingError::InvalidHeader)
+        ));
+
+        let mut truncated = recording(&[datagram(0)]).into_inner();
+        truncated.truncate(truncated.len() - 1);
+        let mut reader = RecordingReader::new(Cursor::new(truncated)).unwrap();
+        assert!(matches!(
+            reader.next(),
+            Some(Err(RecordingError::TruncatedRecord))
+        ));
+    }
+
+    #[test]
+    fn replay_pacing() {
+        let mut replayer = Replayer::new(recording(&[datagram(0), datagram(1000)]))
+            .unwrap()
+            .with_speed(ReplaySpeed::Factor(2.0))
+            .unwrap();
+
+        let first = replayer.next_scheduled().unwrap().unwrap();
+        let second = replayer.next_scheduled().unwrap().unwrap();
+
+        assert_eq!(second.due - first.due, Duration::from_millis(500));
+        assert!(replayer.next_scheduled().is_none());
+    }
+
+    #[test]
+    fn replay_with_very_small_factor() {
+        let mut replayer = Replayer::new(recording(&[datagram(0), datagram(1000)]))
+            .unwrap()
+            .with_speed(ReplaySpeed::Factor(1e-6))
+            .unwrap();
+        let first = replayer.next_scheduled().unwrap().unwrap();
+        let second = replayer.next_scheduled().unwrap().unwrap();
+        assert_eq!(second.due - first.due, Duration::from_secs(1_000_000));
+
+        let mut replayer = Replayer::new(recording(&[datagram(0), datagram(1000)]))
+            .unwrap()
+            .with_speed(ReplaySpeed::Factor(1e-20))
+            .unwrap();
+        assert!(replayer.next_scheduled().unwrap().is_ok());
+        assert!(matches!(
+            replayer.next_scheduled(),
+            Some(Err(RecordingError::ReplayTimeOverflow(_)))
+        ));
+    }
+
+    #[test]
+    fn invalid_replay_speed_is_rejected() {
+        for factor in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                Replayer::new(recording(&[datagram(0)]))
+                    .unwrap()
+                    .with_speed(ReplaySpeed::Factor(factor)),
+                Err(RecordingError::InvalidReplaySpeed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn replay_seek_and_loop() {
+        let mut replayer = Replayer::new(recording(&[datagram(0), datagram(1000), datagram(2000)]))
+            .unwrap()
+            .with_speed(ReplaySpeed::AsFastAsPossible)
+            .unwrap()
+            .with_looping();
+
+        replayer.seek(Duration::from_millis(1500)).unwrap();
+        let times = replayer
+            .by_ref()
+            .take(3)
+            .map(|datagram| datagram.unwrap().time)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            times,
+            vec![datagram(2000).time, datagram(0).time, datagram(1000).time]
+        );
+    }
+
+    #[test]
+    fn rewrite_headers() {
+        let mut payload = [
+            payload(1, Timestamp::Absolute(TimeUnits::new(1000).unwrap())),
+            payload(1, Timestamp::Relative(TimeUnits::new(1000).unwrap())),
+        ]
+        .concat();
+        let time_units = TimeUnits::new(5000).unwrap();
+
+        rewrite_pdus(&mut payload, Some(9), Some(time_units));
+
+        let pdus = crate::parse(&payload).unwrap();
+        assert_eq!(pdus.len(), 2);
+        assert!(pdus.iter().all(|pdu| pdu.header.exercise_id == 9));
+        assert_eq!(pdus[0].header.timestamp, Timestamp::Absolute(time_units));
+        assert_eq!(pdus[1].header.timestamp, Timestamp::Relative(time_units));
+    }
+}
@@ -0,0 +1,801 @@
+//! Simulation Management (SIMAN) protocol agents (IEEE 1278.1-2012, 5.6.5 and 7.5).
+//!
+//! The [`SimulationManager`] is used by a simulation manager to issue requests. It assigns a
+//! `request_id` to each request, correlates the `Acknowledge`, `ActionResponse` and `Data` replies
+//! with the outstanding requests, and retransmits or times out requests that are not answered.
+//!
+//! The [`SimulationAgent`] is used on the simulation side. It passes received requests to a
+//! [`SimulationHandler`] and produces the matching reply, addressed back to the requester.
+//!
+//! Both agents do not perform any I/O: received `Pdu`s are passed in, together with the current time,
+//! and the `PduBody`s to send are returned to the caller.
+//!
+//! The acknowledged service for the reliable (SIMAN-R) variants of the PDUs is provided by the [`reliable`] module.
+pub mod reliable;
+mod retransmission;
+
+pub use retransmission::OutstandingRequest;
+
+use crate::common::Interaction;
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::model::{EntityId, FixedDatum, Pdu, PduBody, VariableDatum};
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::set_data::model::SetData;
+use crate::common::start_resume::model::StartResume;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::enumerations::{AcknowledgeFlag, RequestStatus, ResponseFlag};
+use crate::simulation_management::retransmission::{Retransmission, RetransmissionTracker};
+use crate::variable_parameters::VariableParameters;
+use crate::{ALL_APPLIC, ALL_ENTITIES, ALL_SITES, BodyRaw, NO_ENTITY};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors when creating the SIMAN agents from the configured `VariableParameters`.
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum SimulationManagementError {
+    #[error("Variable parameter {name} has invalid value {value}.")]
+    InvalidParameter { name: &'static str, value: f32 },
+}
+
+/// Converts a variable parameter expressed in seconds to a `Duration`.
+fn duration_parameter(
+    name: &'static str,
+    value: f32,
+) -> Result<Duration, SimulationManagementError> {
+    Duration::try_from_secs_f32(value)
+        .map_err(|_| SimulationManagementError::InvalidParameter { name, value })
+}
+
+/// Converts a variable parameter expressing a count to an integer.
+fn count_parameter(name: &'static str, value: f32) -> Result<u32, SimulationManagementError> {
+    if value.is_finite() && value >= 0.0 && value.fract() == 0.0 && value < u32::MAX as f32 {
+        Ok(value as u32)
+    } else {
+        Err(SimulationManagementError::InvalidParameter { name, value })
+    }
+}
+
+/// An outcome of handling a reply or polling for timeouts in the [`SimulationManager`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ManagerEvent {
+    /// A final reply to an outstanding request was received. The request is no longer pending.
+    Completed { request_id: u32, reply: PduBody },
+    /// An interim reply was received, indicating that the receiver is still processing the request
+    /// (`PendingOperatorAction`, or an Action Response with status `Pending`, `Executing` or `PartiallyComplete`).
+    /// The request remains pending and its timeout is restarted.
+    Progress { request_id: u32, reply: PduBody },
+    /// No reply was received within the timeout; the request must be sent again.
+    Retransmit { request_id: u32, request: PduBody },
+    /// No reply was received after all retransmissions. The request is no longer pending.
+    TimedOut { request_id: u32, request: PduBody },
+}
+
+/// Manager side of the SIMAN protocol, issuing requests and correlating their replies.
+///
+/// Time is passed in explicitly as the simulation time elapsed since an arbitrary (but fixed) epoch.
+#[derive(Clone, Debug)]
+pub struct SimulationManager {
+    own_id: EntityId,
+    next_request_id: u32,
+    pending: RetransmissionTracker,
+}
+
+impl SimulationManager {
+    /// Creates a manager identified by `own_id`, that times out requests after `TO_AUTO_RESPONSE_TIMER`
+    /// from `parameters`. By default requests are not retransmitted.
+    ///
+    /// # Errors
+    /// Returns a `SimulationManagementError` when `TO_AUTO_RESPONSE_TIMER` is negative, not a number, or too large.
+    pub fn new(
+        own_id: EntityId,
+        parameters: &VariableParameters,
+    ) -> Result<Self, SimulationManagementError> {
+        Ok(Self {
+            own_id,
+            next_request_id: 1,
+            pending: RetransmissionTracker::new(
+                duration_parameter("TO_AUTO_RESPONSE_TIMER", parameters.TO_AUTO_RESPONSE_TIMER)?,
+                0,
+            ),
+        })
+    }
+
+    /// Sets the time to wait for a reply before retransmitting or timing out a request.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.pending.timeout = timeout;
+        self
+    }
+
+    /// Sets the number of times a request is retransmitted before it times out.
+    #[must_use]
+    pub fn with_retransmissions(mut self, max_retransmissions: u32) -> Self {
+        self.pending.max_retransmissions = max_retransmissions;
+        self
+    }
+
+    #[must_use]
+    pub fn own_id(&self) -> EntityId {
+        self.own_id
+    }
+
+    /// Registers a request to be sent at time `now`.
+    ///
+    /// The originating id of `request` is set to the manager's id and a new `request_id` is assigned.
+    /// Returns the `request_id` and the body to send, or `None` when `request` is not a SIMAN request
+    /// that expects a reply (Create Entity, Remove Entity, Start/Resume, Stop/Freeze, Action Request,
+    /// Data Query or Set Data).
+    pub fn request(&mut self, mut request: PduBody, now: Duration) -> Option<(u32, PduBody)> {
+        if !is_request(&request) {
+            return None;
+        }
+        let request_id = self.generate_request_id();
+        set_request_ids(&mut request, self.own_id, request_id);
+        let receiving_id = request.receiver().copied().unwrap_or_default();
+        self.pending
+            .insert(request_id, receiving_id, request.clone(), now);
+        Some((request_id, request))
+    }
+
+    fn generate_request_id(&mut self) -> u32 {
+        while self
+            .pending
+            .with_request_id(self.next_request_id)
+            .next()
+            .is_some()
+        {
+            self.next_request_id = self.next_request_id.wrapping_add(1);
+        }
+        let request_id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        request_id
+    }
+
+    /// Handles a received `Pdu`, returning the resulting event if it is a reply to a pending request.
+    pub fn handle(&mut self, pdu: &Pdu, now: Duration) -> Option<ManagerEvent> {
+        self.handle_body(&pdu.body, now)
+    }
+
+    /// Handles a received `PduBody`, returning the resulting event if it is a reply to a pending request.
+    ///
+    /// A reply matches when it is addressed to the manager, carries the `request_id` of a pending request,
+    /// originates from the receiver the request was addressed to, and is of the type expected for that request.
+    /// Only the first final reply completes a request, so later replies to a request sent to multiple
+    /// receivers are ignored.
+    pub fn handle_body(&mut self, reply: &PduBody, now: Duration) -> Option<ManagerEvent> {
+        let (originating_id, receiving_id, request_id) = reply_ids(reply)?;
+        if receiving_id != self.own_id {
+            return None;
+        }
+        let key = self
+            .pending
+            .find_reply(request_id, originating_id, |request| {
+                is_reply_to(request, reply)
+            })?;
+
+        if is_interim(reply) {
+            self.pending.get_mut(&key)?.last_sent = now;
+            return Some(ManagerEvent::Progress {
+                request_id,
+                reply: reply.clone(),
+            });
+        }
+
+        self.pending.remove(&key);
+        Some(ManagerEvent::Completed {
+            request_id,
+            reply: reply.clone(),
+        })
+    }
+
+    /// Checks all pending requests for timeouts at time `now`.
+    ///
+    /// Requests that have not been answered within the timeout are returned for retransmission,
+    /// or are removed and reported as timed out when all retransmissions have been used.
+    pub fn poll(&mut self, now: Duration) -> Vec<ManagerEvent> {
+        self.pending
+            .poll(now)
+            .into_iter()
+            .map(|retransmission| match retransmission {
+                Retransmission::Retransmit {
+                    request_id,
+                    request,
+                } => ManagerEvent::Retransmit {
+                    request_id,
+                    request,
+                },
+                Retransmission::Expired {
+                    request_id,
+                    request,
+                } => ManagerEvent::TimedOut {
+                    request_id,
+                    request,
+                },
+            })
+            .collect()
+    }
+
+    /// Stops waiting for a reply to a request, returning the pending request.
+    pub fn cancel(&mut self, request_id: u32) -> Option<OutstandingRequest> {
+        let key = *self.pending.with_request_id(request_id).next()?.0;
+        self.pending.remove(&key)
+    }
+
+    #[must_use]
+    pub fn pending(&self, request_id: u32) -> Option<&OutstandingRequest> {
+        self.pending
+            .with_request_id(request_id)
+            .next()
+            .map(|(_, pending)| pending)
+    }
+
+    /// Iterates over all pending requests, ordered by `request_id`
+    pub fn iter_pending(&self) -> impl Iterator<Item = (&u32, &OutstandingRequest)> {
+        self.pending
+            .iter()
+            .map(|((request_id, _), pending)| (request_id, pending))
+    }
+
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+fn is_request(body: &PduBody) -> bool {
+    matches!(
+        body,
+        PduBody::CreateEntity(_)
+            | PduBody::RemoveEntity(_)
+            | PduBody::StartResume(_)
+            | PduBody::StopFreeze(_)
+            | PduBody::ActionRequest(_)
+            | PduBody::DataQuery(_)
+            | PduBody::SetData(_)
+    )
+}
+
+fn set_request_ids(body: &mut PduBody, originating_id: EntityId, request_id: u32) {
+    macro_rules! set_ids {
+        ($request:expr) => {{
+            $request.originating_id = originating_id;
+            $request.request_id = request_id;
+        }};
+    }
+
+    match body {
+        PduBody::CreateEntity(request) => set_ids!(request),
+        PduBody::RemoveEntity(request) => set_ids!(request),
+        PduBody::StartResume(request) => set_ids!(request),
+        PduBody::StopFreeze(request) => set_ids!(request),
+        PduBody::ActionRequest(request) => set_ids!(request),
+        PduBody::DataQuery(request) => set_ids!(request),
+        PduBody::SetData(request) => set_ids!(request),
+        _ => {}
+    }
+}
+
+/// Returns the originating id, receiving id and `request_id` of a SIMAN reply.
+fn reply_ids(body: &PduBody) -> Option<(EntityId, EntityId, u32)> {
+    match body {
+        PduBody::Acknowledge(reply) => {
+            Some((reply.originating_id, reply.receiving_id, reply.request_id))
+        }
+        PduBody::ActionResponse(reply) => {
+            Some((reply.originating_id, reply.receiving_id, reply.request_id))
+        }
+        PduBody::Data(reply) => Some((reply.originating_id, reply.receiving_id, reply.request_id)),
+        _ => None,
+    }
+}
+
+fn is_reply_to(request: &PduBody, reply: &PduBody) -> bool {
+    match (request, reply) {
+        (PduBody::CreateEntity(_), PduBody::Acknowledge(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::CreateEntity
+        }
+        (PduBody::RemoveEntity(_), PduBody::Acknowledge(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::RemoveEntity
+        }
+        (PduBody::StartResume(_), PduBody::Acknowledge(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::StartResume
+        }
+        (PduBody::StopFreeze(_), PduBody::Acknowledge(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::StopFreeze
+        }
+        (PduBody::ActionRequest(_), PduBody::ActionResponse(_))
+        | (PduBody::DataQuery(_) | PduBody::SetData(_), PduBody::Data(_)) => true,
+        _ => false,
+    }
+}
+
+fn is_interim(reply: &PduBody) -> bool {
+    match reply {
+        PduBody::Acknowledge(ack) => ack.response_flag == ResponseFlag::PendingOperatorAction,
+        PduBody::ActionResponse(response) => matches!(
+            response.request_status,
+            RequestStatus::Pending | RequestStatus::Executing | RequestStatus::PartiallyComplete
+        ),
+        _ => false,
+    }
+}
+
+/// Datum records returned by a [`SimulationHandler`], to be sent in an Action Response or Data PDU.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DatumRecords {
+    pub fixed_datum_records: Vec<FixedDatum>,
+    pub variable_datum_records: Vec<VariableDatum>,
+}
+
+/// Simulation side handling of SIMAN requests.
+///
+/// Each method is called for a request addressed to the simulation, and returns the content of the reply.
+/// The default implementations reject the request, or reply without any data.
+pub trait SimulationHandler {
+    fn create_entity(&mut self, _request: &CreateEntity) -> ResponseFlag {
+        ResponseFlag::UnableToComply
+    }
+
+    fn remove_entity(&mut self, _request: &RemoveEntity) -> ResponseFlag {
+        ResponseFlag::UnableToComply
+    }
+
+    fn start_resume(&mut self, _request: &StartResume) -> ResponseFlag {
+        ResponseFlag::UnableToComply
+    }
+
+    fn stop_freeze(&mut self, _request: &StopFreeze) -> ResponseFlag {
+        ResponseFlag::UnableToComply
+    }
+
+    fn action_request(&mut self, _request: &ActionRequest) -> (RequestStatus, DatumRecords) {
+        (RequestStatus::RequestRejected, DatumRecords::default())
+    }
+
+    /// Returns the values of the datums requested in a Data Query PDU
+    fn data_query(&mut self, _request: &DataQuery) -> DatumRecords {
+        DatumRecords::default()
+    }
+
+    /// Applies the datums in a Set Data PDU, returning the resulting values of the datums
+    fn set_data(&mut self, _request: &SetData) -> DatumRecords {
+        DatumRecords::default()
+    }
+}
+
+/// Simulation side of the SIMAN protocol, replying to requests using a [`SimulationHandler`].
+///
+/// Requests are accepted when addressed to the agent's id, or to a broadcast id that includes it
+/// (using `ALL_SITES`, `ALL_APPLIC` and `ALL_ENTITIES`). An agent identified by a simulation
+/// address (entity id `NO_ENTITY`) accepts requests for all entities of that simulation.
+#[derive(Clone, Debug)]
+pub struct SimulationAgent<H: SimulationHandler> {
+    own_id: EntityId,
+    handler: H,
+}
+
+impl<H: SimulationHandler> SimulationAgent<H> {
+    #[must_use]
+    pub fn new(own_id: EntityId, handler: H) -> Self {
+        Self { own_id, handler }
+    }
+
+    #[must_use]
+    pub fn own_id(&self) -> EntityId {
+        self.own_id
+    }
+
+    #[must_use]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    pub fn into_handler(self) -> H {
+        self.handler
+    }
+
+    /// Handles a received `Pdu`, returning the reply to send if it is a request addressed to the agent.
+    pub fn handle(&mut self, pdu: &Pdu) -> Option<PduBody> {
+        self.handle_body(&pdu.body)
+    }
+
+    /// Handles a received `PduBody`, returning the reply to send if it is a request addressed to the agent.
+    pub fn handle_body(&mut self, body: &PduBody) -> Option<PduBody> {
+        let reply = match body {
+            PduBody::CreateEntity(request) if self.accepts(request.receiving_id) => acknowledge(
+                self.own_id,
+                request.originating_id,
+                request.request_id,
+                AcknowledgeFlag::CreateEntity,
+                self.handler.create_entity(request),
+            ),
+            PduBody::RemoveEntity(request) if self.accepts(request.receiving_id) => acknowledge(
+                self.own_id,
+                request.originating_id,
+                request.request_id,
+                AcknowledgeFlag::RemoveEntity,
+                self.handler.remove_entity(request),
+            ),
+            PduBody::StartResume(request) if self.accepts(request.receiving_id) => acknowledge(
+                self.own_id,
+                request.originating_id,
+                request.request_id,
+                AcknowledgeFlag::StartResume,
+                self.handler.start_resume(request),
+            ),
+            PduBody::StopFreeze(request) if self.accepts(request.receiving_id) => acknowledge(
+                self.own_id,
+                request.originating_id,
+                request.request_id,
+                AcknowledgeFlag::StopFreeze,
+                self.handler.stop_freeze(request),
+            ),
+            PduBody::ActionRequest(request) if self.accepts(request.receiving_id) => {
+                let (request_status, datums) = self.handler.action_request(request);
+                ActionResponse::builder()
+                    .with_origination_id(self.own_id)
+                    .with_receiving_id(request.originating_id)
+                    .with_request_id(request.request_id)
+                    .with_request_status(request_status)
+                    .with_fixed_datums(datums.fixed_datum_records)
+                    .with_variable_datums(datums.variable_datum_records)
+                    .build()
+                    .into_pdu_body()
+            }
+            PduBody::DataQuery(request) if self.accepts(request.receiving_id) => {
+                let datums = self.handler.data_query(request);
+                data(
+                    self.own_id,
+                    request.originating_id,
+                    request.request_id,
+                    datums,
+                )
+            }
+            PduBody::SetData(request) if self.accepts(request.receiving_id) => {
+                let datums = self.handler.set_data(request);
+                data(
+                    self.own_id,
+                    request.originating_id,
+                    request.request_id,
+                    datums,
+                )
+            }
+            _ => return None,
+        };
+        Some(reply)
+    }
+
+    fn accepts(&self, receiving_id: EntityId) -> bool {
+        is_addressed_to(receiving_id, self.own_id)
+    }
+}
+
+/// Checks whether a PDU with `receiving_id` is addressed to `own_id`, either directly or using
+/// a broadcast id that includes it. An `own_id` with entity id `NO_ENTITY` identifies a simulation,
+/// which is addressed by all PDUs for its entities.
+pub(crate) fn is_addressed_to(receiving_id: EntityId, own_id: EntityId) -> bool {
+    let site = receiving_id.simulation_address.site_id;
+    let application = receiving_id.simulation_address.application_id;
+
+    (site == own_id.simulation_address.site_id || u32::from(site) == ALL_SITES)
+        && (application == own_id.simulation_address.application_id
+            || u32::from(application) == ALL_APPLIC)
+        && (receiving_id.entity_id == own_id.entity_id
+            || u32::from(receiving_id.entity_id) == ALL_ENTITIES
+            || own_id.entity_id == NO_ENTITY)
+}
+
+fn acknowledge(
+    own_id: EntityId,
+    receiving_id: EntityId,
+    request_id: u32,
+    acknowledge_flag: AcknowledgeFlag,
+    response_flag: ResponseFlag,
+) -> PduBody {
+    Acknowledge::builder()
+        .with_origination_id(own_id)
+        .with_receiving_id(receiving_id)
+        .with_acknowledge_flag(acknowledge_flag)
+        .with_response_flag(response_flag)
+        .with_request_id(request_id)
+        .build()
+        .into_pdu_body()
+}
+
+fn data(
+    own_id: EntityId,
+    receiving_id: EntityId,
+    request_id: u32,
+    datums: DatumRecords,
+) -> PduBody {
+    Data::builder()
+        .with_origination_id(own_id)
+        .with_receiving_id(receiving_id)
+        .with_request_id(request_id)
+        .with_fixed_datums(datums.fixed_datum_records)
+        .with_variable_datums(datums.variable_datum_records)
+        .build()
+        .into_pdu_body()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enumerations::VariableRecordType;
+
+    const MANAGER: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 1,
+        },
+        entity_id: NO_ENTITY,
+    };
+
+    fn manager() -> SimulationManager {
+        SimulationManager::new(MANAGER, &VariableParameters::default())
+            .unwrap()
+            .with_timeout(Duration::from_secs(1))
+            .with_retransmissions(1)
+    }
+
+    #[derive(Default)]
+    struct Simulation {
+        running: bool,
+    }
+
+    impl SimulationHandler for Simulation {
+        fn start_resume(&mut self, _request: &StartResume) -> ResponseFlag {
+            self.running = true;
+            ResponseFlag::AbleToComply
+        }
+
+        fn data_query(&mut self, _request: &DataQuery) -> DatumRecords {
+            DatumRecords {
+                fixed_datum_records: vec![FixedDatum::new(VariableRecordType::from(1), 42)],
+                variable_datum_records: vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn request_ids_are_assigned() {
+        let mut manager = manager();
+
+        let (first, body) = manager
+            .request(
+                CreateEntity::builder()
+                    .with_receiving_id(EntityId::new(1, 2, NO_ENTITY))
+                    .build()
+                    .into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        let (second, _) = manager
+            .request(
+                StartResume::builder().build().into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+
+        assert_ne!(first, second);
+        if let PduBody::CreateEntity(request) = body {
+            assert_eq!(request.originating_id, MANAGER);
+            assert_eq!(request.request_id, first);
+        } else {
+            panic!("Expected a CreateEntity body");
+        }
+        assert!(
+            manager
+                .request(Data::builder().build().into_pdu_body(), Duration::ZERO)
+                .is_none()
+        );
+        assert_eq!(manager.pending_len(), 2);
+
+        // A rejected body does not consume a request id
+        let (third, _) = manager
+            .request(
+                StopFreeze::builder().build().into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        assert_eq!(third, second + 1);
+    }
+
+    #[test]
+    fn invalid_response_timer_is_rejected() {
+        let mut parameters = VariableParameters {
+            TO_AUTO_RESPONSE_TIMER: -1.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            SimulationManager::new(MANAGER, &parameters).unwrap_err(),
+            SimulationManagementError::InvalidParameter {
+                name: "TO_AUTO_RESPONSE_TIMER",
+                value: -1.0
+            }
+        );
+        parameters.TO_AUTO_RESPONSE_TIMER = f32::NAN;
+        assert!(SimulationManager::new(MANAGER, &parameters).is_err());
+    }
+
+    #[test]
+    fn agent_replies_are_correlated() {
+        let mut manager = manager();
+        let mut agent = SimulationAgent::new(EntityId::new(1, 2, NO_ENTITY), Simulation::default());
+
+        let (request_id, request) = manager
+            .request(
+                StartResume::builder()
+                    .with_receiving_id(EntityId::new(1, 2, NO_ENTITY))
+                    .build()
+                    .into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        let reply = agent.handle_body(&request).unwrap();
+        assert!(agent.handler().running);
+
+        let event = manager.handle_body(&reply, Duration::from_millis(100));
+        if let Some(ManagerEvent::Completed {
+            request_id: completed,
+            reply: PduBody::Acknowledge(ack),
+        }) = event
+        {
+            assert_eq!(completed, request_id);
+            assert_eq!(ack.acknowledge_flag, AcknowledgeFlag::StartResume);
+            assert_eq!(ack.response_flag, ResponseFlag::AbleToComply);
+            assert_eq!(ack.originating_id, EntityId::new(1, 2, NO_ENTITY));
+        } else {
+            panic!("Expected a completed StartResume request, got {event:?}");
+        }
+        assert_eq!(manager.pending_len(), 0);
+
+        // A duplicate reply no longer matches a pending request
+        assert!(
+            manager
+                .handle_body(&reply, Duration::from_millis(200))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn replies_from_other_receivers_are_ignored() {
+        let mut manager = manager();
+        let mut addressed = SimulationAgent::new(EntityId::new(1, 2, 3), Simulation::default());
+        let mut other = SimulationAgent::new(EntityId::new(1, 3, 3), Simulation::default());
+
+        let (request_id, request) = manager
+            .request(
+                StartResume::builder()
+                    .with_receiving_id(EntityId::new(1, 2, 3))
+                    .build()
+                    .into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        let mut misdirected = StartResume::builder()
+            .with_receiving_id(EntityId::new(1, 3, 3))
+            .build();
+        misdirected.originating_id = MANAGER;
+        misdirected.request_id = request_id;
+        let other_reply = other.handle_body(&misdirected.into_pdu_body()).unwrap();
+
+        assert!(manager.handle_body(&other_reply, Duration::ZERO).is_none());
+        assert_eq!(
+            manager.pending(request_id).unwrap().receiving_id,
+            EntityId::new(1, 2, 3)
+        );
+
+        let reply = addressed.handle_body(&request).unwrap();
+        assert!(matches!(
+            manager.handle_body(&reply, Duration::ZERO),
+            Some(ManagerEvent::Completed { .. })
+        ));
+    }
+
+    #[test]
+    fn agent_replies_with_data() {
+        let mut manager = manager();
+        let mut agent = SimulationAgent::new(EntityId::new(1, 2, 3), Simulation::default());
+
+        let (_, request) = manager
+            .request(
+                DataQuery::builder()
+                    .with_receiving_id(EntityId::new(1, 2, ALL_ENTITIES as u16))
+                    .build()
+                    .into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        let reply = agent.handle_body(&request).unwrap();
+
+        if let Some(ManagerEvent::Completed {
+            reply: PduBody::Data(data),
+            ..
+        }) = manager.handle_body(&reply, Duration::ZERO)
+        {
+            assert_eq!(data.fixed_datum_records[0].datum_value, 42);
+        } else {
+            panic!("Expected a completed DataQuery request");
+        }
+
+        // Requests for other simulations and unsupported requests are ignored or rejected
+        let other = StopFreeze::builder()
+            .with_receiving_id(EntityId::new(1, 3, NO_ENTITY))
+            .build()
+            .into_pdu_body();
+        assert!(agent.handle_body(&other).is_none());
+        let unsupported = StopFreeze::builder()
+            .with_receiving_id(EntityId::new(1, 2, 3))
+            .build()
+            .into_pdu_body();
+        if let Some(PduBody::Acknowledge(ack)) = agent.handle_body(&unsupported) {
+            assert_eq!(ack.response_flag, ResponseFlag::UnableToComply);
+        } else {
+            panic!("Expected an Acknowledge reply");
+        }
+    }
+
+    #[test]
+    fn interim_reply_restarts_timeout() {
+        let mut manager = manager();
+        let (request_id, _) = manager
+            .request(
+                ActionRequest::builder().build().into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+
+        let executing = ActionResponse::builder()
+            .with_receiving_id(MANAGER)
+            .with_request_id(request_id)
+            .with_request_status(RequestStatus::Executing)
+            .build()
+            .into_pdu_body();
+        assert!(matches!(
+            manager.handle_body(&executing, Duration::from_millis(800)),
+            Some(ManagerEvent::Progress { .. })
+        ));
+        assert!(manager.poll(Duration::from_millis(1_500)).is_empty());
+        assert_eq!(manager.pending_len(), 1);
+    }
+
+    #[test]
+    fn retransmit_and_time_out() {
+        let mut manager = manager();
+        let (request_id, request) = manager
+            .request(
+                RemoveEntity::builder().build().into_pdu_body(),
+                Duration::ZERO,
+            )
+            .unwrap();
+
+        assert!(manager.poll(Duration::from_millis(500)).is_empty());
+        assert_eq!(
+            manager.poll(Duration::from_secs(1)),
+            vec![ManagerEvent::Retransmit {
+                request_id,
+                request: request.clone()
+            }]
+        );
+        assert_eq!(manager.pending(request_id).unwrap().retransmissions, 1);
+        assert_eq!(
+            manager.poll(Duration::from_secs(2)),
+            vec![ManagerEvent::TimedOut {
+                request_id,
+                request
+            }]
+        );
+        assert_eq!(manager.pending_len(), 0);
+    }
+}
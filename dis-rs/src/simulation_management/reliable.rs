@@ -0,0 +1,553 @@
+//! Acknowledged service for Simulation Management with reliability (SIMAN-R) PDUs (IEEE 1278.1-2012, 5.12.4).
+//!
+//! Reliable requests that set their `RequiredReliabilityService` to `Acknowledged` are kept by the
+//! [`ReliableSender`] and retransmitted every `SM_REL_RETRY_DELAY` until the receiver acknowledges them,
+//! for at most `SM_REL_RETRY_CNT` times. Requests that are never acknowledged are reported as failed.
+//!
+//! A request is acknowledged by the reply the receiver sends for it:
+//! - an `AcknowledgeR` PDU for Create Entity-R, Remove Entity-R, Start/Resume-R and Stop/Freeze-R,
+//! - an `ActionResponseR` PDU for Action Request-R,
+//! - a `DataR` PDU for Data Query-R and Set Data-R,
+//! - a `RecordR` PDU for Record Query-R and Set Record-R.
+//!
+//! Because requests are retransmitted, the receiver can get the same request more than once. The
+//! [`ReliableReceiver`] processes each request only once, and answers duplicates with the reply
+//! that was sent for the original request.
+use crate::common::BodyInfo;
+use crate::common::model::{EntityId, Pdu, PduBody};
+use crate::enumerations::{AcknowledgeFlag, PduType, RequiredReliabilityService};
+use crate::simulation_management::retransmission::{Retransmission, RetransmissionTracker};
+use crate::simulation_management::{
+    OutstandingRequest, SimulationManagementError, count_parameter, duration_parameter,
+};
+use crate::variable_parameters::VariableParameters;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::time::Duration;
+
+/// An outcome of handling an acknowledgement or polling for retransmissions in the [`ReliableSender`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeliveryEvent {
+    /// The request was acknowledged by the receiver.
+    Delivered {
+        request_id: u32,
+        acknowledgement: PduBody,
+    },
+    /// The request was not acknowledged within `SM_REL_RETRY_DELAY`; it must be sent again.
+    Retransmit { request_id: u32, request: PduBody },
+    /// The request was not acknowledged after `SM_REL_RETRY_CNT` retransmissions.
+    Failed { request_id: u32, request: PduBody },
+}
+
+/// Sending side of the SIMAN-R acknowledged service.
+///
+/// Time is passed in explicitly as the simulation time elapsed since an arbitrary (but fixed) epoch.
+#[derive(Clone, Debug)]
+pub struct ReliableSender {
+    outstanding: RetransmissionTracker,
+}
+
+impl ReliableSender {
+    /// Creates a sender that uses `SM_REL_RETRY_DELAY` and `SM_REL_RETRY_CNT` from `parameters`.
+    ///
+    /// # Errors
+    /// Returns a `SimulationManagementError` when `SM_REL_RETRY_DELAY` is not a valid duration,
+    /// or `SM_REL_RETRY_CNT` is not a non-negative whole number.
+    pub fn new(parameters: &VariableParameters) -> Result<Self, SimulationManagementError> {
+        let (retry_delay, retry_count) = retry_parameters(parameters)?;
+        Ok(Self {
+            outstanding: RetransmissionTracker::new(retry_delay, retry_count),
+        })
+    }
+
+    /// Registers a PDU that is sent at time `now`.
+    ///
+    /// Returns `true` when `body` is a reliable request using the acknowledged service, which is
+    /// then retransmitted until it is acknowledged. A request with the same `request_id` and receiving id
+    /// as an unacknowledged request replaces it.
+    pub fn send(&mut self, body: &PduBody, now: Duration) -> bool {
+        let Some(request) = ReliableRequest::from_body(body) else {
+            return false;
+        };
+        if request.service != RequiredReliabilityService::Acknowledged {
+            return false;
+        }
+        self.outstanding
+            .insert(request.request_id, request.receiving_id, body.clone(), now);
+        true
+    }
+
+    /// Handles a received `Pdu`, returning the resulting event if it acknowledges an outstanding request.
+    pub fn handle(&mut self, pdu: &Pdu) -> Option<DeliveryEvent> {
+        self.handle_body(&pdu.body)
+    }
+
+    /// Handles a received `PduBody`, returning the resulting event if it acknowledges an outstanding request.
+    ///
+    /// An acknowledgement matches when it is addressed to the originator of the request, originates from
+    /// the receiver of the request, carries the same `request_id`, and is the reply expected for the type of request.
+    pub fn handle_body(&mut self, body: &PduBody) -> Option<DeliveryEvent> {
+        let (originating_id, receiving_id, request_id) = acknowledgement_ids(body)?;
+        let key = self
+            .outstanding
+            .find_reply(request_id, originating_id, |request| {
+                ReliableRequest::from_body(request)
+                    .is_some_and(|request| request.originating_id == receiving_id)
+                    && is_acknowledgement(request, body)
+            })?;
+
+        self.outstanding.remove(&key);
+        Some(DeliveryEvent::Delivered {
+            request_id,
+            acknowledgement: body.clone(),
+        })
+    }
+
+    /// Checks all outstanding requests at time `now`, returning the requests to retransmit
+    /// and the requests for which delivery failed.
+    pub fn poll(&mut self, now: Duration) -> Vec<DeliveryEvent> {
+        self.outstanding
+            .poll(now)
+            .into_iter()
+            .map(|retransmission| match retransmission {
+                Retransmission::Retransmit {
+                    request_id,
+                    request,
+                } => DeliveryEvent::Retransmit {
+                    request_id,
+                    request,
+                },
+                Retransmission::Expired {
+                    request_id,
+                    request,
+                } => DeliveryEvent::Failed {
+                    request_id,
+                    request,
+                },
+            })
+            .collect()
+    }
+
+    /// Returns the unacknowledged request with `request_id` sent to `receiving_id`
+    #[must_use]
+    pub fn outstanding(
+        &self,
+        receiving_id: EntityId,
+        request_id: u32,
+    ) -> Option<&OutstandingRequest> {
+        self.outstanding
+            .with_request_id(request_id)
+            .find(|(_, outstanding)| outstanding.receiving_id == receiving_id)
+            .map(|(_, outstanding)| outstanding)
+    }
+
+    #[must_use]
+    pub fn outstanding_len(&self) -> usize {
+        self.outstanding.len()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct RequestKey {
+    originating_id: EntityId,
+    pdu_type: PduType,
+    request_id: u32,
+}
+
+#[derive(Clone, Debug)]
+struct ReceivedRequest {
+    reply: Option<PduBody>,
+    last_received: Duration,
+}
+
+/// Receiving side of the SIMAN-R acknowledged service, suppressing duplicate requests.
+///
+/// Received requests are remembered, together with the reply sent for them, until no more
+/// retransmissions of the request can be expected: `SM_REL_RETRY_DELAY` times one more than `SM_REL_RETRY_CNT`
+/// after the last time the request was received.
+#[derive(Clone, Debug)]
+pub struct ReliableReceiver {
+    retention: Duration,
+    received: HashMap<RequestKey, ReceivedRequest>,
+}
+
+impl ReliableReceiver {
+    /// Creates a receiver that uses `SM_REL_RETRY_DELAY` and `SM_REL_RETRY_CNT` from `parameters`.
+    ///
+    /// # Errors
+    /// Returns a `SimulationManagementError` when `SM_REL_RETRY_DELAY` is not a valid duration,
+    /// `SM_REL_RETRY_CNT` is not a non-negative whole number, or the resulting retention time overflows.
+    pub fn new(parameters: &VariableParameters) -> Result<Self, SimulationManagementError> {
+        let (retry_delay, retry_count) = retry_parameters(parameters)?;
+        let retention = retry_count
+            .checked_add(1)
+            .and_then(|transmissions| retry_delay.checked_mul(transmissions))
+            .ok_or(SimulationManagementError::InvalidParameter {
+                name: "SM_REL_RETRY_DELAY",
+                value: parameters.SM_REL_RETRY_DELAY,
+            })?;
+        Ok(Self {
+            retention,
+            received: HashMap::new(),
+        })
+    }
+
+    /// Handles a received `PduBody` at time `now`, returning the reply to send.
+    ///
+    /// `respond` is called to process the request and produce the reply, unless `body` is a duplicate
+    /// of a reliable request that was received before. In that case the reply to the original request
+    /// is returned again, so that a lost acknowledgement is resent. PDUs that are not reliable requests
+    /// are always passed to `respond`.
+    pub fn receive<F>(&mut self, body: &PduBody, now: Duration, respond: F) -> Option<PduBody>
+    where
+        F: FnOnce(&PduBody) -> Option<PduBody>,
+    {
+        let Some(request) = ReliableRequest::from_body(body) else {
+            return respond(body);
+        };
+        let key = RequestKey {
+            originating_id: request.originating_id,
+            pdu_type: body.body_type(),
+            request_id: request.request_id,
+        };
+
+        match self.received.entry(key) {
+            Entry::Occupied(mut entry) => {
+                let received = entry.get_mut();
+                received.last_received = now;
+                received.reply.clone()
+            }
+            Entry::Vacant(entry) => {
+                let reply = respond(body);
+                entry.insert(ReceivedRequest {
+                    reply: reply.clone(),
+                    last_received: now,
+                });
+                reply
+            }
+        }
+    }
+
+    /// Forgets requests for which no more retransmissions are expected at time `now`.
+    pub fn expire(&mut self, now: Duration) {
+        let retention = self.retention;
+        self.received
+            .retain(|_, received| now.saturating_sub(received.last_received) <= retention);
+    }
+
+    /// Returns the number of remembered requests
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.received.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.received.is_empty()
+    }
+}
+
+fn retry_parameters(
+    parameters: &VariableParameters,
+) -> Result<(Duration, u32), SimulationManagementError> {
+    Ok((
+        duration_parameter("SM_REL_RETRY_DELAY", parameters.SM_REL_RETRY_DELAY)?,
+        count_parameter("SM_REL_RETRY_CNT", parameters.SM_REL_RETRY_CNT)?,
+    ))
+}
+
+/// The fields of a reliable request that are used to correlate it with its acknowledgement.
+struct ReliableRequest {
+    originating_id: EntityId,
+    receiving_id: EntityId,
+    request_id: u32,
+    service: RequiredReliabilityService,
+}
+
+impl ReliableRequest {
+    fn from_body(body: &PduBody) -> Option<Self> {
+        macro_rules! request {
+            ($request:expr) => {
+                Some(Self {
+                    originating_id: $request.originating_id,
+                    receiving_id: $request.receiving_id,
+                    request_id: $request.request_id,
+                    service: $request.required_reliability_service,
+                })
+            };
+        }
+
+        match body {
+            PduBody::CreateEntityR(request) => request!(request),
+            PduBody::RemoveEntityR(request) => request!(request),
+            PduBody::StartResumeR(request) => request!(request),
+            PduBody::StopFreezeR(request) => request!(request),
+            PduBody::ActionRequestR(request) => request!(request),
+            PduBody::DataQueryR(request) => request!(request),
+            PduBody::SetDataR(request) => request!(request),
+            PduBody::RecordQueryR(request) => request!(request),
+            PduBody::SetRecordR(request) => request!(request),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the originating id, receiving id and `request_id` of a PDU that can acknowledge a reliable request.
+fn acknowledgement_ids(body: &PduBody) -> Option<(EntityId, EntityId, u32)> {
+    macro_rules! ids {
+        ($reply:expr) => {
+            Some((
+                $reply.originating_id,
+                $reply.receiving_id,
+                $reply.request_id,
+            ))
+        };
+    }
+
+    match body {
+        PduBody::AcknowledgeR(reply) => ids!(reply),
+        PduBody::ActionResponseR(reply) => ids!(reply),
+        PduBody::DataR(reply) => ids!(reply),
+        PduBody::RecordR(reply) => ids!(reply),
+        _ => None,
+    }
+}
+
+fn is_acknowledgement(request: &PduBody, reply: &PduBody) -> bool {
+    match (request, reply) {
+        (PduBody::CreateEntityR(_), PduBody::AcknowledgeR(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::CreateEntity
+        }
+        (PduBody::RemoveEntityR(_), PduBody::AcknowledgeR(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::RemoveEntity
+        }
+        (PduBody::StartResumeR(_), PduBody::AcknowledgeR(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::StartResume
+        }
+        (PduBody::StopFreezeR(_), PduBody::AcknowledgeR(ack)) => {
+            ack.acknowledge_flag == AcknowledgeFlag::StopFreeze
+        }
+        (PduBody::ActionRequestR(_), PduBody::ActionResponseR(_))
+        | (PduBody::DataQueryR(_) | PduBody::SetDataR(_), PduBody::DataR(_))
+        | (PduBody::RecordQueryR(_) | PduBody::SetRecordR(_), PduBody::RecordR(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyRaw;
+    use crate::common::acknowledge_r::model::AcknowledgeR;
+    use crate::common::create_entity_r::model::CreateEntityR;
+    use crate::common::data_r::model::DataR;
+    use crate::common::set_data_r::model::SetDataR;
+    use crate::enumerations::ResponseFlag;
+
+    const MANAGER: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 1,
+        },
+        entity_id: 0,
+    };
+    const SIMULATION: EntityId = EntityId {
+        simulation_address: crate::common::model::SimulationAddress {
+            site_id: 1,
+            application_id: 2,
+        },
+        entity_id: 0,
+    };
+
+    fn create_entity(service: RequiredReliabilityService) -> PduBody {
+        CreateEntityR::builder()
+            .with_origination_id(MANAGER)
+            .with_receiving_id(SIMULATION)
+            .with_required_reliability_service(service)
+            .with_request_id(7)
+            .build()
+            .into_pdu_body()
+    }
+
+    fn acknowledge(acknowledge_flag: AcknowledgeFlag) -> PduBody {
+        AcknowledgeR::builder()
+            .with_origination_id(SIMULATION)
+            .with_receiving_id(MANAGER)
+            .with_acknowledge_flag(acknowledge_flag)
+            .with_response_flag(ResponseFlag::AbleToComply)
+            .with_request_id(7)
+            .build()
+            .into_pdu_body()
+    }
+
+    #[test]
+    fn acknowledged_request_is_delivered() {
+        let mut sender = ReliableSender::new(&VariableParameters::default()).unwrap();
+
+        assert!(sender.send(
+            &create_entity(RequiredReliabilityService::Acknowledged),
+            Duration::ZERO
+        ));
+        assert!(
+            sender
+                .handle_body(&acknowledge(AcknowledgeFlag::RemoveEntity))
+                .is_none()
+        );
+        assert!(matches!(
+            sender.handle_body(&acknowledge(AcknowledgeFlag::CreateEntity)),
+            Some(DeliveryEvent::Delivered { request_id: 7, .. })
+        ));
+        assert_eq!(sender.outstanding_len(), 0);
+    }
+
+    #[test]
+    fn same_request_id_to_multiple_receivers() {
+        const OTHER_SIMULATION: EntityId = EntityId {
+            simulation_address: crate::common::model::SimulationAddress {
+                site_id: 1,
+                application_id: 3,
+            },
+            entity_id: 0,
+        };
+        let mut sender = ReliableSender::new(&VariableParameters::default()).unwrap();
+        let to_other = CreateEntityR::builder()
+            .with_origination_id(MANAGER)
+            .with_receiving_id(OTHER_SIMULATION)
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_request_id(7)
+            .build()
+            .into_pdu_body();
+
+        sender.send(
+            &create_entity(RequiredReliabilityService::Acknowledged),
+            Duration::ZERO,
+        );
+        sender.send(&to_other, Duration::ZERO);
+        assert_eq!(sender.outstanding_len(), 2);
+
+        assert!(matches!(
+            sender.handle_body(&acknowledge(AcknowledgeFlag::CreateEntity)),
+            Some(DeliveryEvent::Delivered { request_id: 7, .. })
+        ));
+        assert!(sender.outstanding(SIMULATION, 7).is_none());
+        assert_eq!(
+            sender.outstanding(OTHER_SIMULATION, 7).unwrap().request,
+            to_other
+        );
+
+        // A second acknowledgement from the same receiver does not deliver the other request
+        assert!(
+            sender
+                .handle_body(&acknowledge(AcknowledgeFlag::CreateEntity))
+                .is_none()
+        );
+        assert_eq!(sender.outstanding_len(), 1);
+    }
+
+    #[test]
+    fn unacknowledged_service_is_not_tracked() {
+        let mut sender = ReliableSender::new(&VariableParameters::default()).unwrap();
+
+        assert!(!sender.send(
+            &create_entity(RequiredReliabilityService::Unacknowledged),
+            Duration::ZERO
+        ));
+        assert_eq!(sender.outstanding_len(), 0);
+    }
+
+    #[test]
+    fn retransmit_until_failed() {
+        // SM_REL_RETRY_DELAY of 2 s and SM_REL_RETRY_CNT of 3
+        let mut sender = ReliableSender::new(&VariableParameters::default()).unwrap();
+        let request = create_entity(RequiredReliabilityService::Acknowledged);
+        sender.send(&request, Duration::ZERO);
+
+        assert!(sender.poll(Duration::from_secs(1)).is_empty());
+        for retransmission in 1..=3 {
+            assert_eq!(
+                sender.poll(Duration::from_secs(2 * retransmission)),
+                vec![DeliveryEvent::Retransmit {
+                    request_id: 7,
+                    request: request.clone()
+                }]
+            );
+        }
+        assert_eq!(
+            sender.poll(Duration::from_secs(8)),
+            vec![DeliveryEvent::Failed {
+                request_id: 7,
+                request
+            }]
+        );
+        assert_eq!(sender.outstanding_len(), 0);
+    }
+
+    #[test]
+    fn invalid_retry_parameters_are_rejected() {
+        let negative_delay = VariableParameters {
+            SM_REL_RETRY_DELAY: -2.0,
+            ..Default::default()
+        };
+        assert!(ReliableSender::new(&negative_delay).is_err());
+        assert!(ReliableReceiver::new(&negative_delay).is_err());
+
+        for count in [-1.0, 1.5, f32::NAN, f32::INFINITY, 1e10] {
+            let parameters = VariableParameters {
+                SM_REL_RETRY_CNT: count,
+                ..Default::default()
+            };
+            assert!(ReliableSender::new(&parameters).is_err());
+        }
+
+        // The retention time of the receiver overflows
+        let overflowing = VariableParameters {
+            SM_REL_RETRY_DELAY: 1e19,
+            SM_REL_RETRY_CNT: 3.0,
+            ..Default::default()
+        };
+        assert!(ReliableSender::new(&overflowing).is_ok());
+        assert_eq!(
+            ReliableReceiver::new(&overflowing).unwrap_err(),
+            SimulationManagementError::InvalidParameter {
+                name: "SM_REL_RETRY_DELAY",
+                value: 1e19
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_requests_are_suppressed() {
+        let mut receiver = ReliableReceiver::new(&VariableParameters::default()).unwrap();
+        let request = SetDataR::builder()
+            .with_origination_id(MANAGER)
+            .with_receiving_id(SIMULATION)
+            .with_request_id(3)
+            .build()
+            .into_pdu_body();
+        let mut processed = 0;
+        let mut respond = |_: &PduBody| {
+            processed += 1;
+            Some(
+                DataR::builder()
+                    .with_origination_id(SIMULATION)
+                    .with_receiving_id(MANAGER)
+                    .with_request_id(3)
+                    .build()
+                    .into_pdu_body(),
+            )
+        };
+
+        let reply = receiver.receive(&request, Duration::ZERO, &mut respond);
+        let duplicate = receiver.receive(&request, Duration::from_secs(2), &mut respond);
+
+        assert_eq!(processed, 1);
+        assert!(reply.is_some());
+        assert_eq!(reply, duplicate);
+
+        // Retained until SM_REL_RETRY_DELAY * (SM_REL_RETRY_CNT + 1) after the last duplicate
+        receiver.expire(Duration::from_secs(10));
+        assert_eq!(receiver.len(), 1);
+        receiver.expire(Duration::from_secs(11));
+        assert!(receiver.is_empty());
+    }
+}
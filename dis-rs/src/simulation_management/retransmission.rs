@@ -0,0 +1,152 @@
+//! Retransmission of requests until a reply is received, shared by the [`SimulationManager`](super::SimulationManager)
+//! and the [`ReliableSender`](super::reliable::ReliableSender).
+//!
+//! Outstanding requests are keyed on their `request_id` and the id of the receiver they are addressed to,
+//! so the same `request_id` can be outstanding at several receivers at once.
+use crate::common::model::{EntityId, PduBody};
+use crate::simulation_management::is_addressed_to;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// A request that has been sent, but for which no (final) reply has been received yet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutstandingRequest {
+    pub request: PduBody,
+    /// The id the request is addressed to, which can be a broadcast id
+    pub receiving_id: EntityId,
+    /// Time at which the request was last (re)transmitted, or an interim reply was received
+    pub last_sent: Duration,
+    /// Number of retransmissions performed so far
+    pub retransmissions: u32,
+}
+
+/// The `request_id` and receiving id of an outstanding request
+pub(crate) type OutstandingKey = (u32, EntityId);
+
+/// An outcome of polling the [`RetransmissionTracker`].
+pub(crate) enum Retransmission {
+    /// No reply was received within the timeout; the request must be sent again.
+    Retransmit { request_id: u32, request: PduBody },
+    /// No reply was received after all retransmissions. The request is no longer outstanding.
+    Expired { request_id: u32, request: PduBody },
+}
+
+/// Outstanding requests, which are retransmitted when no reply is received within the timeout.
+#[derive(Clone, Debug)]
+pub(crate) struct RetransmissionTracker {
+    pub(crate) timeout: Duration,
+    pub(crate) max_retransmissions: u32,
+    outstanding: BTreeMap<OutstandingKey, OutstandingRequest>,
+}
+
+impl RetransmissionTracker {
+    pub(crate) fn new(timeout: Duration, max_retransmissions: u32) -> Self {
+        Self {
+            timeout,
+            max_retransmissions,
+            outstanding: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a request sent at time `now`, replacing an outstanding request with the same
+    /// `request_id` and receiving id.
+    pub(crate) fn insert(
+        &mut self,
+        request_id: u32,
+        receiving_id: EntityId,
+        request: PduBody,
+        now: Duration,
+    ) {
+        self.outstanding.insert(
+            (request_id, receiving_id),
+            OutstandingRequest {
+                request,
+                receiving_id,
+                last_sent: now,
+                retransmissions: 0,
+            },
+        );
+    }
+
+    /// Iterates over the outstanding requests with the given `request_id`, ordered by receiving id
+    pub(crate) fn with_request_id(
+        &self,
+        request_id: u32,
+    ) -> impl Iterator<Item = (&OutstandingKey, &OutstandingRequest)> {
+        self.outstanding
+            .range((request_id, EntityId::new(0, 0, 0))..)
+            .take_while(move |((id, _), _)| *id == request_id)
+    }
+
+    /// Finds the outstanding request with `request_id` that is addressed to `replying_id`,
+    /// and for which `is_reply` holds.
+    pub(crate) fn find_reply<F>(
+        &self,
+        request_id: u32,
+        replying_id: EntityId,
+        is_reply: F,
+    ) -> Option<OutstandingKey>
+    where
+        F: Fn(&PduBody) -> bool,
+    {
+        self.with_request_id(request_id)
+            .find(|(_, outstanding)| {
+                is_addressed_to(outstanding.receiving_id, replying_id)
+                    && is_reply(&outstanding.request)
+            })
+            .map(|(key, _)| *key)
+    }
+
+    pub(crate) fn get_mut(&mut self, key: &OutstandingKey) -> Option<&mut OutstandingRequest> {
+        self.outstanding.get_mut(key)
+    }
+
+    pub(crate) fn remove(&mut self, key: &OutstandingKey) -> Option<OutstandingRequest> {
+        self.outstanding.remove(key)
+    }
+
+    /// Checks all outstanding requests for timeouts at time `now`.
+    ///
+    /// Requests that have not been answered within the timeout are returned for retransmission,
+    /// or are removed and reported as expired when all retransmissions have been used.
+    pub(crate) fn poll(&mut self, now: Duration) -> Vec<Retransmission> {
+        let mut retransmissions = Vec::new();
+        let mut expired = Vec::new();
+
+        for (key, outstanding) in &mut self.outstanding {
+            if now.saturating_sub(outstanding.last_sent) < self.timeout {
+                continue;
+            }
+            if outstanding.retransmissions < self.max_retransmissions {
+                outstanding.retransmissions += 1;
+                outstanding.last_sent = now;
+                retransmissions.push(Retransmission::Retransmit {
+                    request_id: key.0,
+                    request: outstanding.request.clone(),
+                });
+            } else {
+                expired.push(*key);
+            }
+        }
+
+        for key in expired {
+            if let Some(outstanding) = self.outstanding.remove(&key) {
+                retransmissions.push(Retransmission::Expired {
+                    request_id: key.0,
+                    request: outstanding.request,
+                });
+            }
+        }
+
+        retransmissions
+    }
+
+    /// Iterates over all outstanding requests, ordered by `request_id` and receiving id
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&OutstandingKey, &OutstandingRequest)> {
+        self.outstanding.iter()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.outstanding.len()
+    }
+}
@@ -0,0 +1,398 @@
+//! Local tangent plane coordinate frames and Euler angle conversions.
+//!
+//! Conventions:
+//! - Geodetic positions are WGS-84 latitude and longitude in radians and altitude in meters.
+//! - ENU is the local East-North-Up frame, NED the local North-East-Down frame, both tangent to the
+//!   WGS-84 ellipsoid at a reference point, with axes in meters (or meters per second for velocities).
+//! - DIS `Location`, `VectorF32` velocities and `Orientation` are relative to the geocentric (ECEF) world
+//!   coordinate system (IEEE 1278.1-2012, 6.2.79 and 6.2.85).
+//! - The DIS `Orientation` (psi, theta, phi) is the z-y-x Euler angle sequence rotating the world
+//!   coordinate system into the entity body coordinate system.
+//! - [`HeadingPitchRoll`] is the same z-y-x Euler angle sequence, but rotating the local NED frame into
+//!   the entity body frame: heading is clockwise from true north, pitch is positive nose up,
+//!   and roll is positive right side down.
+use crate::common::model::{Location, Orientation, VectorF32};
+use crate::utils::rotation::RotationMatrix;
+use crate::utils::{ecef_to_geodetic_lla, geodetic_lla_to_ecef};
+
+/// A position or vector in the local East-North-Up frame
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Enu {
+    pub east: f64,
+    pub north: f64,
+    pub up: f64,
+}
+
+impl Enu {
+    #[must_use]
+    pub fn new(east: f64, north: f64, up: f64) -> Self {
+        Self { east, north, up }
+    }
+}
+
+impl From<Ned> for Enu {
+    fn from(value: Ned) -> Self {
+        Self::new(value.east, value.north, -value.down)
+    }
+}
+
+/// A position or vector in the local North-East-Down frame
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ned {
+    pub north: f64,
+    pub east: f64,
+    pub down: f64,
+}
+
+impl Ned {
+    #[must_use]
+    pub fn new(north: f64, east: f64, down: f64) -> Self {
+        Self { north, east, down }
+    }
+}
+
+impl From<Enu> for Ned {
+    fn from(value: Enu) -> Self {
+        Self::new(value.north, value.east, -value.up)
+    }
+}
+
+/// Orientation of an entity relative to the local NED frame, in radians
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HeadingPitchRoll {
+    pub heading: f64,
+    pub pitch: f64,
+    pub roll: f64,
+}
+
+impl HeadingPitchRoll {
+    #[must_use]
+    pub fn new(heading: f64, pitch: f64, roll: f64) -> Self {
+        Self {
+            heading,
+            pitch,
+            roll,
+        }
+    }
+}
+
+/// A local tangent plane around a reference point, converting between DIS world coordinates and local ENU/NED coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LocalTangentPlane {
+    latitude: f64,
+    longitude: f64,
+    origin: [f64; 3],
+    ecef_to_ned: RotationMatrix,
+}
+
+impl LocalTangentPlane {
+    /// Creates a local tangent plane with its origin at the given geodetic position
+    #[must_use]
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        let (x, y, z) = geodetic_lla_to_ecef(latitude, longitude, altitude);
+        Self {
+            latitude,
+            longitude,
+            origin: [x, y, z],
+            ecef_to_ned: ecef_to_ned(latitude, longitude),
+        }
+    }
+
+    /// Creates a local tangent plane with its origin at the given DIS world location
+    #[must_use]
+    pub fn from_location(location: &Location) -> Self {
+        let (latitude, longitude, _altitude) = ecef_to_geodetic_lla(
+            location.x_coordinate,
+            location.y_coordinate,
+            location.z_coordinate,
+        );
+        Self {
+            latitude,
+            longitude,
+            origin: [
+                location.x_coordinate,
+                location.y_coordinate,
+                location.z_coordinate,
+            ],
+            ecef_to_ned: ecef_to_ned(latitude, longitude),
+        }
+    }
+
+    /// Latitude of the origin, in radians
+    #[must_use]
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Longitude of the origin, in radians
+    #[must_use]
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// The origin as DIS world location
+    #[must_use]
+    pub fn origin(&self) -> Location {
+        Location::new(self.origin[0], self.origin[1], self.origin[2])
+    }
+
+    /// Converts a DIS world location to a position in the local NED frame
+    #[must_use]
+    pub fn location_to_ned(&self, location: &Location) -> Ned {
+        let relative = [
+            location.x_coordinate - self.origin[0],
+            location.y_coordinate - self.origin[1],
+            location.z_coordinate - self.origin[2],
+        ];
+        let [north, east, down] = self.ecef_to_ned.rotate(relative);
+        Ned::new(north, east, down)
+    }
+
+    /// Converts a position in the local NED frame to a DIS world location
+    #[must_use]
+    pub fn ned_to_location(&self, ned: &Ned) -> Location {
+        let [x, y, z] = self
+            .ecef_to_ned
+            .inverse()
+            .rotate([ned.north, ned.east, ned.down]);
+        Location::new(x + self.origin[0], y + self.origin[1], z + self.origin[2])
+    }
+
+    /// Converts a DIS world location to a position in the local ENU frame
+    #[must_use]
+    pub fn location_to_enu(&self, location: &Location) -> Enu {
+        self.location_to_ned(location).into()
+    }
+
+    /// Converts a position in the local ENU frame to a DIS world location
+    #[must_use]
+    pub fn enu_to_location(&self, enu: &Enu) -> Location {
+        self.ned_to_location(&Ned::from(*enu))
+    }
+
+    /// Converts a DIS world velocity vector to the local NED frame
+    #[must_use]
+    pub fn velocity_to_ned(&self, velocity: &VectorF32) -> Ned {
+        velocity_to_ned(velocity, self.latitude, self.longitude)
+    }
+
+    /// Converts a velocity vector in the local NED frame to a DIS world velocity vector
+    #[must_use]
+    pub fn ned_to_velocity(&self, ned: &Ned) -> VectorF32 {
+        ned_to_velocity(ned, self.latitude, self.longitude)
+    }
+
+    /// Converts a DIS world velocity vector to the local ENU frame
+    #[must_use]
+    pub fn velocity_to_enu(&self, velocity: &VectorF32) -> Enu {
+        self.velocity_to_ned(velocity).into()
+    }
+
+    /// Converts a velocity vector in the local ENU frame to a DIS world velocity vector
+    #[must_use]
+    pub fn enu_to_velocity(&self, enu: &Enu) -> VectorF32 {
+        self.ned_to_velocity(&Ned::from(*enu))
+    }
+
+    /// Converts a DIS world orientation to heading, pitch and roll at the origin of the plane
+    #[must_use]
+    pub fn orientation_to_heading_pitch_roll(&self, orientation: &Orientation) -> HeadingPitchRoll {
+        orientation_to_heading_pitch_roll(orientation, self.latitude, self.longitude)
+    }
+
+    /// Converts heading, pitch and roll at the origin of the plane to a DIS world orientation
+    #[must_use]
+    pub fn heading_pitch_roll_to_orientation(&self, hpr: &HeadingPitchRoll) -> Orientation {
+        heading_pitch_roll_to_orientation(hpr, self.latitude, self.longitude)
+    }
+}
+
+/// Converts a DIS world velocity vector to the local NED frame at the given latitude and longitude (in radians)
+#[must_use]
+pub fn velocity_to_ned(velocity: &VectorF32, latitude: f64, longitude: f64) -> Ned {
+    let [north, east, down] = ecef_to_ned(latitude, longitude).rotate([
+        f64::from(velocity.first_vector_component),
+        f64::from(velocity.second_vector_component),
+        f64::from(velocity.third_vector_component),
+    ]);
+    Ned::new(north, east, down)
+}
+
+/// Converts a velocity vector in the local NED frame at the given latitude and longitude (in radians) to a DIS world velocity vector
+#[must_use]
+pub fn ned_to_velocity(ned: &Ned, latitude: f64, longitude: f64) -> VectorF32 {
+    let [x, y, z] = ecef_to_ned(latitude, longitude)
+        .inverse()
+        .rotate([ned.north, ned.east, ned.down]);
+    VectorF32::new(x as f32, y as f32, z as f32)
+}
+
+/// Converts a DIS world orientation to heading, pitch and roll at the given latitude and longitude (in radians)
+#[must_use]
+pub fn orientation_to_heading_pitch_roll(
+    orientation: &Orientation,
+    latitude: f64,
+    longitude: f64,
+) -> HeadingPitchRoll {
+    let ned_to_body = ecef_to_ned(latitude, longitude)
+        .inverse()
+        .then(&RotationMatrix::from(*orientation));
+    let (heading, pitch, roll) = ned_to_body.euler_angles();
+    HeadingPitchRoll::new(heading, pitch, roll)
+}
+
+/// Converts heading, pitch and roll at the given latitude and longitude (in radians) to a DIS world orientation
+#[must_use]
+pub fn heading_pitch_roll_to_orientation(
+    hpr: &HeadingPitchRoll,
+    latitude: f64,
+    longitude: f64,
+) -> Orientation {
+    ecef_to_ned(latitude, longitude)
+        .then(&RotationMatrix::from_euler_angles(
+            hpr.heading,
+            hpr.pitch,
+            hpr.roll,
+        ))
+        .into()
+}
+
+/// Rotation matrix from ECEF to the local NED frame at the given latitude and longitude
+fn ecef_to_ned(latitude: f64, longitude: f64) -> RotationMatrix {
+    let (sin_lat, cos_lat) = latitude.sin_cos();
+    let (sin_lon, cos_lon) = longitude.sin_cos();
+    RotationMatrix([
+        [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+        [-sin_lon, cos_lon, 0.0],
+        [-cos_lat * cos_lon, -cos_lat * sin_lon, -sin_lat],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    const EPSILON: f64 = 1.0e-6;
+
+    fn assert_close(actual: f64, expected: f64, epsilon: f64) {
+        assert!(
+            (actual - expected).abs() < epsilon,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn plane() -> LocalTangentPlane {
+        LocalTangentPlane::new(52.0f64.to_radians(), 5.0f64.to_radians(), 10.0)
+    }
+
+    #[test]
+    fn enu_ned_conversion() {
+        let enu = Enu::new(1.0, 2.0, 3.0);
+        let ned = Ned::from(enu);
+
+        assert_eq!(ned, Ned::new(2.0, 1.0, -3.0));
+        assert_eq!(Enu::from(ned), enu);
+    }
+
+    #[test]
+    fn origin_is_zero() {
+        let plane = plane();
+        let ned = plane.location_to_ned(&plane.origin());
+
+        assert_close(ned.north, 0.0, EPSILON);
+        assert_close(ned.east, 0.0, EPSILON);
+        assert_close(ned.down, 0.0, EPSILON);
+    }
+
+    #[test]
+    fn up_is_altitude() {
+        let plane = plane();
+        let (x, y, z) = geodetic_lla_to_ecef(52.0f64.to_radians(), 5.0f64.to_radians(), 110.0);
+        let enu = plane.location_to_enu(&Location::new(x, y, z));
+
+        assert_close(enu.east, 0.0, EPSILON);
+        assert_close(enu.north, 0.0, EPSILON);
+        assert_close(enu.up, 100.0, EPSILON);
+    }
+
+    #[test]
+    fn location_round_trip() {
+        let plane = plane();
+        let enu = Enu::new(1_250.5, -3_400.25, 75.0);
+        let location = plane.enu_to_location(&enu);
+        let result = plane.location_to_enu(&location);
+
+        assert_close(result.east, enu.east, EPSILON);
+        assert_close(result.north, enu.north, EPSILON);
+        assert_close(result.up, enu.up, EPSILON);
+
+        let from_location = LocalTangentPlane::from_location(&plane.origin());
+        assert_close(from_location.latitude(), plane.latitude(), 1.0e-9);
+        assert_close(from_location.longitude(), plane.longitude(), 1.0e-9);
+    }
+
+    #[test]
+    fn velocity_at_equator() {
+        // At lat 0, lon 0 north is ECEF +z, east is ECEF +y and down is ECEF -x
+        let ned = velocity_to_ned(&VectorF32::new(-3.0, 2.0, 1.0), 0.0, 0.0);
+
+        assert_close(ned.north, 1.0, EPSILON);
+        assert_close(ned.east, 2.0, EPSILON);
+        assert_close(ned.down, 3.0, EPSILON);
+    }
+
+    #[test]
+    fn velocity_round_trip() {
+        let plane = plane();
+        let velocity = VectorF32::new(120.5, -30.25, 7.0);
+        let enu = plane.velocity_to_enu(&velocity);
+        let result = plane.enu_to_velocity(&enu);
+
+        assert_close(
+            f64::from(result.first_vector_component),
+            f64::from(velocity.first_vector_component),
+            1.0e-4,
+        );
+        assert_close(
+            f64::from(result.second_vector_component),
+            f64::from(velocity.second_vector_component),
+            1.0e-4,
+        );
+        assert_close(
+            f64::from(result.third_vector_component),
+            f64::from(velocity.third_vector_component),
+            1.0e-4,
+        );
+    }
+
+    #[test]
+    fn heading_east_at_equator() {
+        // Body x-axis along ECEF +y, body z-axis along ECEF -x
+        let orientation = heading_pitch_roll_to_orientation(
+            &HeadingPitchRoll::new(FRAC_PI_2, 0.0, 0.0),
+            0.0,
+            0.0,
+        );
+
+        assert_close(f64::from(orientation.psi), FRAC_PI_2, EPSILON);
+        assert_close(f64::from(orientation.theta), 0.0, EPSILON);
+        assert_close(f64::from(orientation.phi), -FRAC_PI_2, EPSILON);
+    }
+
+    #[test]
+    fn orientation_round_trip() {
+        let plane = plane();
+        let hpr = HeadingPitchRoll::new(
+            135.0f64.to_radians(),
+            10.0f64.to_radians(),
+            -25.0f64.to_radians(),
+        );
+        let orientation = plane.heading_pitch_roll_to_orientation(&hpr);
+        let result = plane.orientation_to_heading_pitch_roll(&orientation);
+
+        assert_close(result.heading, hpr.heading, 1.0e-5);
+        assert_close(result.pitch, hpr.pitch, 1.0e-5);
+        assert_close(result.roll, hpr.roll, 1.0e-5);
+    }
+}
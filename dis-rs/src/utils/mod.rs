@@ -1,3 +1,6 @@
+pub mod frames;
+pub mod rotation;
+
 struct EcefToGeoConstants;
 
 #[allow(clippy::excessive_precision)]
@@ -0,0 +1,502 @@
+//! Quaternion and rotation matrix representations of an `Orientation`.
+//!
+//! Both [`Quaternion`] and [`RotationMatrix`] describe the same rotation as the DIS Euler angles
+//! (IEEE 1278.1-2012, 6.2.32 and Annex E): the transformation of vector coordinates from a reference
+//! frame (for an `Orientation`, the world coordinate system) into the entity body coordinate system.
+//! The rotation matrix is the direction cosine matrix `[R]w->b` of Annex E (E.5), and the quaternion
+//! is the World Orientation Quaternion of E.8.2.3.
+//!
+//! Rotations are composed with `then`, where `a.then(&b)` first applies `a` and then `b`.
+use crate::common::entity_state::model::DrWorldOrientationQuaternion;
+use crate::common::model::{Location, Orientation, VectorF32};
+
+/// Dot products above this value are interpolated linearly, as the quaternions are (nearly) identical.
+const SLERP_LINEAR_THRESHOLD: f64 = 0.9995;
+
+/// A quaternion `w + xi + yj + zk`; rotations are represented by unit quaternions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quaternion {
+    /// The identity rotation
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    #[must_use]
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the unit quaternion with the same direction, or the identity when the norm is zero
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        if norm < f64::EPSILON {
+            Self::IDENTITY
+        } else {
+            self.scale(1.0 / norm)
+        }
+    }
+
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The inverse rotation of a unit quaternion (its conjugate)
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// The rotation that first applies `self` and then `next`
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        // With the frame transformation convention, the Hamilton product `self * next` applies `self` first
+        let (a, b) = (self, next);
+        Self::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+
+    /// The angle in radians of the rotation between two unit quaternions, in the range [0, pi]
+    #[must_use]
+    pub fn angle_to(&self, other: &Self) -> f64 {
+        2.0 * self.dot(other).abs().clamp(0.0, 1.0).acos()
+    }
+
+    /// Spherical linear interpolation between two unit quaternions along the shortest path,
+    /// where `t = 0.0` yields `self` and `t = 1.0` yields `other`.
+    #[must_use]
+    pub fn slerp(&self, other: &Self, t: f64) -> Self {
+        let mut dot = self.dot(other);
+        let mut end = *other;
+        if dot < 0.0 {
+            end = end.scale(-1.0);
+            dot = -dot;
+        }
+
+        if dot > SLERP_LINEAR_THRESHOLD {
+            return self.scale(1.0 - t).add(&end.scale(t)).normalized();
+        }
+
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let start_factor = ((1.0 - t) * theta).sin() / sin_theta;
+        let end_factor = (t * theta).sin() / sin_theta;
+        self.scale(start_factor).add(&end.scale(end_factor))
+    }
+
+    /// Transforms a vector from the reference frame into the rotated frame
+    #[must_use]
+    pub fn rotate_vector(&self, vector: &VectorF32) -> VectorF32 {
+        RotationMatrix::from(*self).rotate_vector(vector)
+    }
+
+    /// Transforms a location from the reference frame into the rotated frame, rotating about the origin
+    #[must_use]
+    pub fn rotate_location(&self, location: &Location) -> Location {
+        RotationMatrix::from(*self).rotate_location(location)
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Self::new(
+            self.w * factor,
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+        )
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+impl From<Orientation> for Quaternion {
+    #[allow(clippy::similar_names)]
+    fn from(value: Orientation) -> Self {
+        let (sin_psi, cos_psi) = (f64::from(value.psi) / 2.0).sin_cos();
+        let (sin_theta, cos_theta) = (f64::from(value.theta) / 2.0).sin_cos();
+        let (sin_phi, cos_phi) = (f64::from(value.phi) / 2.0).sin_cos();
+
+        Self::new(
+            cos_phi * cos_theta * cos_psi + sin_phi * sin_theta * sin_psi,
+            sin_phi * cos_theta * cos_psi - cos_phi * sin_theta * sin_psi,
+            cos_phi * sin_theta * cos_psi + sin_phi * cos_theta * sin_psi,
+            cos_phi * cos_theta * sin_psi - sin_phi * sin_theta * cos_psi,
+        )
+    }
+}
+
+impl From<RotationMatrix> for Quaternion {
+    /// Converts a rotation matrix using Shepperd's method, yielding a quaternion with `w >= 0`
+    #[allow(clippy::many_single_char_names)]
+    fn from(value: RotationMatrix) -> Self {
+        let m = value.0;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > 0.0 {
+            let s = (1.0 + trace).sqrt() * 2.0;
+            Self::new(
+                s / 4.0,
+                (m[1][2] - m[2][1]) / s,
+                (m[2][0] - m[0][2]) / s,
+                (m[0][1] - m[1][0]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Self::new(
+                (m[1][2] - m[2][1]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[2][0] + m[0][2]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Self::new(
+                (m[2][0] - m[0][2]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Self::new(
+                (m[0][1] - m[1][0]) / s,
+                (m[2][0] + m[0][2]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+
+        if q.w < 0.0 { q.scale(-1.0) } else { q }
+    }
+}
+
+impl From<&DrWorldOrientationQuaternion> for Quaternion {
+    /// Converts the DR World Orientation Quaternion, where q0 is transmitted as an unsigned 16-bit integer
+    /// scaled to the range [0, 1] (E.8.2.3). The result is not normalized.
+    fn from(value: &DrWorldOrientationQuaternion) -> Self {
+        Self::new(
+            f64::from(value.nil) / f64::from(u16::MAX),
+            f64::from(value.x),
+            f64::from(value.y),
+            f64::from(value.z),
+        )
+    }
+}
+
+impl From<Quaternion> for DrWorldOrientationQuaternion {
+    /// Converts a unit quaternion to the DR World Orientation Quaternion, flipping its sign when needed as q0 must be positive
+    fn from(value: Quaternion) -> Self {
+        let q = if value.w < 0.0 {
+            value.scale(-1.0)
+        } else {
+            value
+        };
+        Self::default()
+            .with_nil((q.w.clamp(0.0, 1.0) * f64::from(u16::MAX)).round() as u16)
+            .with_x(q.x as f32)
+            .with_y(q.y as f32)
+            .with_z(q.z as f32)
+    }
+}
+
+impl From<Quaternion> for Orientation {
+    fn from(value: Quaternion) -> Self {
+        RotationMatrix::from(value).into()
+    }
+}
+
+/// A 3x3 direction cosine matrix, transforming vector coordinates from a reference frame into a rotated frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RotationMatrix(pub [[f64; 3]; 3]);
+
+impl Default for RotationMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl RotationMatrix {
+    /// The identity rotation
+    pub const IDENTITY: Self = Self([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    /// Rotation matrix for the z-y-x Euler angle sequence (yaw, pitch, roll) in radians (E.5)
+    #[must_use]
+    pub fn from_euler_angles(yaw: f64, pitch: f64, roll: f64) -> Self {
+        let (sin_y, cos_y) = yaw.sin_cos();
+        let (sin_p, cos_p) = pitch.sin_cos();
+        let (sin_r, cos_r) = roll.sin_cos();
+
+        Self([
+            [cos_p * cos_y, cos_p * sin_y, -sin_p],
+            [
+                sin_r * sin_p * cos_y - cos_r * sin_y,
+                sin_r * sin_p * sin_y + cos_r * cos_y,
+                sin_r * cos_p,
+            ],
+            [
+                cos_r * sin_p * cos_y + sin_r * sin_y,
+                cos_r * sin_p * sin_y - sin_r * cos_y,
+                cos_r * cos_p,
+            ],
+        ])
+    }
+
+    /// The z-y-x Euler angles `(yaw, pitch, roll)` in radians of the rotation
+    #[must_use]
+    pub fn euler_angles(&self) -> (f64, f64, f64) {
+        let m = &self.0;
+        let pitch = (-m[0][2]).clamp(-1.0, 1.0).asin();
+        let yaw = m[0][1].atan2(m[0][0]);
+        let roll = m[1][2].atan2(m[2][2]);
+        (yaw, pitch, roll)
+    }
+
+    /// The inverse rotation (the transpose of the matrix)
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut result = [[0.0; 3]; 3];
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                result[j][i] = *value;
+            }
+        }
+        Self(result)
+    }
+
+    /// The rotation that first applies `self` and then `next`
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let mut result = [[0.0; 3]; 3];
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| next.0[i][k] * self.0[k][j]).sum();
+            }
+        }
+        Self(result)
+    }
+
+    /// Transforms the coordinates `v` from the reference frame into the rotated frame
+    #[must_use]
+    pub fn rotate(&self, v: [f64; 3]) -> [f64; 3] {
+        let m = &self.0;
+        [
+            m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+            m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+            m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+        ]
+    }
+
+    /// Transforms a vector from the reference frame into the rotated frame
+    #[must_use]
+    pub fn rotate_vector(&self, vector: &VectorF32) -> VectorF32 {
+        let [x, y, z] = self.rotate([
+            f64::from(vector.first_vector_component),
+            f64::from(vector.second_vector_component),
+            f64::from(vector.third_vector_component),
+        ]);
+        VectorF32::new(x as f32, y as f32, z as f32)
+    }
+
+    /// Transforms a location from the reference frame into the rotated frame, rotating about the origin
+    #[must_use]
+    pub fn rotate_location(&self, location: &Location) -> Location {
+        let [x, y, z] = self.rotate([
+            location.x_coordinate,
+            location.y_coordinate,
+            location.z_coordinate,
+        ]);
+        Location::new(x, y, z)
+    }
+}
+
+impl From<Orientation> for RotationMatrix {
+    fn from(value: Orientation) -> Self {
+        Self::from_euler_angles(
+            f64::from(value.psi),
+            f64::from(value.theta),
+            f64::from(value.phi),
+        )
+    }
+}
+
+impl From<Quaternion> for RotationMatrix {
+    #[allow(clippy::many_single_char_names)]
+    fn from(value: Quaternion) -> Self {
+        let Quaternion { w, x, y, z } = value;
+        Self([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y + w * z),
+                2.0 * (x * z - w * y),
+            ],
+            [
+                2.0 * (x * y - w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z + w * x),
+            ],
+            [
+                2.0 * (x * z + w * y),
+                2.0 * (y * z - w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+}
+
+impl From<RotationMatrix> for Orientation {
+    fn from(value: RotationMatrix) -> Self {
+        let (psi, theta, phi) = value.euler_angles();
+        Orientation::new(psi as f32, theta as f32, phi as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    const EPSILON: f64 = 1.0e-6;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < EPSILON,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn assert_matrix_close(actual: &RotationMatrix, expected: &RotationMatrix) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_close(actual.0[i][j], expected.0[i][j]);
+            }
+        }
+    }
+
+    fn orientation() -> Orientation {
+        Orientation::new(1.2, -0.4, 2.5)
+    }
+
+    #[test]
+    fn quaternion_matches_rotation_matrix() {
+        let from_quaternion = RotationMatrix::from(Quaternion::from(orientation()));
+        let from_orientation = RotationMatrix::from(orientation());
+
+        assert_matrix_close(&from_quaternion, &from_orientation);
+    }
+
+    #[test]
+    fn orientation_round_trip() {
+        let result = Orientation::from(Quaternion::from(orientation()));
+
+        assert_close(f64::from(result.psi), f64::from(orientation().psi));
+        assert_close(f64::from(result.theta), f64::from(orientation().theta));
+        assert_close(f64::from(result.phi), f64::from(orientation().phi));
+    }
+
+    #[test]
+    fn rotation_matrix_to_quaternion_round_trip() {
+        for orientation in [
+            orientation(),
+            Orientation::new(3.0, 0.1, -3.0),
+            Orientation::new(0.0, 1.5, 0.0),
+            Orientation::new(-2.9, -0.2, 0.3),
+        ] {
+            let matrix = RotationMatrix::from(orientation);
+            let quaternion = Quaternion::from(matrix);
+
+            assert_close(quaternion.norm(), 1.0);
+            assert!(quaternion.w >= 0.0);
+            assert_matrix_close(&RotationMatrix::from(quaternion), &matrix);
+        }
+    }
+
+    #[test]
+    fn composition_and_inverse() {
+        let a = Orientation::new(0.3, 0.2, -0.1);
+        let b = Orientation::new(-1.0, 0.5, 0.7);
+
+        let matrix = RotationMatrix::from(a).then(&RotationMatrix::from(b));
+        let quaternion = Quaternion::from(a).then(&Quaternion::from(b));
+        assert_matrix_close(&RotationMatrix::from(quaternion), &matrix);
+
+        let identity = RotationMatrix::from(a).then(&RotationMatrix::from(a).inverse());
+        assert_matrix_close(&identity, &RotationMatrix::IDENTITY);
+        let identity = Quaternion::from(b).then(&Quaternion::from(b).inverse());
+        assert_close(identity.angle_to(&Quaternion::IDENTITY), 0.0);
+    }
+
+    #[test]
+    fn rotate_vector_and_location() {
+        // Yaw of 90 degrees: the world x-axis becomes the body -y axis
+        let rotation = RotationMatrix::from(Orientation::new(FRAC_PI_2 as f32, 0.0, 0.0));
+        let vector = rotation.rotate_vector(&VectorF32::new(1.0, 0.0, 0.0));
+        assert_close(f64::from(vector.first_vector_component), 0.0);
+        assert_close(f64::from(vector.second_vector_component), -1.0);
+        assert_close(f64::from(vector.third_vector_component), 0.0);
+
+        let location = Quaternion::from(rotation).rotate_location(&Location::new(0.0, 2.0, 3.0));
+        assert_close(location.x_coordinate, 2.0);
+        assert_close(location.y_coordinate, 0.0);
+        assert_close(location.z_coordinate, 3.0);
+    }
+
+    #[test]
+    fn slerp_interpolates_along_shortest_path() {
+        let start = Quaternion::from(Orientation::new(0.0, 0.0, 0.0));
+        let end = Quaternion::from(Orientation::new(FRAC_PI_2 as f32, 0.0, 0.0));
+
+        let halfway = Orientation::from(start.slerp(&end, 0.5));
+        assert_close(f64::from(halfway.psi), FRAC_PI_4);
+        assert_close(f64::from(halfway.theta), 0.0);
+        assert_close(f64::from(halfway.phi), 0.0);
+
+        // q and -q are the same rotation
+        let flipped = Quaternion::new(-end.w, -end.x, -end.y, -end.z);
+        let halfway = Orientation::from(start.slerp(&flipped, 0.5));
+        assert_close(f64::from(halfway.psi), FRAC_PI_4);
+
+        assert_close(start.slerp(&end, 0.0).angle_to(&start), 0.0);
+        assert_close(start.slerp(&end, 1.0).angle_to(&end), 0.0);
+        assert_close(start.angle_to(&end), FRAC_PI_2);
+    }
+
+    #[test]
+    fn dr_world_orientation_quaternion_round_trip() {
+        let quaternion = Quaternion::from(orientation());
+        let dr = DrWorldOrientationQuaternion::from(quaternion);
+        let result = Quaternion::from(&dr).normalized();
+
+        assert!(result.angle_to(&quaternion) < 1.0e-4);
+    }
+}
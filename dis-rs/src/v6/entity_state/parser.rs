@@ -1,5 +1,5 @@
+use crate::common::parser::IResult;
 use crate::v6::entity_state::model::EntityCapabilities;
-use nom::IResult;
 use nom::bits::bits;
 use nom::bits::complete::take as take_bits;
 use nom::bytes::complete::take as take_bytes;
@@ -1,7 +1,7 @@
 use crate::common::model::EntityType;
+use crate::common::parser::IResult;
 use crate::enumerations::EntityCapabilities;
 use crate::v7::entity_state::entity_capabilities_from_bytes;
-use nom::IResult;
 use nom::number::complete::be_u32;
 
 pub fn entity_capabilities(
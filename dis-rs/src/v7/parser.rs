@@ -1,3 +1,4 @@
+use crate::common::parser::{IResult, context};
 use crate::constants::{
     BIT_2_IN_BYTE, BIT_3_IN_BYTE, BIT_4_IN_BYTE, BIT_7_IN_BYTE, BITS_2_3_IN_BYTE, BITS_5_6_IN_BYTE,
 };
@@ -12,18 +13,19 @@
     build_pdu_status_lvc, build_pdu_status_rai_cei_lvc_tei,
 };
 use crate::v7::model::PduStatus;
-use nom::IResult;
+use nom::Parser;
 use nom::number::complete::be_u8;
 
 pub fn parse_pdu_status(pdu_type: PduType) -> impl Fn(&[u8]) -> IResult<&[u8], (PduStatus, u16)> {
     move |input: &[u8]| {
         let type_u8: u8 = pdu_type.into();
-        let (input, status) = be_u8(input)?;
-        let (input, padding) = be_u8(input)?;
-        Ok((
-            input,
-            (parse_pdu_status_fields(type_u8, status), u16::from(padding)),
-        ))
+        let (input, status) = context("PDU Status", |input| {
+            let (input, status) = be_u8(input)?;
+            Ok((input, parse_pdu_status_fields(type_u8, status)))
+        })
+        .parse(input)?;
+        let (input, padding) = context("Padding", be_u8).parse(input)?;
+        Ok((input, (status, u16::from(padding))))
     }
 }
 
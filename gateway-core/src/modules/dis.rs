@@ -7,6 +7,7 @@
 use crate::node_data_impl;
 use crate::runtime::{Command, Event};
 use bytes::{Bytes, BytesMut};
+use dis_rs::DisStreamDecoder;
 use dis_rs::enumerations::ProtocolVersion;
 use dis_rs::model::Pdu;
 use serde_derive::{Deserialize, Serialize};
@@ -59,6 +60,8 @@ pub struct DisRxNodeSpec {
     name: String,
     exercise_id: Option<u8>,
     allow_dis_versions: Option<Vec<u8>>,
+    stream: Option<bool>,
+    reject_invalid: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -66,6 +69,8 @@ pub struct DisRxNodeData {
     base: BaseNode,
     exercise_id: Option<u8>,
     allow_dis_versions: Vec<ProtocolVersion>,
+    stream: bool,
+    reject_invalid: bool,
     incoming: Option<Receiver<Bytes>>,
     outgoing: Sender<Pdu>,
 }
@@ -75,6 +80,8 @@ pub struct DisRxNodeRunner {
     name: String,
     exercise_id: Option<u8>,
     allow_dis_versions: Vec<ProtocolVersion>,
+    decoder: Option<DisStreamDecoder>,
+    reject_invalid: bool,
     statistics: DisStatistics,
 }
 
@@ -154,6 +161,8 @@ fn new(
             },
             exercise_id,
             allow_dis_versions,
+            stream: node_spec.stream.unwrap_or_default(),
+            reject_invalid: node_spec.reject_invalid.unwrap_or_default(),
             incoming: None,
             outgoing: out_tx,
         })
@@ -188,6 +197,8 @@ fn spawn_with_data(data: Self::Data) -> Result<JoinHandle<()>, CreationError> {
             name: data.base.name,
             exercise_id: data.exercise_id,
             allow_dis_versions: data.allow_dis_versions,
+            decoder: data.stream.then(DisStreamDecoder::new),
+            reject_invalid: data.reject_invalid,
             statistics: DisStatistics::new(data.base.instance_id),
         };
 
@@ -223,22 +234,56 @@ async fn run(
                 }
                 // receiving from the incoming channel, parse into PDU
                 Some(message) = Self::receive_incoming(self.instance_id, &mut incoming) => {
-                    let pdus = match dis_rs::parse(&message) {
-                        Ok(vec) => { vec }
-                        Err(err) => {
-                            Self::emit_event(&event_tx,
-                                Event::RuntimeError(ExecutionError::NodeExecution {
-                                    node_id: self.id(),
-                                    message: format!("DIS parse error: {err}")
-                                }));
-                            vec![]
+                    let pdus = if let Some(decoder) = self.decoder.as_mut() {
+                        let mut pdus = Vec::new();
+                        for result in decoder.decode(&message) {
+                            match result {
+                                Ok(pdu) => { pdus.push(pdu) }
+                                Err(err) => {
+                                    Self::emit_event(&event_tx,
+                                        Event::RuntimeError(ExecutionError::NodeExecution {
+                                            node_id: self.instance_id,
+                                            message: format!("DIS parse error: {err}")
+                                        }));
+                                }
+                            }
+                        }
+                        pdus
+                    } else {
+                        let mut pdus = Vec::new();
+                        for parsed in dis_rs::parse_each(&message) {
+                            match parsed.result {
+                                Ok(pdu) => { pdus.push(pdu) }
+                                Err(err) => {
+                                    Self::emit_event(&event_tx,
+                                        Event::RuntimeError(ExecutionError::NodeExecution {
+                                            node_id: self.instance_id,
+                                            message: format!("DIS parse error at byte {}: {err}", parsed.offset)
+                                        }));
+                                }
+                            }
                         }
+                        pdus
                     };
                     self.statistics.received_incoming();
 
                     pdus.into_iter()
                         .filter(|pdu| self.allow_dis_versions.contains(&pdu.header.protocol_version))
                         .filter(|pdu| self.exercise_id.is_none() || self.exercise_id.is_some_and(|exercise_id| pdu.header.exercise_id == exercise_id ))
+                        .filter(|pdu| {
+                            if !self.reject_invalid {
+                                return true;
+                            }
+                            let findings = pdu.validate();
+                            for finding in &findings {
+                                Self::emit_event(&event_tx,
+                                    Event::RuntimeError(ExecutionError::NodeExecution {
+                                        node_id: self.instance_id,
+                                        message: format!("Rejected invalid {:?} PDU: {finding}", pdu.header.pdu_type)
+                                    }));
+                            }
+                            findings.is_empty()
+                        })
                         .for_each(|pdu| {
                             let _send_result = outgoing.send(pdu.clone());
                             self.statistics.sent_outgoing();